@@ -1,3 +1,4 @@
+pub use custody::CustodyCmd;
 pub use debug::DebugCmd;
 pub use init::InitCmd;
 pub use migrate::MigrateCmd;
@@ -9,6 +10,7 @@ pub use view::ViewCmd;
 
 use self::tx::TxCmdWithOptions;
 
+mod custody;
 mod debug;
 mod init;
 mod migrate;
@@ -57,6 +59,9 @@ pub enum Command {
     /// Follow the threshold signing protocol.
     #[clap(subcommand, display_order = 500)]
     Threshold(ThresholdCmd),
+    /// Inspect the custody backend's records.
+    #[clap(subcommand, display_order = 550)]
+    Custody(CustodyCmd),
     /// Migrate your balance to another wallet.
     #[clap(subcommand, display_order = 600)]
     Migrate(MigrateCmd),
@@ -79,6 +84,7 @@ impl Command {
             Command::Query(cmd) => cmd.offline(),
             Command::Debug(cmd) => cmd.offline(),
             Command::Threshold(cmd) => cmd.offline(),
+            Command::Custody(cmd) => cmd.offline(),
             Command::Migrate(_) => false,
         }
     }
@@ -0,0 +1,278 @@
+use std::{io::Write, path::PathBuf};
+
+use anyhow::{Context, Result};
+use camino::Utf8Path;
+use penumbra_sdk_custody::encrypted::{Config as EncryptedConfig, KdfParams, Secret, SlotKind};
+use penumbra_sdk_custody::threshold::Terminal as _;
+use rand_core::{OsRng, RngCore};
+
+use crate::{
+    config::{CustodyConfig, GovernanceCustodyConfig, PcliConfig},
+    terminal::ActualTerminal,
+};
+
+#[derive(Debug, clap::Subcommand)]
+pub enum CustodyCmd {
+    /// Inspect the custody audit log.
+    #[clap(subcommand)]
+    Audit(AuditCmd),
+    /// Manage the unlock methods of an encrypted custody backend.
+    #[clap(subcommand)]
+    Keystore(KeystoreCmd),
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum AuditCmd {
+    /// Check that the hash chain of the custody audit log is intact.
+    Verify {
+        /// The audit log to verify. Defaults to the `custody_audit_log` set in the config.
+        #[clap(long)]
+        path: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, clap::Subcommand)]
+pub enum KeystoreCmd {
+    /// List the key slots that can unlock the encrypted config.
+    List {
+        /// Operate on the governance custody backend, rather than the spend custody backend.
+        #[clap(long)]
+        governance: bool,
+    },
+    /// Add another password that can unlock the encrypted config.
+    AddPassword {
+        /// Operate on the governance custody backend, rather than the spend custody backend.
+        #[clap(long)]
+        governance: bool,
+    },
+    /// Add a keyfile that can unlock the encrypted config.
+    ///
+    /// If the file does not exist, a new random keyfile is created at that path.
+    AddKeyfile {
+        /// The path of the keyfile.
+        path: PathBuf,
+        /// Unlock with this keyfile from now on, rather than prompting for a password.
+        #[clap(long)]
+        unlock_with: bool,
+        /// Operate on the governance custody backend, rather than the spend custody backend.
+        #[clap(long)]
+        governance: bool,
+    },
+    /// Change the password of one of the key slots.
+    ChangePassword {
+        /// Operate on the governance custody backend, rather than the spend custody backend.
+        #[clap(long)]
+        governance: bool,
+    },
+    /// Remove a key slot, by the index shown in `list`.
+    RemoveSlot {
+        /// The index of the slot to remove.
+        index: usize,
+        /// Operate on the governance custody backend, rather than the spend custody backend.
+        #[clap(long)]
+        governance: bool,
+    },
+    /// Set the key derivation cost.
+    ///
+    /// Key slots with a lower cost are upgraded the next time they are used to unlock the config.
+    SetKdf {
+        /// Memory cost, in KiB.
+        #[clap(long)]
+        memory_kib: u32,
+        /// Number of iterations.
+        #[clap(long, default_value = "1")]
+        iterations: u32,
+        /// Degree of parallelism.
+        #[clap(long, default_value = "4")]
+        parallelism: u32,
+        /// Operate on the governance custody backend, rather than the spend custody backend.
+        #[clap(long)]
+        governance: bool,
+    },
+}
+
+impl CustodyCmd {
+    pub fn offline(&self) -> bool {
+        match self {
+            CustodyCmd::Audit(_) => true,
+            CustodyCmd::Keystore(_) => true,
+        }
+    }
+
+    /// Custody commands operate on the config file directly, so they take the home
+    /// directory, rather than an `App` with custody services already unlocked.
+    pub async fn exec(&self, home: &Utf8Path) -> Result<()> {
+        let config_path = home.join(crate::CONFIG_FILE_NAME);
+        let mut config = PcliConfig::load(&config_path)?;
+
+        match self {
+            CustodyCmd::Audit(AuditCmd::Verify { path }) => {
+                let path = path
+                    .as_ref()
+                    .or(config.custody_audit_log.as_ref())
+                    .context("no audit log path given, and no custody_audit_log set in config")?;
+                let entries = penumbra_sdk_custody::audit::verify(path)?;
+                println!(
+                    "Audit log {} is intact: {} entries verified.",
+                    path.display(),
+                    entries
+                );
+            }
+            CustodyCmd::Keystore(cmd) => {
+                cmd.exec(&mut config).await?;
+                if cmd.modifies_config() {
+                    config.save(&config_path)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl KeystoreCmd {
+    fn governance(&self) -> bool {
+        match self {
+            KeystoreCmd::List { governance }
+            | KeystoreCmd::AddPassword { governance }
+            | KeystoreCmd::AddKeyfile { governance, .. }
+            | KeystoreCmd::ChangePassword { governance }
+            | KeystoreCmd::RemoveSlot { governance, .. }
+            | KeystoreCmd::SetKdf { governance, .. } => *governance,
+        }
+    }
+
+    /// Whether the command changes the encrypted config, and so needs it saved afterwards.
+    fn modifies_config(&self) -> bool {
+        !matches!(self, KeystoreCmd::List { .. })
+    }
+
+    async fn exec(&self, config: &mut PcliConfig) -> Result<()> {
+        let encrypted = if self.governance() {
+            match &mut config.governance_custody {
+                Some(GovernanceCustodyConfig::Encrypted { config, .. }) => config,
+                _ => anyhow::bail!("governance custody backend is not encrypted"),
+            }
+        } else {
+            match &mut config.custody {
+                CustodyConfig::Encrypted(config) => config,
+                _ => anyhow::bail!("custody backend is not encrypted"),
+            }
+        };
+
+        match self {
+            KeystoreCmd::List { .. } => {
+                if encrypted.slots().is_empty() {
+                    println!("This config uses the original single-password format; it will be converted to key slots on its next change.");
+                }
+                for (index, slot) in encrypted.slots().iter().enumerate() {
+                    let kind = match slot.kind {
+                        SlotKind::Password => "password",
+                        SlotKind::Keyfile => "keyfile",
+                    };
+                    println!(
+                        "{index}: {kind} (memory {} KiB, {} iterations, parallelism {})",
+                        slot.kdf.m_cost, slot.kdf.t_cost, slot.kdf.p_cost
+                    );
+                }
+                if let Some(keyfile) = &encrypted.keyfile {
+                    println!("Unlocking with keyfile {}", keyfile.display());
+                }
+            }
+            KeystoreCmd::AddPassword { .. } => {
+                let existing = Existing::read(encrypted).await?;
+                println!("Choose the new password.");
+                let new = ActualTerminal::get_confirmed_password().await?;
+                encrypted.add_slot(existing.secret(), Secret::Password(&new))?;
+                println!("Added a password slot.");
+            }
+            KeystoreCmd::AddKeyfile {
+                path, unlock_with, ..
+            } => {
+                let existing = Existing::read(encrypted).await?;
+                if !path.exists() {
+                    let mut contents = [0u8; 64];
+                    OsRng.fill_bytes(&mut contents);
+                    // The keyfile unlocks the wallet, so only its owner may read it.
+                    let mut options = std::fs::OpenOptions::new();
+                    options.write(true).create_new(true);
+                    #[cfg(unix)]
+                    {
+                        use std::os::unix::fs::OpenOptionsExt;
+                        options.mode(0o600);
+                    }
+                    options
+                        .open(path)
+                        .and_then(|mut file| file.write_all(&contents))
+                        .with_context(|| format!("failed to write keyfile {}", path.display()))?;
+                    println!("Created a new keyfile at {}; keep it safe.", path.display());
+                }
+                let contents = std::fs::read(path)
+                    .with_context(|| format!("failed to read keyfile {}", path.display()))?;
+                encrypted.add_slot(existing.secret(), Secret::Keyfile(&contents))?;
+                if *unlock_with {
+                    encrypted.keyfile = Some(path.clone());
+                }
+                println!("Added a keyfile slot.");
+            }
+            KeystoreCmd::ChangePassword { .. } => {
+                println!("Enter the current password.");
+                let old = ActualTerminal::default().get_password().await?;
+                println!("Choose the new password.");
+                let new = ActualTerminal::get_confirmed_password().await?;
+                encrypted.change_password(&old, &new)?;
+                println!("Changed the password.");
+            }
+            KeystoreCmd::RemoveSlot { index, .. } => {
+                let existing = Existing::read(encrypted).await?;
+                encrypted.remove_slot(existing.secret(), *index)?;
+                println!("Removed key slot {index}.");
+            }
+            KeystoreCmd::SetKdf {
+                memory_kib,
+                iterations,
+                parallelism,
+                ..
+            } => {
+                let kdf = KdfParams {
+                    m_cost: *memory_kib,
+                    t_cost: *iterations,
+                    p_cost: *parallelism,
+                };
+                kdf.validate()?;
+                encrypted.kdf = kdf;
+                println!("Updated the key derivation cost; weaker key slots will be upgraded when next unlocked.");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Secret material that unlocks an encrypted config: the configured keyfile if
+/// there is one, and otherwise the current password.
+enum Existing {
+    Keyfile(Vec<u8>),
+    Password(String),
+}
+
+impl Existing {
+    async fn read(encrypted: &EncryptedConfig) -> Result<Self> {
+        match encrypted.read_keyfile()? {
+            Some(contents) => Ok(Existing::Keyfile(contents)),
+            None => {
+                println!("Enter the current password.");
+                Ok(Existing::Password(
+                    ActualTerminal::default().get_password().await?,
+                ))
+            }
+        }
+    }
+
+    fn secret(&self) -> Secret<'_> {
+        match self {
+            Existing::Keyfile(contents) => Secret::Keyfile(contents),
+            Existing::Password(password) => Secret::Password(password),
+        }
+    }
+}
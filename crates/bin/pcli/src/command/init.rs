@@ -9,6 +9,10 @@ use penumbra_sdk_custody::threshold;
 #[cfg(feature = "ledger")]
 use penumbra_sdk_custody_ledger_usb as ledger;
 use penumbra_sdk_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+use penumbra_sdk_view::{
+    discovery::{self, DiscoveredAccount},
+    Storage,
+};
 use rand_core::OsRng;
 use termion::screen::IntoAlternateScreen;
 use url::Url;
@@ -85,6 +89,11 @@ pub enum SoftKmsInitCmd {
         /// If set, will write the seed phrase to stdout.
         #[clap(long, action)]
         stdout: bool,
+        /// The BIP44 account to derive the spend key from.
+        ///
+        /// A pcli home tracks exactly one BIP44 account; use a separate home for each account.
+        #[clap(long, default_value = "0")]
+        bip44_account: u32,
     },
     /// Import a spend key from an existing seed phrase.
     #[clap(display_order = 200)]
@@ -94,8 +103,25 @@ pub enum SoftKmsInitCmd {
         /// Use this ONLY if:
         /// - you generated your wallet prior to Testnet 62.
         /// - you need to replicate legacy derivation for some reason.
-        #[clap(long, action)]
+        #[clap(long, action, conflicts_with = "bip44-account")]
         legacy_raw_bip39_derivation: bool,
+        /// The BIP44 account to derive the spend key from.
+        ///
+        /// A pcli home tracks exactly one BIP44 account; use a separate home for each account.
+        #[clap(long, default_value = "0")]
+        bip44_account: u32,
+        /// Scan the chain for other BIP44 accounts of this seed phrase that have history.
+        ///
+        /// Accounts are scanned in order until `--gap-limit` consecutive accounts have no
+        /// history, which takes one full sync per account scanned. The accounts found are only
+        /// reported and recorded in the view database, for `pcli view accounts`: this home still
+        /// tracks just `--bip44-account`, and each other account needs its own home.
+        #[clap(long, action, conflicts_with = "legacy-raw-bip39-derivation")]
+        discover_accounts: bool,
+        /// With `--discover-accounts`, the number of consecutive unused accounts after which
+        /// to stop scanning.
+        #[clap(long, default_value_t = discovery::DEFAULT_GAP_LIMIT)]
+        gap_limit: u32,
     },
 }
 
@@ -119,9 +145,12 @@ fn prompt_for_password(msg: &str) -> Result<String> {
 }
 
 impl SoftKmsInitCmd {
-    fn spend_key(&self, init_type: InitType) -> Result<SpendKey> {
+    fn spend_key(&self, init_type: InitType) -> Result<(SpendKey, SeedPhrase)> {
         Ok(match self {
-            SoftKmsInitCmd::Generate { stdout } => {
+            SoftKmsInitCmd::Generate {
+                stdout,
+                bip44_account,
+            } => {
                 let seed_phrase = SeedPhrase::generate(OsRng);
                 let seed_msg = format!(
                     "YOUR PRIVATE SEED PHRASE ({init_type:?}):\n\n\
@@ -143,25 +172,79 @@ impl SoftKmsInitCmd {
                     let _ = stdin().bytes().next();
                 }
 
-                let path = Bip44Path::new(0);
-                SpendKey::from_seed_phrase_bip44(seed_phrase, &path)
+                let path = Bip44Path::new(*bip44_account);
+                (
+                    SpendKey::from_seed_phrase_bip44(seed_phrase.clone(), &path),
+                    seed_phrase,
+                )
             }
             SoftKmsInitCmd::ImportPhrase {
                 legacy_raw_bip39_derivation,
+                bip44_account,
+                ..
             } => {
                 let seed_phrase = prompt_for_password("Enter seed phrase: ")?;
                 let seed_phrase = SeedPhrase::from_str(&seed_phrase)
                     .context("failed to parse input as seed phrase")?;
 
-                if *legacy_raw_bip39_derivation {
-                    SpendKey::from_seed_phrase_bip39(seed_phrase, 0)
+                let spend_key = if *legacy_raw_bip39_derivation {
+                    SpendKey::from_seed_phrase_bip39(seed_phrase.clone(), 0)
                 } else {
-                    let path = Bip44Path::new(0);
-                    SpendKey::from_seed_phrase_bip44(seed_phrase, &path)
-                }
+                    let path = Bip44Path::new(*bip44_account);
+                    SpendKey::from_seed_phrase_bip44(seed_phrase.clone(), &path)
+                };
+                (spend_key, seed_phrase)
             }
         })
     }
+
+    /// The gap limit for account discovery and the BIP44 account being initialized, if
+    /// discovery was requested.
+    fn discovery_options(&self) -> Option<(u32, u32)> {
+        match self {
+            SoftKmsInitCmd::ImportPhrase {
+                discover_accounts: true,
+                gap_limit,
+                bip44_account,
+                ..
+            } => Some((*gap_limit, *bip44_account)),
+            _ => None,
+        }
+    }
+}
+
+/// Scan for the BIP44 accounts of `seed_phrase` that have history, printing what was found.
+///
+/// A pcli home tracks the single BIP44 account `tracked`, so this also explains how to track
+/// any others that were found.
+async fn discover_accounts(
+    seed_phrase: &SeedPhrase,
+    grpc_url: Url,
+    gap_limit: u32,
+    tracked: u32,
+) -> Result<Vec<DiscoveredAccount>> {
+    println!("Scanning for accounts with history, stopping after {gap_limit} unused accounts...");
+    let accounts = discovery::discover_accounts(grpc_url, gap_limit, |bip44_account| {
+        SpendKey::from_seed_phrase_bip44(seed_phrase.clone(), &Bip44Path::new(bip44_account))
+            .full_viewing_key()
+            .clone()
+    })
+    .await?;
+
+    if accounts.is_empty() {
+        println!("No accounts with history were found.");
+    }
+    for account in &accounts {
+        println!("Found history in {account}");
+    }
+    for account in accounts.iter().filter(|a| a.bip44_account != tracked) {
+        println!(
+            "To track BIP44 account {0}, initialize a separate pcli home with `--bip44-account {0}`.",
+            account.bip44_account
+        );
+    }
+
+    Ok(accounts)
 }
 
 #[derive(Debug, Clone, clap::Subcommand)]
@@ -240,6 +323,7 @@ fn exec_deal(
                 view_url: None,
                 disable_warning: false,
                 governance_custody: None,
+                custody_audit_log: None,
             }
         } else {
             let mut pcli_config = PcliConfig::load(config_path.join(crate::CONFIG_FILE_NAME))?;
@@ -308,9 +392,20 @@ impl InitCmd {
                 .is_some_and(|x| x.governance_custody.is_some()),
         };
 
+        let mut discovered_accounts = None;
         let (full_viewing_key, custody) = match (&init_type, &subcmd, relevant_config_exists) {
             (_, InitSubCmd::SoftKms(cmd), false) => {
-                let spend_key = cmd.spend_key(init_type)?;
+                let (spend_key, seed_phrase) = cmd.spend_key(init_type)?;
+                if let Some((gap_limit, tracked)) = cmd.discovery_options() {
+                    anyhow::ensure!(
+                        matches!(init_type, InitType::SpendKey),
+                        "account discovery is only available when initializing a spend key"
+                    );
+                    discovered_accounts = Some(
+                        discover_accounts(&seed_phrase, self.grpc_url.clone(), gap_limit, tracked)
+                            .await?,
+                    );
+                }
                 (
                     spend_key.full_viewing_key().clone(),
                     if self.encrypted {
@@ -435,6 +530,7 @@ impl InitCmd {
                 view_url: None,
                 disable_warning: false,
                 governance_custody: None,
+                custody_audit_log: None,
             }
         } else {
             let config_path = home_dir.join(crate::CONFIG_FILE_NAME);
@@ -456,6 +552,16 @@ impl InitCmd {
         println!("Writing generated config to {}", config_path);
         config.save(config_path)?;
 
+        if let Some(accounts) = discovered_accounts {
+            let storage = Storage::load_or_initialize(
+                Some(home_dir.join(crate::VIEW_FILE_NAME)),
+                &config.full_viewing_key,
+                self.grpc_url.clone(),
+            )
+            .await?;
+            storage.record_discovered_accounts(accounts).await?;
+        }
+
         if let InitType::GovernanceKey = init_type {
             println!("\nIf you defined a validator on-chain before initializing this separate governance subkey, you need to update its definition to use your new public governance key:\n");
             println!("  governance_key = \"{}\"", config.governance_key());
@@ -3,16 +3,21 @@ use crate::App;
 use clap::Subcommand;
 use comfy_table::{presets, Table};
 use comfy_table::{Cell, ContentArrangement};
-use penumbra_sdk_asset::asset::Cache;
+use futures::TryStreamExt;
+use pbjson_types::Any;
+use penumbra_sdk_asset::asset::{self, Cache};
 use penumbra_sdk_asset::Value;
 use penumbra_sdk_auction::auction::dutch::DutchAuction;
+use penumbra_sdk_auction::auction::twap::TwapAuction;
 use penumbra_sdk_auction::auction::AuctionId;
 use penumbra_sdk_dex::lp::position::{self, Position};
 use penumbra_sdk_num::fixpoint::U128x128;
 use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::core::component::auction::v1 as pb_auction;
 use penumbra_sdk_proto::core::component::auction::v1::query_service_client::QueryServiceClient as AuctionQueryServiceClient;
-use penumbra_sdk_proto::core::component::auction::v1::AuctionStateByIdRequest;
+use penumbra_sdk_proto::core::component::auction::v1::{
+    ActiveAuctionsRequest, ActiveAuctionsResponse, AuctionStateByIdRequest,
+};
 use penumbra_sdk_proto::core::component::dex::v1::query_service_client::QueryServiceClient as DexQueryServiceClient;
 use penumbra_sdk_proto::core::component::dex::v1::LiquidityPositionByIdRequest;
 use penumbra_sdk_proto::DomainType;
@@ -26,56 +31,117 @@ pub enum AuctionCmd {
         #[clap(index = 1)]
         auction_id: AuctionId,
     },
+    /// Commands related to TWAP auctions
+    Twap {
+        #[clap(index = 1)]
+        auction_id: AuctionId,
+    },
+    /// List the currently active auctions.
+    List {
+        /// Only list auctions selling this asset.
+        #[clap(long)]
+        input: Option<String>,
+        /// Only list auctions buying this asset.
+        #[clap(long)]
+        output: Option<String>,
+    },
 }
 
 impl AuctionCmd {
     pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
         match self {
             AuctionCmd::Dutch { auction_id } => {
-                let auction_id = auction_id.clone();
-                let mut auction_client = AuctionQueryServiceClient::new(app.pd_channel().await?);
-                let rsp = auction_client
-                    .auction_state_by_id(AuctionStateByIdRequest {
-                        id: Some(auction_id.into()),
-                    })
-                    .await?
-                    .into_inner();
-
-                let pb_auction_state = rsp
-                    .auction
-                    .ok_or_else(|| anyhow::anyhow!("auction state is missing!"))?;
+                let pb_auction_state = fetch_auction_state(app, *auction_id).await?;
 
                 if pb_auction_state.type_url == pb_auction::DutchAuction::type_url() {
                     let dutch_auction = DutchAuction::decode(pb_auction_state.value)?;
-                    let position = if let Some(position_id) = dutch_auction.state.current_position {
-                        let mut dex_client = DexQueryServiceClient::new(app.pd_channel().await?);
-                        let position: Position = dex_client
-                            .liquidity_position_by_id(LiquidityPositionByIdRequest {
-                                position_id: Some(position_id.into()),
-                            })
-                            .await?
-                            .into_inner()
-                            .data
-                            .expect("a position should exist")
-                            .try_into()
-                            .expect("no decoding error");
-                        Some(position)
-                    } else {
-                        None
-                    };
-
+                    let position =
+                        fetch_position(app, dutch_auction.state.current_position).await?;
                     let asset_cache = app.view().assets().await?;
 
                     render_dutch_auction(&asset_cache, &dutch_auction, None, position).await?;
                 } else {
-                    unimplemented!("only supporting dutch auctions at the moment, come back later");
+                    anyhow::bail!("auction {auction_id} is not a dutch auction");
+                }
+            }
+            AuctionCmd::Twap { auction_id } => {
+                let pb_auction_state = fetch_auction_state(app, *auction_id).await?;
+
+                if pb_auction_state.type_url == pb_auction::TwapAuction::type_url() {
+                    let twap_auction = TwapAuction::decode(pb_auction_state.value)?;
+                    let position = fetch_position(app, twap_auction.state.current_position).await?;
+                    let asset_cache = app.view().assets().await?;
+
+                    render_twap_auction(&asset_cache, &twap_auction, None, position).await?;
+                } else {
+                    anyhow::bail!("auction {auction_id} is not a twap auction");
+                }
+            }
+            AuctionCmd::List { input, output } => {
+                let input_id = input
+                    .as_ref()
+                    .map(|denom| asset::REGISTRY.parse_unit(denom).id());
+                let output_id = output
+                    .as_ref()
+                    .map(|denom| asset::REGISTRY.parse_unit(denom).id());
+
+                let mut auction_client = AuctionQueryServiceClient::new(app.pd_channel().await?);
+                let auctions: Vec<_> = auction_client
+                    .active_auctions(ActiveAuctionsRequest {
+                        input_id: input_id.map(Into::into),
+                        output_id: output_id.map(Into::into),
+                    })
+                    .await?
+                    .into_inner()
+                    .try_collect()
+                    .await?;
+
+                if auctions.is_empty() {
+                    println!("no active auctions found");
+                    return Ok(());
                 }
+
+                let asset_cache = app.view().assets().await?;
+                render_active_auctions(&asset_cache, auctions)?;
             }
         }
         Ok(())
     }
 }
 
+async fn fetch_auction_state(app: &mut App, auction_id: AuctionId) -> anyhow::Result<Any> {
+    let mut auction_client = AuctionQueryServiceClient::new(app.pd_channel().await?);
+    auction_client
+        .auction_state_by_id(AuctionStateByIdRequest {
+            id: Some(auction_id.into()),
+        })
+        .await?
+        .into_inner()
+        .auction
+        .ok_or_else(|| anyhow::anyhow!("auction state is missing!"))
+}
+
+async fn fetch_position(
+    app: &mut App,
+    position_id: Option<position::Id>,
+) -> anyhow::Result<Option<Position>> {
+    let Some(position_id) = position_id else {
+        return Ok(None);
+    };
+    let mut dex_client = DexQueryServiceClient::new(app.pd_channel().await?);
+    let position: Position = dex_client
+        .liquidity_position_by_id(LiquidityPositionByIdRequest {
+            position_id: Some(position_id.into()),
+        })
+        .await?
+        .into_inner()
+        .data
+        .expect("a position should exist")
+        .try_into()
+        .expect("no decoding error");
+    Ok(Some(position))
+}
+
 pub async fn render_dutch_auction(
     asset_cache: &Cache,
     dutch_auction: &DutchAuction,
@@ -164,6 +230,164 @@ pub async fn render_dutch_auction(
     Ok(())
 }
 
+pub async fn render_twap_auction(
+    asset_cache: &Cache,
+    twap_auction: &TwapAuction,
+    local_view: Option<u64>,
+    position: Option<Position>,
+) -> anyhow::Result<()> {
+    let auction_id = twap_auction.description.id();
+    println!("twap auction with id {auction_id:?}:");
+
+    let initial_input = twap_auction.description.input;
+    let input_id = initial_input.asset_id;
+    let output_id = twap_auction.description.output_id;
+
+    let floor_price = (U128x128::from(twap_auction.description.min_output)
+        / U128x128::from(initial_input.amount))
+    .expect("the input is always nonzero");
+
+    let (position_input_reserve, position_output_reserve) = position.as_ref().map_or_else(
+        || (Amount::zero(), Amount::zero()),
+        |lp| {
+            (
+                lp.reserves_for(input_id)
+                    .expect("lp doesn't have reserves for input asset"),
+                lp.reserves_for(output_id)
+                    .expect("lp doesn't have reserves for output asset"),
+            )
+        },
+    );
+
+    let auction_input_reserves = Value {
+        amount: position_input_reserve + twap_auction.state.input_reserves,
+        asset_id: input_id,
+    };
+    let auction_output_reserves = Value {
+        amount: position_output_reserve + twap_auction.state.output_reserves,
+        asset_id: output_id,
+    };
+
+    let start_height = twap_auction.description.start_height;
+    let end_height = twap_auction.description.end_height;
+
+    let mut auction_table = Table::new();
+    auction_table.load_preset(presets::UTF8_FULL);
+    auction_table
+        .set_header(vec![
+            "Auction id",
+            "State",
+            "Height range",
+            "# tranches",
+            "Floor price",
+            "Input",
+            "Balance",
+            "Has lp?",
+        ])
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth)
+        .add_row(vec![
+            Cell::new(truncate_auction_id(&auction_id)).set_delimiter('.'),
+            Cell::new(render_sequence(twap_auction.state.sequence, local_view)),
+            Cell::new(format!("{start_height} -> {end_height}")),
+            Cell::new(twap_auction.description.step_count.to_string()),
+            Cell::new(format!("{}", floor_price)),
+            Cell::new(initial_input.format(asset_cache)),
+            Cell::new(format!(
+                "({}, {})",
+                &auction_input_reserves.format(asset_cache),
+                &auction_output_reserves.format(asset_cache)
+            )),
+            Cell::new(format!(
+                "{}",
+                render_position_id(&twap_auction.state.current_position)
+            ))
+            .set_alignment(comfy_table::CellAlignment::Center),
+        ]);
+
+    if let Some(lp) = position {
+        auction_table.add_row(vec![Cell::new(format!(
+            "{}",
+            render_positions(asset_cache, &[lp])
+        ))]);
+    }
+
+    println!("{auction_table}");
+    Ok(())
+}
+
+fn render_active_auctions(
+    asset_cache: &Cache,
+    auctions: Vec<ActiveAuctionsResponse>,
+) -> anyhow::Result<()> {
+    let mut table = Table::new();
+    table.load_preset(presets::UTF8_FULL);
+    table
+        .set_header(vec![
+            "Auction id",
+            "Type",
+            "Input",
+            "Output",
+            "Height range",
+            "Has lp?",
+        ])
+        .set_content_arrangement(ContentArrangement::DynamicFullWidth);
+
+    for auction in auctions {
+        let auction_id: AuctionId = auction
+            .id
+            .ok_or_else(|| anyhow::anyhow!("missing auction id"))?
+            .try_into()?;
+        let pb_auction_state = auction
+            .auction
+            .ok_or_else(|| anyhow::anyhow!("auction state is missing!"))?;
+
+        let (kind, input, output_id, start_height, end_height, current_position) =
+            if pb_auction_state.type_url == pb_auction::DutchAuction::type_url() {
+                let dutch_auction = DutchAuction::decode(pb_auction_state.value)?;
+                let description = dutch_auction.description;
+                (
+                    "dutch",
+                    description.input,
+                    description.output_id,
+                    description.start_height,
+                    description.end_height,
+                    dutch_auction.state.current_position,
+                )
+            } else if pb_auction_state.type_url == pb_auction::TwapAuction::type_url() {
+                let twap_auction = TwapAuction::decode(pb_auction_state.value)?;
+                let description = twap_auction.description;
+                (
+                    "twap",
+                    description.input,
+                    description.output_id,
+                    description.start_height,
+                    description.end_height,
+                    twap_auction.state.current_position,
+                )
+            } else {
+                tracing::warn!(type_url = %pb_auction_state.type_url, "skipping unknown auction type");
+                continue;
+            };
+
+        let output = asset_cache
+            .get(&output_id)
+            .map_or_else(|| output_id.to_string(), |m| m.default_unit().to_string());
+
+        table.add_row(vec![
+            Cell::new(truncate_auction_id(&auction_id)).set_delimiter('.'),
+            Cell::new(kind),
+            Cell::new(input.format(asset_cache)),
+            Cell::new(output),
+            Cell::new(format!("{start_height} -> {end_height}")),
+            Cell::new(render_position_id(&current_position))
+                .set_alignment(comfy_table::CellAlignment::Center),
+        ]);
+    }
+
+    println!("{table}");
+    Ok(())
+}
+
 fn render_sequence(state: u64, local_seq: Option<u64>) -> String {
     let main = if state == 0 {
         format!("Opened")
@@ -6,14 +6,16 @@ use std::pin::Pin;
 use penumbra_sdk_asset::{asset, asset::Metadata, Value};
 use penumbra_sdk_dex::{
     lp::position::{self, Position},
-    BatchSwapOutputData, DirectedTradingPair, SwapExecution, TradingPair,
+    BatchSwapOutputData, DirectedTradingPair, SimulatedPositionExecution, SwapExecution,
+    TradingPair,
 };
 use penumbra_sdk_proto::core::component::{
     dex::v1::{
         query_service_client::QueryServiceClient as DexQueryServiceClient,
         simulation_service_client::SimulationServiceClient, ArbExecutionRequest,
         BatchSwapOutputDataRequest, LiquidityPositionByIdRequest, LiquidityPositionsByPriceRequest,
-        LiquidityPositionsRequest, SimulateTradeRequest, SwapExecutionRequest,
+        LiquidityPositionsRequest, SimulateTradeRequest, SimulateTradeResponse,
+        SwapExecutionRequest,
     },
     shielded_pool::v1::{
         query_service_client::QueryServiceClient as ShieldedPoolQueryServiceClient,
@@ -148,17 +150,17 @@ impl DexCmd {
             .context("cannot parse batch swap output data")
     }
 
-    pub async fn get_simulated_execution(
+    pub async fn get_trade_simulation(
         &self,
         app: &mut App,
         input: Value,
         output: asset::Id,
-    ) -> Result<SwapExecution> {
+    ) -> Result<SimulateTradeResponse> {
         use penumbra_sdk_proto::core::component::dex::v1::simulate_trade_request::{
             routing::Setting, Routing,
         };
         let mut client = SimulationServiceClient::new(app.pd_channel().await?);
-        client
+        Ok(client
             .simulate_trade(SimulateTradeRequest {
                 input: Some(input.into()),
                 output: Some(output.into()),
@@ -167,11 +169,7 @@ impl DexCmd {
                 }),
             })
             .await?
-            .into_inner()
-            .output
-            .ok_or_else(|| anyhow::anyhow!("proto response missing swap execution"))?
-            .try_into()
-            .context("cannot parse simulation response")
+            .into_inner())
     }
 
     pub async fn get_all_liquidity_positions(
@@ -298,6 +296,131 @@ impl DexCmd {
         Ok(())
     }
 
+    pub async fn print_trade_simulation(
+        &self,
+        app: &mut App,
+        simulation: SimulateTradeResponse,
+    ) -> Result<()> {
+        let swap_execution: SwapExecution = simulation
+            .output
+            .ok_or_else(|| anyhow::anyhow!("proto response missing swap execution"))?
+            .try_into()
+            .context("cannot parse simulation response")?;
+        self.print_swap_execution(app, &swap_execution).await?;
+
+        let cache = app.view().assets().await?;
+        let unit = |id: &asset::Id| -> Result<asset::Unit> {
+            Ok(cache
+                .get(id)
+                .ok_or_else(|| anyhow::anyhow!("unknown asset {id}"))?
+                .default_unit())
+        };
+        let symbol = |id: &asset::Id| -> String {
+            unit(id)
+                .map(|unit| unit.to_string())
+                .unwrap_or_else(|_| id.to_string())
+        };
+        // Converts a price in base units of `start` per base unit of `end`
+        // into display units of `start` per display unit of `end`.
+        let display_price = |price: f64, start: &asset::Id, end: &asset::Id| -> Result<String> {
+            let exponent = i32::from(unit(end)?.exponent()) - i32::from(unit(start)?.exponent());
+            Ok(format!(
+                "{:.6} {}/{}",
+                price * 10f64.powi(exponent),
+                symbol(start),
+                symbol(end)
+            ))
+        };
+
+        let (input_id, output_id) = (
+            swap_execution.input.asset_id,
+            swap_execution.output.asset_id,
+        );
+        if let Some(unfilled) = simulation.unfilled {
+            let unfilled: Value = unfilled.try_into()?;
+            println!("Unfilled input: {}", unfilled.format(&cache));
+        }
+        match simulation.effective_price {
+            Some(price) => println!(
+                "Effective price: {}",
+                display_price(price, &input_id, &output_id)?
+            ),
+            None => println!("Effective price: none, nothing was filled"),
+        }
+        match simulation.mid_price {
+            Some(price) => println!(
+                "Mid price:       {}",
+                display_price(price, &input_id, &output_id)?
+            ),
+            None => println!("Mid price:       none, the market is one-sided"),
+        }
+        if let Some(price_impact_bps) = simulation.price_impact_bps {
+            println!("Price impact:    {:.2} bps", price_impact_bps);
+        }
+        let lp_fees = simulation
+            .lp_fees
+            .into_iter()
+            .map(|fee| -> Result<String> { Ok(Value::try_from(fee)?.format(&cache)) })
+            .collect::<Result<Vec<_>>>()?;
+        if lp_fees.is_empty() {
+            println!("LP fees:         none");
+        } else {
+            println!("LP fees:         {}", lp_fees.join(", "));
+        }
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "Hop",
+            "Position ID",
+            "Received",
+            "Paid out",
+            "Fee",
+            "Exhausted",
+        ]);
+        for execution in simulation.position_executions {
+            let execution: SimulatedPositionExecution = execution.try_into()?;
+            let hop = execution.hop;
+            let value = |asset_id, amount| Value { asset_id, amount }.format(&cache);
+            table.add_row(vec![
+                format!("{} => {}", symbol(&hop.start), symbol(&hop.end)),
+                execution.position_id.to_string(),
+                value(hop.start, execution.input),
+                value(hop.end, execution.output),
+                value(hop.start, execution.fee),
+                if execution.exhausted { "yes" } else { "no" }.to_string(),
+            ]);
+        }
+        println!("\nPositions executed against:\n{}", table);
+
+        if let Some(spread) = simulation.spread_after {
+            let pair = TradingPair::new(input_id, output_id);
+            println!("\nSpread after the trade:");
+            for (start, end, position, price) in [
+                (
+                    pair.asset_1(),
+                    pair.asset_2(),
+                    spread.best_1_to_2_position,
+                    spread.approx_effective_price_1_to_2,
+                ),
+                (
+                    pair.asset_2(),
+                    pair.asset_1(),
+                    spread.best_2_to_1_position,
+                    spread.approx_effective_price_2_to_1,
+                ),
+            ] {
+                let direction = format!("{} => {}", symbol(&start), symbol(&end));
+                match position {
+                    Some(_) => println!("\t{direction}: {}", display_price(price, &start, &end)?),
+                    None => println!("\t{direction}: no liquidity"),
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     pub async fn print_batch_outputs(
         &self,
         app: &mut App,
@@ -391,8 +514,8 @@ impl DexCmd {
                 let input = input.parse::<Value>()?;
                 let into = asset::REGISTRY.parse_unit(into.as_str()).base();
 
-                let swap_execution = self.get_simulated_execution(app, input, into.id()).await?;
-                self.print_swap_execution(app, &swap_execution).await?;
+                let simulation = self.get_trade_simulation(app, input, into.id()).await?;
+                self.print_trade_simulation(app, simulation).await?;
             }
             DexCmd::AllPositions { include_closed } => {
                 let client = DexQueryServiceClient::new(app.pd_channel().await?);
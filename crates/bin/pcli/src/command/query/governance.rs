@@ -4,12 +4,14 @@ use std::{
 };
 
 use anyhow::{Context, Result};
+use comfy_table::{presets, Table};
 use futures::TryStreamExt;
-use penumbra_sdk_governance::Vote;
+use penumbra_sdk_governance::{change::ScheduledParameterChange, proposal_state::Outcome, Vote};
 use penumbra_sdk_proto::core::component::governance::v1::{
     query_service_client::QueryServiceClient as GovernanceQueryServiceClient,
     AllTalliedDelegatorVotesForProposalRequest, ProposalDataRequest, ProposalListRequest,
-    ProposalListResponse, ValidatorVotesRequest, ValidatorVotesResponse,
+    ProposalListResponse, ProposalProjectionRequest, ScheduledParameterChangesRequest,
+    ValidatorVoteBreakdownRequest, ValidatorVotesRequest, ValidatorVotesResponse,
     VotingPowerAtProposalStartRequest,
 };
 use penumbra_sdk_stake::IdentityKey;
@@ -34,6 +36,8 @@ pub enum GovernanceCmd {
         #[clap(subcommand)]
         query: PerProposalCmd,
     },
+    /// List the parameter changes from passed proposals that are waiting for their activation.
+    ScheduledChanges,
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -46,6 +50,11 @@ pub enum PerProposalCmd {
     Period,
     /// Display the most recent tally of votes on the proposal.
     Tally,
+    /// Project the outcome of the proposal if voting ended now, and how many votes would flip it.
+    Projection,
+    /// Break down the votes on the proposal by validator, showing how delegator votes overrode
+    /// each validator's own vote.
+    Breakdown,
 }
 
 impl GovernanceCmd {
@@ -85,6 +94,54 @@ impl GovernanceCmd {
                 }
                 Ok(())
             }
+            GovernanceCmd::ScheduledChanges => {
+                let responses = client
+                    .scheduled_parameter_changes(ScheduledParameterChangesRequest {})
+                    .await?
+                    .into_inner()
+                    .try_collect::<Vec<_>>()
+                    .await
+                    .context("cannot process scheduled parameter changes")?;
+
+                let mut writer = stdout();
+                if responses.is_empty() {
+                    writeln!(writer, "No parameter changes are scheduled.")?;
+                }
+                for response in responses {
+                    let scheduled: ScheduledParameterChange = response
+                        .scheduled_change
+                        .context("scheduled change should always be set")?
+                        .try_into()?;
+                    let proposal_id = response.proposal_id;
+                    let activation = scheduled.activation;
+                    if response.activation_height != 0 {
+                        writeln!(
+                            writer,
+                            "#{proposal_id} activates at {activation} (height {})",
+                            response.activation_height
+                        )?;
+                    } else {
+                        writeln!(writer, "#{proposal_id} activates at {activation}")?;
+                    }
+                    for change in scheduled.changes {
+                        for precondition in change.preconditions {
+                            writeln!(
+                                writer,
+                                "    if {}.{} == {}",
+                                precondition.component, precondition.key, precondition.value
+                            )?;
+                        }
+                        for encoded in change.changes {
+                            writeln!(
+                                writer,
+                                "    set {}.{} = {}",
+                                encoded.component, encoded.key, encoded.value
+                            )?;
+                        }
+                    }
+                }
+                Ok(())
+            }
             GovernanceCmd::Proposal { proposal_id, query } => {
                 match query {
                     &PerProposalCmd::Definition => {
@@ -246,6 +303,90 @@ impl GovernanceCmd {
                         "details": all_votes_and_power,
                         }))?;
                     }
+                    PerProposalCmd::Projection => {
+                        let projection = client
+                            .proposal_projection(ProposalProjectionRequest {
+                                proposal_id: *proposal_id,
+                                ..Default::default()
+                            })
+                            .await?
+                            .into_inner();
+                        let tally: penumbra_sdk_governance::Tally = projection
+                            .current_tally
+                            .context("tally must be set for projection response")?
+                            .try_into()?;
+                        let outcome: Outcome<()> = projection
+                            .projected_outcome
+                            .context("outcome must be set for projection response")?
+                            .try_into()?;
+                        let (outcome, flip_vote) = match outcome {
+                            Outcome::Passed => ("passed", Vote::No),
+                            Outcome::Failed { .. } => ("failed", Vote::Yes),
+                            Outcome::Slashed { .. } => ("slashed", Vote::Yes),
+                        };
+                        // The server reports zero when no votes or overrides can flip the outcome.
+                        let votes_to_flip = if projection.votes_to_flip > 0 {
+                            json!({ flip_vote.to_string(): projection.votes_to_flip })
+                        } else {
+                            serde_json::Value::Null
+                        };
+
+                        json(&json!({
+                            "tally": json_tally(&tally),
+                            "projected_outcome": outcome,
+                            "total_voting_power": projection.total_voting_power,
+                            "unvoted_power": projection.unvoted_power,
+                            "validator_default_power": projection.validator_default_power,
+                            "votes_to_flip": votes_to_flip,
+                        }))?;
+                    }
+                    PerProposalCmd::Breakdown => {
+                        let breakdown = client
+                            .validator_vote_breakdown(ValidatorVoteBreakdownRequest {
+                                proposal_id: *proposal_id,
+                                ..Default::default()
+                            })
+                            .await?
+                            .into_inner()
+                            .try_collect::<Vec<_>>()
+                            .await
+                            .context("cannot process validator vote breakdown")?;
+
+                        let mut table = Table::new();
+                        table.load_preset(presets::NOTHING);
+                        table.set_header(vec![
+                            "Validator",
+                            "Voting Power",
+                            "Validator Vote",
+                            "Delegator Votes",
+                            "Net",
+                        ]);
+                        for response in breakdown {
+                            let identity_key: IdentityKey = response
+                                .identity_key
+                                .context("identity key must be set for breakdown response")?
+                                .try_into()?;
+                            let validator_vote = response
+                                .validator_vote
+                                .map(Vote::try_from)
+                                .transpose()?
+                                .map(|vote| vote.to_string())
+                                .unwrap_or_else(|| "-".to_string());
+                            let delegator_tally: penumbra_sdk_governance::Tally =
+                                response.delegator_tally.unwrap_or_default().try_into()?;
+                            let net_tally: penumbra_sdk_governance::Tally =
+                                response.net_tally.unwrap_or_default().try_into()?;
+                            table.add_row(vec![
+                                identity_key.to_string(),
+                                response.voting_power.to_string(),
+                                validator_vote,
+                                display_tally(&delegator_tally),
+                                display_tally(&net_tally),
+                            ]);
+                        }
+
+                        println!("{table}");
+                    }
                 };
                 Ok(())
             }
@@ -274,6 +415,22 @@ fn json_tally(tally: &penumbra_sdk_governance::Tally) -> serde_json::Value {
     map.into()
 }
 
+fn display_tally(tally: &penumbra_sdk_governance::Tally) -> String {
+    if tally.total() == 0 {
+        return "-".to_string();
+    }
+    [
+        ("yes", tally.yes()),
+        ("no", tally.no()),
+        ("abstain", tally.abstain()),
+    ]
+    .into_iter()
+    .filter(|(_, power)| *power > 0)
+    .map(|(vote, power)| format!("{vote}: {power}"))
+    .collect::<Vec<_>>()
+    .join(", ")
+}
+
 fn toml<T: Serialize>(value: &T) -> Result<()> {
     let mut writer = stdout();
     let string = toml::to_string_pretty(value)?;
@@ -18,15 +18,15 @@ use penumbra_sdk_proto::{
         },
         component::stake::v1::{
             query_service_client::QueryServiceClient as StakeQueryServiceClient,
-            GetValidatorInfoRequest, GetValidatorInfoResponse, ValidatorInfoRequest,
-            ValidatorStatusRequest, ValidatorUptimeRequest,
+            GetValidatorInfoRequest, GetValidatorInfoResponse, ValidatorHistoryRequest,
+            ValidatorInfoRequest, ValidatorStatusRequest, ValidatorUptimeRequest,
         },
     },
     DomainType,
 };
 use penumbra_sdk_stake::{
     rate::RateData,
-    validator::{self, Info, Status, Validator, ValidatorToml},
+    validator::{self, EpochSummary, Info, Status, Validator, ValidatorToml},
     IdentityKey, Uptime, BPS_SQUARED_SCALING_FACTOR,
 };
 
@@ -62,6 +62,21 @@ pub enum ValidatorCmd {
         /// The identity key of the validator to fetch.
         identity_key: String,
     },
+    /// Show the per-epoch performance history of one or more validators.
+    ///
+    /// When several validators are given, a comparison of their performance
+    /// over the requested epochs is shown after their individual histories.
+    History {
+        /// The identity keys of the validators to fetch.
+        #[clap(required = true)]
+        identity_keys: Vec<String>,
+        /// The index of the first epoch to show.
+        #[clap(long, default_value_t = 0)]
+        start_epoch: u64,
+        /// The index of the last epoch to show [default: the latest completed epoch].
+        #[clap(long)]
+        end_epoch: Option<u64>,
+    },
 }
 
 impl ValidatorCmd {
@@ -370,10 +385,11 @@ impl ValidatorCmd {
 
                 // Parse the validator status, or return an error if it was not found within the
                 // client's response.
-                let info = validator_info
+                let info: Info = validator_info
                     .ok_or_else(|| anyhow!("response did not include validator info"))?
                     .try_into()
                     .context("parsing validator info")?;
+                let pending_commission_increase = info.pending_commission_increase.clone();
 
                 // Initialize a table, add a header and insert this validator's information.
                 let mut table = Table::new();
@@ -390,6 +406,133 @@ impl ValidatorCmd {
                     ])
                     .add_row(StatusRow::new(info));
                 println!("{table}");
+
+                if let Some(pending) = pending_commission_increase {
+                    println!(
+                        "Announced commission increase: {}bps, effective at the end of epoch {}",
+                        pending.funding_streams.total_rate_bps(),
+                        pending.effective_epoch_index
+                    );
+                }
+            }
+            ValidatorCmd::History {
+                identity_keys,
+                start_epoch,
+                end_epoch,
+            } => {
+                let mut client = StakeQueryServiceClient::new(app.pd_channel().await?);
+
+                let mut histories = Vec::new();
+                for identity_key in identity_keys {
+                    let identity_key = identity_key.parse::<IdentityKey>()?;
+                    let history = client
+                        .validator_history(ValidatorHistoryRequest {
+                            identity_key: Some(identity_key.into()),
+                            start_epoch_index: *start_epoch,
+                            end_epoch_index: *end_epoch,
+                        })
+                        .await?
+                        .into_inner()
+                        .try_collect::<Vec<_>>()
+                        .await?
+                        .into_iter()
+                        .map(|response| {
+                            response
+                                .summary
+                                .ok_or_else(|| anyhow!("response did not include a summary"))?
+                                .try_into()
+                        })
+                        .collect::<Result<Vec<EpochSummary>>>()?;
+                    histories.push((identity_key, history));
+                }
+
+                for (identity_key, history) in histories.iter() {
+                    println!("Validator {identity_key}:");
+                    if history.is_empty() {
+                        println!("  no history recorded in the requested epochs");
+                        continue;
+                    }
+
+                    let mut table = Table::new();
+                    table.load_preset(presets::NOTHING).set_header(vec![
+                        "Epoch",
+                        "State",
+                        "Voting Power",
+                        "Commission",
+                        "Exchange Rate",
+                        "Signed",
+                    ]);
+                    for summary in history {
+                        let signed = match summary.signed_ratio() {
+                            Some(ratio) => format!(
+                                "{:.2}% ({}/{})",
+                                100.0 * ratio,
+                                summary.tracked_blocks - summary.missed_blocks,
+                                summary.tracked_blocks
+                            ),
+                            None => "-".to_string(),
+                        };
+                        table.add_row(vec![
+                            summary.epoch_index.to_string(),
+                            summary.state.to_string(),
+                            format!("{:.3}", (summary.voting_power.value() as f64) * 1e-6),
+                            format!("{}bps", summary.commission_bps),
+                            format!("{:.8}", exchange_rate(&summary.rate_data)),
+                            signed,
+                        ]);
+                    }
+                    println!("{table}");
+                }
+
+                if histories.len() > 1 {
+                    let mut table = Table::new();
+                    table.load_preset(presets::NOTHING).set_header(vec![
+                        "Identity Key",
+                        "Epochs",
+                        "Avg. Commission",
+                        "Exchange Rate Growth",
+                        "Signed",
+                    ]);
+                    for (identity_key, history) in histories.iter() {
+                        let (Some(first), Some(last)) = (history.first(), history.last()) else {
+                            table.add_row(vec![
+                                identity_key.to_string(),
+                                "0".to_string(),
+                                "-".to_string(),
+                                "-".to_string(),
+                                "-".to_string(),
+                            ]);
+                            continue;
+                        };
+
+                        let epochs = history.len();
+                        let avg_commission = history
+                            .iter()
+                            .map(|summary| summary.commission_bps as f64)
+                            .sum::<f64>()
+                            / epochs as f64;
+                        let growth = 100.0
+                            * (exchange_rate(&last.rate_data) / exchange_rate(&first.rate_data)
+                                - 1.0);
+                        let tracked: u64 = history.iter().map(|s| s.tracked_blocks).sum();
+                        let missed: u64 = history.iter().map(|s| s.missed_blocks).sum();
+                        let signed = if tracked == 0 {
+                            "-".to_string()
+                        } else {
+                            format!("{:.2}%", 100.0 * (tracked - missed) as f64 / tracked as f64)
+                        };
+
+                        table.add_row(vec![
+                            identity_key.to_string(),
+                            epochs.to_string(),
+                            format!("{avg_commission:.0}bps"),
+                            format!("{growth:.4}%"),
+                            signed,
+                        ]);
+                    }
+                    println!("Comparison:");
+                    println!("{table}");
+                }
             }
         }
 
@@ -397,6 +540,11 @@ impl ValidatorCmd {
     }
 }
 
+/// Returns the exchange rate of delegation tokens to staking tokens in `rate_data`.
+fn exchange_rate(rate_data: &RateData) -> f64 {
+    rate_data.validator_exchange_rate.value() as f64 / 1_0000_0000.0
+}
+
 /// A row within the `status` command's table output.
 struct StatusRow {
     power: f64,
@@ -431,6 +579,7 @@ impl StatusRow {
                     validator_exchange_rate,
                     ..
                 },
+            ..
         }: Info,
     ) -> Self {
         // Calculate the scaled voting power, exchange rate, and commissions.
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use penumbra_sdk_custody::threshold::Terminal;
+use penumbra_sdk_custody::{encrypted::Secret, threshold::Terminal};
 
 use crate::{
     config::{CustodyConfig, GovernanceCustodyConfig},
@@ -24,10 +24,13 @@ impl ThresholdCmd {
     pub async fn exec(&self, app: &mut App) -> Result<()> {
         let config = match app.config.custody.clone() {
             CustodyConfig::Threshold(config) => Some(config),
-            CustodyConfig::Encrypted(config) => {
-                let password = ActualTerminal::default().get_password().await?;
-                config.convert_to_threshold(&password)?
-            }
+            CustodyConfig::Encrypted(config) => match config.read_keyfile()? {
+                Some(keyfile) => config.convert_to_threshold(Secret::Keyfile(&keyfile))?,
+                None => {
+                    let password = ActualTerminal::default().get_password().await?;
+                    config.convert_to_threshold(Secret::Password(&password))?
+                }
+            },
             _ => None, // If not threshold, we can't sign using threshold config
         };
         let governance_config = match &app.config.governance_custody {
@@ -34,7 +34,9 @@ use penumbra_sdk_dex::{
 };
 use penumbra_sdk_fee::FeeTier;
 use penumbra_sdk_governance::{
-    proposal::ProposalToml, proposal_state::State as ProposalState, Vote,
+    proposal::ProposalToml,
+    proposal_state::{Outcome, State as ProposalState},
+    Vote,
 };
 use penumbra_sdk_keys::{keys::AddressIndex, Address};
 use penumbra_sdk_num::Amount;
@@ -87,6 +89,7 @@ use tonic::transport::{Channel, ClientTlsConfig};
 use url::Url;
 
 use crate::command::tx::auction::AuctionCmd;
+use crate::command::utils::claimable_deposits;
 use crate::App;
 use clap::Parser;
 
@@ -1066,6 +1069,51 @@ impl TxCmd {
 
                 app.build_and_submit_transaction(plan).await?;
             }
+            TxCmd::Proposal(ProposalCmd::ClaimAll { source, fee_tier }) => {
+                let channel = app.pd_channel().await?;
+                let claimable = claimable_deposits(app.view(), channel)
+                    .await?
+                    .into_iter()
+                    .filter(|deposit| deposit.account == *source)
+                    .collect::<Vec<_>>();
+
+                if claimable.is_empty() {
+                    println!("No proposal deposits are ready to be claimed.");
+                    return Ok(());
+                }
+
+                let mut planner = Planner::new(OsRng);
+                planner
+                    .set_gas_prices(gas_prices)
+                    .set_fee_tier((*fee_tier).into());
+                for deposit in claimable {
+                    println!(
+                        "Claiming deposit for proposal {} ({})",
+                        deposit.proposal_id,
+                        match deposit.outcome {
+                            Outcome::Passed => "passed",
+                            Outcome::Failed { .. } => "failed",
+                            Outcome::Slashed { .. } => "slashed",
+                        }
+                    );
+                    planner.proposal_deposit_claim(
+                        deposit.proposal_id,
+                        deposit.deposit_amount,
+                        deposit.outcome,
+                    );
+                }
+
+                let plan = planner
+                    .plan(
+                        app.view
+                            .as_mut()
+                            .context("view service must be initialized")?,
+                        AddressIndex::new(*source),
+                    )
+                    .await?;
+
+                app.build_and_submit_transaction(plan).await?;
+            }
             TxCmd::Vote {
                 vote,
                 source,
@@ -1137,7 +1185,8 @@ impl TxCmd {
 
                 tracing::info!(?order);
                 let source = AddressIndex::new(order.source());
-                let positions = order.as_position(&asset_cache, OsRng)?;
+                let current_height = app.view().status().await?.full_sync_height;
+                let positions = order.as_position(&asset_cache, current_height, OsRng)?;
                 tracing::info!(?positions);
                 for position in &positions {
                     println!("Position id: {}", position.id());
@@ -1530,6 +1579,9 @@ impl TxCmd {
             TxCmd::Auction(AuctionCmd::Dutch(auction_cmd)) => {
                 auction_cmd.exec(app).await?;
             }
+            TxCmd::Auction(AuctionCmd::Twap(auction_cmd)) => {
+                auction_cmd.exec(app).await?;
+            }
             TxCmd::Broadcast { transaction } => {
                 let transaction: Transaction = serde_json::from_slice(&fs::read(transaction)?)?;
                 app.submit_transaction(transaction).await?;
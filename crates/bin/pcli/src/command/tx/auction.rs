@@ -1,11 +1,16 @@
 use crate::command::tx::auction::dutch::DutchCmd;
+use crate::command::tx::auction::twap::TwapCmd;
 use clap::Subcommand;
 
 pub mod dutch;
+pub mod twap;
 
 #[derive(Debug, Subcommand)]
 pub enum AuctionCmd {
     /// Commands related to Dutch auctions
     #[clap(display_order = 100, subcommand)]
     Dutch(DutchCmd),
+    /// Commands related to TWAP auctions
+    #[clap(display_order = 200, subcommand)]
+    Twap(TwapCmd),
 }
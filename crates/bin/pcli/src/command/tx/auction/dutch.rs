@@ -13,7 +13,9 @@ use penumbra_sdk_auction::auction::{
 };
 use penumbra_sdk_keys::keys::AddressIndex;
 use penumbra_sdk_num::Amount;
-use penumbra_sdk_proto::{view::v1::GasPricesRequest, DomainType};
+use penumbra_sdk_proto::{
+    core::component::auction::v1 as pb, view::v1::GasPricesRequest, DomainType, Name,
+};
 use penumbra_sdk_view::ViewClient;
 use penumbra_sdk_wallet::plan::Planner;
 use rand::RngCore;
@@ -449,7 +451,7 @@ async fn fetch_dutch_auction_states(
         .await?
         .into_iter()
         .filter_map(|(id, _, local_seq, state, _)| {
-            if let Some(state) = state {
+            if let Some(state) = state.filter(|s| s.type_url == pb::DutchAuction::type_url()) {
                 if let Ok(da) = DutchAuction::decode(state.value) {
                     Some((id, da, local_seq))
                 } else {
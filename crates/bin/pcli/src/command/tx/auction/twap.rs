@@ -0,0 +1,232 @@
+use crate::command::tx::FeeTier;
+use crate::App;
+use anyhow::Result;
+use anyhow::{anyhow, bail, Context};
+use clap::Subcommand;
+use penumbra_sdk_asset::Value;
+use penumbra_sdk_auction::auction::{twap::TwapAuction, twap::TwapAuctionDescription, AuctionId};
+use penumbra_sdk_keys::keys::AddressIndex;
+use penumbra_sdk_proto::{
+    core::component::auction::v1 as pb_auction, view::v1::GasPricesRequest, DomainType, Name,
+};
+use penumbra_sdk_view::ViewClient;
+use penumbra_sdk_wallet::plan::Planner;
+use rand::RngCore;
+use rand_core::OsRng;
+
+/// Commands related to TWAP auctions
+#[derive(Debug, Subcommand)]
+pub enum TwapCmd {
+    /// Schedule a TWAP auction, selling the input in even tranches over a block window.
+    #[clap(display_order = 100, name = "schedule")]
+    TwapAuctionSchedule {
+        /// Source account initiating the auction.
+        #[clap(long, display_order = 100, default_value = "0")]
+        source: u32,
+        /// The value the seller wishes to auction.
+        #[clap(long, display_order = 200)]
+        input: String,
+        /// The minimum output the seller is willing to receive for the whole input.
+        ///
+        /// This implicitly defines the floor price of every tranche.
+        #[clap(long, display_order = 300)]
+        min_output: String,
+        /// The block height at which the first tranche is released.
+        #[clap(long, display_order = 400)]
+        start_height: u64,
+        /// The block height at which the auction ends.
+        #[clap(long, display_order = 500)]
+        end_height: u64,
+        /// The number of tranches to release the input in.
+        ///
+        /// `end_height - start_height` must be a multiple of `step_count`.
+        #[clap(long, display_order = 600)]
+        step_count: u64,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t, display_order = 1000)]
+        fee_tier: FeeTier,
+    },
+    /// Terminate a TWAP auction.
+    #[clap(display_order = 300, name = "end")]
+    TwapAuctionEnd {
+        /// Source account terminating the auction.
+        #[clap(long, display_order = 100, default_value = "0")]
+        source: u32,
+        /// If set, ends all TWAP auctions owned by the specified account.
+        #[clap(long, display_order = 150)]
+        all: bool,
+        /// Identifiers of the auctions to end, if `--all` is not set.
+        #[clap(display_order = 200)]
+        auction_ids: Vec<AuctionId>,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t, display_order = 300)]
+        fee_tier: FeeTier,
+    },
+    /// Withdraw a TWAP auction, and claim its reserves.
+    #[clap(display_order = 200, name = "withdraw")]
+    TwapAuctionWithdraw {
+        /// Source account withdrawing from the auction.
+        #[clap(long, display_order = 100, default_value = "0")]
+        source: u32,
+        /// If set, withdraws all ended TWAP auctions owned by the specified account.
+        #[clap(long, display_order = 150)]
+        all: bool,
+        /// Identifiers of the auctions to withdraw, if `--all` is not set.
+        #[clap(display_order = 200)]
+        auction_ids: Vec<AuctionId>,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t, display_order = 600)]
+        fee_tier: FeeTier,
+    },
+}
+
+impl TwapCmd {
+    /// Process the command by performing the appropriate action.
+    pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
+        let gas_prices = app
+            .view
+            .as_mut()
+            .context("view service must be initialized")?
+            .gas_prices(GasPricesRequest {})
+            .await?
+            .into_inner()
+            .gas_prices
+            .expect("gas prices must be available")
+            .try_into()?;
+
+        let mut planner = Planner::new(OsRng);
+        planner.set_gas_prices(gas_prices);
+
+        let source = match self {
+            TwapCmd::TwapAuctionSchedule {
+                source,
+                input,
+                min_output,
+                start_height,
+                end_height,
+                step_count,
+                fee_tier,
+            } => {
+                let mut nonce = [0u8; 32];
+                OsRng.fill_bytes(&mut nonce);
+
+                let input = input.parse::<Value>()?;
+                let min_output = min_output.parse::<Value>()?;
+
+                planner
+                    .set_fee_tier((*fee_tier).into())
+                    .twap_auction_schedule(TwapAuctionDescription {
+                        input,
+                        output_id: min_output.asset_id,
+                        min_output: min_output.amount,
+                        start_height: *start_height,
+                        end_height: *end_height,
+                        step_count: *step_count,
+                        nonce,
+                    });
+                *source
+            }
+            TwapCmd::TwapAuctionEnd {
+                source,
+                all,
+                auction_ids,
+                fee_tier,
+            } => {
+                let auction_ids = match (all, auction_ids.is_empty()) {
+                    (true, _) => twap_auction_states(app.view(), *source, false)
+                        .await?
+                        .into_iter()
+                        // Auctions we track as opened (local_seq == 0) need to be closed.
+                        .filter(|(_, _, local_seq)| *local_seq == 0)
+                        .map(|(id, _, _)| id)
+                        .collect(),
+                    (false, false) => auction_ids.to_owned(),
+                    (false, true) => bail!("auction_ids are required when --all is not set"),
+                };
+
+                if auction_ids.is_empty() {
+                    println!("no active auctions to end");
+                    return Ok(());
+                }
+
+                planner.set_fee_tier((*fee_tier).into());
+                for auction_id in auction_ids {
+                    planner.twap_auction_end(auction_id);
+                }
+                *source
+            }
+            TwapCmd::TwapAuctionWithdraw {
+                source,
+                all,
+                auction_ids,
+                fee_tier,
+            } => {
+                let withdrawable: Vec<_> = twap_auction_states(app.view(), *source, true)
+                    .await?
+                    .into_iter()
+                    // Auctions we track as closed (local_seq == 1) can be withdrawn.
+                    .filter(|(_, _, local_seq)| *local_seq == 1)
+                    .collect();
+
+                let auctions = match (all, auction_ids.is_empty()) {
+                    (true, _) => withdrawable.into_iter().map(|(_, a, _)| a).collect(),
+                    (false, false) => auction_ids
+                        .iter()
+                        .map(|auction_id| {
+                            withdrawable
+                                .iter()
+                                .find(|(id, _, _)| id == auction_id)
+                                .map(|(_, auction, _)| auction.clone())
+                                .ok_or_else(|| {
+                                    anyhow!("auction id {auction_id} is not an ended twap auction")
+                                })
+                        })
+                        .collect::<Result<Vec<_>>>()?,
+                    (false, true) => bail!("auction_ids are required when --all is not set"),
+                };
+
+                if auctions.is_empty() {
+                    println!("no ended auctions to withdraw");
+                    return Ok(());
+                }
+
+                planner.set_fee_tier((*fee_tier).into());
+                for auction in &auctions {
+                    planner.twap_auction_withdraw(auction);
+                }
+                *source
+            }
+        };
+
+        let plan = planner
+            .plan(
+                app.view
+                    .as_mut()
+                    .context("view service must be initialized")?,
+                AddressIndex::new(source),
+            )
+            .await
+            .context("can't build twap auction transaction")?;
+        app.build_and_submit_transaction(plan).await?;
+        Ok(())
+    }
+}
+
+/// Fetch the TWAP auctions owned by `source`, with their latest chain state.
+async fn twap_auction_states(
+    view_client: &mut impl ViewClient,
+    source: impl Into<AddressIndex>,
+    include_inactive: bool,
+) -> Result<Vec<(AuctionId, TwapAuction, u64)>> {
+    let auctions = view_client
+        .auctions(Some(source.into()), include_inactive, true)
+        .await?
+        .into_iter()
+        .filter_map(|(id, _, local_seq, state, _)| {
+            let state = state.filter(|s| s.type_url == pb_auction::TwapAuction::type_url())?;
+            let auction = TwapAuction::decode(state.value).ok()?;
+            Some((id, auction, local_seq))
+        })
+        .collect();
+    Ok(auctions)
+}
@@ -101,6 +101,12 @@ pub enum OrderCmd {
         /// When set, tags the position as an auto-closing buy.
         #[clap(long)]
         auto_close: bool,
+        /// Close the position automatically after the given number of blocks.
+        #[clap(long, conflicts_with = "expires-at")]
+        expires_in: Option<u64>,
+        /// Close the position automatically at the end of the given block height.
+        #[clap(long)]
+        expires_at: Option<u64>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
@@ -121,6 +127,12 @@ pub enum OrderCmd {
         /// When set, tags the position as an auto-closing sell.
         #[clap(long)]
         auto_close: bool,
+        /// Close the position automatically after the given number of blocks.
+        #[clap(long, conflicts_with = "expires-at")]
+        expires_in: Option<u64>,
+        /// Close the position automatically at the end of the given block height.
+        #[clap(long)]
+        expires_at: Option<u64>,
         /// The selected fee tier to multiply the fee amount by.
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
@@ -152,6 +164,25 @@ impl OrderCmd {
         }
     }
 
+    /// The height at which the order's positions expire, if any, given the current height.
+    pub fn expires_at_height(&self, current_height: u64) -> Option<u64> {
+        let (expires_in, expires_at) = match self {
+            OrderCmd::Buy {
+                expires_in,
+                expires_at,
+                ..
+            } => (expires_in, expires_at),
+            OrderCmd::Sell {
+                expires_in,
+                expires_at,
+                ..
+            } => (expires_in, expires_at),
+        };
+        expires_in
+            .map(|blocks| current_height.saturating_add(blocks))
+            .or(*expires_at)
+    }
+
     pub fn num_copies(&self) -> u32 {
         match self {
             OrderCmd::Buy { num_copies, .. } => *num_copies,
@@ -163,8 +194,17 @@ impl OrderCmd {
         &self,
         // Preserved since we'll need it after denom metadata refactor
         _asset_cache: &asset::Cache,
+        current_height: u64,
         mut rng: impl CryptoRngCore,
     ) -> Result<Vec<Position>> {
+        let expires_at_height = self.expires_at_height(current_height);
+        if let Some(expires_at_height) = expires_at_height {
+            anyhow::ensure!(
+                expires_at_height >= current_height,
+                "order would expire at height {expires_at_height}, before the current height {current_height}"
+            );
+        }
+
         let positions = match self {
             OrderCmd::Buy { buy_order, .. } => {
                 tracing::info!(?buy_order, "parsing buy order");
@@ -175,6 +215,7 @@ impl OrderCmd {
                     if self.is_auto_closing() {
                         position.close_on_fill = true;
                     }
+                    position.expires_at_height = expires_at_height;
                     positions.push(position);
                 }
                 positions
@@ -189,6 +230,7 @@ impl OrderCmd {
                     if self.is_auto_closing() {
                         position.close_on_fill = true;
                     }
+                    position.expires_at_height = expires_at_height;
                     positions.push(position);
                 }
                 positions
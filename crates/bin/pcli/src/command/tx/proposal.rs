@@ -1,7 +1,10 @@
 use anyhow::{Context, Result};
 
 use penumbra_sdk_app::params::AppParameters;
-use penumbra_sdk_governance::{change::ParameterChange, Proposal, ProposalPayload};
+use penumbra_sdk_governance::{
+    change::{Activation, ParameterChange, ScheduledParameterChange},
+    Proposal, ProposalPayload,
+};
 use penumbra_sdk_proto::DomainType;
 use penumbra_sdk_transaction::TransactionPlan;
 
@@ -14,7 +17,7 @@ pub enum ProposalCmd {
         /// The file to output the template to.
         #[clap(long, global = true)]
         file: Option<camino::Utf8PathBuf>,
-        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, or community-pool-spend].
+        /// The kind of the proposal to template [one of: signaling, emergency, parameter-change, community-pool-spend, upgrade-plan, or scheduled-parameter-change].
         #[clap(subcommand)]
         kind: ProposalKindCmd,
     },
@@ -63,6 +66,19 @@ pub enum ProposalCmd {
         #[clap(short, long, default_value_t)]
         fee_tier: FeeTier,
     },
+    /// Claim the deposits for all proposals you submitted that have finished voting, in a single
+    /// transaction.
+    ///
+    /// This finds the proposal NFTs held by the account whose proposals have finished voting, and
+    /// makes a deposit claim for each of them, as with `deposit-claim`.
+    ClaimAll {
+        /// Only spend funds originally received by the given account.
+        #[clap(long, default_value = "0")]
+        source: u32,
+        /// The selected fee tier to multiply the fee amount by.
+        #[clap(short, long, default_value_t)]
+        fee_tier: FeeTier,
+    },
 }
 
 #[derive(Debug, clap::Subcommand)]
@@ -84,6 +100,16 @@ pub enum ProposalKindCmd {
     },
     /// Generate a template for an upgrade proposal,
     UpgradePlan,
+    /// Generate a template for a parameter change proposal that takes effect at a given height
+    /// or epoch.
+    ScheduledParameterChange {
+        /// The height at the beginning of which the changes should take effect.
+        #[clap(long, conflicts_with = "activation-epoch")]
+        activation_height: Option<u64>,
+        /// The index of the epoch at the beginning of which the changes should take effect.
+        #[clap(long)]
+        activation_epoch: Option<u64>,
+    },
 }
 
 impl ProposalKindCmd {
@@ -118,6 +144,24 @@ impl ProposalKindCmd {
                 }
             }
             ProposalKindCmd::UpgradePlan { .. } => ProposalPayload::UpgradePlan { height: 0 },
+            ProposalKindCmd::ScheduledParameterChange {
+                activation_height,
+                activation_epoch,
+            } => {
+                let activation = match (activation_height, activation_epoch) {
+                    (Some(height), _) => Activation::Height(*height),
+                    (None, Some(epoch)) => Activation::Epoch(*epoch),
+                    (None, None) => anyhow::bail!(
+                        "a scheduled parameter change needs an --activation-height or --activation-epoch"
+                    ),
+                };
+                ProposalPayload::ScheduledParameterChange(ScheduledParameterChange {
+                    changes: vec![ParameterChange::encode_parameters(
+                        serde_json::value::to_value(app_params.clone())?,
+                    )],
+                    activation,
+                })
+            }
         };
 
         Ok(Proposal {
@@ -136,6 +180,7 @@ impl ProposalCmd {
             ProposalCmd::Submit { .. } => false,
             ProposalCmd::Withdraw { .. } => false,
             ProposalCmd::DepositClaim { .. } => false,
+            ProposalCmd::ClaimAll { .. } => false,
         }
     }
 }
@@ -1,15 +1,32 @@
+use anyhow::Context;
+use dialoguer::Confirm;
+use rand_core::{CryptoRngCore, OsRng};
+
 use crate::App;
 
+pub mod concentrated;
 pub mod linear;
 pub mod xyk;
 
+use concentrated::Concentrated;
 use linear::Linear;
-use penumbra_sdk_dex::DirectedUnitPair;
+use penumbra_sdk_asset::asset;
+use penumbra_sdk_dex::{
+    lp::{position::Position, Reserves},
+    DirectedTradingPair, DirectedUnitPair,
+};
+use penumbra_sdk_keys::keys::AddressIndex;
+use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::core::component::dex::v1::{
-    query_service_client::QueryServiceClient as DexQueryServiceClient, SpreadRequest,
+    query_service_client::QueryServiceClient as DexQueryServiceClient, CandlestickDataRequest,
+    CandlestickInterval, SpreadRequest,
 };
+use penumbra_sdk_proto::view::v1::GasPricesRequest;
+use penumbra_sdk_view::{Planner, ViewClient};
 use xyk::ConstantProduct;
 
+use crate::dex_utils::replicate::volatility;
+
 /// Queries the chain for a transaction by hash.
 #[derive(Debug, clap::Subcommand)]
 pub enum ReplicateCmd {
@@ -19,6 +36,9 @@ pub enum ReplicateCmd {
     ConstantProduct(ConstantProduct),
     /// Create a set of positions that allocate liquidity linearly across a price range.
     Linear(Linear),
+    /// Create a set of positions that concentrate liquidity around the current price,
+    /// across a price range that can extend further on one side than the other.
+    Concentrated(Concentrated),
 }
 
 impl ReplicateCmd {
@@ -26,6 +46,7 @@ impl ReplicateCmd {
         match self {
             ReplicateCmd::ConstantProduct(xyk_cmd) => xyk_cmd.exec(app).await?,
             ReplicateCmd::Linear(linear_cmd) => linear_cmd.exec(app).await?,
+            ReplicateCmd::Concentrated(concentrated_cmd) => concentrated_cmd.exec(app).await?,
         };
         Ok(())
     }
@@ -35,6 +56,367 @@ impl ReplicateCmd {
     }
 }
 
+/// Standard fee tiers for liquidity positions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum PositionFeeTier {
+    /// 1bps, for pairs that barely move, like stablecoins.
+    Lowest,
+    /// 5bps, for pairs that move little.
+    Low,
+    /// 30bps, for most pairs.
+    Medium,
+    /// 100bps, for volatile pairs.
+    High,
+}
+
+impl PositionFeeTier {
+    pub fn bps(&self) -> u32 {
+        match self {
+            PositionFeeTier::Lowest => 1,
+            PositionFeeTier::Low => 5,
+            PositionFeeTier::Medium => 30,
+            PositionFeeTier::High => 100,
+        }
+    }
+
+    /// Suggests a fee tier for a pair with the given hourly volatility.
+    ///
+    /// The more the price moves, the more liquidity loses to better-informed
+    /// traders, so the fee needs to be higher to compensate.
+    pub fn for_hourly_volatility(volatility: f64) -> Self {
+        if volatility < 0.0005 {
+            PositionFeeTier::Lowest
+        } else if volatility < 0.0025 {
+            PositionFeeTier::Low
+        } else if volatility < 0.01 {
+            PositionFeeTier::Medium
+        } else {
+            PositionFeeTier::High
+        }
+    }
+}
+
+/// Options for deriving the fee and price range from recent price volatility.
+#[derive(Debug, Clone, clap::Args)]
+pub struct VolatilityArgs {
+    /// Derive the fee tier and price range from the volatility of the pair's
+    /// hourly candlesticks, unless they're specified explicitly.
+    #[clap(long, display_order = 600)]
+    pub from_volatility: bool,
+    /// The number of hours of candlesticks to estimate volatility from.
+    ///
+    /// The derived price range covers the movement expected over as many hours.
+    #[clap(long, default_value_t = 24, display_order = 601)]
+    pub lookback_hours: u64,
+    /// The number of standard deviations of expected price movement that the
+    /// derived price range covers.
+    #[clap(long, default_value_t = 2.0, display_order = 602)]
+    pub range_std_devs: f64,
+}
+
+impl VolatilityArgs {
+    /// Fetches the hourly volatility of the pair, if requested.
+    pub async fn fetch(
+        &self,
+        app: &mut App,
+        pair: &DirectedUnitPair,
+    ) -> anyhow::Result<Option<f64>> {
+        if !self.from_volatility {
+            return Ok(None);
+        }
+        if self.lookback_hours < 3 {
+            anyhow::bail!("volatility must be estimated from at least 3 hours of candlesticks");
+        }
+
+        // Candlesticks only record trades in one direction, so we use whichever
+        // direction traded more often.
+        let mut client = DexQueryServiceClient::new(app.pd_channel().await?);
+        let mut best: Option<(usize, f64)> = None;
+        for (start, end) in [
+            (pair.start.id(), pair.end.id()),
+            (pair.end.id(), pair.start.id()),
+        ] {
+            let candles = client
+                .candlestick_data(CandlestickDataRequest {
+                    pair: Some(DirectedTradingPair::new(start, end).into()),
+                    limit: self.lookback_hours,
+                    start_height: 0,
+                    interval: CandlestickInterval::Hour as i32,
+                })
+                .await?
+                .into_inner()
+                .data;
+            let closes: Vec<f64> = candles.iter().map(|candle| candle.close).collect();
+            if let Some(vol) = volatility::log_return_volatility(&closes) {
+                if best.map_or(true, |(samples, _)| closes.len() > samples) {
+                    best = Some((closes.len(), vol));
+                }
+            }
+        }
+
+        let (samples, vol) = best.ok_or_else(|| {
+            anyhow::anyhow!(
+                "not enough recent trading on {pair} to estimate its volatility, specify the fee and price range instead"
+            )
+        })?;
+        tracing::debug!(samples, vol, "estimated hourly volatility");
+        println!(
+            "Estimated hourly volatility from {samples} candlesticks: {:.3}%",
+            vol * 100.0
+        );
+        Ok(Some(vol))
+    }
+
+    /// Returns the width, in log terms, of a price range derived from `vol`,
+    /// covering `std_devs` standard deviations of movement over the lookback.
+    pub fn range_width(&self, vol: f64, std_devs: f64) -> anyhow::Result<f64> {
+        let width = volatility::log_range_width(vol, self.lookback_hours as f64, std_devs);
+        if width <= 0.0 {
+            anyhow::bail!("the price hasn't moved recently, specify the price range instead");
+        }
+        Ok(width)
+    }
+}
+
+/// Resolves the fee to charge, in basis points, from an explicit fee, a fee
+/// tier, the tier suggested by the pair's hourly volatility, or `default`, in
+/// that order of precedence.
+pub fn resolve_fee_bps(
+    fee_bps: Option<u32>,
+    fee_tier: Option<PositionFeeTier>,
+    vol: Option<f64>,
+    default: u32,
+) -> u32 {
+    if let Some(fee_bps) = fee_bps {
+        return fee_bps;
+    }
+    if let Some(fee_tier) = fee_tier {
+        return fee_tier.bps();
+    }
+    match vol {
+        Some(vol) => {
+            let tier = PositionFeeTier::for_hourly_volatility(vol);
+            println!(
+                "Suggested fee tier for this volatility: {tier:?} ({}bps)",
+                tier.bps()
+            );
+            tier.bps()
+        }
+        None => default,
+    }
+}
+
+/// Builds a position quoting `price` on `pair`, holding `value` of asset 2.
+///
+/// Prices are the amount of asset 2 required to purchase 1 unit of asset 1.
+/// Positions quoting above the current price hold the equivalent amount of
+/// asset 1 instead, so that they aren't immediately arbitraged.
+pub(crate) fn position_at<R: CryptoRngCore>(
+    rng: R,
+    pair: &DirectedUnitPair,
+    price: f64,
+    current_price: f64,
+    value: f64,
+    fee_bps: u32,
+) -> Position {
+    // Cross-multiply exponents and prices for trading function coefficients
+    //
+    // We want to write
+    // p = EndUnit * price
+    // q = StartUnit
+    // However, if EndUnit is too small, it might not round correctly after multiplying by price
+    // To handle this, conditionally apply a scaling factor if the EndUnit amount is too small.
+    let scale = if pair.end.unit_amount().value() < 1_000_000 {
+        1_000_000
+    } else {
+        1
+    };
+
+    let p = Amount::from(((pair.end.unit_amount().value() * scale) as f64 * price) as u128);
+    let q = pair.start.unit_amount() * Amount::from(scale);
+
+    // Compute reserves
+    let reserves = if price < current_price {
+        // If the position's price is _less_ than the current price, fund it with asset 2
+        // so the position isn't immediately arbitraged.
+        Reserves {
+            r1: Amount::zero(),
+            r2: Amount::from(value as u128),
+        }
+    } else {
+        // If the position's price is _greater_ than the current price, fund it with
+        // an equivalent amount of asset 1 as the target per-position amount of asset 2.
+        let asset_1 = value / price;
+        Reserves {
+            r1: Amount::from(asset_1 as u128),
+            r2: Amount::zero(),
+        }
+    };
+
+    Position::new(
+        rng,
+        pair.into_directed_trading_pair(),
+        fee_bps,
+        p,
+        q,
+        reserves,
+    )
+}
+
+/// Returns the amounts of asset 1 and asset 2 that `positions` would hold if
+/// the market moved to `price`, excluding fees earned along the way.
+///
+/// Positions quoting above `price` would have bought asset 1 with all their
+/// asset 2, and positions quoting below it would have sold all their asset 1.
+pub(crate) fn inventory_at(
+    pair: &DirectedUnitPair,
+    positions: &[Position],
+    price: f64,
+) -> (Amount, Amount) {
+    let raw_price =
+        price * pair.end.unit_amount().value() as f64 / pair.start.unit_amount().value() as f64;
+
+    positions.iter().fold(
+        (Amount::zero(), Amount::zero()),
+        |(start, end), position| {
+            let phi = position
+                .phi
+                .orient_start(pair.start.id())
+                .expect("start is part of position");
+            let r_start = position
+                .reserves_for(pair.start.id())
+                .expect("start is part of position");
+            let r_end = position
+                .reserves_for(pair.end.id())
+                .expect("end is part of position");
+
+            // The trading function `p * R_start + q * R_end` is invariant under
+            // trades (up to fees), and the position quotes a price of `p / q`.
+            let (p, q) = (phi.p.value() as f64, phi.q.value() as f64);
+            let invariant = p * r_start.value() as f64 + q * r_end.value() as f64;
+            let position_price = p / q;
+
+            if position_price > raw_price {
+                (start + Amount::from((invariant / p) as u128), end)
+            } else if position_price < raw_price {
+                (start, end + Amount::from((invariant / q) as u128))
+            } else {
+                (start + r_start, end + r_end)
+            }
+        },
+    )
+}
+
+/// Prints the total reserves needed to open `positions`, and the positions themselves.
+pub(crate) fn print_summary(
+    pair: &DirectedUnitPair,
+    positions: &[Position],
+    asset_cache: &asset::Cache,
+) {
+    let (amount_start, amount_end) = inventory_totals(pair, positions);
+    let amount_start = pair.start.format_value(amount_start);
+    let amount_end = pair.end.format_value(amount_end);
+
+    println!("#################################################################################");
+    println!("########################### LIQUIDITY SUMMARY ###################################");
+    println!("#################################################################################");
+    println!("\nYou want to provide liquidity on the pair {}", pair);
+    println!("You will need:",);
+    println!(" -> {amount_start}{}", pair.start);
+    println!(" -> {amount_end}{}", pair.end);
+
+    println!("You will create the following positions:");
+    println!(
+        "{}",
+        crate::command::utils::render_positions(asset_cache, positions),
+    );
+}
+
+/// Prints what `positions` would hold if the market moved to each of `prices`.
+pub(crate) fn print_inventory_preview(
+    pair: &DirectedUnitPair,
+    positions: &[Position],
+    prices: &[(&str, f64)],
+) {
+    println!("Expected inventory, excluding fees earned:");
+    for (label, price) in prices {
+        let (amount_start, amount_end) = inventory_at(pair, positions, *price);
+        println!(
+            " -> at the {label} of {price:.6}: {}{} and {}{}",
+            pair.start.format_value(amount_start),
+            pair.start,
+            pair.end.format_value(amount_end),
+            pair.end,
+        );
+    }
+}
+
+/// Returns the total reserves of `positions`, in asset 1 and asset 2.
+fn inventory_totals(pair: &DirectedUnitPair, positions: &[Position]) -> (Amount, Amount) {
+    positions
+        .iter()
+        .fold((Amount::zero(), Amount::zero()), |acc, pos| {
+            tracing::debug!(?pos);
+            (
+                acc.0
+                    + pos
+                        .reserves_for(pair.start.id())
+                        .expect("start is part of position"),
+                acc.1
+                    + pos
+                        .reserves_for(pair.end.id())
+                        .expect("end is part of position"),
+            )
+        })
+}
+
+/// Asks for confirmation unless `yes` is set, then opens `positions`, funded
+/// from the `source` account.
+pub(crate) async fn submit_positions(
+    app: &mut App,
+    positions: &[Position],
+    yes: bool,
+    source: u32,
+) -> anyhow::Result<()> {
+    if !yes
+        && !Confirm::new()
+            .with_prompt("Do you want to open those liquidity positions on-chain?")
+            .interact()?
+    {
+        return Ok(());
+    }
+
+    let gas_prices = app
+        .view
+        .as_mut()
+        .context("view service must be initialized")?
+        .gas_prices(GasPricesRequest {})
+        .await?
+        .into_inner()
+        .gas_prices
+        .expect("gas prices must be available")
+        .try_into()?;
+
+    let mut planner = Planner::new(OsRng);
+    planner.set_gas_prices(gas_prices);
+    positions.iter().for_each(|position| {
+        planner.position_open(position.clone());
+    });
+
+    let plan = planner
+        .plan(
+            app.view
+                .as_mut()
+                .context("view service must be initialized")?,
+            AddressIndex::new(source),
+        )
+        .await?;
+    let tx_id = app.build_and_submit_transaction(plan).await?;
+    println!("posted with transaction id: {tx_id}");
+
+    Ok(())
+}
+
 pub async fn process_price_or_fetch_spread(
     app: &mut App,
     user_price: Option<f64>,
@@ -105,4 +487,57 @@ mod tests {
         assert_eq!(adjust_price_by_exponents(base_price, &pair3), 1200000.0);
         assert_eq!(adjust_price_by_exponents(base_price, &pair4), 1.2);
     }
+
+    #[test]
+    fn test_inventory_at_price_bounds() {
+        let pair: DirectedUnitPair = "penumbra:gm".parse().unwrap();
+        let mut rng = OsRng;
+        // One position buying UM at 1.5, and one selling it at 2.5.
+        let positions = vec![
+            position_at(&mut rng, &pair, 1.5, 2.0, 3_000_000.0, 30),
+            position_at(&mut rng, &pair, 2.5, 2.0, 5_000_000.0, 30),
+        ];
+
+        // At the current price, neither position has traded.
+        let (um, gm) = inventory_at(&pair, &positions, 2.0);
+        assert_eq!(um, 2_000_000u64.into());
+        assert_eq!(gm, 3_000_000u64.into());
+
+        // Below both prices, the buy fills and the sell doesn't.
+        let (um, gm) = inventory_at(&pair, &positions, 1.0);
+        assert_eq!(um, 4_000_000u64.into());
+        assert_eq!(gm, 0u64.into());
+
+        // Above both prices, the sell fills and the buy doesn't.
+        let (um, gm) = inventory_at(&pair, &positions, 3.0);
+        assert_eq!(um, 0u64.into());
+        assert_eq!(gm, 8_000_000u64.into());
+    }
+
+    #[test]
+    fn test_fee_tier_for_volatility() {
+        assert_eq!(
+            PositionFeeTier::for_hourly_volatility(0.0001),
+            PositionFeeTier::Lowest
+        );
+        assert_eq!(
+            PositionFeeTier::for_hourly_volatility(0.001),
+            PositionFeeTier::Low
+        );
+        assert_eq!(
+            PositionFeeTier::for_hourly_volatility(0.005),
+            PositionFeeTier::Medium
+        );
+        assert_eq!(
+            PositionFeeTier::for_hourly_volatility(0.05),
+            PositionFeeTier::High
+        );
+        assert_eq!(resolve_fee_bps(Some(7), None, Some(0.05), 50), 7);
+        assert_eq!(
+            resolve_fee_bps(None, Some(PositionFeeTier::Low), Some(0.05), 50),
+            5
+        );
+        assert_eq!(resolve_fee_bps(None, None, Some(0.05), 50), 100);
+        assert_eq!(resolve_fee_bps(None, None, None, 50), 50);
+    }
 }
@@ -0,0 +1,342 @@
+use rand_core::{CryptoRngCore, OsRng};
+
+use penumbra_sdk_asset::Value;
+use penumbra_sdk_dex::{lp::position::Position, DirectedUnitPair};
+use penumbra_sdk_view::ViewClient;
+
+use super::{PositionFeeTier, VolatilityArgs};
+use crate::dex_utils::replicate::volatility;
+use crate::App;
+
+/// The fee charged by concentrated positions when no fee is specified.
+const DEFAULT_FEE_BPS: u32 = 30;
+
+#[derive(Debug, Clone, clap::Args)]
+pub struct Concentrated {
+    /// The pair to provide liquidity for.
+    pub pair: DirectedUnitPair,
+
+    /// The target amount of liquidity (in asset 2) to provide.
+    ///
+    /// Note that the actual amount of liquidity provided will be a mix of
+    /// asset 1 and asset 2, depending on the current price.
+    pub input: Value,
+
+    /// The lower bound of the price range.
+    ///
+    /// Prices are the amount of asset 2 required to purchase 1 unit of asset 1.
+    /// Required unless `--from-volatility` is set.
+    #[clap(short, long, display_order = 100)]
+    pub lower_price: Option<f64>,
+    /// The upper bound of the price range.
+    ///
+    /// Prices are the amount of asset 2 required to purchase 1 unit of asset 1.
+    /// Required unless `--from-volatility` is set.
+    #[clap(short, long, display_order = 101)]
+    pub upper_price: Option<f64>,
+    /// With `--from-volatility`, the number of standard deviations of expected
+    /// price movement that the range covers below the current price.
+    ///
+    /// Defaults to `--range-std-devs`.
+    #[clap(long, display_order = 102)]
+    pub lower_std_devs: Option<f64>,
+    /// With `--from-volatility`, the number of standard deviations of expected
+    /// price movement that the range covers above the current price.
+    ///
+    /// Defaults to `--range-std-devs`.
+    #[clap(long, display_order = 103)]
+    pub upper_std_devs: Option<f64>,
+
+    /// The percentage fee to apply to each trade, expressed in basis points.
+    ///
+    /// Defaults to 30bps, or to the fee tier suggested by `--from-volatility`.
+    #[clap(short, long, display_order = 200)]
+    pub fee_bps: Option<u32>,
+    /// The fee tier to apply to each trade, as an alternative to `--fee-bps`.
+    #[clap(long, value_enum, conflicts_with = "fee-bps", display_order = 201)]
+    pub fee_tier: Option<PositionFeeTier>,
+
+    /// The number of positions to create.
+    #[clap(short, long, default_value_t = 16, display_order = 300)]
+    pub num_positions: u32,
+    /// How strongly liquidity is concentrated around the current price.
+    ///
+    /// The positions at either bound of the range hold `e^-concentration` as
+    /// much liquidity as those at the current price, so 0 spreads liquidity
+    /// evenly across the range.
+    #[clap(long, default_value_t = 2.0, display_order = 301)]
+    pub concentration: f64,
+
+    /// The current price. If not provided, the current price is fetched from
+    /// the chain.
+    ///
+    /// This is used to center the liquidity, and to determine which positions
+    /// should be funded with asset 1 and which with asset 2.
+    #[clap(short, long, display_order = 400)]
+    pub current_price: Option<f64>,
+
+    /// `--yes` means all prompt interaction are skipped and agreed.
+    #[clap(short, long, display_order = 501)]
+    pub yes: bool,
+
+    /// The account to use to fund the LPs and store the LP tokens.
+    #[clap(long, default_value = "0", display_order = 503)]
+    pub source: u32,
+
+    #[clap(flatten)]
+    pub volatility: VolatilityArgs,
+}
+
+impl Concentrated {
+    pub async fn exec(&self, app: &mut App) -> anyhow::Result<()> {
+        self.validate()?;
+
+        let pair = self.pair.clone();
+
+        let mut asset_cache = app.view().assets().await?;
+        if !asset_cache.contains_key(&pair.start.id()) {
+            asset_cache.extend(std::iter::once(pair.start.base()));
+        }
+        if !asset_cache.contains_key(&pair.end.id()) {
+            asset_cache.extend(std::iter::once(pair.end.base()));
+        }
+
+        let current_price =
+            super::process_price_or_fetch_spread(app, self.current_price, self.pair.clone())
+                .await?;
+
+        tracing::debug!(?self);
+        tracing::debug!(?current_price);
+
+        let vol = self.volatility.fetch(app, &pair).await?;
+        let (lower_price, upper_price) = self.price_range(current_price, vol)?;
+        let fee_bps = super::resolve_fee_bps(self.fee_bps, self.fee_tier, vol, DEFAULT_FEE_BPS);
+        if lower_price >= current_price || current_price >= upper_price {
+            anyhow::bail!(
+                "the current price {current_price} must be within the price range [{lower_price}, {upper_price}]"
+            )
+        } else if fee_bps > 5000 {
+            anyhow::bail!("the maximum fee is 5000bps (50%)")
+        }
+
+        let positions = self.build_positions(
+            OsRng,
+            current_price,
+            lower_price,
+            upper_price,
+            fee_bps,
+            self.input,
+        );
+
+        super::print_summary(&pair, &positions, &asset_cache);
+        super::print_inventory_preview(
+            &pair,
+            &positions,
+            &[
+                ("lower price", lower_price),
+                ("current price", current_price),
+                ("upper price", upper_price),
+            ],
+        );
+
+        super::submit_positions(app, &positions, self.yes, self.source).await
+    }
+
+    /// Returns the price range to provide liquidity over, deriving the bounds
+    /// that weren't specified from the volatility `vol`, if available.
+    fn price_range(&self, current_price: f64, vol: Option<f64>) -> anyhow::Result<(f64, f64)> {
+        let (derived_lower, derived_upper) = match vol {
+            Some(vol) => {
+                let std_devs = self.volatility.range_std_devs;
+                let lower_width = self
+                    .volatility
+                    .range_width(vol, self.lower_std_devs.unwrap_or(std_devs))?;
+                let upper_width = self
+                    .volatility
+                    .range_width(vol, self.upper_std_devs.unwrap_or(std_devs))?;
+                let (lower, upper) =
+                    volatility::price_range(current_price, lower_width, upper_width);
+                (Some(lower), Some(upper))
+            }
+            None => (None, None),
+        };
+
+        let lower_price = self.lower_price.or(derived_lower).ok_or_else(|| {
+            anyhow::anyhow!("specify a lower price with --lower-price, or use --from-volatility")
+        })?;
+        let upper_price = self.upper_price.or(derived_upper).ok_or_else(|| {
+            anyhow::anyhow!("specify an upper price with --upper-price, or use --from-volatility")
+        })?;
+        Ok((lower_price, upper_price))
+    }
+
+    /// Builds positions at geometrically spaced prices across the range, with
+    /// liquidity decaying exponentially with the distance from the current price.
+    ///
+    /// Distances are normalized separately on each side of the current price,
+    /// so both bounds receive the same share of liquidity however asymmetric
+    /// the range is.
+    fn build_positions<R: CryptoRngCore>(
+        &self,
+        mut rng: R,
+        current_price: f64,
+        lower_price: f64,
+        upper_price: f64,
+        fee_bps: u32,
+        input: Value,
+    ) -> Vec<Position> {
+        let step = (upper_price / lower_price).ln() / (self.num_positions - 1) as f64;
+        let prices: Vec<f64> = (0..self.num_positions)
+            .map(|i| lower_price * (step * i as f64).exp())
+            .collect();
+
+        let weights: Vec<f64> = prices
+            .iter()
+            .map(|price| {
+                let distance = if *price < current_price {
+                    (current_price / price).ln() / (current_price / lower_price).ln()
+                } else {
+                    (price / current_price).ln() / (upper_price / current_price).ln()
+                };
+                (-self.concentration * distance).exp()
+            })
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+
+        // We are treating asset 2 as the numeraire, and allocate its value
+        // across positions in proportion to their weights.
+        let total_input = input.amount.value() as f64;
+
+        tracing::debug!(?current_price, ?step, ?total_input, ?weights);
+
+        prices
+            .iter()
+            .zip(weights)
+            .map(|(price, weight)| {
+                super::position_at(
+                    &mut rng,
+                    &self.pair,
+                    *price,
+                    current_price,
+                    total_input * weight / total_weight,
+                    fee_bps,
+                )
+            })
+            .collect()
+    }
+
+    fn validate(&self) -> anyhow::Result<()> {
+        if self.input.asset_id != self.pair.end.id() {
+            anyhow::bail!("liquidity target is specified in terms of asset 2 but provided input is for a different asset")
+        } else if self.input.amount == 0u64.into() {
+            anyhow::bail!("the quantity of liquidity supplied must be non-zero.",)
+        } else if self.fee_bps.is_some_and(|fee_bps| fee_bps > 5000) {
+            anyhow::bail!("the maximum fee is 5000bps (50%)")
+        } else if self.current_price.is_some()
+            && self.current_price.expect("current price is Some") <= 0.0
+        {
+            anyhow::bail!("the supplied current price must be positive")
+        } else if self
+            .lower_price
+            .is_some_and(|lower_price| lower_price <= 0.0)
+        {
+            anyhow::bail!("the lower price must be positive")
+        } else if self
+            .lower_price
+            .zip(self.upper_price)
+            .is_some_and(|(lower_price, upper_price)| lower_price >= upper_price)
+        {
+            anyhow::bail!("the lower price must be less than the upper price")
+        } else if !self.volatility.from_volatility
+            && (self.lower_price.is_none() || self.upper_price.is_none())
+        {
+            anyhow::bail!("specify both --lower-price and --upper-price, or use --from-volatility")
+        } else if [self.lower_std_devs, self.upper_std_devs]
+            .into_iter()
+            .flatten()
+            .any(|std_devs| std_devs <= 0.0)
+        {
+            anyhow::bail!("the number of standard deviations must be positive")
+        } else if self.concentration < 0.0 {
+            anyhow::bail!("the concentration must not be negative")
+        } else if self.num_positions <= 2 {
+            anyhow::bail!("the number of positions must be greater than 2")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::SeedableRng;
+    use rand_chacha::ChaCha20Rng;
+
+    use super::*;
+
+    #[test]
+    fn concentrates_liquidity_around_current_price() {
+        let params = Concentrated {
+            pair: "penumbra:gm".parse().unwrap(),
+            input: "1000gm".parse().unwrap(),
+            lower_price: Some(1.5),
+            upper_price: Some(4.0),
+            lower_std_devs: None,
+            upper_std_devs: None,
+            fee_bps: Some(30),
+            fee_tier: None,
+            num_positions: 8,
+            concentration: 2.0,
+            current_price: Some(1.9),
+            yes: false,
+            source: 0,
+            volatility: VolatilityArgs {
+                from_volatility: false,
+                lookback_hours: 24,
+                range_std_devs: 2.0,
+            },
+        };
+        params.validate().unwrap();
+
+        let mut rng = ChaCha20Rng::seed_from_u64(12345);
+        let positions = params.build_positions(
+            &mut rng,
+            params.current_price.unwrap(),
+            params.lower_price.unwrap(),
+            params.upper_price.unwrap(),
+            params.fee_bps.unwrap(),
+            params.input,
+        );
+        assert_eq!(positions.len(), 8);
+
+        let um_id = params.pair.start.id();
+        let gm_id = params.pair.end.id();
+
+        // The range is asymmetric, so only the lowest two positions are below
+        // the current price, funded with GM, and the rest with UM.
+        let gm: Vec<u128> = positions
+            .iter()
+            .map(|position| position.reserves_for(gm_id).unwrap().value())
+            .collect();
+        let um: Vec<u128> = positions
+            .iter()
+            .map(|position| position.reserves_for(um_id).unwrap().value())
+            .collect();
+        assert!(gm[..2].iter().all(|amount| *amount > 0));
+        assert!(um[..2].iter().all(|amount| *amount == 0));
+        assert!(gm[2..].iter().all(|amount| *amount == 0));
+        assert!(um[2..].iter().all(|amount| *amount > 0));
+
+        // Liquidity decreases away from the current price on both sides.
+        assert!(gm[0] < gm[1]);
+        let um_value: Vec<f64> = positions[2..]
+            .iter()
+            .map(|position| {
+                let phi = position.phi.orient_start(um_id).unwrap();
+                position.reserves_for(um_id).unwrap().value() as f64 * phi.p.value() as f64
+                    / phi.q.value() as f64
+            })
+            .collect();
+        assert!(um_value.windows(2).all(|pair| pair[0] > pair[1]));
+    }
+}
@@ -1,19 +1,16 @@
-use anyhow::Context;
-use dialoguer::Confirm;
 use rand_core::{CryptoRngCore, OsRng};
 
 use penumbra_sdk_asset::Value;
-use penumbra_sdk_dex::{
-    lp::{position::Position, Reserves},
-    DirectedUnitPair,
-};
-use penumbra_sdk_keys::keys::AddressIndex;
-use penumbra_sdk_num::Amount;
-use penumbra_sdk_proto::view::v1::GasPricesRequest;
-use penumbra_sdk_view::{Planner, ViewClient};
+use penumbra_sdk_dex::{lp::position::Position, DirectedUnitPair};
+use penumbra_sdk_view::ViewClient;
 
+use super::{PositionFeeTier, VolatilityArgs};
+use crate::dex_utils::replicate::volatility;
 use crate::App;
 
+/// The fee charged by linear positions when no fee is specified.
+const DEFAULT_FEE_BPS: u32 = 50;
+
 #[derive(Debug, Clone, clap::Args)]
 pub struct Linear {
     /// The pair to provide liquidity for.
@@ -28,17 +25,24 @@ pub struct Linear {
     /// The lower bound of the price range.
     ///
     /// Prices are the amount of asset 2 required to purchase 1 unit of asset 1.
+    /// Required unless `--from-volatility` is set.
     #[clap(short, long, display_order = 100)]
-    pub lower_price: f64,
+    pub lower_price: Option<f64>,
     /// The upper bound of the price range.
     ///
     /// Prices are the amount of asset 2 required to purchase 1 unit of asset 1.
+    /// Required unless `--from-volatility` is set.
     #[clap(short, long, display_order = 101)]
-    pub upper_price: f64,
+    pub upper_price: Option<f64>,
 
     /// The percentage fee to apply to each trade, expressed in basis points.
-    #[clap(short, long, default_value_t = 50u32, display_order = 200)]
-    pub fee_bps: u32,
+    ///
+    /// Defaults to 50bps, or to the fee tier suggested by `--from-volatility`.
+    #[clap(short, long, display_order = 200)]
+    pub fee_bps: Option<u32>,
+    /// The fee tier to apply to each trade, as an alternative to `--fee-bps`.
+    #[clap(long, value_enum, conflicts_with = "fee-bps", display_order = 201)]
+    pub fee_tier: Option<PositionFeeTier>,
 
     /// The number of positions to create.
     #[clap(short, long, default_value_t = 16, display_order = 300)]
@@ -65,6 +69,9 @@ pub struct Linear {
     /// The account to use to fund the LPs and store the LP tokens.
     #[clap(long, default_value = "0", display_order = 503)]
     pub source: u32,
+
+    #[clap(flatten)]
+    pub volatility: VolatilityArgs,
 }
 
 impl Linear {
@@ -91,97 +98,78 @@ impl Linear {
         tracing::debug!(?self);
         tracing::debug!(?current_price);
 
-        let positions = self.build_positions(OsRng, current_price, self.input);
-
-        let (amount_start, amount_end) =
-            positions
-                .iter()
-                .fold((Amount::zero(), Amount::zero()), |acc, pos| {
-                    tracing::debug!(?pos);
-                    (
-                        acc.0
-                            + pos
-                                .reserves_for(pair.start.id())
-                                .expect("start is part of position"),
-                        acc.1
-                            + pos
-                                .reserves_for(pair.end.id())
-                                .expect("end is part of position"),
-                    )
-                });
-        let amount_start = pair.start.format_value(amount_start);
-        let amount_end = pair.end.format_value(amount_end);
+        let vol = self.volatility.fetch(app, &pair).await?;
+        let (lower_price, upper_price) = self.price_range(current_price, vol)?;
+        let fee_bps = super::resolve_fee_bps(self.fee_bps, self.fee_tier, vol, DEFAULT_FEE_BPS);
+        if lower_price >= upper_price {
+            anyhow::bail!("the lower price must be less than the upper price")
+        } else if fee_bps > 5000 {
+            anyhow::bail!("the maximum fee is 5000bps (50%)")
+        }
 
-        println!(
-            "#################################################################################"
-        );
-        println!(
-            "########################### LIQUIDITY SUMMARY ###################################"
+        let positions = self.build_positions(
+            OsRng,
+            current_price,
+            lower_price,
+            upper_price,
+            fee_bps,
+            self.input,
         );
-        println!(
-            "#################################################################################"
-        );
-        println!("\nYou want to provide liquidity on the pair {}", pair);
-        println!("You will need:",);
-        println!(" -> {amount_start}{}", pair.start);
-        println!(" -> {amount_end}{}", pair.end);
 
-        println!("You will create the following positions:");
-        println!(
-            "{}",
-            crate::command::utils::render_positions(&asset_cache, &positions),
+        super::print_summary(&pair, &positions, &asset_cache);
+        super::print_inventory_preview(
+            &pair,
+            &positions,
+            &[
+                ("lower price", lower_price),
+                ("current price", current_price),
+                ("upper price", upper_price),
+            ],
         );
 
-        if !self.yes
-            && !Confirm::new()
-                .with_prompt("Do you want to open those liquidity positions on-chain?")
-                .interact()?
-        {
-            return Ok(());
-        }
+        super::submit_positions(app, &positions, self.yes, self.source).await
+    }
 
-        let gas_prices = app
-            .view
-            .as_mut()
-            .context("view service must be initialized")?
-            .gas_prices(GasPricesRequest {})
-            .await?
-            .into_inner()
-            .gas_prices
-            .expect("gas prices must be available")
-            .try_into()?;
-
-        let mut planner = Planner::new(OsRng);
-        planner.set_gas_prices(gas_prices);
-        positions.iter().for_each(|position| {
-            planner.position_open(position.clone());
-        });
-
-        let plan = planner
-            .plan(
-                app.view
-                    .as_mut()
-                    .context("view service must be initialized")?,
-                AddressIndex::new(self.source),
-            )
-            .await?;
-        let tx_id = app.build_and_submit_transaction(plan).await?;
-        println!("posted with transaction id: {tx_id}");
-
-        Ok(())
+    /// Returns the price range to provide liquidity over, deriving the bounds
+    /// that weren't specified from the volatility `vol`, if available.
+    fn price_range(&self, current_price: f64, vol: Option<f64>) -> anyhow::Result<(f64, f64)> {
+        let width = vol
+            .map(|vol| {
+                self.volatility
+                    .range_width(vol, self.volatility.range_std_devs)
+            })
+            .transpose()?;
+        let (derived_lower, derived_upper) = match width {
+            Some(width) => {
+                let (lower, upper) = volatility::price_range(current_price, width, width);
+                (Some(lower), Some(upper))
+            }
+            None => (None, None),
+        };
+
+        let lower_price = self.lower_price.or(derived_lower).ok_or_else(|| {
+            anyhow::anyhow!("specify a lower price with --lower-price, or use --from-volatility")
+        })?;
+        let upper_price = self.upper_price.or(derived_upper).ok_or_else(|| {
+            anyhow::anyhow!("specify an upper price with --upper-price, or use --from-volatility")
+        })?;
+        Ok((lower_price, upper_price))
     }
 
     fn build_positions<R: CryptoRngCore>(
         &self,
         mut rng: R,
         current_price: f64,
+        lower_price: f64,
+        upper_price: f64,
+        fee_bps: u32,
         input: Value,
     ) -> Vec<Position> {
         // The step width is num_positions-1 because it's between the endpoints
         // |---|---|---|---|
         // 0   1   2   3   4
         //   0   1   2   3
-        let step_width = (self.upper_price - self.lower_price) / (self.num_positions - 1) as f64;
+        let step_width = (upper_price - lower_price) / (self.num_positions - 1) as f64;
 
         // We are treating asset 2 as the numeraire and want to have an even spread
         // of asset 2 value across all positions.
@@ -195,55 +183,19 @@ impl Linear {
             ?asset_2_per_position
         );
 
-        let mut positions = vec![];
-
-        let dtp = self.pair.into_directed_trading_pair();
-
-        for i in 0..self.num_positions {
-            let position_price = self.lower_price + step_width * i as f64;
-
-            // Cross-multiply exponents and prices for trading function coefficients
-            //
-            // We want to write
-            // p = EndUnit * price
-            // q = StartUnit
-            // However, if EndUnit is too small, it might not round correctly after multiplying by price
-            // To handle this, conditionally apply a scaling factor if the EndUnit amount is too small.
-            let scale = if self.pair.end.unit_amount().value() < 1_000_000 {
-                1_000_000
-            } else {
-                1
-            };
-
-            let p = Amount::from(
-                ((self.pair.end.unit_amount().value() * scale) as f64 * position_price) as u128,
-            );
-            let q = self.pair.start.unit_amount() * Amount::from(scale);
-
-            // Compute reserves
-            let reserves = if position_price < current_price {
-                // If the position's price is _less_ than the current price, fund it with asset 2
-                // so the position isn't immediately arbitraged.
-                Reserves {
-                    r1: Amount::zero(),
-                    r2: Amount::from(asset_2_per_position as u128),
-                }
-            } else {
-                // If the position's price is _greater_ than the current price, fund it with
-                // an equivalent amount of asset 1 as the target per-position amount of asset 2.
-                let asset_1 = asset_2_per_position / position_price;
-                Reserves {
-                    r1: Amount::from(asset_1 as u128),
-                    r2: Amount::zero(),
-                }
-            };
-
-            let position = Position::new(&mut rng, dtp, self.fee_bps, p, q, reserves);
-
-            positions.push(position);
-        }
-
-        positions
+        (0..self.num_positions)
+            .map(|i| {
+                let position_price = lower_price + step_width * i as f64;
+                super::position_at(
+                    &mut rng,
+                    &self.pair,
+                    position_price,
+                    current_price,
+                    asset_2_per_position,
+                    fee_bps,
+                )
+            })
+            .collect()
     }
 
     fn validate(&self) -> anyhow::Result<()> {
@@ -251,14 +203,22 @@ impl Linear {
             anyhow::bail!("liquidity target is specified in terms of asset 2 but provided input is for a different asset")
         } else if self.input.amount == 0u64.into() {
             anyhow::bail!("the quantity of liquidity supplied must be non-zero.",)
-        } else if self.fee_bps > 5000 {
+        } else if self.fee_bps.is_some_and(|fee_bps| fee_bps > 5000) {
             anyhow::bail!("the maximum fee is 5000bps (50%)")
         } else if self.current_price.is_some()
             && self.current_price.expect("current price is Some") <= 0.0
         {
             anyhow::bail!("the supplied current price must be positive")
-        } else if self.lower_price >= self.upper_price {
+        } else if self
+            .lower_price
+            .zip(self.upper_price)
+            .is_some_and(|(lower_price, upper_price)| lower_price >= upper_price)
+        {
             anyhow::bail!("the lower price must be less than the upper price")
+        } else if !self.volatility.from_volatility
+            && (self.lower_price.is_none() || self.upper_price.is_none())
+        {
+            anyhow::bail!("specify both --lower-price and --upper-price, or use --from-volatility")
         } else if self.num_positions <= 2 {
             anyhow::bail!("the number of positions must be greater than 2")
         } else {
@@ -279,14 +239,20 @@ mod tests {
         let params = Linear {
             pair: "penumbra:gm".parse().unwrap(),
             input: "1000gm".parse().unwrap(),
-            lower_price: 1.8,
-            upper_price: 2.2,
-            fee_bps: 50,
+            lower_price: Some(1.8),
+            upper_price: Some(2.2),
+            fee_bps: Some(50),
+            fee_tier: None,
             num_positions: 5,
             current_price: Some(2.05),
             close_on_fill: false,
             yes: false,
             source: 0,
+            volatility: VolatilityArgs {
+                from_volatility: false,
+                lookback_hours: 24,
+                range_std_devs: 2.0,
+            },
         };
 
         let mut rng = ChaCha20Rng::seed_from_u64(12345);
@@ -294,6 +260,9 @@ mod tests {
         let positions = params.build_positions(
             &mut rng,
             params.current_price.unwrap(),
+            params.lower_price.unwrap(),
+            params.upper_price.unwrap(),
+            params.fee_bps.unwrap(),
             params.input.clone(),
         );
 
@@ -1,17 +1,15 @@
 use std::io::Write;
 use std::path::PathBuf;
 
-use anyhow::{anyhow, Context};
+use anyhow::anyhow;
 use dialoguer::Confirm;
-use rand_core::OsRng;
 
 use penumbra_sdk_asset::Value;
 use penumbra_sdk_dex::{lp::position::Position, DirectedUnitPair};
-use penumbra_sdk_keys::keys::AddressIndex;
-use penumbra_sdk_num::{fixpoint::U128x128, Amount};
-use penumbra_sdk_proto::view::v1::GasPricesRequest;
-use penumbra_sdk_view::{Planner, ViewClient};
+use penumbra_sdk_num::fixpoint::U128x128;
+use penumbra_sdk_view::ViewClient;
 
+use super::{PositionFeeTier, VolatilityArgs};
 use crate::dex_utils;
 use crate::dex_utils::replicate::debug;
 use crate::{warning, App};
@@ -24,8 +22,14 @@ pub struct ConstantProduct {
     #[clap(short, long)]
     pub current_price: Option<f64>,
 
-    #[clap(short, long, default_value_t = 0u32)]
-    pub fee_bps: u32,
+    /// The percentage fee to apply to each trade, expressed in basis points.
+    ///
+    /// Defaults to 0bps, or to the fee tier suggested by `--from-volatility`.
+    #[clap(short, long)]
+    pub fee_bps: Option<u32>,
+    /// The fee tier to apply to each trade, as an alternative to `--fee-bps`.
+    #[clap(long, value_enum, conflicts_with = "fee-bps")]
+    pub fee_tier: Option<PositionFeeTier>,
     /// `--yes` means all prompt interaction are skipped and agreed.
     #[clap(short, long)]
     pub yes: bool,
@@ -34,6 +38,9 @@ pub struct ConstantProduct {
     pub debug_file: Option<PathBuf>,
     #[clap(long, default_value = "0", hide(true))]
     pub source: u32,
+
+    #[clap(flatten)]
+    pub volatility: VolatilityArgs,
 }
 
 impl ConstantProduct {
@@ -44,31 +51,21 @@ impl ConstantProduct {
             super::process_price_or_fetch_spread(app, self.current_price, self.pair.clone())
                 .await?;
 
+        // The positions of an xy=k curve span a fixed set of prices around the
+        // current one, so volatility only informs the fee.
+        let vol = self.volatility.fetch(app, &pair).await?;
+        let fee_bps = super::resolve_fee_bps(self.fee_bps, self.fee_tier, vol, 0);
+        if fee_bps > 5000 {
+            anyhow::bail!("the maximum fee is 5000bps (50%)")
+        }
+
         let positions = dex_utils::replicate::xyk::replicate(
             &pair,
             &self.input,
             current_price.try_into()?,
-            self.fee_bps,
+            fee_bps,
         )?;
 
-        let (amount_start, amount_end) =
-            positions
-                .iter()
-                .fold((Amount::zero(), Amount::zero()), |acc, pos| {
-                    (
-                        acc.0
-                            + pos
-                                .reserves_for(pair.start.id())
-                                .expect("start is part of position"),
-                        acc.1
-                            + pos
-                                .reserves_for(pair.end.id())
-                                .expect("end is part of position"),
-                    )
-                });
-        let amount_start = pair.start.format_value(amount_start);
-        let amount_end = pair.end.format_value(amount_end);
-
         warning::rmm();
 
         if !self.yes
@@ -79,26 +76,19 @@ impl ConstantProduct {
             return Ok(());
         }
         println!("\nso it shall be...\n\n");
-        println!(
-            "#################################################################################"
-        );
-        println!(
-            "########################### LIQUIDITY SUMMARY ###################################"
-        );
-        println!(
-            "#################################################################################"
-        );
-        println!("\nYou want to provide liquidity on the pair {}", pair);
-        println!("You will need:",);
-        println!(" -> {amount_start}{}", pair.start);
-        println!(" -> {amount_end}{}", pair.end);
         // TODO(erwan): would be nice to print current balance?
-
-        println!("You will create the following positions:");
         let asset_cache = app.view().assets().await?;
-        println!(
-            "{}",
-            crate::command::utils::render_positions(&asset_cache, &positions),
+        super::print_summary(&pair, &positions, &asset_cache);
+
+        let (lowest_price, highest_price) = Self::price_bounds(&pair, &positions);
+        super::print_inventory_preview(
+            &pair,
+            &positions,
+            &[
+                ("lowest position price", lowest_price),
+                ("current price", current_price),
+                ("highest position price", highest_price),
+            ],
         );
 
         if let Some(debug_file) = &self.debug_file {
@@ -112,43 +102,22 @@ impl ConstantProduct {
             return Ok(());
         }
 
-        if !self.yes
-            && !Confirm::new()
-                .with_prompt("Do you want to open those liquidity positions on-chain?")
-                .interact()?
-        {
-            return Ok(());
-        }
-
-        let gas_prices = app
-            .view
-            .as_mut()
-            .context("view service must be initialized")?
-            .gas_prices(GasPricesRequest {})
-            .await?
-            .into_inner()
-            .gas_prices
-            .expect("gas prices must be available")
-            .try_into()?;
-
-        let mut planner = Planner::new(OsRng);
-        planner.set_gas_prices(gas_prices);
-        positions.iter().for_each(|position| {
-            planner.position_open(position.clone());
-        });
-
-        let plan = planner
-            .plan(
-                app.view
-                    .as_mut()
-                    .context("view service must be initialized")?,
-                AddressIndex::new(self.source),
-            )
-            .await?;
-        let tx_id = app.build_and_submit_transaction(plan).await?;
-        println!("posted with transaction id: {tx_id}");
+        super::submit_positions(app, &positions, self.yes, self.source).await
+    }
 
-        Ok(())
+    /// Returns the lowest and highest prices quoted by `positions`.
+    ///
+    /// Prices are the amount of asset 2 required to purchase 1 unit of asset 1.
+    fn price_bounds(pair: &DirectedUnitPair, positions: &[Position]) -> (f64, f64) {
+        let unit_ratio =
+            pair.start.unit_amount().value() as f64 / pair.end.unit_amount().value() as f64;
+        positions
+            .iter()
+            .filter_map(|position| position.phi.orient_start(pair.start.id()))
+            .map(|phi| phi.p.value() as f64 / phi.q.value() as f64 * unit_ratio)
+            .fold((f64::INFINITY, 0.0), |(lowest, highest), price| {
+                (lowest.min(price), highest.max(price))
+            })
     }
 
     fn validate(&self) -> anyhow::Result<()> {
@@ -157,7 +126,7 @@ impl ConstantProduct {
             anyhow::bail!("you must supply liquidity with an asset that's part of the market")
         } else if self.input.amount == 0u64.into() {
             anyhow::bail!("the quantity of liquidity supplied must be non-zero.",)
-        } else if self.fee_bps > 5000 {
+        } else if self.fee_bps.is_some_and(|fee_bps| fee_bps > 5000) {
             anyhow::bail!("the maximum fee is 5000bps (50%)")
         } else if self.current_price.is_some()
             && self.current_price.expect("current price is Some") <= 0.0
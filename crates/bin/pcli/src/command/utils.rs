@@ -1,6 +1,19 @@
+use std::collections::BTreeSet;
+
+use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
 use penumbra_sdk_asset::{asset, Value};
 use penumbra_sdk_dex::lp::position::Position;
+use penumbra_sdk_governance::{
+    proposal_nft::{self, ProposalNft},
+    proposal_state::{Outcome, State as ProposalState},
+};
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_proto::core::component::governance::v1::{
+    query_service_client::QueryServiceClient as GovernanceQueryServiceClient, ProposalDataRequest,
+};
+use penumbra_sdk_view::ViewClient;
+use tonic::transport::Channel;
 
 pub(crate) fn render_positions(asset_cache: &asset::Cache, positions: &[Position]) -> String {
     let mut table = Table::new();
@@ -102,3 +115,86 @@ pub(crate) fn render_positions(asset_cache: &asset::Cache, positions: &[Position
 
     format!("{table}")
 }
+
+/// A deposit for a finished proposal which the wallet holds the proposal NFT for.
+#[derive(Debug, Clone)]
+pub(crate) struct ClaimableDeposit {
+    /// The account holding the proposal NFT.
+    pub account: u32,
+    /// The id of the proposal.
+    pub proposal_id: u64,
+    /// The amount deposited with the proposal.
+    pub deposit_amount: Amount,
+    /// The outcome of the proposal.
+    pub outcome: Outcome<()>,
+}
+
+/// Find the proposal deposits held by the wallet which can be claimed.
+///
+/// A deposit claim consumes the `deposit` or `unbonding_deposit` proposal NFT, so each of these
+/// in view storage is a candidate; it can be claimed once its proposal has finished voting.
+pub(crate) async fn claimable_deposits<V: ViewClient + ?Sized>(
+    view: &mut V,
+    channel: Channel,
+) -> Result<Vec<ClaimableDeposit>> {
+    let asset_cache = view.assets().await?;
+    let notes = view.unspent_notes_by_account_and_asset().await?;
+
+    let mut candidates = BTreeSet::new();
+    for (account, notes_by_asset) in notes {
+        for (asset_id, records) in notes_by_asset {
+            let Some(denom) = asset_cache.get(&asset_id) else {
+                continue;
+            };
+            let Ok(nft) = ProposalNft::try_from(denom.clone()) else {
+                continue;
+            };
+            let unclaimed = matches!(
+                nft.proposal_state(),
+                proposal_nft::Kind::Deposit | proposal_nft::Kind::UnbondingDeposit
+            );
+            if unclaimed && records.iter().any(|r| r.note.amount() > Amount::zero()) {
+                candidates.insert((account, nft.proposal_id()));
+            }
+        }
+    }
+
+    let mut client = GovernanceQueryServiceClient::new(channel);
+    let mut claimable = Vec::new();
+    for (account, proposal_id) in candidates {
+        let proposal = client
+            .proposal_data(ProposalDataRequest { proposal_id })
+            .await?
+            .into_inner();
+        let state: ProposalState = proposal
+            .state
+            .context(format!(
+                "proposal state for proposal {} was not found",
+                proposal_id
+            ))?
+            .try_into()?;
+
+        // Proposals which are still voting (or were withdrawn, but haven't finished voting yet)
+        // can't have their deposits claimed yet.
+        let ProposalState::Finished { outcome } = state else {
+            continue;
+        };
+
+        let deposit_amount: Amount = proposal
+            .proposal_deposit_amount
+            .context(format!(
+                "proposal deposit amount for proposal {} was not found",
+                proposal_id
+            ))?
+            .try_into()?;
+
+        claimable.push(ClaimableDeposit {
+            account,
+            proposal_id,
+            deposit_amount,
+            outcome: outcome.map(|_| ()),
+        });
+    }
+
+    Ok(claimable)
+}
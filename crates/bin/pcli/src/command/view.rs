@@ -1,5 +1,6 @@
 use anyhow::Result;
 
+use accounts::AccountsCmd;
 use address::AddressCmd;
 use balance::BalanceCmd;
 use lps::LiquidityPositionsCmd;
@@ -13,6 +14,7 @@ use crate::App;
 
 use self::auction::AuctionCmd;
 
+mod accounts;
 mod address;
 mod auction;
 mod balance;
@@ -30,6 +32,11 @@ pub enum ViewCmd {
     Auction(AuctionCmd),
     /// View your wallet id
     WalletId(WalletIdCmd),
+    /// View the BIP44 accounts of your seed phrase that account discovery found to have history.
+    ///
+    /// This home only tracks the account it was initialized with; the others are listed so they
+    /// can be initialized in separate homes, with `--bip44-account`.
+    Accounts(AccountsCmd),
     /// View one of your addresses, either by numerical index, or a random ephemeral one.
     Address(AddressCmd),
     /// View the Noble forwarding address associated with one of your addresses, either by numerical index, or a random ephemeral one.
@@ -60,6 +67,7 @@ impl ViewCmd {
         match self {
             ViewCmd::Auction(auction_cmd) => auction_cmd.offline(),
             ViewCmd::WalletId(wallet_id_cmd) => wallet_id_cmd.offline(),
+            ViewCmd::Accounts(accounts_cmd) => accounts_cmd.offline(),
             ViewCmd::Address(address_cmd) => address_cmd.offline(),
             ViewCmd::NobleAddress(address_cmd) => address_cmd.offline(),
             ViewCmd::Balance(balance_cmd) => balance_cmd.offline(),
@@ -99,6 +107,9 @@ impl ViewCmd {
             ViewCmd::Reset(_reset) => {
                 // The wallet has already been reset by a short-circuiting path.
             }
+            ViewCmd::Accounts(_accounts_cmd) => {
+                // The accounts have already been listed by a short-circuiting path.
+            }
             ViewCmd::Address(address_cmd) => {
                 address_cmd.exec(&full_viewing_key)?;
             }
@@ -106,8 +117,9 @@ impl ViewCmd {
                 noble_address_cmd.exec(&full_viewing_key)?;
             }
             ViewCmd::Balance(balance_cmd) => {
+                let channel = app.pd_channel().await?;
                 let view_client = app.view();
-                balance_cmd.exec(view_client).await?;
+                balance_cmd.exec(view_client, channel).await?;
             }
             ViewCmd::Staked(staked_cmd) => {
                 let channel = app.pd_channel().await?;
@@ -0,0 +1,36 @@
+use anyhow::Result;
+use camino::Utf8Path;
+
+use penumbra_sdk_view::Storage;
+
+#[derive(Debug, clap::Parser)]
+pub struct AccountsCmd {}
+
+impl AccountsCmd {
+    /// Determine if this command requires a network sync before it executes.
+    pub fn offline(&self) -> bool {
+        true
+    }
+
+    /// Reads the local view database directly, since discovery results are not served over the
+    /// view service.
+    pub async fn exec(&self, data_path: impl AsRef<Utf8Path>) -> Result<()> {
+        let view_path = data_path.as_ref().join(crate::VIEW_FILE_NAME);
+        if !view_path.is_file() {
+            anyhow::bail!("No view data exists at {}", view_path);
+        }
+
+        let accounts = Storage::load(&view_path)
+            .await?
+            .discovered_accounts()
+            .await?;
+        if accounts.is_empty() {
+            println!("No accounts have been discovered; run `pcli init soft-kms import-phrase --discover-accounts` to scan for them.");
+        }
+        for account in accounts {
+            println!("{account}");
+        }
+
+        Ok(())
+    }
+}
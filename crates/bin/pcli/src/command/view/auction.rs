@@ -1,11 +1,12 @@
 use anyhow::Result;
 use comfy_table::{presets, Cell, ContentArrangement, Table};
 use penumbra_sdk_auction::auction::dutch::DutchAuction;
+use penumbra_sdk_auction::auction::twap::TwapAuction;
 use penumbra_sdk_keys::FullViewingKey;
 use penumbra_sdk_proto::{core::component::auction::v1 as pb_auction, DomainType, Name};
 use penumbra_sdk_view::ViewClient;
 
-use crate::command::query::auction::render_dutch_auction;
+use crate::command::query::auction::{render_dutch_auction, render_twap_auction};
 
 #[derive(Debug, clap::Args)]
 pub struct AuctionCmd {
@@ -51,8 +52,20 @@ impl AuctionCmd {
                     )
                     .await
                     .expect("no rendering errors");
+                } else if pb_auction_state.type_url == pb_auction::TwapAuction::type_url() {
+                    let twap_auction = TwapAuction::decode(pb_auction_state.value)
+                        .expect("no deserialization error");
+                    let asset_cache = view_client.assets().await?;
+                    render_twap_auction(
+                        &asset_cache,
+                        &twap_auction,
+                        Some(local_seq),
+                        positions.get(0).cloned(),
+                    )
+                    .await
+                    .expect("no rendering errors");
                 } else {
-                    unimplemented!("only supporting dutch auctions at the moment, come back later");
+                    unimplemented!("unsupported auction type: {}", pb_auction_state.type_url);
                 }
             } else {
                 let position_ids: Vec<String> = positions
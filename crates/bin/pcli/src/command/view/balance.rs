@@ -1,9 +1,14 @@
+use std::collections::BTreeMap;
+
 use anyhow::Result;
 use comfy_table::{presets, Table};
 
 use penumbra_sdk_keys::AddressView;
 use penumbra_sdk_sct::CommitmentSource;
 use penumbra_sdk_view::ViewClient;
+use tonic::transport::Channel;
+
+use crate::command::utils::claimable_deposits;
 
 #[derive(Debug, clap::Args)]
 pub struct BalanceCmd {
@@ -17,7 +22,7 @@ impl BalanceCmd {
         false
     }
 
-    pub async fn exec<V: ViewClient>(&self, view: &mut V) -> Result<()> {
+    pub async fn exec<V: ViewClient>(&self, view: &mut V, channel: Channel) -> Result<()> {
         let asset_cache = view.assets().await?;
 
         // Initialize the table
@@ -63,8 +68,6 @@ impl BalanceCmd {
             }
 
             println!("{table}");
-
-            return Ok(());
         } else {
             table.set_header(vec!["Account", "Amount"]);
 
@@ -93,9 +96,35 @@ impl BalanceCmd {
             }
 
             println!("{table}");
+        }
 
-            return Ok(());
+        // Remind the user of any proposal deposits they can claim. This is only a reminder, so a
+        // failure to check shouldn't prevent showing the balance.
+        let deposits = claimable_deposits(view, channel)
+            .await
+            .unwrap_or_else(|error| {
+                tracing::warn!(?error, "unable to check for claimable proposal deposits");
+                Vec::new()
+            });
+        let mut claimable = BTreeMap::<u32, Vec<u64>>::new();
+        for deposit in deposits {
+            claimable
+                .entry(deposit.account)
+                .or_default()
+                .push(deposit.proposal_id);
         }
+        for (account, proposal_ids) in claimable {
+            let proposal_ids = proposal_ids
+                .iter()
+                .map(|id| format!("#{id}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            println!(
+                "Account {account} can claim the deposits for finished proposals {proposal_ids} with `pcli tx proposal claim-all --source {account}`."
+            );
+        }
+
+        Ok(())
     }
 }
 
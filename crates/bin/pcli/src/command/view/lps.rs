@@ -1,5 +1,7 @@
 use anyhow::{anyhow, Result};
+use comfy_table::{presets, Table};
 use futures::stream::TryStreamExt;
+use penumbra_sdk_asset::{asset, Balance, Value};
 use penumbra_sdk_dex::lp::position::{Position, State};
 use penumbra_sdk_proto::core::component::dex::v1::{
     query_service_client::QueryServiceClient as DexQueryServiceClient,
@@ -10,7 +12,20 @@ use penumbra_sdk_view::ViewClient;
 use crate::{command::utils, App};
 
 #[derive(Debug, clap::Args)]
-pub struct LiquidityPositionsCmd {}
+pub struct LiquidityPositionsCmd {
+    /// Show the fees, rewards and profit and loss of every position you
+    /// control, including closed and withdrawn ones.
+    ///
+    /// Profit and loss is measured against holding the initial reserves of a
+    /// position, valuing both at current prices. Full nodes only keep recent
+    /// position activity, which this caches, so anything that depends on
+    /// activity that wasn't cached in time is shown as unknown.
+    #[clap(long)]
+    pub pnl: bool,
+    /// The asset to value positions in, with `--pnl`.
+    #[clap(long, default_value = "penumbra", requires = "pnl")]
+    pub numeraire: String,
+}
 
 impl LiquidityPositionsCmd {
     pub fn offline(&self) -> bool {
@@ -18,6 +33,10 @@ impl LiquidityPositionsCmd {
     }
 
     pub async fn exec(&self, app: &mut App) -> Result<()> {
+        if self.pnl {
+            return self.exec_pnl(app).await;
+        }
+
         let my_position_ids = app
             .view()
             .owned_position_ids(Some(State::Opened), None, None)
@@ -45,4 +64,67 @@ impl LiquidityPositionsCmd {
 
         Ok(())
     }
+
+    async fn exec_pnl(&self, app: &mut App) -> Result<()> {
+        let numeraire = asset::REGISTRY.parse_unit(&self.numeraire).id();
+        let pnls = app.view().position_pnl(numeraire, vec![], None).await?;
+        let asset_cache = app.view().assets().await?;
+
+        let missing = |known: bool| if known { "unpriced" } else { "unknown" }.to_string();
+        let format_value = |value: Option<Value>, known: bool| match value {
+            Some(value) => value.format(&asset_cache),
+            None => missing(known),
+        };
+        let format_balance = |balance: Option<&Balance>, known: bool| match balance {
+            Some(balance) => match (balance.provided().next(), balance.required().next()) {
+                (Some(gain), _) => format!("+{}", gain.format(&asset_cache)),
+                (None, Some(loss)) => format!("-{}", loss.format(&asset_cache)),
+                (None, None) => "0".to_string(),
+            },
+            None => missing(known),
+        };
+
+        let mut table = Table::new();
+        table.load_preset(presets::NOTHING);
+        table.set_header(vec![
+            "ID",
+            "State",
+            "Executions",
+            "Fees",
+            "Rewards",
+            "Realized PnL",
+            "Unrealized PnL",
+            "Total PnL",
+        ]);
+
+        for pnl in &pnls {
+            let withdrawn = matches!(pnl.position.state, State::Withdrawn { .. });
+            let realized_known =
+                !withdrawn || (pnl.withdrawn.is_some() && pnl.initial_reserves.is_some());
+            let unrealized_known = withdrawn || pnl.initial_reserves.is_some();
+            let executions = match pnl.executions {
+                Some(executions) => executions.to_string(),
+                None => missing(false),
+            };
+            let total = pnl
+                .realized_pnl
+                .clone()
+                .zip(pnl.unrealized_pnl.clone())
+                .map(|(realized, unrealized)| realized + unrealized);
+            table.add_row(vec![
+                pnl.position.id().to_string(),
+                pnl.position.state.to_string(),
+                executions,
+                format_value(pnl.fees_value, pnl.fees.is_some()),
+                format_value(pnl.rewards_value, pnl.rewards.is_some()),
+                format_balance(pnl.realized_pnl.as_ref(), realized_known),
+                format_balance(pnl.unrealized_pnl.as_ref(), unrealized_known),
+                format_balance(total.as_ref(), realized_known && unrealized_known),
+            ]);
+        }
+
+        println!("{table}");
+
+        Ok(())
+    }
 }
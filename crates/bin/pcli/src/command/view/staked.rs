@@ -1,20 +1,45 @@
 use std::collections::BTreeMap;
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use comfy_table::{presets, Table};
 use futures::TryStreamExt;
 use tonic::transport::Channel;
 
+use penumbra_sdk_app::params::AppParameters;
 use penumbra_sdk_asset::{Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_sdk_keys::FullViewingKey;
-use penumbra_sdk_proto::core::component::stake::v1::{
-    query_service_client::QueryServiceClient as StakeQueryServiceClient, ValidatorInfoRequest,
+use penumbra_sdk_proto::core::{
+    app::v1::{
+        query_service_client::QueryServiceClient as AppQueryServiceClient, AppParametersRequest,
+    },
+    component::{
+        sct::v1::{
+            query_service_client::QueryServiceClient as SctQueryServiceClient, EpochByHeightRequest,
+        },
+        stake::v1::{
+            query_service_client::QueryServiceClient as StakeQueryServiceClient,
+            ValidatorHistoryRequest, ValidatorInfoRequest,
+        },
+    },
+};
+use penumbra_sdk_sct::epoch::Epoch;
+use penumbra_sdk_stake::{
+    rate::RateData,
+    validator::{self, EpochSummary},
+    DelegationToken, IdentityKey, UnbondingToken,
 };
-use penumbra_sdk_stake::{validator, DelegationToken};
 use penumbra_sdk_view::ViewClient;
 
+/// The approximate duration of a block, used to annualize the trailing rewards.
+const APPROX_BLOCK_SECONDS: f64 = 5.0;
+const SECONDS_PER_YEAR: f64 = 365.0 * 24.0 * 60.0 * 60.0;
+
 #[derive(Debug, clap::Parser)]
-pub struct StakedCmd {}
+pub struct StakedCmd {
+    /// The number of trailing epochs over which to compute each validator's APY.
+    #[clap(long, default_value_t = 10)]
+    apy_epochs: u64,
+}
 
 impl StakedCmd {
     pub fn offline(&self) -> bool {
@@ -29,7 +54,20 @@ impl StakedCmd {
     ) -> Result<()> {
         let asset_cache = view_client.assets().await?;
 
-        let mut client = StakeQueryServiceClient::new(pd_channel);
+        let mut client = StakeQueryServiceClient::new(pd_channel.clone());
+        let mut sct_client = SctQueryServiceClient::new(pd_channel.clone());
+
+        let params: AppParameters = AppQueryServiceClient::new(pd_channel)
+            .app_parameters(tonic::Request::new(AppParametersRequest {}))
+            .await?
+            .into_inner()
+            .app_parameters
+            .ok_or_else(|| anyhow::anyhow!("empty AppParametersResponse message"))?
+            .try_into()?;
+        let epoch_duration = params.sct_params.epoch_duration;
+
+        let current_height = view_client.status().await?.full_sync_height;
+        let current_epoch = epoch_by_height(&mut sct_client, current_height).await?;
 
         let validators = client
             .validator_info(ValidatorInfoRequest {
@@ -47,13 +85,26 @@ impl StakedCmd {
         let notes = view_client.unspent_notes_by_asset_and_address().await?;
         let mut total = 0u128;
 
+        // Epoch indices of the heights at which notes were created, to avoid
+        // looking up the same height twice.
+        let mut epochs_by_height = BTreeMap::<u64, u64>::new();
+
         let mut table = Table::new();
         table.load_preset(presets::NOTHING);
-        table.set_header(vec!["Name", "Value", "Exch. Rate", "Tokens"]);
-        table
-            .get_column_mut(1)
-            .expect("column 1 exists")
-            .set_cell_alignment(comfy_table::CellAlignment::Right);
+        table.set_header(vec![
+            "Name",
+            "Value",
+            "Exch. Rate",
+            "Tokens",
+            "Rewards (min)",
+            format!("APY ({}ep)", self.apy_epochs).as_str(),
+        ]);
+        for column in [1, 4, 5] {
+            table
+                .get_column_mut(column)
+                .expect("column exists")
+                .set_cell_alignment(comfy_table::CellAlignment::Right);
+        }
 
         for (asset_id, notes_by_address) in notes.iter() {
             let dt = if let Some(Ok(dt)) = asset_cache
@@ -84,6 +135,8 @@ impl StakedCmd {
                         "missing data".to_string(),
                         "missing data".to_string(),
                         delegation.format(&asset_cache),
+                        "missing data".to_string(),
+                        "missing data".to_string(),
                     ]);
                     continue;
                 }
@@ -103,11 +156,74 @@ impl StakedCmd {
                 validator_exchange_rate / 1_0000_0000.0
             };
 
+            // Find the epoch in which each note of delegation tokens was created. This is
+            // not necessarily when the stake was delegated: a note created by sending
+            // delegation tokens to ourselves only accrues rewards from then on, so the
+            // rewards computed below are a lower bound.
+            let mut acquisitions = Vec::new();
+            for note in notes_by_address.values().flatten() {
+                let epoch_index = match epochs_by_height.get(&note.height_created) {
+                    Some(index) => *index,
+                    None => {
+                        let index = epoch_by_height(&mut sct_client, note.height_created)
+                            .await?
+                            .index;
+                        epochs_by_height.insert(note.height_created, index);
+                        index
+                    }
+                };
+                acquisitions.push((epoch_index, note.note.amount()));
+            }
+
+            // Fetch the validator's rate data in every epoch we need, from the earliest
+            // acquisition or the start of the APY window, whichever comes first.
+            let apy_start_epoch = current_epoch.index.saturating_sub(self.apy_epochs);
+            let start_epoch = acquisitions
+                .iter()
+                .map(|(epoch_index, _)| *epoch_index)
+                .min()
+                .unwrap_or(apy_start_epoch)
+                .min(apy_start_epoch);
+            let mut rates =
+                rates_by_epoch(&mut client, &info.validator.identity_key, start_epoch).await?;
+            rates.insert(current_epoch.index, info.rate_data.clone());
+
+            // The unrealized rewards are the difference between the current value of the
+            // delegation tokens and their value in the epoch their note was created in.
+            let rewards = acquisitions
+                .iter()
+                .map(|(epoch_index, amount)| {
+                    let acquired = rates.get(epoch_index)?.unbonded_amount(*amount).value();
+                    let current = info.rate_data.unbonded_amount(*amount).value();
+                    Some(current as i128 - acquired as i128)
+                })
+                .sum::<Option<i128>>();
+            let rewards = match rewards {
+                Some(rewards) => format_signed_stake(rewards, &asset_cache),
+                None => "unknown".to_string(),
+            };
+
+            let apy = match rates.range(apy_start_epoch..current_epoch.index).next() {
+                Some((then_index, then_rate)) => format!(
+                    "{:.2}%",
+                    100.0
+                        * trailing_apy(
+                            then_rate,
+                            &info.rate_data,
+                            current_epoch.index - then_index,
+                            epoch_duration,
+                        )
+                ),
+                None => "-".to_string(),
+            };
+
             table.add_row(vec![
                 info.validator.name.clone(),
                 unbonded.format(&asset_cache),
                 format!("{rate:.4}"),
                 delegation.format(&asset_cache),
+                rewards,
+                apy,
             ]);
 
             total += u128::from(unbonded.amount);
@@ -131,6 +247,8 @@ impl StakedCmd {
             unbonded.format(&asset_cache),
             format!("{:.4}", 1.0),
             unbonded.format(&asset_cache),
+            String::new(),
+            String::new(),
         ]);
 
         let total = Value {
@@ -143,9 +261,161 @@ impl StakedCmd {
             total.format(&asset_cache),
             String::new(),
             String::new(),
+            String::new(),
+            String::new(),
         ]);
         println!("{table}");
+        println!();
+        println!(
+            "Rewards are counted from the creation of each note of delegation tokens, \
+             so they are a lower bound for stake that was moved between notes."
+        );
+
+        // Finally, list the pending unbonding claims, with the epoch they are released in.
+        let mut claims = Table::new();
+        claims.load_preset(presets::NOTHING);
+        claims.set_header(vec![
+            "Validator",
+            "Amount",
+            "Release Height",
+            "Release Epoch",
+        ]);
+        claims
+            .get_column_mut(1)
+            .expect("column 1 exists")
+            .set_cell_alignment(comfy_table::CellAlignment::Right);
+
+        let mut unbonding_tokens = notes
+            .iter()
+            .filter_map(|(asset_id, notes_by_address)| {
+                let token = asset_cache
+                    .get(asset_id)
+                    .and_then(|denom| UnbondingToken::try_from(denom.clone()).ok())?;
+                let amount = notes_by_address
+                    .values()
+                    .flat_map(|notes| notes.iter().map(|n| n.note.amount()))
+                    .sum();
+                Some((token, amount))
+            })
+            .collect::<Vec<_>>();
+        unbonding_tokens.sort_by_key(|(token, _)| token.unbonding_start_height());
+        let has_claims = !unbonding_tokens.is_empty();
+
+        for (token, amount) in unbonding_tokens {
+            let bonding_state = validators
+                .iter()
+                .find(|v| v.validator.identity_key == token.validator())
+                .map(|info| info.status.bonding_state.clone());
+
+            let upper_bound_height =
+                token.unbonding_start_height() + params.stake_params.unbonding_delay;
+            let release_height = match bonding_state {
+                Some(validator::BondingState::Unbonding { unbonds_at_height })
+                    if unbonds_at_height > token.unbonding_start_height() =>
+                {
+                    unbonds_at_height.min(upper_bound_height)
+                }
+                Some(validator::BondingState::Unbonding { .. })
+                | Some(validator::BondingState::Unbonded) => current_height,
+                Some(validator::BondingState::Bonded) | None => upper_bound_height,
+            };
+
+            let release_epoch = if release_height <= current_height {
+                "claimable now".to_string()
+            } else {
+                // Epochs may end early, so this is only an estimate.
+                let epochs_ahead =
+                    (release_height - current_epoch.start_height) / epoch_duration.max(1);
+                format!("~{}", current_epoch.index + epochs_ahead)
+            };
+
+            let name = validators
+                .iter()
+                .find(|v| v.validator.identity_key == token.validator())
+                .map(|info| info.validator.name.clone())
+                .unwrap_or_else(|| token.validator().to_string());
+
+            claims.add_row(vec![
+                name,
+                Value {
+                    amount,
+                    asset_id: *STAKING_TOKEN_ASSET_ID,
+                }
+                .format(&asset_cache),
+                release_height.to_string(),
+                release_epoch,
+            ]);
+        }
+
+        if has_claims {
+            println!();
+            println!("Pending unbonding claims (before any slashing penalty):");
+            println!("{claims}");
+        }
 
         Ok(())
     }
 }
+
+async fn epoch_by_height(
+    client: &mut SctQueryServiceClient<Channel>,
+    height: u64,
+) -> Result<Epoch> {
+    Ok(client
+        .epoch_by_height(EpochByHeightRequest { height })
+        .await?
+        .into_inner()
+        .epoch
+        .context("epoch must be available")?
+        .into())
+}
+
+/// Fetches the rate data of a validator in every recorded epoch since `start_epoch`.
+async fn rates_by_epoch(
+    client: &mut StakeQueryServiceClient<Channel>,
+    identity_key: &IdentityKey,
+    start_epoch: u64,
+) -> Result<BTreeMap<u64, RateData>> {
+    client
+        .validator_history(ValidatorHistoryRequest {
+            identity_key: Some((*identity_key).into()),
+            start_epoch_index: start_epoch,
+            end_epoch_index: None,
+        })
+        .await?
+        .into_inner()
+        .map_err(anyhow::Error::from)
+        .and_then(|response| async move {
+            let summary: EpochSummary = response
+                .summary
+                .context("response did not include a summary")?
+                .try_into()?;
+            Ok((summary.epoch_index, summary.rate_data))
+        })
+        .try_collect()
+        .await
+}
+
+/// Annualizes the growth of a validator's exchange rate over `epochs` epochs.
+fn trailing_apy(then: &RateData, now: &RateData, epochs: u64, epoch_duration: u64) -> f64 {
+    let then = then.validator_exchange_rate.value() as f64;
+    let now = now.validator_exchange_rate.value() as f64;
+    if then == 0.0 || epochs == 0 {
+        return 0.0;
+    }
+    let epochs_per_year = SECONDS_PER_YEAR / (APPROX_BLOCK_SECONDS * epoch_duration.max(1) as f64);
+    (now / then).powf(epochs_per_year / epochs as f64) - 1.0
+}
+
+/// Formats a signed amount of the staking token.
+fn format_signed_stake(amount: i128, asset_cache: &penumbra_sdk_asset::asset::Cache) -> String {
+    let value = Value {
+        amount: amount.unsigned_abs().into(),
+        asset_id: *STAKING_TOKEN_ASSET_ID,
+    };
+    if amount < 0 {
+        format!("-{}", value.format(asset_cache))
+    } else {
+        value.format(asset_cache)
+    }
+}
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
 use anyhow::{Context, Result};
 #[cfg(feature = "ledger")]
@@ -32,6 +32,10 @@ pub struct PcliConfig {
     pub custody: CustodyConfig,
     /// The governance custody backend to use.
     pub governance_custody: Option<GovernanceCustodyConfig>,
+    /// If set, record every custody authorization decision in a hash-chained
+    /// audit log at this path.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub custody_audit_log: Option<PathBuf>,
 }
 
 impl PcliConfig {
@@ -128,6 +132,7 @@ mod tests {
                 penumbra_sdk_keys::test_keys::SPEND_KEY.clone(),
             )),
             governance_custody: None,
+            custody_audit_log: None,
         };
 
         let mut config2 = config.clone();
@@ -4,7 +4,7 @@ const APPROXIMATION_TOLERANCE: f64 = 1e-8;
 
 pub mod xyk;
 pub mod balancer {}
-pub mod volatility {}
+pub mod volatility;
 
 pub mod math_utils;
 
@@ -0,0 +1,40 @@
+//! Estimates of recent price volatility, used to suggest fees and price ranges
+//! for replicated liquidity.
+
+/// Returns the sample standard deviation of the log returns between consecutive
+/// prices, or `None` if there are fewer than two returns.
+///
+/// The volatility is per sampling interval: for hourly prices, it is the typical
+/// relative price movement over an hour. Non-positive prices are skipped.
+pub fn log_return_volatility(prices: &[f64]) -> Option<f64> {
+    let prices: Vec<f64> = prices
+        .iter()
+        .copied()
+        .filter(|price| *price > 0.0)
+        .collect();
+    let returns: Vec<f64> = prices
+        .windows(2)
+        .map(|window| (window[1] / window[0]).ln())
+        .collect();
+    if returns.len() < 2 {
+        return None;
+    }
+
+    let n = returns.len() as f64;
+    let mean = returns.iter().sum::<f64>() / n;
+    let variance = returns.iter().map(|r| (r - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    Some(variance.sqrt())
+}
+
+/// Returns how far, in log terms, the price is expected to move within
+/// `std_devs` standard deviations over `periods` sampling intervals, treating
+/// the log price as a random walk with the per-interval `volatility`.
+pub fn log_range_width(volatility: f64, periods: f64, std_devs: f64) -> f64 {
+    std_devs * volatility * periods.sqrt()
+}
+
+/// Returns the price range spanning `lower_width` below and `upper_width` above
+/// `center`, with widths in log terms (see [`log_range_width`]).
+pub fn price_range(center: f64, lower_width: f64, upper_width: f64) -> (f64, f64) {
+    (center * (-lower_width).exp(), center * upper_width.exp())
+}
@@ -1,4 +1,4 @@
-use crate::dex_utils::replicate::xyk;
+use crate::dex_utils::replicate::{volatility, xyk};
 const PRECISION_BOUND: f64 = 0.0001;
 
 fn approx_eq(a: f64, b: f64) -> bool {
@@ -35,3 +35,41 @@ fn test_xyk_solver() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+/// Tests that volatility measures the dispersion of log returns, not their trend.
+fn test_log_return_volatility() {
+    // A steady 1% rise every interval has no volatility.
+    let trending: Vec<f64> = (0..10).map(|i| 2.0 * 1.01f64.powi(i)).collect();
+    let vol = volatility::log_return_volatility(&trending).expect("enough samples");
+    assert!(approx_eq(vol, 0.0));
+
+    // Alternating between two prices has log returns of +/- ln(1.1).
+    let alternating = [1.0, 1.1, 1.0, 1.1, 1.0];
+    let vol = volatility::log_return_volatility(&alternating).expect("enough samples");
+    // The sample standard deviation of [a, -a, a, -a] is a * sqrt(4/3).
+    assert!(approx_eq(vol, 1.1f64.ln() * (4.0f64 / 3.0).sqrt()));
+
+    // Volatility is the same whichever way the pair is quoted.
+    let inverted: Vec<f64> = alternating.iter().map(|price| 1.0 / price).collect();
+    let inverted_vol = volatility::log_return_volatility(&inverted).expect("enough samples");
+    assert!(approx_eq(vol, inverted_vol));
+
+    // Two prices only give a single return.
+    assert!(volatility::log_return_volatility(&[1.0, 1.1]).is_none());
+}
+
+#[test]
+/// Tests that price ranges are symmetric in log terms around the center.
+fn test_price_range() {
+    let width = volatility::log_range_width(0.01, 16.0, 2.0);
+    assert!(approx_eq(width, 0.08));
+
+    let (lower, upper) = volatility::price_range(2.0, width, width);
+    assert!(approx_eq(lower * upper, 4.0));
+    assert!(approx_eq((upper / 2.0).ln(), 0.08));
+
+    let (lower, upper) = volatility::price_range(2.0, width, 2.0 * width);
+    assert!(approx_eq((2.0 / lower).ln(), 0.08));
+    assert!(approx_eq((upper / 2.0).ln(), 0.16));
+}
@@ -46,6 +46,11 @@ async fn main() -> Result<()> {
         reset.exec(opt.home.as_path())?;
         return Ok(());
     }
+    // The view accounts command reads the view database directly, rather than through the view service.
+    if let Command::View(ViewCmd::Accounts(accounts_cmd)) = &opt.cmd {
+        accounts_cmd.exec(opt.home.as_path()).await?;
+        return Ok(());
+    }
     // The debug command takes the home dir directly
     if let Command::Debug(debug_cmd) = &opt.cmd {
         let dd = opt.home.into_std_path_buf();
@@ -53,6 +58,10 @@ async fn main() -> Result<()> {
         return Ok(());
     }
 
+    // The custody command operates on the config file rather than on unlocked
+    // custody services, so it needs the home dir after the `App` is built.
+    let home = opt.home.clone();
+
     let (mut app, cmd) = opt.into_app().await?;
 
     if !cmd.offline() {
@@ -71,6 +80,7 @@ async fn main() -> Result<()> {
         Command::Validator(cmd) => cmd.exec(&mut app).await?,
         Command::Query(cmd) => cmd.exec(&mut app).await?,
         Command::Threshold(cmd) => cmd.exec(&mut app).await?,
+        Command::Custody(cmd) => cmd.exec(home.as_path()).await?,
         Command::Migrate(cmd) => cmd.exec(&mut app).await?,
     }
 
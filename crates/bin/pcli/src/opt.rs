@@ -7,11 +7,18 @@ use crate::{
 use anyhow::Result;
 use camino::Utf8PathBuf;
 use clap::Parser;
-use penumbra_sdk_custody::{null_kms::NullKms, soft_kms::SoftKms};
-use penumbra_sdk_proto::box_grpc_svc;
+use penumbra_sdk_custody::{
+    audit::{AuditLog, Log},
+    encrypted::Config as EncryptedConfig,
+    null_kms::NullKms,
+    soft_kms::SoftKms,
+};
+use penumbra_sdk_keys::FullViewingKey;
+use penumbra_sdk_proto::box_grpc_svc::{self, BoxGrpcService};
 use penumbra_sdk_proto::{
     custody::v1::{
-        custody_service_client::CustodyServiceClient, custody_service_server::CustodyServiceServer,
+        custody_service_client::CustodyServiceClient,
+        custody_service_server::{CustodyService, CustodyServiceServer},
     },
     view::v1::{view_service_client::ViewServiceClient, view_service_server::ViewServiceServer},
 };
@@ -64,21 +71,28 @@ impl Opt {
 
     pub async fn into_app(self) -> Result<(App, Command)> {
         let config = self.load_config()?;
+        let config_path = self.home.join(crate::CONFIG_FILE_NAME);
         let fvk = config.full_viewing_key.clone();
 
+        // Open the custody audit log, if one is configured, so that it can be
+        // shared by the spend and governance custody services...
+        let audit_log = config
+            .custody_audit_log
+            .as_ref()
+            .map(Log::open)
+            .transpose()?;
+
         // Build the custody service...
         let custody = match &config.custody {
             CustodyConfig::ViewOnly => {
                 tracing::info!("using view-only custody service");
                 let null_kms = NullKms::default();
-                let custody_svc = CustodyServiceServer::new(null_kms);
-                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                local_custody(null_kms, audit_log.as_ref(), &fvk)
             }
             CustodyConfig::SoftKms(config) => {
                 tracing::info!("using software KMS custody service");
                 let soft_kms = SoftKms::new(config.clone());
-                let custody_svc = CustodyServiceServer::new(soft_kms);
-                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                local_custody(soft_kms, audit_log.as_ref(), &fvk)
             }
             CustodyConfig::Threshold(config) => {
                 tracing::info!("using manual threshold custody service");
@@ -88,8 +102,7 @@ impl Opt {
                         fvk: Some(fvk.clone()),
                     },
                 );
-                let custody_svc = CustodyServiceServer::new(threshold_kms);
-                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                local_custody(threshold_kms, audit_log.as_ref(), &fvk)
             }
             CustodyConfig::Encrypted(config) => {
                 tracing::info!("using encrypted custody service");
@@ -98,16 +111,15 @@ impl Opt {
                     ActualTerminal {
                         fvk: Some(fvk.clone()),
                     },
-                );
-                let custody_svc = CustodyServiceServer::new(encrypted_kms);
-                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                )
+                .with_upgrade_hook(persist_upgraded_custody(config_path.clone(), false));
+                local_custody(encrypted_kms, audit_log.as_ref(), &fvk)
             }
             #[cfg(feature = "ledger")]
             CustodyConfig::Ledger(config) => {
                 tracing::info!("using ledger custody service");
                 let service = penumbra_sdk_custody_ledger_usb::Service::new(config.clone());
-                let custody_svc = CustodyServiceServer::new(service);
-                CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                local_custody(service, audit_log.as_ref(), &fvk)
             }
         };
 
@@ -119,8 +131,7 @@ impl Opt {
                         "using separate software KMS custody service for validator voting"
                     );
                     let soft_kms = SoftKms::new(config.clone());
-                    let custody_svc = CustodyServiceServer::new(soft_kms);
-                    CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                    local_custody(soft_kms, audit_log.as_ref(), &fvk)
                 }
                 GovernanceCustodyConfig::Threshold(config) => {
                     tracing::info!(
@@ -128,19 +139,22 @@ impl Opt {
                     );
                     let threshold_kms = penumbra_sdk_custody::threshold::Threshold::new(
                         config.clone(),
-                        ActualTerminal { fvk: Some(fvk) },
+                        ActualTerminal {
+                            fvk: Some(fvk.clone()),
+                        },
                     );
-                    let custody_svc = CustodyServiceServer::new(threshold_kms);
-                    CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                    local_custody(threshold_kms, audit_log.as_ref(), &fvk)
                 }
                 GovernanceCustodyConfig::Encrypted { config, .. } => {
                     tracing::info!("using separate encrypted custody service for validator voting");
                     let encrypted_kms = penumbra_sdk_custody::encrypted::Encrypted::new(
                         config.clone(),
-                        ActualTerminal { fvk: Some(fvk) },
-                    );
-                    let custody_svc = CustodyServiceServer::new(encrypted_kms);
-                    CustodyServiceClient::new(box_grpc_svc::local(custody_svc))
+                        ActualTerminal {
+                            fvk: Some(fvk.clone()),
+                        },
+                    )
+                    .with_upgrade_hook(persist_upgraded_custody(config_path.clone(), true));
+                    local_custody(encrypted_kms, audit_log.as_ref(), &fvk)
                 }
             },
             None => custody.clone(), // If no separate custody for validator voting, use the same one
@@ -194,3 +208,40 @@ impl Opt {
         Ok((app, self.cmd))
     }
 }
+
+/// Serve a custody service in-process, recording its decisions in the audit log if one is configured.
+fn local_custody<S: CustodyService>(
+    service: S,
+    audit_log: Option<&Log>,
+    fvk: &FullViewingKey,
+) -> CustodyServiceClient<BoxGrpcService> {
+    match audit_log {
+        Some(log) => {
+            let audited = AuditLog::new(service, fvk.clone(), log.clone());
+            CustodyServiceClient::new(box_grpc_svc::local(CustodyServiceServer::new(audited)))
+        }
+        None => CustodyServiceClient::new(box_grpc_svc::local(CustodyServiceServer::new(service))),
+    }
+}
+
+/// A hook that writes an upgraded encrypted custody config back to the pcli config file.
+fn persist_upgraded_custody(
+    config_path: Utf8PathBuf,
+    governance: bool,
+) -> impl Fn(&EncryptedConfig) -> Result<()> + Send + Sync + 'static {
+    move |upgraded| {
+        let mut config = PcliConfig::load(&config_path)?;
+        match (
+            governance,
+            &mut config.custody,
+            &mut config.governance_custody,
+        ) {
+            (false, CustodyConfig::Encrypted(encrypted), _) => *encrypted = upgraded.clone(),
+            (true, _, Some(GovernanceCustodyConfig::Encrypted { config, .. })) => {
+                *config = upgraded.clone()
+            }
+            _ => anyhow::bail!("encrypted custody config is no longer present in pcli config"),
+        }
+        config.save(&config_path)
+    }
+}
@@ -459,6 +459,46 @@ impl TransactionViewExt for TransactionView {
                     action = format!("{} -> [{}]", x.action.auction_id, inside);
                     ["Dutch Auction Withdraw", &action]
                 }
+                penumbra_sdk_transaction::ActionView::ActionTwapAuctionSchedule(x) => {
+                    let description = &x.action.description;
+
+                    let input: String = format_value_view(&create_value_view(
+                        description.input,
+                        x.input_metadata.clone(),
+                    ));
+                    let min_output: String = format_value_view(&create_value_view(
+                        Value {
+                            amount: description.min_output,
+                            asset_id: description.output_id,
+                        },
+                        x.output_metadata.clone(),
+                    ));
+                    action = format!(
+                        "{} -> at least {}, blocks {}..{}, in {} tranches ({})",
+                        input,
+                        min_output,
+                        description.start_height,
+                        description.end_height,
+                        description.step_count,
+                        x.auction_id
+                    );
+                    ["TWAP Auction Schedule", &action]
+                }
+                penumbra_sdk_transaction::ActionView::ActionTwapAuctionEnd(x) => {
+                    action = format!("{}", x.auction_id);
+                    ["TWAP Auction End", &action]
+                }
+                penumbra_sdk_transaction::ActionView::ActionTwapAuctionWithdraw(x) => {
+                    let inside = x
+                        .reserves
+                        .iter()
+                        .map(|value| format_value_view(value))
+                        .collect::<Vec<_>>()
+                        .as_slice()
+                        .join(", ");
+                    action = format!("{} -> [{}]", x.action.auction_id, inside);
+                    ["TWAP Auction Withdraw", &action]
+                }
                 penumbra_sdk_transaction::ActionView::ActionLiquidityTournamentVote(_) => todo!(),
             };
 
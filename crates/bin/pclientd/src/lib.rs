@@ -5,11 +5,13 @@ use std::io::IsTerminal;
 use std::io::Read;
 use std::net::SocketAddr;
 use std::path::Path;
+use std::time::Duration;
 
 use anyhow::{Context, Result};
 use camino::Utf8PathBuf;
 use clap::Parser;
 use directories::ProjectDirs;
+use penumbra_sdk_custody::encrypted::{self, Encrypted, InnerConfig};
 use penumbra_sdk_custody::policy::{AuthPolicy, PreAuthorizationPolicy};
 use penumbra_sdk_custody::soft_kms::{self, SoftKms};
 use penumbra_sdk_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
@@ -21,7 +23,7 @@ use penumbra_sdk_proto::{
     custody::v1::custody_service_server::CustodyServiceServer,
     view::v1::view_service_server::ViewServiceServer,
 };
-use penumbra_sdk_view::{Storage, ViewServer};
+use penumbra_sdk_view::{discovery, Storage, ViewServer};
 use reqwest;
 use rpassword::prompt_password;
 use serde::{Deserialize, Serialize};
@@ -36,6 +38,7 @@ use tonic::transport::Server;
 use url::Url;
 
 mod proxy;
+mod terminal;
 pub use proxy::{
     AppQueryProxy, ChainQueryProxy, CompactBlockQueryProxy, DexQueryProxy, DexSimulationProxy,
     GovernanceQueryProxy, SctQueryProxy, ShieldedPoolQueryProxy, StakeQueryProxy,
@@ -43,6 +46,7 @@ pub use proxy::{
 };
 
 use crate::proxy::FeeQueryProxy;
+use crate::terminal::ConsoleTerminal;
 
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -56,6 +60,12 @@ pub struct PclientdConfig {
     pub bind_addr: SocketAddr,
     /// Optional KMS config for custody mode
     pub kms_config: Option<soft_kms::Config>,
+    /// Optional password-encrypted KMS config for custody mode, used instead of `kms_config`.
+    pub encrypted_kms_config: Option<encrypted::Config>,
+    /// How long encrypted custody stays unlocked without being used, in seconds.
+    ///
+    /// If unset, encrypted custody stays unlocked until pclientd is restarted.
+    pub custody_session_timeout_secs: Option<u64>,
 }
 
 impl PclientdConfig {
@@ -115,6 +125,37 @@ pub enum Command {
         /// Sets the address to bind to serve gRPC.
         #[clap(long, display_order = 900, default_value = "127.0.0.1:8081")]
         bind_addr: SocketAddr,
+        /// In custody mode, encrypt the spend key with a password.
+        ///
+        /// pclientd will prompt for the password on its console when it first
+        /// needs to sign, and again after the custody session times out.
+        #[clap(long, display_order = 200, conflicts_with = "view")]
+        encrypt: bool,
+        /// With `--encrypt`, how long custody stays unlocked without being used, in seconds.
+        #[clap(long, display_order = 200, default_value = "900")]
+        session_timeout_secs: u64,
+        /// In custody mode, the BIP44 account to derive the spend key from.
+        ///
+        /// A pclientd home tracks exactly one BIP44 account; use a separate home for each
+        /// account.
+        #[clap(
+            long,
+            display_order = 300,
+            default_value = "0",
+            conflicts_with = "view"
+        )]
+        bip44_account: u32,
+        /// In custody mode, scan the chain for the BIP44 accounts of the seed phrase
+        /// that have history, and record them in the pclientd database.
+        ///
+        /// The accounts found are only reported: this home still tracks just
+        /// `--bip44-account`, and each other account needs its own home.
+        #[clap(long, display_order = 300, conflicts_with = "view")]
+        discover_accounts: bool,
+        /// With `--discover-accounts`, the number of consecutive unused accounts after
+        /// which to stop scanning.
+        #[clap(long, display_order = 300, default_value_t = discovery::DEFAULT_GAP_LIMIT)]
+        gap_limit: u32,
     },
     /// Start running `pclientd`.
     Start {},
@@ -232,6 +273,11 @@ impl Opt {
                 view,
                 grpc_url,
                 bind_addr,
+                encrypt,
+                session_timeout_secs,
+                bip44_account,
+                discover_accounts,
+                gap_limit,
             } => {
                 // Check that the home directory is empty.
                 opt.check_home_nonempty()?;
@@ -240,6 +286,7 @@ impl Opt {
                 let key_material: String;
                 let spend_key: Option<SpendKey>;
                 let full_viewing_key: FullViewingKey;
+                let mut discovered_accounts = None;
 
                 // If view-only mode is requested, prompt for a FullViewingKey.
                 if *view {
@@ -255,9 +302,37 @@ impl Opt {
                             "Enter your seed phrase to enable pclientd custody mode: ",
                         )?
                         .to_owned();
+                    let seed_phrase = SeedPhrase::from_str(key_material.as_str())?;
+                    if *discover_accounts {
+                        println!("Scanning for accounts with history...");
+                        let accounts =
+                            discovery::discover_accounts(grpc_url.clone(), *gap_limit, |account| {
+                                SpendKey::from_seed_phrase_bip44(
+                                    seed_phrase.clone(),
+                                    &Bip44Path::new(account),
+                                )
+                                .full_viewing_key()
+                                .clone()
+                            })
+                            .await?;
+                        for account in &accounts {
+                            println!("Found history in {account}");
+                        }
+                        // A pclientd home tracks a single BIP44 account.
+                        for account in accounts
+                            .iter()
+                            .filter(|a| a.bip44_account != *bip44_account)
+                        {
+                            println!(
+                                "To track BIP44 account {0}, initialize a separate pclientd home with `--bip44-account {0}`.",
+                                account.bip44_account
+                            );
+                        }
+                        discovered_accounts = Some(accounts);
+                    }
                     let sk = SpendKey::from_seed_phrase_bip44(
-                        SeedPhrase::from_str(key_material.as_str())?,
-                        &Bip44Path::new(0),
+                        seed_phrase,
+                        &Bip44Path::new(*bip44_account),
                     );
                     full_viewing_key = sk.full_viewing_key().clone();
                     spend_key = Some(sk);
@@ -296,8 +371,26 @@ impl Opt {
                     }
                 });
 
+                let (kms_config, encrypted_kms_config) = match kms_config {
+                    Some(kms_config) if *encrypt => {
+                        let password = opt.prompt_for_password(
+                            "Enter a password to encrypt the spend key with: ",
+                        )?;
+                        anyhow::ensure!(!password.is_empty(), "password must not be empty");
+                        let encrypted_config =
+                            encrypted::Config::create(&password, InnerConfig::SoftKms(kms_config))?;
+                        (None, Some(encrypted_config))
+                    }
+                    kms_config => (kms_config, None),
+                };
+                let custody_session_timeout_secs = encrypted_kms_config
+                    .is_some()
+                    .then_some(*session_timeout_secs);
+
                 let client_config = PclientdConfig {
                     kms_config,
+                    encrypted_kms_config,
+                    custody_session_timeout_secs,
                     full_viewing_key,
                     grpc_url: grpc_url.clone(),
                     bind_addr: *bind_addr,
@@ -314,6 +407,13 @@ impl Opt {
 
                 config_file.write_all(encoded.as_bytes())?;
 
+                if let Some(accounts) = discovered_accounts {
+                    let storage = opt
+                        .init_sqlite(&client_config.full_viewing_key, grpc_url)
+                        .await?;
+                    storage.record_discovered_accounts(accounts).await?;
+                }
+
                 Ok(())
             }
             Command::Start {} => {
@@ -325,6 +425,9 @@ impl Opt {
                 let storage = opt
                     .load_or_init_sqlite(&config.full_viewing_key, &config.grpc_url)
                     .await?;
+                for account in storage.discovered_accounts().await? {
+                    tracing::info!(%account, "seed phrase has history in discovered account");
+                }
 
                 let proxy_channel = ViewServer::get_pd_channel(config.grpc_url.clone()).await?;
 
@@ -345,11 +448,35 @@ impl Opt {
                 let custody_service = config.kms_config.as_ref().map(|kms_config| {
                     CustodyServiceServer::new(SoftKms::new(kms_config.spend_key.clone().into()))
                 });
+                let encrypted_custody_service =
+                    config
+                        .encrypted_kms_config
+                        .as_ref()
+                        .map(|encrypted_config| {
+                            let config_path = opt.config_path();
+                            let mut encrypted_kms =
+                                Encrypted::new(encrypted_config.clone(), ConsoleTerminal)
+                                    .with_upgrade_hook(move |upgraded| {
+                                        let mut config = PclientdConfig::load(&config_path)?;
+                                        config.encrypted_kms_config = Some(upgraded.clone());
+                                        config.save(&config_path)
+                                    });
+                            if let Some(secs) = config.custody_session_timeout_secs {
+                                encrypted_kms =
+                                    encrypted_kms.with_session_timeout(Duration::from_secs(secs));
+                            }
+                            CustodyServiceServer::new(encrypted_kms)
+                        });
+                anyhow::ensure!(
+                    custody_service.is_none() || encrypted_custody_service.is_none(),
+                    "only one of kms_config and encrypted_kms_config may be set"
+                );
 
                 let server = Server::builder()
                     .accept_http1(true)
                     .add_service(tonic_web::enable(view_service))
                     .add_optional_service(custody_service.map(tonic_web::enable))
+                    .add_optional_service(encrypted_custody_service.map(tonic_web::enable))
                     .add_service(tonic_web::enable(app_query_proxy))
                     .add_service(tonic_web::enable(governance_query_proxy))
                     .add_service(tonic_web::enable(dex_query_proxy))
@@ -0,0 +1,39 @@
+use anyhow::Result;
+use penumbra_sdk_custody::threshold::{SigningRequest, Terminal};
+use rpassword::prompt_password;
+use tonic::async_trait;
+
+/// A minimal [`Terminal`] used to unlock encrypted custody from the console pclientd runs in.
+///
+/// pclientd serves requests non-interactively, so it only supports prompting
+/// for a password, not the interactive parts of threshold signing.
+#[derive(Clone, Default)]
+pub struct ConsoleTerminal;
+
+#[async_trait]
+impl Terminal for ConsoleTerminal {
+    async fn confirm_request(&self, _request: &SigningRequest) -> Result<bool> {
+        anyhow::bail!("pclientd cannot interactively confirm signing requests")
+    }
+
+    fn explain(&self, msg: &str) -> Result<()> {
+        tracing::info!("{}", msg);
+        Ok(())
+    }
+
+    async fn broadcast(&self, _data: &str) -> Result<()> {
+        anyhow::bail!("pclientd cannot broadcast threshold signing messages")
+    }
+
+    async fn read_line_raw(&self) -> Result<String> {
+        anyhow::bail!("pclientd cannot read threshold signing responses")
+    }
+
+    async fn get_password(&self) -> Result<String> {
+        tracing::info!("custody is locked, waiting for the password on the console");
+        Ok(tokio::task::spawn_blocking(|| {
+            prompt_password("Enter the password to unlock pclientd custody: ")
+        })
+        .await??)
+    }
+}
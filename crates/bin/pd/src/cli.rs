@@ -171,6 +171,13 @@ pub enum MigrateCommand {
         #[clap(long, value_name = "VERSION")]
         target_app_version: Option<u64>,
     },
+    /// Backfill the index of active auctions, then reset the halt bit and
+    /// produce a new genesis.
+    ActiveAuctions {
+        /// Optional app version to set during migration.
+        #[clap(long, value_name = "VERSION")]
+        target_app_version: Option<u64>,
+    },
 }
 
 #[derive(Debug, Subcommand)]
@@ -12,7 +12,7 @@ use cnidarium::Storage;
 use metrics_exporter_prometheus::PrometheusBuilder;
 use pd::{
     cli::{MigrateCommand, NetworkCommand, Opt, RootCommand},
-    migrate::Migration::{IbcClientRecovery, NoOp, ReadyToStart},
+    migrate::Migration::{ActiveAuctions, IbcClientRecovery, NoOp, ReadyToStart},
     network::{
         config::{get_network_dir, parse_tm_address, url_has_necessary_parts},
         generate::NetworkConfig,
@@ -546,6 +546,22 @@ async fn main() -> anyhow::Result<()> {
                     .await
                     .context("failed to perform no-op migration")?;
                 }
+                Some(MigrateCommand::ActiveAuctions { target_app_version }) => {
+                    tracing::info!(target_app_version, "performing active auctions migration");
+                    ActiveAuctions
+                        .migrate_with_params(
+                            pd_home,
+                            comet_home,
+                            None,
+                            force,
+                            vec![target_app_version
+                                .map(|v| v.to_string())
+                                .unwrap_or_default()],
+                        )
+                        .instrument(pd_migrate_span)
+                        .await
+                        .context("failed to perform active auctions migration")?;
+                }
                 None => {
                     if ready_to_start {
                         // backward compatible interface.
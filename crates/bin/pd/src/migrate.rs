@@ -84,6 +84,9 @@ pub enum Migration {
     /// No-op migration
     /// - Resets halt bit and produces new genesis without state changes
     NoOp,
+    /// Active auctions migration
+    /// - Backfill the index of active auctions with the open Dutch auctions
+    ActiveAuctions,
 }
 
 impl Migration {
@@ -211,6 +214,28 @@ impl Migration {
                 // Early return since the new framework handles genesis generation.
                 return Ok(());
             }
+            Migration::ActiveAuctions => {
+                storage.release().await;
+
+                // Parse optional app_version from first parameter
+                let app_version = if !params.is_empty() && !params[0].is_empty() {
+                    Some(
+                        params[0]
+                            .parse::<u64>()
+                            .context("app_version must be a valid u64")?,
+                    )
+                } else {
+                    None
+                };
+
+                let migration =
+                    migrate2::active_auctions::ActiveAuctionsMigration::new(app_version);
+                migration
+                    .run(pd_home.clone(), comet_home.clone(), genesis_start)
+                    .await?;
+                // Early return since the new framework handles genesis generation.
+                return Ok(());
+            }
             // We keep historical migrations around for now, this will help inform an abstracted
             // design. Feel free to remove it if it's causing you trouble.
             _ => unimplemented!("the specified migration is unimplemented"),
@@ -0,0 +1,39 @@
+//! A migration that backfills the index of active auctions.
+//!
+//! The index is maintained as auctions are scheduled and closed, so the Dutch
+//! auctions that were already active before the upgrade are indexed here.
+
+use anyhow::Result;
+use cnidarium::StateDelta;
+use penumbra_sdk_auction::component::ActiveAuctionsBackfill;
+
+use super::Migration;
+
+/// A migration that indexes the active Dutch auctions, in addition to resetting
+/// the halt bit.
+pub struct ActiveAuctionsMigration {
+    target_app_version: Option<u64>,
+}
+
+impl ActiveAuctionsMigration {
+    /// Create a new active auctions migration with an optional target app version.
+    pub fn new(target_app_version: Option<u64>) -> Self {
+        Self { target_app_version }
+    }
+}
+
+impl Migration for ActiveAuctionsMigration {
+    fn name(&self) -> &'static str {
+        "active-auctions"
+    }
+
+    fn target_app_version(&self) -> Option<u64> {
+        self.target_app_version
+    }
+
+    async fn migrate_inner(&self, delta: &mut StateDelta<cnidarium::Snapshot>) -> Result<()> {
+        let indexed = delta.backfill_active_dutch_auctions().await?;
+        tracing::info!(indexed, "indexed active dutch auctions");
+        Ok(())
+    }
+}
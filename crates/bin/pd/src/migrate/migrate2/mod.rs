@@ -1,3 +1,4 @@
+pub mod active_auctions;
 pub mod framework;
 pub mod ibc_client_recovery;
 pub mod noop;
@@ -343,6 +343,7 @@ async fn truncate_proposal_fields(delta: &mut StateDelta<Snapshot>) -> anyhow::R
                     },
                 ));
             }
+            pb_governance::proposal::Payload::ScheduledParameterChange(_scheduled) => {}
         };
 
         // Store the truncated proposal data
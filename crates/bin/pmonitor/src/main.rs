@@ -254,6 +254,7 @@ impl Opt {
             full_viewing_key: fvk.clone(),
             disable_warning: true,
             custody: pcli::config::CustodyConfig::ViewOnly,
+            custody_audit_log: None,
         };
 
         let pcli_config_path = wallet_dir.join("config.toml");
@@ -0,0 +1,311 @@
+use {
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    decaf377_rdsa::VerificationKey,
+    penumbra_sdk_app::{
+        genesis::{AppState, Content},
+        server::consensus::Consensus,
+    },
+    penumbra_sdk_governance::{
+        change::{Activation, EncodedParameter, ParameterChange, ScheduledParameterChange},
+        proposal_state::{Outcome, State as ProposalState},
+        Proposal, ProposalPayload, ProposalSubmit, StateReadExt as _, ValidatorVote,
+        ValidatorVoteBody, ValidatorVoteReason, Vote,
+    },
+    penumbra_sdk_keys::{
+        keys::{SpendKey, SpendKeyBytes},
+        test_keys,
+    },
+    penumbra_sdk_mock_client::MockClient,
+    penumbra_sdk_mock_consensus::TestNode,
+    penumbra_sdk_num::Amount,
+    penumbra_sdk_proto::{
+        core::keys::v1::{GovernanceKey, IdentityKey},
+        penumbra::core::component::stake::v1::Validator as PenumbraValidator,
+        DomainType,
+    },
+    penumbra_sdk_sct::component::clock::EpochRead as _,
+    penumbra_sdk_shielded_pool::{genesis::Allocation, OutputPlan},
+    penumbra_sdk_stake::DelegationToken,
+    penumbra_sdk_transaction::{
+        memo::MemoPlaintext, plan::MemoPlan, ActionPlan, TransactionParameters, TransactionPlan,
+    },
+    rand::Rng,
+    rand_core::OsRng,
+    std::ops::Deref,
+    tap::{Tap, TapFallible},
+    tracing::{error_span, Instrument},
+};
+
+mod common;
+
+const EPOCH_DURATION: u64 = 4;
+const PROPOSAL_VOTING_BLOCKS: u64 = 3;
+
+/// The height at which the changes scheduled by height should take effect.
+const ACTIVATION_HEIGHT: u64 = 6;
+/// The index of the epoch at the start of which the changes scheduled by epoch should take effect.
+const ACTIVATION_EPOCH: u64 = 2;
+
+/// Exercises that the app applies scheduled parameter changes exactly at their activation height
+/// or epoch, and rejects changes that would activate before the end of voting.
+#[tokio::test]
+async fn app_can_schedule_parameter_changes() -> anyhow::Result<()> {
+    // Install a test logger, and acquire some temporary storage.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+
+    // Define a helper to get the current proposal deposit amount, the parameter we will change.
+    let deposit_amount = || async {
+        storage
+            .latest_snapshot()
+            .get_governance_params()
+            .await
+            .map(|params| params.proposal_deposit_amount)
+    };
+
+    // Generate a set of consensus keys.
+    let consensus_sk = ed25519_consensus::SigningKey::new(OsRng);
+    let consensus_vk = consensus_sk.verification_key();
+
+    // Generate a set of identity keys, which we will also use for governance.
+    let spend_key: SpendKey = SpendKeyBytes(OsRng.gen()).into();
+    let (identity_sk, identity_vk) = {
+        let sk = spend_key.spend_auth_key();
+        let vk = VerificationKey::from(sk);
+        (sk, vk)
+    };
+
+    // Define a validator and an associated genesis allocation.
+    let (validator, allocation) = {
+        let v = PenumbraValidator {
+            identity_key: Some(IdentityKey {
+                ik: identity_vk.to_bytes().to_vec(),
+            }),
+            governance_key: Some(GovernanceKey {
+                gk: identity_vk.to_bytes().to_vec(),
+            }),
+            consensus_key: consensus_vk.as_bytes().to_vec(),
+            enabled: true,
+            sequence_number: 0,
+            name: String::default(),
+            website: String::default(),
+            description: String::default(),
+            funding_streams: Vec::default(),
+        };
+
+        let (address, _) = spend_key
+            .full_viewing_key()
+            .incoming()
+            .payment_address(0u32.into());
+
+        let ik = penumbra_sdk_stake::IdentityKey(identity_vk.into());
+        let delegation_denom = DelegationToken::from(ik).denom();
+
+        let allocation = Allocation {
+            raw_amount: 1000u128.into(),
+            raw_denom: delegation_denom.to_string(),
+            address,
+        };
+
+        (v, allocation)
+    };
+
+    // Define our application state, and start the test node.
+    let mut test_node = {
+        let mut content = Content {
+            chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+            governance_content: penumbra_sdk_governance::genesis::Content {
+                governance_params: penumbra_sdk_governance::params::GovernanceParameters {
+                    proposal_deposit_amount: 0_u32.into(),
+                    proposal_voting_blocks: PROPOSAL_VOTING_BLOCKS,
+                    ..Default::default()
+                },
+            },
+            ..Default::default()
+        }
+        .with_epoch_duration(EPOCH_DURATION);
+        content.stake_content.validators.push(validator);
+        content.shielded_pool_content.allocations.push(allocation);
+        let app_state = AppState::Content(content);
+        let app_state = serde_json::to_vec(&app_state).unwrap();
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .app_state(app_state)
+            .init_chain(consensus)
+            .await
+            .tap_ok(|e| tracing::info!(hash = %e.last_app_hash_hex(), "finished init chain"))?
+    };
+
+    // Sync the mock client, using the test wallet's spend key, to the latest snapshot.
+    let client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?;
+
+    // Propose to change the deposit amount at a height, and to change it again at an epoch. Each
+    // change must also be valid on its own, so neither of them can keep the deposit at zero.
+    let by_height = client
+        .witness_auth_build(&proposal_plan(
+            0,
+            "1",
+            Activation::Height(ACTIVATION_HEIGHT),
+        ))
+        .await?;
+    let by_epoch = client
+        .witness_auth_build(&proposal_plan(1, "2", Activation::Epoch(ACTIVATION_EPOCH)))
+        .await?;
+    test_node
+        .block()
+        .with_data(vec![by_height.encode_to_vec(), by_epoch.encode_to_vec()])
+        .execute()
+        .instrument(error_span!(
+            "executing block with scheduled parameter changes"
+        ))
+        .await?;
+
+    // A change scheduled for an epoch that is expected to start before voting ends is rejected.
+    let too_early = {
+        let snapshot = storage.latest_snapshot();
+        let height = snapshot.get_block_height().await?;
+        let epoch = snapshot.get_current_epoch().await?;
+        assert!(
+            epoch.start_height + EPOCH_DURATION <= height + 1 + PROPOSAL_VOTING_BLOCKS,
+            "the next epoch should be expected to start before voting ends"
+        );
+        client
+            .witness_auth_build(&proposal_plan(2, "3", Activation::Epoch(epoch.index + 1)))
+            .await?
+    };
+    test_node
+        .block()
+        .with_data(vec![too_early.encode_to_vec()])
+        .execute()
+        .instrument(error_span!(
+            "executing block with a scheduled parameter change that activates too early"
+        ))
+        .await?;
+    assert_eq!(
+        storage.latest_snapshot().next_proposal_id().await?,
+        2,
+        "a change activating before the end of voting should be rejected"
+    );
+
+    // Vote for both proposals with the validator.
+    let votes: Vec<ActionPlan> = [0, 1]
+        .into_iter()
+        .map(|proposal| {
+            let body = ValidatorVoteBody {
+                proposal,
+                vote: Vote::Yes,
+                identity_key: penumbra_sdk_stake::IdentityKey(identity_vk.to_bytes().into()),
+                governance_key: penumbra_sdk_stake::GovernanceKey(identity_vk),
+                reason: ValidatorVoteReason("test reason".to_owned()),
+            };
+            let auth_sig = identity_sk.sign(OsRng, body.encode_to_vec().as_slice());
+            ActionPlan::ValidatorVote(ValidatorVote { body, auth_sig })
+        })
+        .collect();
+    let tx = client
+        .witness_auth_build(&transaction_plan(votes, None))
+        .await?;
+    test_node
+        .block()
+        .with_data(vec![tx.encode_to_vec()])
+        .execute()
+        .instrument(error_span!("executing block with validator votes"))
+        .await?;
+
+    // Step through blocks until the activation epoch, checking the deposit amount at each height.
+    loop {
+        let snapshot = storage.latest_snapshot();
+        let height = snapshot.get_block_height().await?;
+        let epoch = snapshot.get_current_epoch().await?;
+        let expected: Amount = if epoch.index >= ACTIVATION_EPOCH {
+            2_u32.into()
+        } else if height >= ACTIVATION_HEIGHT {
+            1_u32.into()
+        } else {
+            0_u32.into()
+        };
+        assert_eq!(
+            deposit_amount().await?,
+            expected,
+            "unexpected deposit amount at height {height} in epoch {}",
+            epoch.index
+        );
+        if epoch.index >= ACTIVATION_EPOCH {
+            assert_eq!(
+                epoch.start_height, height,
+                "the epoch change should be applied at the start of the epoch"
+            );
+            break;
+        }
+        test_node.block().execute().await?;
+    }
+
+    // Both proposals should have passed.
+    for proposal in [0, 1] {
+        assert_eq!(
+            storage.latest_snapshot().proposal_state(proposal).await?,
+            Some(ProposalState::Finished {
+                outcome: Outcome::Passed,
+            }),
+            "proposal {proposal} should have passed"
+        );
+    }
+
+    // Free our temporary storage.
+    Ok(())
+        .tap(|_| drop(test_node))
+        .tap(|_| drop(storage))
+        .tap(|_| drop(guard))
+}
+
+/// Returns a plan to propose changing the deposit amount to `deposit`, at the given activation.
+fn proposal_plan(id: u64, deposit: &str, activation: Activation) -> TransactionPlan {
+    let proposal_submit = ProposalSubmit {
+        proposal: Proposal {
+            id,
+            title: format!("deposit of {deposit} at {activation}"),
+            description: "a proposal to change the proposal deposit amount".to_owned(),
+            payload: ProposalPayload::ScheduledParameterChange(ScheduledParameterChange {
+                changes: vec![ParameterChange {
+                    changes: vec![EncodedParameter {
+                        component: "governanceParams".to_owned(),
+                        key: "proposalDepositAmount".to_owned(),
+                        value: format!(r#"{{"lo":"{deposit}"}}"#),
+                    }],
+                    preconditions: vec![],
+                }],
+                activation,
+            }),
+        },
+        deposit_amount: 0_u32.into(),
+    };
+    let output = OutputPlan::new(
+        &mut OsRng,
+        proposal_submit.proposal_nft_value(),
+        test_keys::ADDRESS_0.deref().clone(),
+    );
+    transaction_plan(
+        vec![ActionPlan::ProposalSubmit(proposal_submit), output.into()],
+        Some(MemoPlan::new(
+            &mut OsRng,
+            MemoPlaintext::blank_memo(test_keys::ADDRESS_0.deref().clone()),
+        )),
+    )
+}
+
+fn transaction_plan(actions: Vec<ActionPlan>, memo: Option<MemoPlan>) -> TransactionPlan {
+    TransactionPlan {
+        actions,
+        memo,
+        detection_data: None,
+        transaction_parameters: TransactionParameters {
+            chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+            ..Default::default()
+        },
+    }
+    .with_populated_detection_data(OsRng, Default::default())
+}
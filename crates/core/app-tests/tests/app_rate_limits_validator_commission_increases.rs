@@ -0,0 +1,255 @@
+use {
+    self::common::{BuilderExt, TestNodeExt},
+    cnidarium::TempStorage,
+    common::TempStorageExt as _,
+    decaf377_rdsa::{SigningKey, SpendAuth, VerificationKey},
+    penumbra_sdk_app::{
+        genesis::{self, AppState},
+        server::consensus::Consensus,
+    },
+    penumbra_sdk_keys::test_keys,
+    penumbra_sdk_mock_client::MockClient,
+    penumbra_sdk_mock_consensus::TestNode,
+    penumbra_sdk_proto::DomainType,
+    penumbra_sdk_sct::component::clock::EpochRead as _,
+    penumbra_sdk_stake::{
+        component::validator_handler::ValidatorDataRead as _, validator::Validator, FundingStream,
+        FundingStreams, GovernanceKey, IdentityKey,
+    },
+    penumbra_sdk_transaction::TransactionPlan,
+    rand_core::OsRng,
+    tap::Tap,
+    tracing::{error_span, Instrument},
+};
+
+mod common;
+
+/// The length of the [`penumbra_sdk_sct`] epoch.
+///
+/// This test relies on several epochs turning over, so we will work with a shorter epoch duration.
+const EPOCH_DURATION: u64 = 8;
+
+/// The largest commission increase a validator may announce, in basis points.
+const MAX_COMMISSION_INCREASE_BPS: u64 = 500;
+
+/// The number of epochs a commission increase is delayed by.
+const COMMISSION_INCREASE_DELAY_EPOCHS: u64 = 1;
+
+/// Returns a plan for a transaction uploading the given validator definition.
+fn definition_plan(validator: &Validator, signing_key: &SigningKey<SpendAuth>) -> TransactionPlan {
+    use {
+        penumbra_sdk_stake::validator,
+        penumbra_sdk_transaction::{ActionPlan, TransactionParameters},
+    };
+    let bytes = validator.encode_to_vec();
+    let auth_sig = signing_key.sign(OsRng, &bytes);
+    let action = ActionPlan::ValidatorDefinition(validator::Definition {
+        validator: validator.clone(),
+        auth_sig,
+    });
+    TransactionPlan {
+        actions: vec![action.into()],
+        memo: None,
+        detection_data: None, // We'll set this automatically below
+        transaction_parameters: TransactionParameters {
+            chain_id: TestNode::<()>::CHAIN_ID.to_string(),
+            ..Default::default()
+        },
+    }
+    .with_populated_detection_data(OsRng, Default::default())
+}
+
+/// Returns funding streams sending `rate_bps` to the community pool.
+fn commission(rate_bps: u16) -> anyhow::Result<FundingStreams> {
+    vec![FundingStream::ToCommunityPool { rate_bps }].try_into()
+}
+
+#[tokio::test]
+async fn app_rate_limits_validator_commission_increases() -> anyhow::Result<()> {
+    // Install a test logger, acquire some temporary storage, and start the test node.
+    let guard = common::set_tracing_subscriber();
+    let storage = TempStorage::new_with_penumbra_prefixes().await?;
+
+    // Configure an AppState with shorter epochs and explicit commission increase limits.
+    let app_state = AppState::Content(
+        genesis::Content::default()
+            .with_epoch_duration(EPOCH_DURATION)
+            .with_chain_id(TestNode::<()>::CHAIN_ID.to_string())
+            .with_commission_increase_limits(
+                MAX_COMMISSION_INCREASE_BPS,
+                COMMISSION_INCREASE_DELAY_EPOCHS,
+            ),
+    );
+
+    // Start the test node.
+    let mut node = {
+        let consensus = Consensus::new(storage.as_ref().clone());
+        TestNode::builder()
+            .single_validator()
+            .with_penumbra_auto_app_state(app_state)?
+            .init_chain(consensus)
+            .await
+    }?;
+
+    // Sync the mock client, using the test wallet's spend key, to the latest snapshot.
+    let client = MockClient::new(test_keys::SPEND_KEY.clone())
+        .with_sync_to_storage(&storage)
+        .await?;
+
+    // Define a new validator, which does not charge any commission.
+    let validator_id_sk = SigningKey::<SpendAuth>::new(OsRng);
+    let validator_id = IdentityKey(VerificationKey::from(&validator_id_sk).into());
+    let validator_consensus_sk = ed25519_consensus::SigningKey::new(OsRng);
+    let validator_consensus = validator_consensus_sk.verification_key();
+    node.keyring_mut()
+        .insert(validator_consensus, validator_consensus_sk);
+    let validator = Validator {
+        identity_key: validator_id.clone(),
+        consensus_key: tendermint::PublicKey::from_raw_ed25519(&validator_consensus.to_bytes())
+            .expect("consensus key is valid"),
+        governance_key: GovernanceKey(validator_id_sk.into()),
+        enabled: true,
+        sequence_number: 0,
+        name: "test validator".to_string(),
+        website: String::default(),
+        description: String::default(),
+        funding_streams: FundingStreams::default(),
+    };
+    let tx = client
+        .witness_auth_build(&definition_plan(&validator, &validator_id_sk))
+        .await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!("executing block with validator definition"))
+        .await?;
+
+    // Try to raise the commission by more than the allowed amount. This should be rejected.
+    let too_large_increase = Validator {
+        sequence_number: 1,
+        funding_streams: commission(1_000)?,
+        ..validator.clone()
+    };
+    let tx = client
+        .witness_auth_build(&definition_plan(&too_large_increase, &validator_id_sk))
+        .await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!(
+            "executing block with excessive commission increase"
+        ))
+        .await?;
+    {
+        let snapshot = storage.latest_snapshot();
+        let definition = snapshot
+            .get_validator_definition(&validator_id)
+            .await?
+            .expect("validator is defined");
+        assert_eq!(
+            definition.sequence_number, 0,
+            "excessive commission increases should be rejected"
+        );
+        assert!(snapshot
+            .get_pending_commission_increase(&validator_id)
+            .await?
+            .is_none());
+    }
+
+    // Raise the commission within the allowed amount. The definition is accepted, but the
+    // validator keeps its previous funding streams until the increase takes effect.
+    let allowed_increase = Validator {
+        sequence_number: 1,
+        funding_streams: commission(300)?,
+        ..validator.clone()
+    };
+    let tx = client
+        .witness_auth_build(&definition_plan(&allowed_increase, &validator_id_sk))
+        .await?;
+    node.block()
+        .add_tx(tx.encode_to_vec())
+        .execute()
+        .instrument(error_span!(
+            "executing block with allowed commission increase"
+        ))
+        .await?;
+    let announced_in = {
+        let snapshot = storage.latest_snapshot();
+        let definition = snapshot
+            .get_validator_definition(&validator_id)
+            .await?
+            .expect("validator is defined");
+        assert_eq!(definition.sequence_number, 1);
+        assert_eq!(
+            definition.funding_streams.total_rate_bps(),
+            0,
+            "commission increases should not take effect immediately"
+        );
+        let pending = snapshot
+            .get_pending_commission_increase(&validator_id)
+            .await?
+            .expect("commission increase is pending");
+        let current_epoch = snapshot.get_current_epoch().await?.index;
+        assert_eq!(pending.funding_streams, commission(300)?);
+        assert_eq!(
+            pending.effective_epoch_index,
+            current_epoch + COMMISSION_INCREASE_DELAY_EPOCHS
+        );
+        current_epoch
+    };
+
+    // The increase is still pending after the epoch it was announced in ends...
+    node.fast_forward_to_next_epoch(&storage).await?;
+    {
+        let snapshot = storage.latest_snapshot();
+        assert_eq!(snapshot.get_current_epoch().await?.index, announced_in + 1);
+        let definition = snapshot
+            .get_validator_definition(&validator_id)
+            .await?
+            .expect("validator is defined");
+        assert_eq!(definition.funding_streams.total_rate_bps(), 0);
+        assert!(snapshot
+            .get_pending_commission_increase(&validator_id)
+            .await?
+            .is_some());
+    }
+
+    // ...and takes effect once the delay has elapsed.
+    node.fast_forward_to_next_epoch(&storage).await?;
+    {
+        let snapshot = storage.latest_snapshot();
+        let definition = snapshot
+            .get_validator_definition(&validator_id)
+            .await?
+            .expect("validator is defined");
+        assert_eq!(definition.funding_streams, commission(300)?);
+        assert!(snapshot
+            .get_pending_commission_increase(&validator_id)
+            .await?
+            .is_none());
+    }
+
+    // The summary of the epoch in which the increase took effect still shows the previous
+    // commission, while the summary of the following epoch shows the new one.
+    node.fast_forward_to_next_epoch(&storage).await?;
+    {
+        use {futures::TryStreamExt, penumbra_sdk_stake::component::ValidatorHistoryRead as _};
+        let commissions = storage
+            .latest_snapshot()
+            .validator_history(&validator_id, announced_in + 1, Some(announced_in + 2))?
+            .map_ok(|summary| summary.commission_bps)
+            .try_collect::<Vec<_>>()
+            .await?;
+        assert_eq!(
+            commissions,
+            vec![0, 300],
+            "epoch summaries should use the commission in effect during the epoch"
+        );
+    }
+
+    // The test passed. Free our temporary storage and drop our tracing subscriber.
+    Ok(())
+        .tap(|_| drop(node))
+        .tap(|_| drop(storage))
+        .tap(|_| drop(guard))
+}
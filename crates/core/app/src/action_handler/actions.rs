@@ -56,6 +56,9 @@ impl AppActionHandler for Action {
             Action::ActionDutchAuctionSchedule(action) => action.check_stateless(()).await,
             Action::ActionDutchAuctionEnd(action) => action.check_stateless(()).await,
             Action::ActionDutchAuctionWithdraw(action) => action.check_stateless(()).await,
+            Action::ActionTwapAuctionSchedule(action) => action.check_stateless(()).await,
+            Action::ActionTwapAuctionEnd(action) => action.check_stateless(()).await,
+            Action::ActionTwapAuctionWithdraw(action) => action.check_stateless(()).await,
             Action::ActionLiquidityTournamentVote(action) => action.check_stateless(context).await,
         }
     }
@@ -98,6 +101,9 @@ impl AppActionHandler for Action {
             Action::ActionDutchAuctionSchedule(action) => action.check_historical(state).await,
             Action::ActionDutchAuctionEnd(action) => action.check_historical(state).await,
             Action::ActionDutchAuctionWithdraw(action) => action.check_historical(state).await,
+            Action::ActionTwapAuctionSchedule(action) => action.check_historical(state).await,
+            Action::ActionTwapAuctionEnd(action) => action.check_historical(state).await,
+            Action::ActionTwapAuctionWithdraw(action) => action.check_historical(state).await,
             Action::ActionLiquidityTournamentVote(action) => action.check_historical(state).await,
         }
     }
@@ -140,6 +146,9 @@ impl AppActionHandler for Action {
             Action::ActionDutchAuctionSchedule(action) => action.check_and_execute(state).await,
             Action::ActionDutchAuctionEnd(action) => action.check_and_execute(state).await,
             Action::ActionDutchAuctionWithdraw(action) => action.check_and_execute(state).await,
+            Action::ActionTwapAuctionSchedule(action) => action.check_and_execute(state).await,
+            Action::ActionTwapAuctionEnd(action) => action.check_and_execute(state).await,
+            Action::ActionTwapAuctionWithdraw(action) => action.check_and_execute(state).await,
             Action::ActionLiquidityTournamentVote(action) => action.check_and_execute(state).await,
         }
     }
@@ -11,6 +11,7 @@ use cnidarium::StateWrite;
 use penumbra_sdk_asset::STAKING_TOKEN_DENOM;
 use penumbra_sdk_community_pool::component::StateReadExt as _;
 use penumbra_sdk_governance::{
+    change::Activation,
     component::{StateReadExt as _, StateWriteExt as _},
     event,
     proposal::{Proposal, ProposalPayload},
@@ -22,6 +23,7 @@ use penumbra_sdk_keys::keys::{FullViewingKey, NullifierKey};
 use penumbra_sdk_proto::{DomainType, StateWriteProto as _};
 use penumbra_sdk_sct::component::clock::EpochRead;
 use penumbra_sdk_sct::component::tree::SctRead;
+use penumbra_sdk_sct::component::StateReadExt as _;
 use penumbra_sdk_shielded_pool::component::AssetRegistry;
 use penumbra_sdk_transaction::{AuthorizationData, Transaction, TransactionPlan, WitnessData};
 
@@ -106,6 +108,9 @@ impl AppActionHandler for ProposalSubmit {
                         | ActionDutchAuctionSchedule(_)
                         | ActionDutchAuctionEnd(_)
                         | ActionDutchAuctionWithdraw(_) => {}
+                        ActionTwapAuctionSchedule(_)
+                        | ActionTwapAuctionEnd(_)
+                        | ActionTwapAuctionWithdraw(_) => {}
                     }
                 }
             }
@@ -118,6 +123,9 @@ impl AppActionHandler for ProposalSubmit {
                 let _ = &ClientId::from_str(client_id)
                     .context("can't decode client id from IBC proposal")?;
             }
+            ScheduledParameterChange(_scheduled) => {
+                /* no stateless checks -- see check-and-execute below */
+            }
         }
 
         Ok(())
@@ -223,6 +231,53 @@ impl AppActionHandler for ProposalSubmit {
                     .map_err(|e| tonic::Status::aborted(format!("invalid client id: {e}")))?;
                 let _ = state.get_client_state(client_id).await?;
             }
+            ProposalPayload::ScheduledParameterChange(scheduled) => {
+                // The changes must not be scheduled to activate before the proposal could
+                // possibly pass, otherwise voters couldn't rely on the activation.
+                let voting_end = state
+                    .get_block_height()
+                    .await?
+                    .saturating_add(governance_parameters.proposal_voting_blocks);
+                match scheduled.activation {
+                    Activation::Height(height) => {
+                        anyhow::ensure!(
+                            height > voting_end,
+                            "scheduled parameter change activates at height {height}, before the end of voting at height {voting_end}",
+                        );
+                    }
+                    Activation::Epoch(epoch_index) => {
+                        // Epochs can end early, so the height at which a future epoch starts
+                        // is only an estimate. If the epoch starts before voting ends anyway,
+                        // the proposal fails to enact.
+                        let current_epoch = state.get_current_epoch().await?;
+                        anyhow::ensure!(
+                            epoch_index > current_epoch.index,
+                            "scheduled parameter change activates at epoch {epoch_index}, which is not in the future (current epoch is {})",
+                            current_epoch.index,
+                        );
+                        let epoch_duration = state.get_epoch_duration_parameter().await?;
+                        let expected_start = current_epoch.start_height.saturating_add(
+                            (epoch_index - current_epoch.index).saturating_mul(epoch_duration),
+                        );
+                        anyhow::ensure!(
+                            expected_start > voting_end,
+                            "scheduled parameter change activates at epoch {epoch_index}, which is expected to start at height {expected_start}, before the end of voting at height {voting_end}",
+                        );
+                    }
+                }
+
+                // Check that each change is valid and could be applied on top of the previous
+                // ones, starting from the current parameters. As for unscheduled parameter
+                // changes, this doesn't guarantee that they will apply at activation time.
+                let mut parameters = state.get_app_params().await?;
+                for (i, change) in scheduled.changes.iter().enumerate() {
+                    parameters = change.apply_changes(parameters).with_context(|| {
+                        format!(
+                            "proposed parameter change #{i} does not apply to current parameters"
+                        )
+                    })?;
+                }
+            }
         }
 
         // (end of former check_stateful checks)
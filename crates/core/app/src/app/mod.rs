@@ -338,6 +338,54 @@ impl App {
             }
         }
 
+        // Likewise, apply the parameter changes whose scheduled activation is this block. Each
+        // change is applied on top of the previous ones, and a change that fails to apply is
+        // skipped without affecting the others.
+        let scheduled_changes = state_tx
+            .take_scheduled_param_changes_for_height(begin_block.header.height.into())
+            .await
+            .expect("scheduled param changes should always be readable");
+        if !scheduled_changes.is_empty() {
+            let mut params = state_tx
+                .get_app_params()
+                .await
+                .expect("must be able to read app params");
+            let mut any_applied = false;
+            for (proposal_id, scheduled) in scheduled_changes {
+                for change in scheduled.changes {
+                    match change.apply_changes(params.clone()) {
+                        Ok(new_params) => {
+                            tracing::info!(
+                                proposal_id,
+                                ?change,
+                                "applied scheduled app parameter change"
+                            );
+                            params = new_params;
+                            any_applied = true;
+                        }
+                        Err(e) => {
+                            tracing::info!(
+                                proposal_id,
+                                ?change,
+                                ?e,
+                                "failed to apply scheduled app parameter change"
+                            );
+                        }
+                    }
+                }
+            }
+            if any_applied {
+                state_tx.put_app_params(params.clone());
+                state_tx.mark_scheduled_param_changes_applied();
+                state_tx.record_proto(
+                    EventAppParametersChange {
+                        new_parameters: params,
+                    }
+                    .to_proto(),
+                )
+            }
+        }
+
         // Run each of the begin block handlers for each component, in sequence:
         let mut arc_state_tx = Arc::new(state_tx);
         Sct::begin_block(&mut arc_state_tx, begin_block).await;
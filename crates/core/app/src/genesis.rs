@@ -207,6 +207,24 @@ impl Content {
             ..self
         }
     }
+
+    pub fn with_commission_increase_limits(
+        self,
+        max_commission_increase_bps: u64,
+        commission_increase_delay_epochs: u64,
+    ) -> Self {
+        Self {
+            stake_content: penumbra_sdk_stake::genesis::Content {
+                stake_params: penumbra_sdk_stake::params::StakeParameters {
+                    max_commission_increase_bps,
+                    commission_increase_delay_epochs,
+                    ..self.stake_content.stake_params
+                },
+                ..self.stake_content
+            },
+            ..self
+        }
+    }
 }
 
 #[cfg(test)]
@@ -4,7 +4,7 @@ use anyhow::Context;
 use anyhow::Result;
 use penumbra_sdk_auction::params::AuctionParameters;
 use penumbra_sdk_community_pool::params::CommunityPoolParameters;
-use penumbra_sdk_dex::DexParameters;
+use penumbra_sdk_dex::{CandlestickInterval, DexParameters};
 use penumbra_sdk_distributions::params::DistributionsParameters;
 use penumbra_sdk_fee::FeeParameters;
 use penumbra_sdk_funding::params::FundingParameters;
@@ -105,6 +105,8 @@ impl AppParameters {
                     missed_blocks_maximum: _,
                     min_validator_stake: _,
                     unbonding_delay: _,
+                    max_commission_increase_bps: _,
+                    commission_increase_delay_epochs: _,
                 },
             dex_params:
                 DexParameters {
@@ -113,6 +115,9 @@ impl AppParameters {
                     max_hops: _,
                     max_positions_per_pair: _,
                     max_execution_budget: _,
+                    max_split_paths: _,
+                    candlestick_intervals: _,
+                    composed_path_prices: _,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -205,6 +210,8 @@ impl AppParameters {
                     missed_blocks_maximum,
                     min_validator_stake,
                     unbonding_delay,
+                    max_commission_increase_bps,
+                    commission_increase_delay_epochs,
                 },
             dex_params:
                 DexParameters {
@@ -213,6 +220,9 @@ impl AppParameters {
                     max_hops: _,
                     max_positions_per_pair: _,
                     max_execution_budget: _,
+                    max_split_paths: _,
+                    candlestick_intervals,
+                    composed_path_prices: _,
                 },
             // IMPORTANT: Don't use `..` here! We want to ensure every single field is verified!
         } = self;
@@ -255,6 +265,14 @@ impl AppParameters {
                 *missed_blocks_maximum >= 1,
                 "missed blocks maximum must be at least 1",
             ),
+            (
+                *max_commission_increase_bps <= 10_000,
+                "max commission increase must be at most 10,000 basis points",
+            ),
+            (
+                *commission_increase_delay_epochs <= 100,
+                "commission increase delay must be at most 100 epochs",
+            ),
             (
                 (!*inbound_ics20_transfers_enabled && !*outbound_ics20_transfers_enabled)
                     || *ibc_enabled,
@@ -284,6 +302,10 @@ impl AppParameters {
                 *min_validator_stake >= 1_000_000u128.into(),
                 "the minimum validator stake must be at least 1penumbra",
             ),
+            (
+                !candlestick_intervals.contains(&CandlestickInterval::Block),
+                "candlestick intervals must not include the per-block interval",
+            ),
         ])
     }
 }
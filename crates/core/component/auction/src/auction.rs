@@ -1,6 +1,7 @@
 pub mod dutch;
 pub mod id;
 pub mod nft;
+pub mod twap;
 
 pub use id::AuctionId;
 pub use nft::AuctionNft;
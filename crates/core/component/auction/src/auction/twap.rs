@@ -0,0 +1,199 @@
+use std::num::NonZeroU64;
+
+use anyhow::anyhow;
+use penumbra_sdk_asset::{asset, Value};
+use penumbra_sdk_dex::lp::position::{self};
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::auction::AuctionId;
+
+pub mod actions;
+pub use actions::{ActionTwapAuctionEnd, ActionTwapAuctionSchedule, ActionTwapAuctionWithdraw};
+
+pub const TWAP_AUCTION_DOMAIN_SEP: &[u8] = b"penumbra_TA_nft";
+
+/// A deployed TWAP auction, containing an immutable description
+/// and stateful data about its current state.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(try_from = "pb::TwapAuction", into = "pb::TwapAuction")]
+pub struct TwapAuction {
+    pub description: TwapAuctionDescription,
+    pub state: TwapAuctionState,
+}
+
+/* Protobuf impls for `TwapAuction` */
+impl DomainType for TwapAuction {
+    type Proto = pb::TwapAuction;
+}
+
+impl From<TwapAuction> for pb::TwapAuction {
+    fn from(domain: TwapAuction) -> Self {
+        pb::TwapAuction {
+            description: Some(domain.description.into()),
+            state: Some(domain.state.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::TwapAuction> for TwapAuction {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::TwapAuction) -> Result<Self, Self::Error> {
+        Ok(TwapAuction {
+            description: msg
+                .description
+                .ok_or_else(|| anyhow!("TwapAuction is missing description"))?
+                .try_into()?,
+            state: msg
+                .state
+                .ok_or_else(|| anyhow!("TwapAuction is missing a state field"))?
+                .try_into()?,
+        })
+    }
+}
+/* ********************************** */
+
+/// A description of the immutable parts of a TWAP auction.
+///
+/// The input is released in `step_count` even tranches between `start_height`
+/// and `end_height`, each offered at the floor price implied by `min_output`.
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(
+    try_from = "pb::TwapAuctionDescription",
+    into = "pb::TwapAuctionDescription"
+)]
+pub struct TwapAuctionDescription {
+    pub input: Value,
+    pub output_id: asset::Id,
+    pub min_output: Amount,
+    pub start_height: u64,
+    pub end_height: u64,
+    pub step_count: u64,
+    pub nonce: [u8; 32],
+}
+
+impl TwapAuctionDescription {
+    /// Compute the unique identifier for the auction description.
+    pub fn id(&self) -> AuctionId {
+        let mut state = blake2b_simd::Params::default()
+            .personal(TWAP_AUCTION_DOMAIN_SEP)
+            .to_state();
+
+        state.update(&self.nonce);
+        state.update(&self.input.asset_id.to_bytes());
+        state.update(&self.input.amount.to_le_bytes());
+        state.update(&self.output_id.to_bytes());
+        state.update(&self.min_output.to_le_bytes());
+        state.update(&self.start_height.to_le_bytes());
+        state.update(&self.end_height.to_le_bytes());
+        state.update(&self.step_count.to_le_bytes());
+
+        let hash = state.finalize();
+        let mut bytes = [0; 32];
+        bytes[0..32].copy_from_slice(&hash.as_bytes()[0..32]);
+        AuctionId(bytes)
+    }
+}
+
+/* Protobuf impls */
+impl DomainType for TwapAuctionDescription {
+    type Proto = pb::TwapAuctionDescription;
+}
+
+impl From<TwapAuctionDescription> for pb::TwapAuctionDescription {
+    fn from(domain: TwapAuctionDescription) -> Self {
+        Self {
+            input: Some(domain.input.into()),
+            output_id: Some(domain.output_id.into()),
+            min_output: Some(domain.min_output.into()),
+            start_height: domain.start_height,
+            end_height: domain.end_height,
+            step_count: domain.step_count,
+            nonce: domain.nonce.as_slice().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::TwapAuctionDescription> for TwapAuctionDescription {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::TwapAuctionDescription) -> Result<Self, Self::Error> {
+        let d = TwapAuctionDescription {
+            input: msg
+                .input
+                .ok_or_else(|| anyhow!("TwapAuctionDescription message is missing input"))?
+                .try_into()?,
+            output_id: msg
+                .output_id
+                .ok_or_else(|| {
+                    anyhow!("TwapAuctionDescription message is missing an output identifier")
+                })?
+                .try_into()?,
+            min_output: msg
+                .min_output
+                .ok_or_else(|| anyhow!("TwapAuctionDescription message is missing min output"))?
+                .try_into()?,
+            start_height: msg.start_height,
+            end_height: msg.end_height,
+            step_count: msg.step_count,
+            nonce: msg.nonce.as_slice().try_into()?,
+        };
+        Ok(d)
+    }
+}
+/* ********************************** */
+
+/// A stateful description of a TWAP auction, recording its state (via a sequence number),
+/// the current position id associated to it (if any), and its amount IO.
+///
+/// The sequence number follows the same state machine as Dutch auctions,
+/// see [`DutchAuctionState`](super::dutch::DutchAuctionState).
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Debug)]
+#[serde(try_from = "pb::TwapAuctionState", into = "pb::TwapAuctionState")]
+pub struct TwapAuctionState {
+    pub sequence: u64,
+    pub current_position: Option<position::Id>,
+    pub next_trigger: Option<NonZeroU64>,
+    pub input_reserves: Amount,
+    pub output_reserves: Amount,
+}
+
+/* Protobuf impls for `TwapAuctionState` */
+impl DomainType for TwapAuctionState {
+    type Proto = pb::TwapAuctionState;
+}
+
+impl From<TwapAuctionState> for pb::TwapAuctionState {
+    fn from(domain: TwapAuctionState) -> Self {
+        Self {
+            seq: domain.sequence,
+            current_position: domain.current_position.map(Into::into),
+            next_trigger: domain.next_trigger.map_or(0u64, Into::into),
+            input_reserves: Some(domain.input_reserves.into()),
+            output_reserves: Some(domain.output_reserves.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::TwapAuctionState> for TwapAuctionState {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::TwapAuctionState) -> Result<Self, Self::Error> {
+        Ok(TwapAuctionState {
+            sequence: msg.seq,
+            current_position: msg.current_position.map(TryInto::try_into).transpose()?,
+            next_trigger: NonZeroU64::new(msg.next_trigger),
+            input_reserves: msg
+                .input_reserves
+                .ok_or_else(|| anyhow!("TwapAuctionState message is missing input reserves"))?
+                .try_into()?,
+            output_reserves: msg
+                .output_reserves
+                .ok_or_else(|| anyhow!("TwapAuctionState message is missing output reserves"))?
+                .try_into()?,
+        })
+    }
+}
+/* ********************************** */
@@ -0,0 +1,13 @@
+pub mod schedule;
+pub use schedule::ActionTwapAuctionSchedule;
+
+pub mod end;
+pub use end::ActionTwapAuctionEnd;
+
+pub mod withdraw;
+pub use withdraw::ActionTwapAuctionWithdraw;
+
+pub mod plan;
+pub use plan::ActionTwapAuctionWithdrawPlan;
+
+pub mod view;
@@ -0,0 +1,74 @@
+use crate::auction::{nft::AuctionNft, twap::TwapAuctionDescription};
+use anyhow::anyhow;
+use penumbra_sdk_asset::{Balance, Value};
+use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
+use penumbra_sdk_txhash::{EffectHash, EffectingData};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ActionTwapAuctionSchedule",
+    into = "pb::ActionTwapAuctionSchedule"
+)]
+pub struct ActionTwapAuctionSchedule {
+    pub description: TwapAuctionDescription,
+}
+
+impl ActionTwapAuctionSchedule {
+    /// Compute the value balance corresponding to this action:
+    ///
+    /// # Diagram
+    ///
+    ///  ┌────────────────────┬──────────────────────┐
+    ///  │      Burn (-)      │       Mint (+)       │
+    ///  ├────────────────────┼──────────────────────┤
+    ///  │    input value     │  opened auction nft  │
+    ///  └────────────────────┴──────────────────────┘                  
+    pub fn balance(&self) -> Balance {
+        let opened_auction_nft = AuctionNft::new(self.description.id(), 0u64);
+        let opened_auction_nft_value = Value {
+            asset_id: opened_auction_nft.metadata.id(),
+            amount: 1u128.into(),
+        };
+
+        let output_nft_balance = Balance::from(opened_auction_nft_value);
+        let input_balance = Balance::from(self.description.input);
+
+        output_nft_balance - input_balance
+    }
+}
+
+/* Effect hash */
+impl EffectingData for ActionTwapAuctionSchedule {
+    fn effect_hash(&self) -> EffectHash {
+        EffectHash::from_proto_effecting_data(&self.to_proto())
+    }
+}
+
+/* Protobuf impls */
+impl DomainType for ActionTwapAuctionSchedule {
+    type Proto = pb::ActionTwapAuctionSchedule;
+}
+
+impl From<ActionTwapAuctionSchedule> for pb::ActionTwapAuctionSchedule {
+    fn from(domain: ActionTwapAuctionSchedule) -> Self {
+        pb::ActionTwapAuctionSchedule {
+            description: Some(domain.description.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::ActionTwapAuctionSchedule> for ActionTwapAuctionSchedule {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ActionTwapAuctionSchedule) -> Result<Self, Self::Error> {
+        Ok(ActionTwapAuctionSchedule {
+            description: msg
+                .description
+                .ok_or_else(|| {
+                    anyhow!("ActionTwapAuctionSchedule message is missing a description")
+                })?
+                .try_into()?,
+        })
+    }
+}
@@ -0,0 +1,132 @@
+use crate::auction::{
+    id::AuctionId,
+    twap::{
+        actions::{ActionTwapAuctionSchedule, ActionTwapAuctionWithdraw},
+        asset::Metadata,
+    },
+};
+use anyhow::anyhow;
+use penumbra_sdk_asset::ValueView;
+use penumbra_sdk_proto::{core::component::auction::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/* Domain type definitions */
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ActionTwapAuctionScheduleView",
+    into = "pb::ActionTwapAuctionScheduleView"
+)]
+pub struct ActionTwapAuctionScheduleView {
+    pub action: ActionTwapAuctionSchedule,
+    pub auction_id: AuctionId,
+    pub input_metadata: Option<Metadata>,
+    pub output_metadata: Option<Metadata>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ActionTwapAuctionWithdrawView",
+    into = "pb::ActionTwapAuctionWithdrawView"
+)]
+pub struct ActionTwapAuctionWithdrawView {
+    pub action: ActionTwapAuctionWithdraw,
+    // A sequence of values that sum together to the provided
+    // reserves commitment.
+    pub reserves: Vec<ValueView>,
+}
+
+/* Conversion back to an action */
+
+impl From<ActionTwapAuctionScheduleView> for ActionTwapAuctionSchedule {
+    fn from(value: ActionTwapAuctionScheduleView) -> Self {
+        value.action
+    }
+}
+
+impl From<ActionTwapAuctionWithdrawView> for ActionTwapAuctionWithdraw {
+    fn from(value: ActionTwapAuctionWithdrawView) -> Self {
+        value.action
+    }
+}
+
+/* Protobuf impls */
+impl DomainType for ActionTwapAuctionScheduleView {
+    type Proto = pb::ActionTwapAuctionScheduleView;
+}
+
+impl From<ActionTwapAuctionScheduleView> for pb::ActionTwapAuctionScheduleView {
+    fn from(domain: ActionTwapAuctionScheduleView) -> Self {
+        pb::ActionTwapAuctionScheduleView {
+            action: Some(domain.action.into()),
+            auction_id: Some(domain.auction_id.into()),
+            input_metadata: domain.input_metadata.map(Into::into),
+            output_metadata: domain.output_metadata.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::ActionTwapAuctionScheduleView> for ActionTwapAuctionScheduleView {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ActionTwapAuctionScheduleView) -> Result<Self, Self::Error> {
+        Ok(ActionTwapAuctionScheduleView {
+            action: msg
+                .action
+                .ok_or_else(|| {
+                    anyhow!("ActionTwapAuctionScheduleView message is missing an action")
+                })?
+                .try_into()?,
+            auction_id: msg
+                .auction_id
+                .ok_or_else(|| {
+                    anyhow!("ActionTwapAuctionScheduleView message is missing an auction_id")
+                })?
+                .try_into()?,
+            input_metadata: msg
+                .input_metadata
+                .map(|input| input.try_into())
+                .transpose()?,
+            output_metadata: msg
+                .output_metadata
+                .map(|output| output.try_into())
+                .transpose()?,
+        })
+    }
+}
+/* Protobuf impls */
+impl DomainType for ActionTwapAuctionWithdrawView {
+    type Proto = pb::ActionTwapAuctionWithdrawView;
+}
+
+impl From<ActionTwapAuctionWithdrawView> for pb::ActionTwapAuctionWithdrawView {
+    fn from(domain: ActionTwapAuctionWithdrawView) -> Self {
+        pb::ActionTwapAuctionWithdrawView {
+            action: Some(domain.action.into()),
+            reserves: domain
+                .reserves
+                .into_iter()
+                .map(Into::into)
+                .collect::<Vec<_>>(),
+        }
+    }
+}
+
+impl TryFrom<pb::ActionTwapAuctionWithdrawView> for ActionTwapAuctionWithdrawView {
+    type Error = anyhow::Error;
+
+    fn try_from(msg: pb::ActionTwapAuctionWithdrawView) -> Result<Self, Self::Error> {
+        Ok(ActionTwapAuctionWithdrawView {
+            action: msg
+                .action
+                .ok_or_else(|| {
+                    anyhow!("ActionTwapAuctionWithdrawView message is missing an action")
+                })?
+                .try_into()?,
+            reserves: msg
+                .reserves
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
@@ -1 +1,2 @@
 pub mod dutch;
+pub mod twap;
@@ -0,0 +1,3 @@
+mod end;
+mod schedule;
+mod withdraw;
@@ -0,0 +1,111 @@
+use crate::auction::dutch::actions::schedule::MAX_AUCTION_AMOUNT_RESERVES;
+use crate::auction::twap::TwapAuctionDescription;
+use crate::component::AuctionStoreRead;
+use anyhow::{ensure, Result};
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use cnidarium_component::ActionHandler;
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_sct::component::clock::EpochRead;
+
+use crate::auction::twap::ActionTwapAuctionSchedule;
+use crate::component::TwapAuctionManager;
+
+#[async_trait]
+impl ActionHandler for ActionTwapAuctionSchedule {
+    type CheckStatelessContext = ();
+    async fn check_stateless(&self, _context: ()) -> Result<()> {
+        let TwapAuctionDescription {
+            input,
+            output_id,
+            min_output,
+            start_height,
+            end_height,
+            step_count,
+            nonce: _,
+        } = self.description;
+
+        // Fail fast if the input is zero.
+        ensure!(
+            input.amount > Amount::zero(),
+            "input amount MUST be positive (got zero)"
+        );
+
+        // Check that the input amount is less than 52 bits wide.
+        ensure!(
+            input.amount <= MAX_AUCTION_AMOUNT_RESERVES.into(),
+            "input amount MUST be less than 52 bits wide"
+        );
+
+        // Check that we disallow identical input/output ids.
+        ensure!(
+            input.asset_id != output_id,
+            "input id MUST be different from output id"
+        );
+
+        // Check that the min output is greater than zero.
+        ensure!(min_output > 0u128.into(), "min output MUST be positive");
+
+        // Check that the min output is less than 52 bits wide.
+        ensure!(
+            min_output <= MAX_AUCTION_AMOUNT_RESERVES.into(),
+            "min output amount MUST be less than 52 bits wide"
+        );
+
+        // Check that the start and end height are valid.
+        ensure!(
+            start_height < end_height,
+            "the start height MUST be strictly less than the end height (got: start={} >= end={})",
+            start_height,
+            end_height
+        );
+
+        // Check that the step count is positive.
+        ensure!(step_count > 0, "step count MUST be positive (got zero)");
+
+        // Check that the step count is less than 255.
+        ensure!(
+            step_count <= 255,
+            "the twap auction step count MUST be less than 255 (got: {step_count})",
+        );
+
+        // Check that height delta is a multiple of `step_count`.
+        let block_window = end_height.checked_sub(start_height).ok_or_else(|| {
+            anyhow::anyhow!(
+                "underflow ({end_height} < {start_height}) - the validation rules are incoherent!"
+            )
+        })?;
+        ensure!(
+            (block_window % step_count) == 0,
+            "the block window ({block_window}) MUST be a multiple of the step count ({step_count})"
+        );
+
+        Ok(())
+    }
+
+    async fn check_and_execute<S: StateWrite>(&self, mut state: S) -> Result<()> {
+        let schedule = self;
+
+        // Check that `start_height` is in the future.
+        let current_height = state.get_block_height().await?;
+        let start_height = schedule.description.start_height;
+        ensure!(
+            start_height > current_height,
+            "twap auction MUST start in the future (start={}, current={})",
+            start_height,
+            current_height
+        );
+
+        // Check that the `auction_id` is unused.
+        let id = schedule.description.id();
+        ensure!(
+            !state.auction_id_exists(id).await,
+            "the supplied auction id is already known to the chain (id={id})"
+        );
+
+        state
+            .schedule_twap_auction(schedule.description.clone())
+            .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,174 @@
+use std::pin::Pin;
+
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::{Stream, StreamExt, TryStreamExt};
+use pbjson_types::Any;
+use penumbra_sdk_asset::asset;
+use penumbra_sdk_proto::{
+    core::component::auction::v1 as pb, DomainType, Message, Name, StateReadProto, StateWriteProto,
+};
+use tracing::instrument;
+
+use crate::{
+    auction::{dutch::DutchAuction, AuctionId},
+    state_key,
+};
+
+/// Provide access to the index of active auctions.
+///
+/// An auction is active from the moment it is scheduled, until it is closed,
+/// either by its owner or because it ran to completion.
+pub trait ActiveAuctionsRead: StateRead {
+    /// Stream the identifiers of the currently active auctions.
+    ///
+    /// The index is keyed by `(input_id, output_id)`, so the scan is narrowed
+    /// to the matching prefix when `input_id` is set. Filtering on `output_id`
+    /// alone cannot use the index, callers must filter the auction states.
+    fn active_auction_ids(
+        &self,
+        input_id: Option<asset::Id>,
+        output_id: Option<asset::Id>,
+    ) -> Pin<Box<dyn Stream<Item = Result<AuctionId>> + Send + 'static>> {
+        let prefix = match (input_id, output_id) {
+            (Some(input_id), Some(output_id)) => state_key::active::by_pair(input_id, output_id),
+            (Some(input_id), None) => state_key::active::by_input(input_id),
+            (None, _) => state_key::active::prefix().to_string(),
+        };
+
+        self.nonverifiable_prefix::<AuctionId>(prefix.as_bytes())
+            .map(|res| res.map(|(_, auction_id)| auction_id))
+            .boxed()
+    }
+}
+
+impl<T: StateRead + ?Sized> ActiveAuctionsRead for T {}
+
+pub(crate) trait ActiveAuctionsIndex: StateWrite {
+    /// Record an auction as active, indexing it by its asset pair.
+    #[instrument(skip(self))]
+    fn index_active_auction(
+        &mut self,
+        input_id: asset::Id,
+        output_id: asset::Id,
+        auction_id: AuctionId,
+    ) {
+        let key = state_key::active::auction(input_id, output_id, auction_id);
+        self.nonverifiable_put(key.as_bytes().to_vec(), auction_id);
+    }
+
+    /// Remove an auction from the index of active auctions.
+    #[instrument(skip(self))]
+    fn unindex_active_auction(
+        &mut self,
+        input_id: asset::Id,
+        output_id: asset::Id,
+        auction_id: AuctionId,
+    ) {
+        let key = state_key::active::auction(input_id, output_id, auction_id);
+        self.nonverifiable_delete(key.as_bytes().to_vec());
+    }
+}
+
+impl<T: StateWrite + ?Sized> ActiveAuctionsIndex for T {}
+
+/// Backfill the index of active auctions.
+///
+/// Auctions are indexed as they are scheduled, so the Dutch auctions that were
+/// already active when the index was introduced must be indexed by the upgrade
+/// migration.
+#[async_trait]
+pub trait ActiveAuctionsBackfill: StateWrite {
+    /// Index every open Dutch auction in the auction store, returning how many
+    /// were indexed.
+    #[instrument(skip(self))]
+    async fn backfill_active_dutch_auctions(&mut self) -> Result<usize> {
+        let active: Vec<DutchAuction> = self
+            .prefix_raw(state_key::auction_store::prefix())
+            .try_filter_map(|(_, raw)| async move {
+                let any_auction = Any::decode(raw.as_slice())?;
+                if any_auction.type_url != pb::DutchAuction::type_url() {
+                    return Ok(None);
+                }
+                let auction = DutchAuction::decode(any_auction.value.as_ref())?;
+                // Sequence 0 is the opened state, later ones are closed or withdrawn.
+                Ok((auction.state.sequence == 0).then_some(auction))
+            })
+            .try_collect()
+            .await?;
+
+        for auction in &active {
+            self.index_active_auction(
+                auction.description.input.asset_id,
+                auction.description.output_id,
+                auction.description.id(),
+            );
+        }
+
+        Ok(active.len())
+    }
+}
+
+impl<T: StateWrite + ?Sized> ActiveAuctionsBackfill for T {}
+
+#[cfg(test)]
+mod tests {
+    use cnidarium::StateDelta;
+    use penumbra_sdk_asset::Value;
+    use penumbra_sdk_num::Amount;
+
+    use super::*;
+    use crate::auction::dutch::{DutchAuctionDescription, DutchAuctionState};
+
+    fn put_dutch_auction(state: &mut impl StateWrite, nonce: u8, sequence: u64) -> AuctionId {
+        let cache = asset::Cache::with_known_assets();
+        let description = DutchAuctionDescription {
+            input: Value {
+                amount: 100u64.into(),
+                asset_id: cache.get_unit("gm").expect("gm is a known asset").id(),
+            },
+            output_id: cache.get_unit("gn").expect("gn is a known asset").id(),
+            max_output: 200u64.into(),
+            min_output: 100u64.into(),
+            start_height: 10,
+            end_height: 20,
+            step_count: 10,
+            nonce: [nonce; 32],
+        };
+        let id = description.id();
+        let auction = DutchAuction {
+            description,
+            state: DutchAuctionState {
+                sequence,
+                current_position: None,
+                next_trigger: None,
+                input_reserves: 100u64.into(),
+                output_reserves: Amount::zero(),
+            },
+        };
+        let any_auction = Any {
+            type_url: pb::DutchAuction::type_url(),
+            value: auction.encode_to_vec().into(),
+        };
+        state.put_raw(
+            state_key::auction_store::by_id(id),
+            any_auction.encode_to_vec(),
+        );
+        id
+    }
+
+    #[tokio::test]
+    async fn backfill_indexes_open_dutch_auctions() -> anyhow::Result<()> {
+        let mut state = StateDelta::new(());
+        let open = put_dutch_auction(&mut state, 1, 0);
+        let _closed = put_dutch_auction(&mut state, 2, 1);
+        let _withdrawn = put_dutch_auction(&mut state, 3, 2);
+
+        assert_eq!(state.backfill_active_dutch_auctions().await?, 1);
+        let active: Vec<_> = state.active_auction_ids(None, None).try_collect().await?;
+        assert_eq!(active, vec![open]);
+
+        Ok(())
+    }
+}
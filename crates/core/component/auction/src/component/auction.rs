@@ -1,4 +1,5 @@
 use crate::component::dutch_auction::HandleDutchTriggers;
+use crate::component::twap_auction::HandleTwapTriggers;
 use crate::event;
 use anyhow::Result;
 use async_trait::async_trait;
@@ -46,6 +47,7 @@ impl Component for Auction {
     ) {
         let state: &mut S = Arc::get_mut(state).expect("state should be unique");
         let _ = state.process_triggers(end_block.height as u64).await;
+        let _ = state.process_twap_triggers(end_block.height as u64).await;
     }
 
     #[instrument(name = "auction", skip(_state))]
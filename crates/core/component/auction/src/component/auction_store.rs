@@ -8,7 +8,7 @@ use penumbra_sdk_proto::Name;
 use penumbra_sdk_proto::StateReadProto;
 
 use crate::{
-    auction::{dutch::DutchAuction, id::AuctionId},
+    auction::{dutch::DutchAuction, id::AuctionId, twap::TwapAuction},
     state_key,
 };
 
@@ -43,6 +43,29 @@ pub trait AuctionStoreRead: StateRead {
         Ok(Some(DutchAuction::decode(any_auction.value.as_ref())?))
     }
 
+    /// Fetch a [`TwapAuction`] from storage, returning `None` if none
+    /// were found with the provided identifier.
+    ///
+    /// # Errors
+    /// This method returns an error if the auction state associated with the
+    /// specified `auction_id` is *not* of type `TwapAuction`.
+    async fn get_twap_auction_by_id(&self, auction_id: AuctionId) -> Result<Option<TwapAuction>> {
+        let Some(any_auction) = self.get_raw_auction(auction_id).await else {
+            return Ok(None);
+        };
+
+        let twap_auction_type_str = pb::TwapAuction::type_url();
+
+        anyhow::ensure!(
+            any_auction.type_url == twap_auction_type_str,
+            "error deserializing auction state, expected type to be {}, but got: {}",
+            twap_auction_type_str,
+            any_auction.type_url
+        );
+
+        Ok(Some(TwapAuction::decode(any_auction.value.as_ref())?))
+    }
+
     /// Returns raw auction data if found under the specified `auction_id`,
     /// and `None` otherwise
     async fn get_raw_auction(&self, auction_id: AuctionId) -> Option<Any> {
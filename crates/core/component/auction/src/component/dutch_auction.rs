@@ -3,7 +3,9 @@ use std::pin::Pin;
 
 use crate::auction::dutch::{DutchAuction, DutchAuctionDescription, DutchAuctionState};
 use crate::auction::AuctionId;
+use crate::component::position_allocator::AuctionPositionAllocator;
 use crate::component::trigger_data::TriggerData;
+use crate::component::ActiveAuctionsIndex;
 use crate::component::AuctionCircuitBreaker;
 use crate::component::AuctionStoreRead;
 use crate::{event, state_key};
@@ -12,9 +14,7 @@ use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use futures::StreamExt;
 use penumbra_sdk_asset::{Balance, Value};
-use penumbra_sdk_dex::component::{PositionManager, PositionRead, StateReadExt as _};
-use penumbra_sdk_dex::lp::position::{self, Position};
-use penumbra_sdk_dex::lp::Reserves;
+use penumbra_sdk_dex::component::PositionManager;
 use penumbra_sdk_dex::DirectedTradingPair;
 use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::core::component::auction::v1 as pb;
@@ -23,6 +23,9 @@ use penumbra_sdk_sct::component::clock::EpochRead;
 use prost::{Message, Name};
 use tracing::instrument;
 
+/// The domain separator used to derive the nonces of Dutch auction positions.
+const DUTCH_AUCTION_NONCE_DOMAIN_SEP: &[u8] = b"penum-DA-nonce";
+
 #[async_trait]
 pub(crate) trait DutchAuctionManager: StateWrite {
     /// Schedule an auction for the specified [`DutchAuctionDescritpion`], initializing
@@ -77,6 +80,12 @@ pub(crate) trait DutchAuctionManager: StateWrite {
             .context("failed to schedule auction")?;
         // Set the trigger
         self.set_trigger_for_dutch_id(auction_id, next_trigger);
+        // Index the auction as active
+        self.index_active_auction(
+            description.input.asset_id,
+            description.output_id,
+            auction_id,
+        );
         // Write position to state
         self.write_dutch_auction_state(dutch_auction);
         // Emit an event
@@ -226,6 +235,7 @@ pub(crate) trait DutchAuctionManager: StateWrite {
             // If the termination condition has been reached, we set the auction
             // sequence to 1 (Closed).
             new_dutch_auction.state.sequence = 1;
+            self.unindex_active_auction(auction_input_id, auction_output_id, auction_id);
         } else {
             // Otherwise, we compute the next trigger height and generate a liquidity
             // position for the new auction round.
@@ -245,7 +255,14 @@ pub(crate) trait DutchAuctionManager: StateWrite {
             // In that case, we will not have a position id to track, but we register the auction
             // for the next trigger.
             let maybe_id = self
-                .allocate_position(pair, input_reserves, step_index, price, auction_nonce)
+                .allocate_position(
+                    pair,
+                    input_reserves,
+                    step_index,
+                    price,
+                    DUTCH_AUCTION_NONCE_DOMAIN_SEP,
+                    auction_nonce,
+                )
                 .await;
             new_dutch_auction.state.current_position = maybe_id;
             new_dutch_auction.state.next_trigger = NonZeroU64::new(next_trigger);
@@ -359,6 +376,7 @@ pub(crate) trait DutchAuctionManager: StateWrite {
         if let Some(height) = next_trigger {
             self.unset_trigger_for_dutch_id(auction_id, height.into())
         }
+        self.unindex_active_auction(input_id, output_id, auction_id);
         let closed_auction = DutchAuction {
             description: auction_to_close.description,
             state: DutchAuctionState {
@@ -473,95 +491,6 @@ pub(crate) trait DutchAuctionData: StateRead {
 impl<T: StateRead + ?Sized> DutchAuctionData for T {}
 
 trait Inner: StateWrite {
-    #[instrument(skip(self, auction_nonce), ret, level = "debug")]
-    /// Allocate a liquidity position for a Dutch auction.
-    /// Returns `None` if no position was allocated, otherwise returns the position id.
-    ///
-    /// # Panics
-    /// This method panics if a serious invariant is breached:
-    /// - A VCB update fails
-    /// - opening a position fails with an error (invariant breach)
-    async fn allocate_position(
-        &mut self,
-        pair: DirectedTradingPair,
-        input_reserves: Amount,
-        step_index: u64,
-        (p, q): (Amount, Amount),
-        auction_nonce: [u8; 32],
-    ) -> Option<position::Id> {
-        // Before we do all this work of allocating the LP, or figuring out a nonce
-        // we check if the DEX is enabled. If it is not, we can short-circuit.
-        if !self
-            .get_dex_params()
-            .await
-            .expect("dex parameters are available")
-            .is_enabled
-        {
-            return None;
-        }
-
-        // Next, we construct a liquidity position for this auction, and send it to
-        // the DEX. We must fina nonce that is unique, and that the DEX will accept.
-        // To do this, we hash the auction nonce, step index, and attempt counter.
-        //
-        // `position_nonce = H(DS || auction_nonce || step_index || attempt_counter)`
-        // until the resulting position id (based on the nonce) is unique and accepted.
-        //
-        // We must do this because `PositionManager::open_position` will reject duplicates.
-        let mut attempt_counter = 0u64;
-
-        loop {
-            tracing::trace!(attempt_counter, "trying to find a valid nonce to deploy lp");
-            let lp_reserves = Reserves {
-                r1: input_reserves,
-                r2: Amount::zero(),
-            };
-
-            let full_hash = blake2b_simd::Params::default()
-                .personal(b"penum-DA-nonce")
-                .to_state()
-                .update(&auction_nonce)
-                .update(&step_index.to_le_bytes())
-                .update(&attempt_counter.to_le_bytes())
-                .finalize();
-            let mut tough_nonce = [0u8; 32];
-            tough_nonce[0..32].copy_from_slice(&full_hash.as_bytes()[0..32]);
-
-            let mut lp = Position::new_with_nonce(tough_nonce, pair, 0u32, p, q, lp_reserves);
-            // PSA, hackers: Our goal is to *only* acquire some output assets.
-            // This means that we want to close the position as soon as it gets
-            // filled. Otherwise, it could round-trip back to the input asset,
-            // thus defeating the purpose for this logic.
-            lp.close_on_fill = true;
-
-            let position_id = lp.id();
-
-            if self.check_position_by_id(&position_id).await {
-                tracing::debug!(
-                    attempt_counter,
-                    ?position_id,
-                    "another position with our attempted id exists, retrying"
-                );
-                attempt_counter += 1;
-                continue;
-            } else {
-                tracing::debug!(
-                    attempt_counter,
-                    ?position_id,
-                    "attempting to open position with unique id"
-                );
-                self.auction_vcb_debit(lp.reserves_1())
-                    .await
-                    .expect("r1 vcb debit does not underflow");
-                self.auction_vcb_debit(lp.reserves_2())
-                    .await
-                    .expect("r2 vcb debit does not underflow");
-                self.open_position(lp).await.expect("auction can open a LP");
-                return Some(position_id);
-            }
-        }
-    }
-
     /// Serialize a `DutchAuction` as an `Any` into chain state.
     #[instrument(skip(self))]
     fn write_dutch_auction_state(&mut self, new_state: DutchAuction) {
@@ -1,13 +1,19 @@
 pub mod action_handler;
+mod active_index;
 mod auction;
 mod auction_store;
 mod dutch_auction;
 pub mod metrics;
+mod position_allocator;
 pub mod rpc;
 mod trigger_data;
+mod twap_auction;
 
+pub(crate) use active_index::ActiveAuctionsIndex;
+pub use active_index::{ActiveAuctionsBackfill, ActiveAuctionsRead};
 pub use auction::Auction;
 pub(crate) use auction::AuctionCircuitBreaker;
 pub use auction::{StateReadExt, StateWriteExt};
 pub use auction_store::AuctionStoreRead;
 pub(crate) use dutch_auction::DutchAuctionManager;
+pub(crate) use twap_auction::TwapAuctionManager;
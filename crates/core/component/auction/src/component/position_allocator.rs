@@ -0,0 +1,107 @@
+use crate::component::AuctionCircuitBreaker;
+use cnidarium::StateWrite;
+use penumbra_sdk_dex::component::{PositionManager, PositionRead, StateReadExt as _};
+use penumbra_sdk_dex::lp::position::{self, Position};
+use penumbra_sdk_dex::lp::Reserves;
+use penumbra_sdk_dex::DirectedTradingPair;
+use penumbra_sdk_num::Amount;
+use tracing::instrument;
+
+/// Deploys the protocol-controlled liquidity positions backing auctions.
+pub(crate) trait AuctionPositionAllocator: StateWrite {
+    #[instrument(skip(self, auction_nonce), ret, level = "debug")]
+    /// Allocate a liquidity position for an auction, selling `input_reserves`
+    /// of the pair's start asset at the price `p/q`.
+    /// Returns `None` if no position was allocated, otherwise returns the position id.
+    ///
+    /// The position nonce is derived from `auction_nonce` and `step_index`, using
+    /// `nonce_domain_sep` to separate auction kinds.
+    ///
+    /// # Panics
+    /// This method panics if a serious invariant is breached:
+    /// - A VCB update fails
+    /// - opening a position fails with an error (invariant breach)
+    async fn allocate_position(
+        &mut self,
+        pair: DirectedTradingPair,
+        input_reserves: Amount,
+        step_index: u64,
+        (p, q): (Amount, Amount),
+        nonce_domain_sep: &[u8],
+        auction_nonce: [u8; 32],
+    ) -> Option<position::Id> {
+        // Before we do all this work of allocating the LP, or figuring out a nonce
+        // we check if the DEX is enabled. If it is not, we can short-circuit.
+        if !self
+            .get_dex_params()
+            .await
+            .expect("dex parameters are available")
+            .is_enabled
+        {
+            return None;
+        }
+
+        // Next, we construct a liquidity position for this auction, and send it to
+        // the DEX. We must fina nonce that is unique, and that the DEX will accept.
+        // To do this, we hash the auction nonce, step index, and attempt counter.
+        //
+        // `position_nonce = H(DS || auction_nonce || step_index || attempt_counter)`
+        // until the resulting position id (based on the nonce) is unique and accepted.
+        //
+        // We must do this because `PositionManager::open_position` will reject duplicates.
+        let mut attempt_counter = 0u64;
+
+        loop {
+            tracing::trace!(attempt_counter, "trying to find a valid nonce to deploy lp");
+            let lp_reserves = Reserves {
+                r1: input_reserves,
+                r2: Amount::zero(),
+            };
+
+            let full_hash = blake2b_simd::Params::default()
+                .personal(nonce_domain_sep)
+                .to_state()
+                .update(&auction_nonce)
+                .update(&step_index.to_le_bytes())
+                .update(&attempt_counter.to_le_bytes())
+                .finalize();
+            let mut tough_nonce = [0u8; 32];
+            tough_nonce[0..32].copy_from_slice(&full_hash.as_bytes()[0..32]);
+
+            let mut lp = Position::new_with_nonce(tough_nonce, pair, 0u32, p, q, lp_reserves);
+            // PSA, hackers: Our goal is to *only* acquire some output assets.
+            // This means that we want to close the position as soon as it gets
+            // filled. Otherwise, it could round-trip back to the input asset,
+            // thus defeating the purpose for this logic.
+            lp.close_on_fill = true;
+
+            let position_id = lp.id();
+
+            if self.check_position_by_id(&position_id).await {
+                tracing::debug!(
+                    attempt_counter,
+                    ?position_id,
+                    "another position with our attempted id exists, retrying"
+                );
+                attempt_counter += 1;
+                continue;
+            } else {
+                tracing::debug!(
+                    attempt_counter,
+                    ?position_id,
+                    "attempting to open position with unique id"
+                );
+                self.auction_vcb_debit(lp.reserves_1())
+                    .await
+                    .expect("r1 vcb debit does not underflow");
+                self.auction_vcb_debit(lp.reserves_2())
+                    .await
+                    .expect("r2 vcb debit does not underflow");
+                self.open_position(lp).await.expect("auction can open a LP");
+                return Some(position_id);
+            }
+        }
+    }
+}
+
+impl<T: StateWrite + ?Sized> AuctionPositionAllocator for T {}
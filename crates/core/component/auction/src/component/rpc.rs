@@ -1,12 +1,16 @@
 #![allow(unused)] // TODO: remove this when filling in the RPCs
 
+use pbjson_types::Any;
+use penumbra_sdk_asset::asset;
 use penumbra_sdk_dex::{component::PositionRead, lp::position};
 use penumbra_sdk_proto::{
     core::component::auction::v1 as pb,
     core::component::auction::v1::{
-        query_service_server::QueryService, AuctionStateByIdRequest, AuctionStateByIdResponse,
-        AuctionStateByIdsRequest, AuctionStateByIdsResponse, DutchAuctionState,
+        query_service_server::QueryService, ActiveAuctionsRequest, ActiveAuctionsResponse,
+        AuctionStateByIdRequest, AuctionStateByIdResponse, AuctionStateByIdsRequest,
+        AuctionStateByIdsResponse, DutchAuctionState,
     },
+    core::component::dex::v1 as pb_dex,
     DomainType,
 };
 
@@ -19,9 +23,10 @@ use tonic::Status;
 use tracing::instrument;
 
 use crate::auction::dutch::DutchAuction;
+use crate::auction::twap::TwapAuction;
 
-use super::{action_handler::dutch, AuctionStoreRead};
-use cnidarium::Storage;
+use super::{action_handler::dutch, ActiveAuctionsRead, AuctionStoreRead};
+use cnidarium::{Snapshot, Storage};
 
 pub struct Server {
     storage: Storage,
@@ -54,27 +59,8 @@ impl QueryService for Server {
             .await
             .ok_or_else(|| tonic::Status::not_found("auction data not found for specified id"))?;
 
-        // Note: we can easily optimize this by adding a lookup table for auction_id -> position id and
-        // save on deserialization or needing to "support" things in this rpc.
-        let maybe_lp = if raw_auction.type_url == pb::DutchAuction::type_url() {
-            let dutch_auction = DutchAuction::decode(raw_auction.value.as_ref())
-                .map_err(|_| tonic::Status::internal("error deserializing auction state"))?;
-
-            dutch_auction.state.current_position
-        } else {
-            return Err(tonic::Status::unimplemented("unrecognized auction type"));
-        };
-
-        let positions = match maybe_lp {
-            Some(id) => state
-                .position_by_id(&id)
-                .await
-                .map_err(|_| tonic::Status::internal("error fetching position state"))?
-                .into_iter()
-                .map(Into::into)
-                .collect(),
-            None => Vec::new(),
-        };
+        let (_, maybe_lp) = auction_output_and_position(&raw_auction)?;
+        let positions = fetch_positions(&state, maybe_lp).await?;
 
         Ok(tonic::Response::new(AuctionStateByIdResponse {
             auction: Some(raw_auction),
@@ -93,4 +79,102 @@ impl QueryService for Server {
     ) -> Result<tonic::Response<Self::AuctionStateByIdsStream>, Status> {
         todo!()
     }
+
+    type ActiveAuctionsStream =
+        Pin<Box<dyn futures::Stream<Item = Result<ActiveAuctionsResponse, tonic::Status>> + Send>>;
+
+    #[instrument(skip(self, request))]
+    async fn active_auctions(
+        &self,
+        request: tonic::Request<ActiveAuctionsRequest>,
+    ) -> Result<tonic::Response<Self::ActiveAuctionsStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let input_id: Option<asset::Id> = request
+            .input_id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("invalid input asset id"))?;
+        let output_id: Option<asset::Id> = request
+            .output_id
+            .map(TryInto::try_into)
+            .transpose()
+            .map_err(|_| Status::invalid_argument("invalid output asset id"))?;
+
+        let s = try_stream! {
+            let mut auction_ids = state.active_auction_ids(input_id, output_id);
+            while let Some(auction_id) = auction_ids.next().await {
+                let auction_id = auction_id.map_err(|e| {
+                    Status::internal(format!("error reading active auction index: {e}"))
+                })?;
+
+                let Some(raw_auction) = state.get_raw_auction(auction_id).await else {
+                    continue;
+                };
+
+                let (auction_output_id, maybe_lp) = auction_output_and_position(&raw_auction)?;
+                // The index can only narrow the scan on the output asset if
+                // the input asset is specified, so we filter it here.
+                if output_id.is_some_and(|id| id != auction_output_id) {
+                    continue;
+                }
+
+                let positions = fetch_positions(&state, maybe_lp).await?;
+
+                yield ActiveAuctionsResponse {
+                    id: Some(auction_id.into()),
+                    auction: Some(raw_auction),
+                    positions,
+                };
+            }
+        };
+
+        Ok(tonic::Response::new(s.boxed()))
+    }
+}
+
+/// Decode the output asset of an auction, and the position it currently controls, if any.
+///
+/// Note: we can easily optimize this by adding a lookup table for auction_id -> position id and
+/// save on deserialization or needing to "support" things in this rpc.
+fn auction_output_and_position(
+    raw_auction: &Any,
+) -> Result<(asset::Id, Option<position::Id>), Status> {
+    if raw_auction.type_url == pb::DutchAuction::type_url() {
+        let dutch_auction = DutchAuction::decode(raw_auction.value.as_ref())
+            .map_err(|_| tonic::Status::internal("error deserializing auction state"))?;
+
+        Ok((
+            dutch_auction.description.output_id,
+            dutch_auction.state.current_position,
+        ))
+    } else if raw_auction.type_url == pb::TwapAuction::type_url() {
+        let twap_auction = TwapAuction::decode(raw_auction.value.as_ref())
+            .map_err(|_| tonic::Status::internal("error deserializing auction state"))?;
+
+        Ok((
+            twap_auction.description.output_id,
+            twap_auction.state.current_position,
+        ))
+    } else {
+        Err(tonic::Status::unimplemented("unrecognized auction type"))
+    }
+}
+
+async fn fetch_positions(
+    state: &Snapshot,
+    maybe_lp: Option<position::Id>,
+) -> Result<Vec<pb_dex::Position>, Status> {
+    let positions = match maybe_lp {
+        Some(id) => state
+            .position_by_id(&id)
+            .await
+            .map_err(|_| tonic::Status::internal("error fetching position state"))?
+            .into_iter()
+            .map(Into::into)
+            .collect(),
+        None => Vec::new(),
+    };
+    Ok(positions)
 }
@@ -0,0 +1,598 @@
+use std::num::NonZeroU64;
+use std::pin::Pin;
+
+use crate::auction::twap::{TwapAuction, TwapAuctionDescription, TwapAuctionState};
+use crate::auction::AuctionId;
+use crate::component::position_allocator::AuctionPositionAllocator;
+use crate::component::trigger_data::TriggerData;
+use crate::component::ActiveAuctionsIndex;
+use crate::component::AuctionCircuitBreaker;
+use crate::component::AuctionStoreRead;
+use crate::{event, state_key};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::StreamExt;
+use penumbra_sdk_asset::{Balance, Value};
+use penumbra_sdk_dex::component::PositionManager;
+use penumbra_sdk_dex::DirectedTradingPair;
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_proto::core::component::auction::v1 as pb;
+use penumbra_sdk_proto::StateWriteProto;
+use prost::{Message, Name};
+use tracing::instrument;
+
+/// The domain separator used to derive the nonces of TWAP auction positions.
+const TWAP_AUCTION_NONCE_DOMAIN_SEP: &[u8] = b"penum-TA-nonce";
+
+#[async_trait]
+pub(crate) trait TwapAuctionManager: StateWrite {
+    /// Schedule an auction for the specified [`TwapAuctionDescription`], initializing
+    /// its state, and registering it for execution by the component.
+    ///
+    /// Unlike Dutch auctions, the first trigger fires at `start_height`, so that
+    /// the first tranche is released as soon as the auction starts.
+    #[instrument(skip(self), level = "debug")]
+    async fn schedule_twap_auction(&mut self, description: TwapAuctionDescription) -> Result<()> {
+        let auction_id = description.id();
+        tracing::debug!(?auction_id, "attempting to schedule a twap auction");
+
+        let next_trigger = description.start_height;
+
+        let state = TwapAuctionState {
+            sequence: 0,
+            current_position: None,
+            next_trigger: NonZeroU64::new(next_trigger),
+            input_reserves: description.input.amount,
+            output_reserves: Amount::zero(),
+        };
+
+        let twap_auction = TwapAuction {
+            description: description.clone(),
+            state,
+        };
+
+        // Deposit into the component's value balance.
+        self.auction_vcb_credit(twap_auction.description.input)
+            .await
+            .context("failed to schedule auction")?;
+        // Set the trigger
+        self.set_trigger_for_twap_id(auction_id, next_trigger);
+        // Index the auction as active
+        self.index_active_auction(
+            description.input.asset_id,
+            description.output_id,
+            auction_id,
+        );
+        // Write position to state
+        self.write_twap_auction_state(twap_auction);
+        // Emit an event
+        self.record_proto(event::twap_auction_schedule_event(auction_id, description));
+        Ok(())
+    }
+
+    /// Execute the [`TwapAuction`] associated with [`AuctionId`], releasing
+    /// the next tranche of its input.
+    ///
+    /// For a given auction, this translates into withdrawing the previous liquidity
+    /// position, crediting its reserves to the auction, and deploying whatever part
+    /// of the input has been released but not sold yet at the floor price.
+    #[instrument(skip(self))]
+    async fn execute_twap_auction(
+        &mut self,
+        auction_id: AuctionId,
+        trigger_height: u64,
+    ) -> Result<()> {
+        tracing::trace!(?auction_id, "executing a twap auction");
+        let mut auction = self
+            .get_twap_auction_by_id(auction_id)
+            .await
+            .expect("no deserialization errors")
+            .expect("the auction exists");
+
+        // Ending an auction removes its trigger, but if one is left behind, the
+        // auction has already been settled and there is nothing to execute.
+        if auction.state.sequence != 0 {
+            tracing::debug!(
+                sequence = auction.state.sequence,
+                "twap auction is no longer open, skipping execution"
+            );
+            self.unset_trigger_for_twap_id(auction_id, trigger_height);
+            return Ok(());
+        }
+
+        let auction_input_id = auction.description.input.asset_id;
+        let auction_output_id = auction.description.output_id;
+        let auction_trigger = TriggerData {
+            start_height: auction.description.start_height,
+            end_height: auction.description.end_height,
+            step_count: auction.description.step_count,
+        };
+
+        // Recover the LP's balances, if it exists.
+        let lp_reserves = if let Some(auction_lp_id) = auction.state.current_position {
+            self.close_position_by_id(&auction_lp_id)
+                .await
+                .map_err(|e| {
+                    tracing::error!(
+                        ?e,
+                        ?auction_lp_id,
+                        ?auction_id,
+                        "failed to close twap auction LP"
+                    )
+                })
+                .expect("position should exist and be opened or closed");
+            self.withdraw_position(auction_lp_id, 0u64)
+                .await
+                .map_err(|e| {
+                    tracing::error!(
+                        ?e,
+                        ?auction_lp_id,
+                        ?auction_id,
+                        "failed to withdraw twap auction LP"
+                    )
+                })
+                .expect("no state incoherence")
+        } else {
+            Balance::zero()
+        };
+
+        // We remove the execution trigger that we are currently processing,
+        // and untrack the old position.
+        self.unset_trigger_for_twap_id(auction_id, trigger_height);
+        auction.state.current_position = None;
+        auction.state.next_trigger = None;
+
+        /* *********** value transfer *************** */
+        let (lp_inflow_input_asset, lp_inflow_output_asset) =
+            lp_inflows(&lp_reserves, auction_input_id, auction_output_id);
+
+        self.auction_vcb_credit(lp_inflow_input_asset)
+            .await
+            .context("failed to absorb LP inflow of input asset into auction value balance")?;
+        self.auction_vcb_credit(lp_inflow_output_asset)
+            .await
+            .context("failed to absorb LP inflow of output asset into auction value balance")?;
+
+        auction.state.input_reserves += lp_inflow_input_asset.amount;
+        auction.state.output_reserves += lp_inflow_output_asset.amount;
+        /* ***************** end value transfer ************************** */
+
+        let step_index = auction_trigger
+            .compute_step_index(trigger_height)
+            .expect("trigger data is validated");
+
+        let is_auction_expired = step_index >= auction.description.step_count;
+        let is_auction_filled = auction.state.input_reserves == Amount::zero();
+
+        if is_auction_expired || is_auction_filled {
+            auction.state.sequence = 1;
+            self.unindex_active_auction(auction_input_id, auction_output_id, auction_id);
+        } else {
+            let next_trigger = auction_trigger.compute_next_trigger_height(trigger_height);
+
+            // Only the part of the input that has been released, but not sold yet,
+            // is offered. This carries any unsold remainder over to the next tranche.
+            let tranche = compute_tranche_at_step(
+                &auction.description,
+                auction.state.input_reserves,
+                step_index,
+            );
+
+            if tranche > Amount::zero() {
+                auction.state.input_reserves -= tranche;
+                let pair = DirectedTradingPair::new(auction_input_id, auction_output_id);
+                // Every tranche is offered at the floor price.
+                let price = (
+                    auction.description.min_output,
+                    auction.description.input.amount,
+                );
+
+                // As for Dutch auctions, the position might not be allocated if the DEX
+                // is disabled, in which case we return the tranche to the reserves.
+                let maybe_id = self
+                    .allocate_position(
+                        pair,
+                        tranche,
+                        step_index,
+                        price,
+                        TWAP_AUCTION_NONCE_DOMAIN_SEP,
+                        auction.description.nonce,
+                    )
+                    .await;
+                if maybe_id.is_none() {
+                    auction.state.input_reserves += tranche;
+                }
+                auction.state.current_position = maybe_id;
+            }
+
+            auction.state.next_trigger = NonZeroU64::new(next_trigger);
+            self.set_trigger_for_twap_id(auction_id, next_trigger);
+        }
+
+        let auction_state = auction.state.clone();
+        self.write_twap_auction_state(auction);
+
+        if is_auction_expired {
+            self.record_proto(event::twap_auction_expired(auction_id, auction_state));
+        } else if is_auction_filled {
+            self.record_proto(event::twap_auction_exhausted(auction_id, auction_state));
+        } else {
+            self.record_proto(event::twap_auction_updated(auction_id, auction_state));
+        }
+        Ok(())
+    }
+
+    /// Terminate and update the supplied TWAP auction state.
+    #[instrument(skip_all, fields(auction_id = %auction_to_close.description.id(), current_sequence = auction_to_close.state.sequence))]
+    async fn end_twap_auction(&mut self, mut auction_to_close: TwapAuction) -> Result<()> {
+        // If the auction is already closed, or withdrawn, we short-circuit.
+        if auction_to_close.state.sequence >= 1 {
+            tracing::trace!("twap auction is already closed, short-circuiting");
+            return Ok(());
+        }
+
+        let auction_id = auction_to_close.description.id();
+        let input_id = auction_to_close.description.input.asset_id;
+        let output_id = auction_to_close.description.output_id;
+
+        /* ********* Value transfer ********* */
+        let lp_reserves = if let Some(position_id) = auction_to_close.state.current_position {
+            self.close_position_by_id(&position_id).await?;
+            self.withdraw_position(position_id, 0).await?
+        } else {
+            Balance::zero()
+        };
+        let (lp_inflow_input_asset, lp_inflow_output_asset) =
+            lp_inflows(&lp_reserves, input_id, output_id);
+
+        self.auction_vcb_credit(lp_inflow_input_asset)
+            .await
+            .context("failed to absorb LP inflow of input asset into auction value balance")?;
+        self.auction_vcb_credit(lp_inflow_output_asset)
+            .await
+            .context("failed to absorb LP inflow of output asset into auction value balance")?;
+        /* ******** End value transfer ******* */
+
+        if let Some(height) = auction_to_close.state.next_trigger {
+            self.unset_trigger_for_twap_id(auction_id, height.into())
+        }
+        self.unindex_active_auction(input_id, output_id, auction_id);
+
+        auction_to_close.state.sequence = 1;
+        auction_to_close.state.current_position = None;
+        auction_to_close.state.next_trigger = None;
+        auction_to_close.state.input_reserves += lp_inflow_input_asset.amount;
+        auction_to_close.state.output_reserves += lp_inflow_output_asset.amount;
+        self.write_twap_auction_state(auction_to_close);
+        Ok(())
+    }
+
+    /// Withdraw a TWAP auction, zero-ing out its state, and increasing its sequence
+    /// number.
+    ///
+    /// # Errors
+    /// This method errors if the auction is still open, since its reserves may be
+    /// deployed in the DEX.
+    async fn withdraw_twap_auction(&mut self, mut auction: TwapAuction) -> Result<Balance> {
+        anyhow::ensure!(
+            auction.state.sequence >= 1,
+            "the twap auction must be ended before it is withdrawn"
+        );
+
+        let previous_input_reserves = Value {
+            amount: auction.state.input_reserves,
+            asset_id: auction.description.input.asset_id,
+        };
+        let previous_output_reserves = Value {
+            amount: auction.state.output_reserves,
+            asset_id: auction.description.output_id,
+        };
+
+        // We debit the auction's value balance with the outflows, aborting
+        // if the balance underflows.
+        self.auction_vcb_debit(previous_input_reserves)
+            .await
+            .context("couldn't withdraw input reserves from auction")?;
+        self.auction_vcb_debit(previous_output_reserves)
+            .await
+            .context("couldn't withdraw output reserves from auction")?;
+
+        let withdraw_balance =
+            Balance::from(previous_input_reserves) + Balance::from(previous_output_reserves);
+
+        auction.state.sequence = auction.state.sequence.saturating_add(1);
+        auction.state.current_position = None;
+        auction.state.next_trigger = None;
+        auction.state.input_reserves = Amount::zero();
+        auction.state.output_reserves = Amount::zero();
+        self.record_proto(event::twap_auction_withdrawn(
+            auction.description.id(),
+            auction.state.clone(),
+        ));
+        self.write_twap_auction_state(auction);
+
+        Ok(withdraw_balance)
+    }
+}
+
+impl<T: StateWrite + ?Sized> TwapAuctionManager for T {}
+
+#[async_trait]
+pub(crate) trait HandleTwapTriggers: StateWrite {
+    /// Process the trigger height for a [`TwapAuction`].
+    #[instrument(skip(self))]
+    async fn process_twap_triggers(&mut self, trigger_height: u64) -> Result<()> {
+        let auction_ids: Vec<AuctionId> = self
+            .stream_twap_ids_by_trigger(trigger_height)
+            .await
+            .collect()
+            .await;
+
+        for auction_id in auction_ids.into_iter() {
+            self.execute_twap_auction(auction_id, trigger_height)
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> HandleTwapTriggers for T {}
+
+#[async_trait]
+pub(crate) trait TwapAuctionData: StateRead {
+    async fn stream_twap_ids_by_trigger(
+        &self,
+        trigger_height: u64,
+    ) -> Pin<Box<dyn futures::Stream<Item = AuctionId> + Send + 'static>> {
+        use penumbra_sdk_proto::StateReadProto;
+        let prefix_key = state_key::twap::trigger::by_height(trigger_height)
+            .as_bytes()
+            .to_vec();
+
+        self.nonverifiable_prefix::<AuctionId>(&prefix_key)
+            .map(|res| {
+                let (_, auction_id) = res.expect("no deserialization error");
+                auction_id
+            })
+            .boxed()
+    }
+}
+
+impl<T: StateRead + ?Sized> TwapAuctionData for T {}
+
+trait Inner: StateWrite {
+    /// Serialize a `TwapAuction` as an `Any` into chain state.
+    #[instrument(skip(self))]
+    fn write_twap_auction_state(&mut self, new_state: TwapAuction) {
+        let id = new_state.description.id();
+        let key = state_key::auction_store::by_id(id);
+        let pb_state: pb::TwapAuction = new_state.into();
+
+        let any_auction = prost_types::Any {
+            type_url: pb::TwapAuction::type_url(),
+            value: pb_state.encode_to_vec(),
+        };
+
+        self.put_raw(key, any_auction.encode_to_vec());
+    }
+
+    /// Set a trigger for a TWAP auction.
+    #[instrument(skip(self))]
+    fn set_trigger_for_twap_id(&mut self, auction_id: AuctionId, trigger_height: u64) {
+        let trigger_path = state_key::twap::trigger::auction_at_height(auction_id, trigger_height);
+        self.nonverifiable_put(trigger_path.as_bytes().to_vec(), auction_id);
+    }
+
+    /// Delete a trigger for a TWAP auction.
+    #[instrument(skip(self))]
+    fn unset_trigger_for_twap_id(&mut self, auction_id: AuctionId, trigger_height: u64) {
+        let trigger_path = state_key::twap::trigger::auction_at_height(auction_id, trigger_height);
+        self.nonverifiable_delete(trigger_path.as_bytes().to_vec());
+    }
+}
+
+impl<T: StateWrite + ?Sized> Inner for T {}
+
+/// Split the reserves withdrawn from an auction LP into its input and output inflows.
+fn lp_inflows(
+    lp_reserves: &Balance,
+    input_id: penumbra_sdk_asset::asset::Id,
+    output_id: penumbra_sdk_asset::asset::Id,
+) -> (Value, Value) {
+    let inflow = |asset_id| Value {
+        asset_id,
+        amount: lp_reserves
+            .provided()
+            .filter(|v| v.asset_id == asset_id)
+            .map(|v| v.amount)
+            .sum::<Amount>(),
+    };
+    (inflow(input_id), inflow(output_id))
+}
+
+/// Compute the amount of input to offer at `step_index`, given the auction's
+/// current `input_reserves`.
+///
+/// After `step_index + 1` steps, `input * (step_index + 1) / step_count` has been
+/// released. Whatever part of it hasn't been sold yet is offered, which is capped
+/// by the reserves.
+fn compute_tranche_at_step(
+    description: &TwapAuctionDescription,
+    input_reserves: Amount,
+    step_index: u64,
+) -> Amount {
+    let input = description.input.amount;
+    let released = input * Amount::from(step_index + 1) / Amount::from(description.step_count);
+    let sold = input.saturating_sub(&input_reserves);
+    released.saturating_sub(&sold).min(input_reserves)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use penumbra_sdk_asset::asset;
+
+    fn description(input: u64, step_count: u64) -> TwapAuctionDescription {
+        let cache = asset::Cache::with_known_assets();
+        let gm = cache.get_unit("gm").expect("gm is a known asset").id();
+        let gn = cache.get_unit("gn").expect("gn is a known asset").id();
+        TwapAuctionDescription {
+            input: Value {
+                amount: input.into(),
+                asset_id: gm,
+            },
+            output_id: gn,
+            min_output: 50u64.into(),
+            start_height: 10,
+            end_height: 10 + 4 * step_count,
+            step_count,
+            nonce: [0u8; 32],
+        }
+    }
+
+    #[test]
+    fn tranches_release_input_evenly() {
+        let twap = description(100, 4);
+
+        // Nothing sells: each step offers everything released so far.
+        let mut reserves = Amount::from(100u64);
+        let offered: Vec<_> = (0..4)
+            .map(|step| compute_tranche_at_step(&twap, reserves, step))
+            .collect();
+        assert_eq!(
+            offered,
+            vec![25u64.into(), 50u64.into(), 75u64.into(), 100u64.into()]
+        );
+
+        // Everything sells: each step offers exactly one tranche.
+        for step in 0..4 {
+            let tranche = compute_tranche_at_step(&twap, reserves, step);
+            assert_eq!(tranche, 25u64.into());
+            reserves -= tranche;
+        }
+        assert_eq!(reserves, Amount::zero());
+    }
+
+    #[test]
+    fn tranches_carry_over_unsold_input() {
+        let twap = description(100, 4);
+
+        // The first tranche only sold 10, so the next step offers its 15
+        // remaining units, along with the second tranche.
+        assert_eq!(
+            compute_tranche_at_step(&twap, 90u64.into(), 1),
+            Amount::from(40u64)
+        );
+        // Indivisible inputs are released as the rounding allows.
+        let twap = description(3, 4);
+        let offered: Vec<_> = (0..4)
+            .map(|step| compute_tranche_at_step(&twap, 3u64.into(), step))
+            .collect();
+        assert_eq!(
+            offered,
+            vec![0u64.into(), 1u64.into(), 2u64.into(), 3u64.into()]
+        );
+    }
+
+    #[test]
+    fn triggers_cover_the_auction_window() {
+        let twap = description(100, 4);
+        let trigger = TriggerData {
+            start_height: twap.start_height,
+            end_height: twap.end_height,
+            step_count: twap.step_count,
+        };
+
+        // The first tranche is released at the start height, and the auction
+        // closes at the end height.
+        let mut height = twap.start_height;
+        let mut steps = vec![];
+        while height <= twap.end_height {
+            steps.push(trigger.compute_step_index(height).expect("valid trigger"));
+            height = trigger.compute_next_trigger_height(height);
+        }
+        assert_eq!(steps, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    /// Walks an auction through scheduling, its first trigger, ending it and
+    /// withdrawing it, checking that its input flows through the auction's value
+    /// balance and back out.
+    async fn twap_auction_lifecycle_balances_value() -> anyhow::Result<()> {
+        use crate::component::StateReadExt as _;
+        use cnidarium::StateDelta;
+        use penumbra_sdk_dex::{component::StateWriteExt as _, DexParameters};
+        use penumbra_sdk_sct::component::clock::EpochManager as _;
+
+        let mut state = StateDelta::new(());
+        state.put_dex_params(DexParameters::default());
+        let twap = description(100, 4);
+        let id = twap.id();
+        let input_id = twap.input.asset_id;
+
+        // Scheduling the auction deposits its input.
+        state.schedule_twap_auction(twap.clone()).await?;
+        assert_eq!(
+            state.get_auction_value_balance_for(&input_id).await,
+            100u64.into()
+        );
+
+        // The first trigger deploys the first tranche into the DEX.
+        state.put_block_height(twap.start_height);
+        state.process_twap_triggers(twap.start_height).await?;
+        let auction = state
+            .get_twap_auction_by_id(id)
+            .await?
+            .expect("auction exists");
+        assert_eq!(auction.state.sequence, 0);
+        assert!(auction.state.current_position.is_some());
+        assert_eq!(auction.state.input_reserves, 75u64.into());
+        assert_eq!(
+            state.get_auction_value_balance_for(&input_id).await,
+            75u64.into()
+        );
+
+        // An open auction can't be withdrawn, since part of its input is in the DEX.
+        assert!(state.withdraw_twap_auction(auction.clone()).await.is_err());
+
+        // Ending the auction recovers the unsold tranche.
+        let next_trigger = auction.state.next_trigger.expect("auction has a trigger");
+        state.end_twap_auction(auction).await?;
+        let auction = state
+            .get_twap_auction_by_id(id)
+            .await?
+            .expect("auction exists");
+        assert_eq!(auction.state.sequence, 1);
+        assert_eq!(auction.state.current_position, None);
+        assert_eq!(auction.state.input_reserves, 100u64.into());
+        assert_eq!(
+            state.get_auction_value_balance_for(&input_id).await,
+            100u64.into()
+        );
+
+        // A leftover trigger for an ended auction doesn't execute it.
+        state.execute_twap_auction(id, next_trigger.into()).await?;
+        let ended = state
+            .get_twap_auction_by_id(id)
+            .await?
+            .expect("auction exists");
+        assert_eq!(ended.state, auction.state);
+
+        // Withdrawing the auction returns its reserves and empties its value balance.
+        let withdrawn = state.withdraw_twap_auction(auction).await?;
+        assert_eq!(withdrawn, Balance::from(twap.input));
+        assert_eq!(
+            state.get_auction_value_balance_for(&input_id).await,
+            Amount::zero()
+        );
+        let auction = state
+            .get_twap_auction_by_id(id)
+            .await?
+            .expect("auction exists");
+        assert_eq!(auction.state.sequence, 2);
+        assert_eq!(auction.state.input_reserves, Amount::zero());
+
+        Ok(())
+    }
+}
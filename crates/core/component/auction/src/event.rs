@@ -1,4 +1,5 @@
 use crate::auction::dutch::{DutchAuctionDescription, DutchAuctionState};
+use crate::auction::twap::{TwapAuctionDescription, TwapAuctionState};
 use crate::auction::AuctionId;
 use penumbra_sdk_asset::asset;
 use penumbra_sdk_num::Amount;
@@ -73,6 +74,66 @@ pub fn dutch_auction_withdrawn(
     }
 }
 
+/// Event for a TWAP auction that has been scheduled.
+pub fn twap_auction_schedule_event(
+    id: AuctionId,
+    description: TwapAuctionDescription,
+) -> pb::EventTwapAuctionScheduled {
+    pb::EventTwapAuctionScheduled {
+        auction_id: Some(id.into()),
+        description: Some(description.into()),
+    }
+}
+
+/// Event for a TWAP auction releasing a new tranche.
+pub fn twap_auction_updated(id: AuctionId, state: TwapAuctionState) -> pb::EventTwapAuctionUpdated {
+    pb::EventTwapAuctionUpdated {
+        auction_id: Some(id.into()),
+        state: Some(state.into()),
+    }
+}
+
+/// Event for a TWAP auction that is ending because it has been closed by its owner.
+pub fn twap_auction_closed_by_user(
+    id: AuctionId,
+    state: TwapAuctionState,
+) -> pb::EventTwapAuctionEnded {
+    pb::EventTwapAuctionEnded {
+        auction_id: Some(id.into()),
+        state: Some(state.into()),
+        reason: pb::event_dutch_auction_ended::Reason::ClosedByOwner as i32,
+    }
+}
+
+/// Event for a TWAP auction that is ending because it has expired.
+pub fn twap_auction_expired(id: AuctionId, state: TwapAuctionState) -> pb::EventTwapAuctionEnded {
+    pb::EventTwapAuctionEnded {
+        auction_id: Some(id.into()),
+        state: Some(state.into()),
+        reason: pb::event_dutch_auction_ended::Reason::Expired as i32,
+    }
+}
+
+/// Event for a TWAP auction that is ending because it has been completely filled.
+pub fn twap_auction_exhausted(id: AuctionId, state: TwapAuctionState) -> pb::EventTwapAuctionEnded {
+    pb::EventTwapAuctionEnded {
+        auction_id: Some(id.into()),
+        state: Some(state.into()),
+        reason: pb::event_dutch_auction_ended::Reason::Filled as i32,
+    }
+}
+
+/// Event for a TWAP auction that is withdrawn by a user after ending.
+pub fn twap_auction_withdrawn(
+    id: AuctionId,
+    state: TwapAuctionState,
+) -> pb::EventTwapAuctionWithdrawn {
+    pb::EventTwapAuctionWithdrawn {
+        auction_id: Some(id.into()),
+        state: Some(state.into()),
+    }
+}
+
 // Event for value flowing *into* the auction component.
 pub fn auction_vcb_credit(
     asset_id: asset::Id,
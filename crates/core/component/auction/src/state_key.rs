@@ -34,6 +34,27 @@ pub mod auction_store {
     }
 }
 
+pub mod active {
+    use crate::auction::id::AuctionId;
+    use penumbra_sdk_asset::asset;
+
+    pub fn prefix() -> &'static str {
+        "auction/active/"
+    }
+
+    pub fn by_input(input_id: asset::Id) -> String {
+        format!("{}{input_id}/", prefix())
+    }
+
+    pub fn by_pair(input_id: asset::Id, output_id: asset::Id) -> String {
+        format!("{}{output_id}/", by_input(input_id))
+    }
+
+    pub fn auction(input_id: asset::Id, output_id: asset::Id, auction_id: AuctionId) -> String {
+        format!("{}{auction_id}", by_pair(input_id, output_id))
+    }
+}
+
 pub mod dutch {
     pub mod trigger {
         use crate::auction::id::AuctionId;
@@ -52,5 +73,23 @@ pub mod dutch {
     }
 }
 
+pub mod twap {
+    pub mod trigger {
+        use crate::auction::id::AuctionId;
+
+        pub fn prefix() -> &'static str {
+            "auction/twap/trigger/"
+        }
+
+        pub fn by_height(trigger_height: u64) -> String {
+            format!("{}{trigger_height:020}/", prefix())
+        }
+
+        pub fn auction_at_height(auction_id: AuctionId, trigger_height: u64) -> String {
+            format!("{}{auction_id}", by_height(trigger_height))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {}
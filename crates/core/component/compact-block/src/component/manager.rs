@@ -46,7 +46,8 @@ trait Inner: StateWrite {
             .param_changes_for_height(height)
             .await
             .expect("should be able to check for param changes")
-            .is_some();
+            .is_some()
+            || self.scheduled_param_changes_applied();
         // Force app_parameters_updated to true for the genesis compactblock.
         app_parameters_updated = app_parameters_updated || height == 0;
 
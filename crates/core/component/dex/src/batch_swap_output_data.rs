@@ -81,6 +81,29 @@ impl BatchSwapOutputData {
                 .expect("rounded amount is integral"),
         )
     }
+
+    /// The clearing prices of the batch, as `(price_1, price_2)`, where `price_1`
+    /// is the price of asset 1 in units of asset 2 and `price_2` its inverse.
+    ///
+    /// Both directions of the batch are combined, by comparing the total amount
+    /// of each asset that changed hands. Returns `None` if nothing was exchanged.
+    pub fn clearing_prices(&self) -> Option<(U128x128, U128x128)> {
+        let traded_1 = self
+            .delta_1
+            .checked_sub(&self.unfilled_1)?
+            .checked_add(&self.lambda_1)?;
+        let traded_2 = self
+            .delta_2
+            .checked_sub(&self.unfilled_2)?
+            .checked_add(&self.lambda_2)?;
+        if traded_1 == Amount::zero() || traded_2 == Amount::zero() {
+            return None;
+        }
+        Some((
+            U128x128::ratio(traded_2, traded_1).ok()?,
+            U128x128::ratio(traded_1, traded_2).ok()?,
+        ))
+    }
 }
 
 impl ToConstraintField<Fq> for BatchSwapOutputData {
@@ -54,3 +54,167 @@ impl TryFrom<pb::CandlestickData> for CandlestickData {
         })
     }
 }
+
+/// The interval over which a [`CandlestickData`] aggregates executions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum CandlestickInterval {
+    /// One candlestick per block.
+    Block,
+    /// One candlestick per minute of block time.
+    Minute,
+    /// One candlestick per hour of block time.
+    Hour,
+    /// One candlestick per day of block time.
+    Day,
+    /// One candlestick per epoch.
+    Epoch,
+}
+
+impl CandlestickInterval {
+    /// The intervals block candlesticks are rolled up into by default, see
+    /// [`DexParameters::candlestick_intervals`](crate::DexParameters::candlestick_intervals).
+    pub const DEFAULT_ROLLUPS: [CandlestickInterval; 4] = [
+        CandlestickInterval::Minute,
+        CandlestickInterval::Hour,
+        CandlestickInterval::Day,
+        CandlestickInterval::Epoch,
+    ];
+
+    /// The length of the interval in seconds of block time, if it is time-based.
+    pub fn seconds(&self) -> Option<u64> {
+        match self {
+            CandlestickInterval::Minute => Some(60),
+            CandlestickInterval::Hour => Some(60 * 60),
+            CandlestickInterval::Day => Some(24 * 60 * 60),
+            CandlestickInterval::Block | CandlestickInterval::Epoch => None,
+        }
+    }
+
+    /// A short name for the interval, used in state keys.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CandlestickInterval::Block => "block",
+            CandlestickInterval::Minute => "1m",
+            CandlestickInterval::Hour => "1h",
+            CandlestickInterval::Day => "1d",
+            CandlestickInterval::Epoch => "epoch",
+        }
+    }
+
+    /// The bucket a block at the given unix timestamp and epoch falls into.
+    ///
+    /// Time-based buckets are identified by the unix timestamp they start at,
+    /// and epoch buckets by the epoch index. Block candlesticks are not bucketed.
+    pub fn bucket(&self, unix_timestamp: u64, epoch_index: u64) -> Option<u64> {
+        match self {
+            CandlestickInterval::Block => None,
+            CandlestickInterval::Epoch => Some(epoch_index),
+            _ => {
+                let seconds = self.seconds().expect("time-based interval has a length");
+                Some(unix_timestamp - unix_timestamp % seconds)
+            }
+        }
+    }
+
+    /// The bucket `count` buckets before `bucket`, saturating at the first one.
+    pub fn buckets_before(&self, bucket: u64, count: u64) -> u64 {
+        let step = self.seconds().unwrap_or(1);
+        bucket.saturating_sub(count.saturating_mul(step))
+    }
+}
+
+impl CandlestickData {
+    /// Fold the candlestick of a later block into this one.
+    pub fn merge(&mut self, later: &CandlestickData) {
+        self.close = later.close;
+        self.high = self.high.max(later.high);
+        self.low = self.low.min(later.low);
+        self.direct_volume += later.direct_volume;
+        self.swap_volume += later.swap_volume;
+    }
+}
+
+impl From<CandlestickInterval> for pb::CandlestickInterval {
+    fn from(interval: CandlestickInterval) -> Self {
+        match interval {
+            CandlestickInterval::Block => pb::CandlestickInterval::Unspecified,
+            CandlestickInterval::Minute => pb::CandlestickInterval::Minute,
+            CandlestickInterval::Hour => pb::CandlestickInterval::Hour,
+            CandlestickInterval::Day => pb::CandlestickInterval::Day,
+            CandlestickInterval::Epoch => pb::CandlestickInterval::Epoch,
+        }
+    }
+}
+
+impl From<pb::CandlestickInterval> for CandlestickInterval {
+    fn from(interval: pb::CandlestickInterval) -> Self {
+        match interval {
+            pb::CandlestickInterval::Unspecified => CandlestickInterval::Block,
+            pb::CandlestickInterval::Minute => CandlestickInterval::Minute,
+            pb::CandlestickInterval::Hour => CandlestickInterval::Hour,
+            pb::CandlestickInterval::Day => CandlestickInterval::Day,
+            pb::CandlestickInterval::Epoch => CandlestickInterval::Epoch,
+        }
+    }
+}
+
+impl TryFrom<i32> for CandlestickInterval {
+    type Error = anyhow::Error;
+    fn try_from(interval: i32) -> Result<Self, Self::Error> {
+        pb::CandlestickInterval::try_from(interval)
+            .map(Into::into)
+            .map_err(|_| anyhow::anyhow!("unknown candlestick interval {interval}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn time_buckets_start_on_interval_boundaries() {
+        let t = 1_700_000_123;
+        assert_eq!(
+            CandlestickInterval::Minute.bucket(t, 7),
+            Some(1_700_000_100)
+        );
+        assert_eq!(CandlestickInterval::Hour.bucket(t, 7), Some(1_699_999_200));
+        assert_eq!(CandlestickInterval::Day.bucket(t, 7), Some(1_699_920_000));
+        assert_eq!(CandlestickInterval::Epoch.bucket(t, 7), Some(7));
+        assert_eq!(CandlestickInterval::Block.bucket(t, 7), None);
+    }
+
+    #[test]
+    fn merge_keeps_open_and_extends_range() {
+        let mut candle = CandlestickData {
+            height: 10,
+            open: 2.0,
+            close: 2.5,
+            high: 3.0,
+            low: 1.5,
+            direct_volume: 1.0,
+            swap_volume: 2.0,
+        };
+        candle.merge(&CandlestickData {
+            height: 12,
+            open: 2.4,
+            close: 1.0,
+            high: 2.4,
+            low: 0.5,
+            direct_volume: 3.0,
+            swap_volume: 4.0,
+        });
+        assert_eq!(
+            candle,
+            CandlestickData {
+                height: 10,
+                open: 2.0,
+                close: 1.0,
+                high: 3.0,
+                low: 0.5,
+                direct_volume: 4.0,
+                swap_volume: 6.0,
+            }
+        );
+    }
+}
@@ -11,7 +11,9 @@ use tonic::async_trait;
 use crate::event::EventCandlestickData;
 use crate::{lp::position::Position, state_key::candlesticks, DirectedTradingPair, SwapExecution};
 
-use crate::CandlestickData;
+use crate::{CandlestickData, CandlestickInterval};
+
+use super::StateReadExt as _;
 
 #[async_trait]
 pub trait CandlestickRead: StateRead {
@@ -53,9 +55,76 @@ pub trait CandlestickRead: StateRead {
             .try_collect()
             .await
     }
+
+    /// Get the candlestick rolled up over the given interval bucket, if any executions occurred in it.
+    #[tracing::instrument(level = "debug", skip(self))]
+    async fn get_candlestick_rollup(
+        &self,
+        trading_pair: &DirectedTradingPair,
+        interval: CandlestickInterval,
+        bucket: u64,
+    ) -> Result<Option<CandlestickData>> {
+        self.nonverifiable_get(
+            candlesticks::rollup::by_pair_interval_and_bucket(trading_pair, interval, bucket)
+                .as_bytes(),
+        )
+        .await
+    }
+
+    /// Get up to `limit` candlesticks for the given interval, starting from `start_bucket`.
+    ///
+    /// For [`CandlestickInterval::Block`], buckets are block heights.
+    async fn candlesticks_by_interval(
+        &self,
+        trading_pair: &DirectedTradingPair,
+        interval: CandlestickInterval,
+        start_bucket: u64,
+        limit: usize,
+    ) -> Result<Vec<CandlestickData>> {
+        if interval == CandlestickInterval::Block {
+            return self.candlesticks(trading_pair, start_bucket, limit).await;
+        }
+
+        let prefix = candlesticks::rollup::by_pair_and_interval(trading_pair, interval);
+        let start_bucket_key = format!("{:020}", start_bucket).as_bytes().to_vec();
+        let range = self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), start_bucket_key..)
+            .context("error forming range query")?;
+
+        range
+            .take(limit)
+            .and_then(|(_k, v)| async move {
+                CandlestickData::decode(v.as_ref()).context("error deserializing candlestick")
+            })
+            .try_collect()
+            .await
+    }
+
+    /// Get the bucket of the given interval that the block at `height` falls into.
+    async fn candlestick_bucket_at_height(
+        &self,
+        interval: CandlestickInterval,
+        height: u64,
+    ) -> Result<u64> {
+        let unix_timestamp = match interval.seconds() {
+            Some(_) => unix_seconds(self.get_block_timestamp(height).await?)?,
+            None => 0,
+        };
+        let epoch_index = match interval {
+            CandlestickInterval::Epoch => self.get_epoch_by_height(height).await?.index,
+            _ => 0,
+        };
+        Ok(interval
+            .bucket(unix_timestamp, epoch_index)
+            .unwrap_or(height))
+    }
 }
 impl<T: StateRead + ?Sized> CandlestickRead for T {}
 
+fn unix_seconds(time: tendermint::Time) -> Result<u64> {
+    u64::try_from(time.unix_timestamp()).context("block timestamp is before the unix epoch")
+}
+
 #[async_trait]
 pub trait Chandelier: StateWrite {
     #[tracing::instrument(level = "debug", skip(self))]
@@ -148,6 +217,9 @@ pub trait Chandelier: StateWrite {
     #[tracing::instrument(level = "debug", skip(self))]
     async fn finalize_block_candlesticks(&mut self) -> Result<()> {
         let height = self.get_block_height().await?;
+        let unix_timestamp = unix_seconds(self.get_current_block_timestamp().await?)?;
+        let epoch_index = self.get_current_epoch().await?.index;
+        let rollups = self.get_dex_params().await?.candlestick_intervals;
 
         // Fetch all the executions for the block.
         let block_executions = self.block_executions();
@@ -217,7 +289,34 @@ pub trait Chandelier: StateWrite {
                     stick: candlestick,
                 }
                 .to_proto(),
-            )
+            );
+
+            // Fold the block's candlestick into the in-progress candle of each rolled-up interval.
+            for &interval in &rollups {
+                // Block candlesticks are already recorded above.
+                let Some(bucket) = interval.bucket(unix_timestamp, epoch_index) else {
+                    continue;
+                };
+                let rollup = match self
+                    .get_candlestick_rollup(trading_pair, interval, bucket)
+                    .await?
+                {
+                    Some(mut rollup) => {
+                        rollup.merge(&candlestick);
+                        rollup
+                    }
+                    None => candlestick,
+                };
+                self.nonverifiable_put(
+                    candlesticks::rollup::by_pair_interval_and_bucket(
+                        trading_pair,
+                        interval,
+                        bucket,
+                    )
+                    .into(),
+                    rollup,
+                );
+            }
         }
 
         Ok(())
@@ -272,16 +371,16 @@ mod tests {
 
     use cnidarium::{ArcStateDeltaExt as _, StateDelta, TempStorage};
     use cnidarium_component::Component as _;
-    use penumbra_sdk_asset::asset;
+    use penumbra_sdk_asset::{asset, Value};
     use penumbra_sdk_sct::{component::clock::EpochManager as _, epoch::Epoch};
     use tendermint::abci;
 
     use crate::{
         component::{
             router::create_buy, tests::TempStorageExt as _, Dex, PositionManager as _,
-            SwapDataRead, SwapDataWrite,
+            StateWriteExt as _, SwapDataRead, SwapDataWrite,
         },
-        DirectedUnitPair,
+        DexParameters, DirectedUnitPair,
     };
 
     use super::*;
@@ -483,4 +582,141 @@ mod tests {
         );
         Ok(())
     }
+
+    async fn swap_block(
+        storage: &TempStorage,
+        height: u64,
+        epoch_index: u64,
+        unix_timestamp: i64,
+        executions: &[(u64, u64)],
+        pair: DirectedTradingPair,
+    ) -> anyhow::Result<()> {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_block_height(height);
+        state.put_block_timestamp(
+            height,
+            tendermint::Time::from_unix_timestamp(unix_timestamp, 0).unwrap(),
+        );
+        state.put_epoch_by_height(
+            height,
+            Epoch {
+                index: epoch_index,
+                start_height: 0,
+            },
+        );
+        for (input, output) in executions {
+            state
+                .record_swap_execution(&SwapExecution {
+                    traces: vec![],
+                    input: Value {
+                        amount: (*input).into(),
+                        asset_id: pair.start,
+                    },
+                    output: Value {
+                        amount: (*output).into(),
+                        asset_id: pair.end,
+                    },
+                })
+                .await;
+        }
+        state.finalize_block_candlesticks().await?;
+        storage.commit(state).await?;
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// Block candlesticks are rolled up into time and epoch intervals.
+    async fn chandelier_rollups() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+        // Two blocks in the same minute, then one in the next minute and epoch.
+        swap_block(&storage, 1, 0, 60, &[(1, 2), (1, 4)], pair).await?;
+        swap_block(&storage, 2, 0, 90, &[(2, 2)], pair).await?;
+        swap_block(&storage, 3, 1, 125, &[(1, 3)], pair).await?;
+
+        let state = storage.latest_snapshot();
+        let minutes = state
+            .candlesticks_by_interval(&pair, CandlestickInterval::Minute, 0, 10)
+            .await?;
+        assert_eq!(minutes.len(), 2, "two minutes had executions");
+        assert_eq!(minutes[0].height, 1, "first minute starts at height 1");
+        assert_eq!(minutes[0].open, 2.0);
+        assert_eq!(minutes[0].close, 1.0);
+        assert_eq!(minutes[0].high, 4.0);
+        assert_eq!(minutes[0].low, 1.0);
+        assert_eq!(minutes[0].swap_volume, 4.0);
+        assert_eq!(minutes[1].height, 3);
+        assert_eq!(minutes[1].open, 3.0);
+
+        let hours = state
+            .candlesticks_by_interval(&pair, CandlestickInterval::Hour, 0, 10)
+            .await?;
+        assert_eq!(hours.len(), 1, "all blocks fall in the first hour");
+        assert_eq!(hours[0].close, 3.0);
+        assert_eq!(hours[0].swap_volume, 5.0);
+
+        let epochs = state
+            .candlesticks_by_interval(&pair, CandlestickInterval::Epoch, 0, 10)
+            .await?;
+        assert_eq!(epochs.len(), 2, "two epochs had executions");
+        assert_eq!(epochs[0].close, 1.0);
+        assert_eq!(epochs[1].height, 3);
+
+        assert_eq!(
+            state
+                .candlestick_bucket_at_height(CandlestickInterval::Minute, 2)
+                .await?,
+            60
+        );
+        assert_eq!(
+            state
+                .candlestick_bucket_at_height(CandlestickInterval::Epoch, 3)
+                .await?,
+            1
+        );
+
+        // Later buckets only are returned when starting from a later bucket.
+        let later = state
+            .candlesticks_by_interval(&pair, CandlestickInterval::Minute, 120, 10)
+            .await?;
+        assert_eq!(later.len(), 1);
+        assert_eq!(later[0].height, 3);
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// Only the intervals set in the DEX parameters are rolled up.
+    async fn chandelier_rollups_follow_params() -> anyhow::Result<()> {
+        let _ = tracing_subscriber::fmt::try_init();
+        let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state.put_dex_params(DexParameters {
+            candlestick_intervals: vec![CandlestickInterval::Hour],
+            ..DexParameters::default()
+        });
+        storage.commit(state).await?;
+
+        let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+        let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+        let pair = DirectedTradingPair::new(gm.id(), gn.id());
+
+        swap_block(&storage, 1, 0, 60, &[(1, 2)], pair).await?;
+
+        let state = storage.latest_snapshot();
+        let hours = state
+            .candlesticks_by_interval(&pair, CandlestickInterval::Hour, 0, 10)
+            .await?;
+        assert_eq!(hours.len(), 1);
+        let minutes = state
+            .candlesticks_by_interval(&pair, CandlestickInterval::Minute, 0, 10)
+            .await?;
+        assert!(minutes.is_empty(), "minutes are not rolled up");
+        Ok(())
+    }
 }
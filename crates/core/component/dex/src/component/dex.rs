@@ -5,11 +5,12 @@ use anyhow::Result;
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
 use cnidarium_component::Component;
+use futures::StreamExt as _;
 use penumbra_sdk_asset::asset;
 use penumbra_sdk_asset::{Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_sdk_fee::component::StateWriteExt as _;
 use penumbra_sdk_fee::Fee;
-use penumbra_sdk_num::Amount;
+use penumbra_sdk_num::{fixpoint::U128x128, Amount};
 use penumbra_sdk_proto::{DomainType as _, StateReadProto, StateWriteProto};
 use tendermint::v0_37::abci;
 use tracing::instrument;
@@ -17,10 +18,12 @@ use tracing::instrument;
 use crate::state_key::block_scoped;
 use crate::{
     component::SwapDataRead, component::SwapDataWrite, event, genesis, state_key,
-    BatchSwapOutputData, DexParameters, DirectedTradingPair, SwapExecution, TradingPair,
+    BatchSwapOutputData, DexParameters, DirectedTradingPair, PriceAccumulator, SwapExecution,
+    TradingPair,
 };
 
 use super::eviction_manager::EvictionManager;
+use super::position_manager::activity_tracker::PositionActivityTracker as _;
 use super::{
     chandelier::Chandelier,
     router::{HandleBatchSwaps, RoutingParams},
@@ -210,6 +213,9 @@ impl Component for Dex {
             max_hops: routing_params.max_hops + 2,
             fixed_candidates,
             price_limit: Some(1u64.into()),
+            // Arbitrage searches for cycles one at a time.
+            max_split_paths: 0,
+            composed_path_prices: routing_params.composed_path_prices,
         };
 
         match state
@@ -242,12 +248,26 @@ impl Component for Dex {
             .await
             .expect("closing queued positions should not fail");
 
-        // 5. Finalize the candlestick data for the block.
+        // 6. Close all positions that expire at this height, also after execution.
+        Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .close_expired_positions()
+            .await
+            .expect("closing expired positions should not fail");
+
+        // 7. Finalize the candlestick data for the block.
         Arc::get_mut(state)
             .expect("state should be uniquely referenced after batch swaps complete")
             .finalize_block_candlesticks()
             .await
             .expect("finalizing block candlesticks should not fail");
+
+        // 8. Prune position activity that has fallen out of the retention window.
+        let _ = Arc::get_mut(state)
+            .expect("state should be uniquely referenced after batch swaps complete")
+            .prune_position_activity()
+            .await
+            .map_err(|e| tracing::error!(?e, "error pruning position activity, skipping"));
     }
 
     #[instrument(name = "dex", skip(_state))]
@@ -299,10 +319,104 @@ pub trait StateReadExt: StateRead {
         self.object_get(block_scoped::active::trading_pairs())
             .unwrap_or_default()
     }
+
+    /// Gets the latest price accumulator for a trading pair, if it has ever traded.
+    async fn price_accumulator(
+        &self,
+        trading_pair: TradingPair,
+    ) -> Result<Option<PriceAccumulator>> {
+        self.get(&state_key::price_accumulator::latest(&trading_pair))
+            .await
+    }
+
+    /// Computes the time-weighted average price of `pair.start`, in units of
+    /// `pair.end`, over the blocks in `(start_height, end_height]`.
+    ///
+    /// Only the latest accumulator is kept in verifiable state, so `start` must be
+    /// a copy of [`price_accumulator`](Self::price_accumulator) that the caller
+    /// saved at `start_height`, and `end_height` must not be before the latest
+    /// update.
+    ///
+    /// Returns `None` if the pair has never traded.
+    async fn time_weighted_average_price_since(
+        &self,
+        pair: DirectedTradingPair,
+        start: &PriceAccumulator,
+        start_height: u64,
+        end_height: u64,
+    ) -> Result<Option<U128x128>> {
+        let Some(end) = self.price_accumulator(pair.into()).await? else {
+            return Ok(None);
+        };
+        PriceAccumulator::time_weighted_average_price(start, start_height, &end, end_height, &pair)
+            .map(Some)
+    }
 }
 
 impl<T: StateRead + ?Sized> StateReadExt for T {}
 
+/// Read access to past price accumulators, for serving RPCs.
+///
+/// Past accumulators are kept in nonverifiable storage, which differs between
+/// nodes (for instance, a node that joined via state sync has none), so this
+/// must not be used by consensus logic.
+#[async_trait]
+pub(crate) trait PriceHistoryRead: StateRead {
+    /// Gets the price accumulator for a trading pair as of the end of the block at `height`.
+    ///
+    /// Past accumulators are kept in nonverifiable storage, so on a node that
+    /// joined the network via state sync, only the latest one is available.
+    async fn price_accumulator_at(
+        &self,
+        trading_pair: TradingPair,
+        height: u64,
+    ) -> Result<Option<PriceAccumulator>> {
+        if let Some(latest) = self.price_accumulator(trading_pair).await? {
+            if latest.height <= height {
+                return Ok(Some(latest));
+            }
+        }
+
+        let prefix = state_key::price_accumulator::history_by_pair(&trading_pair);
+        let start = state_key::price_accumulator::history_height_key(height).into_bytes();
+        let mut range = self.nonverifiable_range_raw(Some(prefix.as_bytes()), start..)?;
+        match range.next().await {
+            Some(entry) => {
+                let (_key, value) = entry?;
+                Ok(Some(PriceAccumulator::decode(value.as_ref())?))
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Computes the time-weighted average price of `pair.start`, in units of
+    /// `pair.end`, over the blocks in `(start_height, end_height]`.
+    ///
+    /// Returns `None` if the pair had not traded by `start_height`.
+    async fn time_weighted_average_price(
+        &self,
+        pair: DirectedTradingPair,
+        start_height: u64,
+        end_height: u64,
+    ) -> Result<Option<U128x128>> {
+        let trading_pair: TradingPair = pair.into();
+        let Some(start) = self
+            .price_accumulator_at(trading_pair, start_height)
+            .await?
+        else {
+            return Ok(None);
+        };
+        let end = self
+            .price_accumulator_at(trading_pair, end_height)
+            .await?
+            .ok_or_else(|| anyhow::anyhow!("missing price accumulator at height {end_height}"))?;
+        PriceAccumulator::time_weighted_average_price(&start, start_height, &end, end_height, &pair)
+            .map(Some)
+    }
+}
+
+impl<T: StateRead + ?Sized> PriceHistoryRead for T {}
+
 /// Extension trait providing write access to dex data.
 #[async_trait]
 pub trait StateWriteExt: StateWrite {
@@ -393,6 +507,9 @@ pub(crate) trait InternalDexWrite: StateWrite {
             self.put_swap_execution_at_height(height, tp_2_for_1, swap_execution);
         }
 
+        // Fold the clearing prices into the pair's price accumulator.
+        self.record_clearing_prices(&output_data).await?;
+
         // ... and also add it to the set in the compact block to be pushed out to clients.
         let mut outputs = self.pending_batch_swap_outputs();
         outputs.insert(trading_pair, output_data);
@@ -414,6 +531,37 @@ pub(crate) trait InternalDexWrite: StateWrite {
     fn set_arb_execution(&mut self, height: u64, execution: SwapExecution) {
         self.put(state_key::arb_execution(height), execution);
     }
+
+    /// Update the price accumulator of a trading pair with the clearing prices of its batch.
+    async fn record_clearing_prices(&mut self, output_data: &BatchSwapOutputData) -> Result<()> {
+        let Some(prices) = output_data.clearing_prices() else {
+            return Ok(());
+        };
+        let trading_pair = output_data.trading_pair;
+        let height = output_data.height;
+
+        let accumulator = match self.price_accumulator(trading_pair).await? {
+            Some(accumulator) => match accumulator.observe(height, prices) {
+                Ok(accumulator) => accumulator,
+                Err(e) => {
+                    // An overflowing accumulator shouldn't halt the chain, so leave it as-is.
+                    tracing::warn!(?e, ?trading_pair, "failed to update price accumulator");
+                    return Ok(());
+                }
+            },
+            None => PriceAccumulator::new(trading_pair, height, prices),
+        };
+
+        self.put(
+            state_key::price_accumulator::latest(&trading_pair),
+            accumulator,
+        );
+        self.nonverifiable_put(
+            state_key::price_accumulator::history_by_pair_and_height(&trading_pair, height).into(),
+            accumulator,
+        );
+        Ok(())
+    }
 }
 
 impl<T: StateWrite + ?Sized> InternalDexWrite for T {}
@@ -99,11 +99,13 @@ pub(crate) trait EvictionManager: StateWrite {
                 .try_collect::<BTreeSet<position::Id>>()
                 .await?;
 
-            let overlap = overhead_ab.intersection(&overhead_ba);
+            let overlap: Vec<_> = overhead_ab.intersection(&overhead_ba).collect();
 
-            for id in overlap {
+            for id in overlap.iter() {
                 self.close_position_by_id(id).await?;
             }
+            metrics::counter!(crate::component::metrics::DEX_POSITIONS_EVICTED)
+                .increment(overlap.len() as u64);
         }
         Ok(())
     }
@@ -45,6 +45,16 @@ pub fn register_metrics() {
         Unit::Seconds,
         "The time spent processing a SimulateTrade RPC request"
     );
+    describe_counter!(
+        DEX_POSITIONS_EXPIRED,
+        Unit::Count,
+        "The number of liquidity positions closed because they reached their expiry height"
+    );
+    describe_counter!(
+        DEX_POSITIONS_EVICTED,
+        Unit::Count,
+        "The number of liquidity positions closed to enforce the per-pair position limit"
+    );
 }
 
 // We configure buckets for the DEX routing times manually, in order to ensure
@@ -78,6 +88,8 @@ pub const DEX_ARB_DURATION: &str = "penumbra_dex_arb_duration_seconds";
 pub const DEX_BATCH_DURATION: &str = "penumbra_dex_batch_duration_seconds";
 pub const DEX_RPC_SIMULATE_TRADE_DURATION: &str =
     "penumbra_dex_rpc_simulate_trade_duration_seconds";
+pub const DEX_POSITIONS_EXPIRED: &str = "penumbra_dex_positions_expired_total";
+pub const DEX_POSITIONS_EVICTED: &str = "penumbra_dex_positions_evicted_total";
 
 /// An extension trait providing DEX-related interfaces for [`PrometheusBuilder`].
 ///
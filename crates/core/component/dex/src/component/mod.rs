@@ -2,6 +2,7 @@
 //! supplies based on liquidity provider interactions.
 
 pub mod metrics;
+pub mod replay;
 pub mod rpc;
 
 pub mod router;
@@ -30,6 +31,7 @@ pub(crate) use circuit_breaker::ExecutionCircuitBreaker;
 pub(crate) use circuit_breaker::ValueCircuitBreaker;
 pub use circuit_breaker::ValueCircuitBreakerRead;
 pub(crate) use dex::InternalDexWrite;
+pub(crate) use dex::PriceHistoryRead;
 pub(crate) use swap_manager::SwapDataWrite;
 pub(crate) use swap_manager::SwapManager;
 
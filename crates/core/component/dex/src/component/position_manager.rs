@@ -7,10 +7,12 @@ use async_trait::async_trait;
 use cnidarium::{EscapedByteSlice, StateRead, StateWrite};
 use futures::Stream;
 use futures::StreamExt;
+use futures::TryStreamExt;
 use penumbra_sdk_asset::{asset, Balance, Value, STAKING_TOKEN_ASSET_ID};
 use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::DomainType;
 use penumbra_sdk_proto::{StateReadProto, StateWriteProto};
+use penumbra_sdk_sct::component::clock::EpochRead as _;
 use tap::Tap;
 use tracing::instrument;
 
@@ -18,11 +20,15 @@ use crate::component::{
     dex::InternalDexWrite,
     dex::StateReadExt as _,
     position_manager::{
-        base_liquidity_index::AssetByLiquidityIndex, inventory_index::PositionByInventoryIndex,
-        price_index::PositionByPriceIndex, volume_tracker::PositionVolumeTracker,
+        activity_tracker::PositionActivityTracker,
+        base_liquidity_index::AssetByLiquidityIndex,
+        expiry_index::{PositionByExpiryIndex, PositionByExpiryRead as _},
+        inventory_index::PositionByInventoryIndex,
+        price_index::PositionByPriceIndex,
+        volume_tracker::PositionVolumeTracker,
     },
 };
-use crate::lp::Reserves;
+use crate::lp::{PositionActivity, Reserves};
 use crate::{
     component::position_manager::counter::PositionCounter,
     component::ValueCircuitBreaker,
@@ -36,8 +42,10 @@ use super::chandelier::Chandelier;
 
 const DYNAMIC_ASSET_LIMIT: usize = 10;
 
+pub(crate) mod activity_tracker;
 mod base_liquidity_index;
 pub(crate) mod counter;
+pub(crate) mod expiry_index;
 pub(crate) mod inventory_index;
 pub(crate) mod price_index;
 pub(crate) mod volume_tracker;
@@ -79,6 +87,21 @@ pub trait PositionRead: StateRead {
             .boxed()
     }
 
+    /// Returns a stream of the per-block [`PositionActivity`] of a position,
+    /// starting at `start_height`.
+    fn position_activity(
+        &self,
+        id: &position::Id,
+        start_height: u64,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<PositionActivity>> + Send + 'static>>> {
+        let prefix = state_key::position_activity::by_position(id);
+        let start_height_key = state_key::position_activity::height_key(start_height).into_bytes();
+        Ok(self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), start_height_key..)?
+            .and_then(|(_, v)| async move { PositionActivity::decode(v.as_ref()) })
+            .boxed())
+    }
+
     async fn position_by_id(&self, id: &position::Id) -> Result<Option<position::Position>> {
         self.get(&state_key::position_by_id(id)).await
     }
@@ -258,6 +281,20 @@ pub trait PositionManager: StateWrite + PositionRead {
         Ok(())
     }
 
+    /// Close all opened positions whose expiry height has been reached.
+    #[instrument(skip_all)]
+    async fn close_expired_positions(&mut self) -> Result<()> {
+        let height = self.get_block_height().await?;
+        let expired = self.positions_expiring_by(height).await?;
+        for id in expired.iter() {
+            tracing::debug!(position_to_close = ?id, "closing expired position");
+            self.close_position_by_id(id).await?;
+        }
+        metrics::counter!(crate::component::metrics::DEX_POSITIONS_EXPIRED)
+            .increment(expired.len() as u64);
+        Ok(())
+    }
+
     /// Opens a new position, updating all necessary indexes and checking for
     /// its nonexistence prior to being opened.
     ///
@@ -281,6 +318,15 @@ pub trait PositionManager: StateWrite + PositionRead {
             anyhow::bail!("attempted to open a position with a state besides `Opened`");
         }
 
+        // Positions can only be set to expire in the current block or later.
+        if let Some(expires_at_height) = position.expires_at_height {
+            let current_height = self.get_block_height().await?;
+            ensure!(
+                expires_at_height >= current_height,
+                "attempted to open a position expiring at height {expires_at_height}, before the current height {current_height}",
+            );
+        }
+
         // Validate that the position ID doesn't collide
         if let Some(existing_lp) = self.position_by_id(&id).await? {
             anyhow::bail!(
@@ -405,6 +451,14 @@ pub trait PositionManager: StateWrite + PositionRead {
             .map_err(|e| tracing::warn!(?e, "failed to record position execution"))
             .ok();
 
+        // Likewise for the position's activity.
+        self.track_position_activity(&prev_state, |activity| {
+            activity.record_execution(&prev_state, &new_state)
+        })
+        .await
+        .map_err(|e| tracing::warn!(?e, "failed to track position activity"))
+        .ok();
+
         self.update_position(&position_id, Some(prev_state), new_state)
             .await
     }
@@ -470,6 +524,13 @@ pub trait PositionManager: StateWrite + PositionRead {
         // Grab a copy of the final reserves of the position to return to the caller.
         let reserves = prev_state.reserves.balance(&prev_state.phi.pair);
 
+        self.track_position_activity(&prev_state, |activity| {
+            activity.record_withdrawal(&prev_state.reserves)
+        })
+        .await
+        .map_err(|e| tracing::warn!(?e, "failed to track position activity"))
+        .ok();
+
         // Debit the DEX for the outflows from this position.
         self.dex_vcb_debit(prev_state.reserves_1()).await?;
         self.dex_vcb_debit(prev_state.reserves_2()).await?;
@@ -525,6 +586,12 @@ pub trait PositionManager: StateWrite + PositionRead {
 
             new_state
         };
+        self.track_position_activity(&prev_state, |activity| {
+            activity.record_reward(&prev_state, &new_state)
+        })
+        .await
+        .map_err(|e| tracing::warn!(?e, "failed to track position activity"))
+        .ok();
         self.update_position(&position_id, Some(prev_state), new_state)
             .await?;
         // At this point, we can credit the VCB, because the update passed.
@@ -567,6 +634,7 @@ trait Inner: StateWrite {
             .await?;
         self.update_position_by_price_index(&id, &prev_state, &new_state)?;
         self.update_volume_index(&id, &prev_state, &new_state).await;
+        self.update_position_by_expiry_index(&id, &prev_state, &new_state)?;
 
         self.put(state_key::position_by_id(&id), new_state.clone());
         Ok(new_state)
@@ -0,0 +1,71 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::StateWrite;
+use futures::{StreamExt as _, TryStreamExt as _};
+use penumbra_sdk_proto::{StateReadProto, StateWriteProto};
+use penumbra_sdk_sct::component::clock::EpochRead;
+
+use crate::lp::{position::Position, PositionActivity};
+use crate::state_key::position_activity;
+
+/// The number of blocks the activity of positions is kept for, about 30 days
+/// of 5 second blocks. Clients are expected to fetch and keep it before then.
+pub(crate) const POSITION_ACTIVITY_RETENTION_BLOCKS: u64 = 518_400;
+
+#[async_trait]
+pub(crate) trait PositionActivityTracker: StateWrite {
+    /// Folds a change to `prev_state` into the position's activity for the
+    /// current block, which is kept in nonverifiable storage.
+    async fn track_position_activity<F>(&mut self, prev_state: &Position, record: F) -> Result<()>
+    where
+        F: FnOnce(&mut PositionActivity) + Send,
+    {
+        let height = self.get_block_height().await?;
+        let position_id = prev_state.id();
+        let key = position_activity::by_position_and_height(&position_id, height);
+
+        let mut activity = match self.nonverifiable_get(key.as_bytes()).await? {
+            Some(activity) => activity,
+            None => {
+                // Index the first change in the block, so that it can be pruned.
+                self.nonverifiable_put_raw(
+                    position_activity::by_height::key(height, &position_id),
+                    vec![],
+                );
+                PositionActivity::new(position_id, height, prev_state.reserves.clone())
+            }
+        };
+        record(&mut activity);
+        self.nonverifiable_put(key.into(), activity);
+
+        Ok(())
+    }
+
+    /// Deletes the activity of positions that is older than the retention window.
+    async fn prune_position_activity(&mut self) -> Result<()> {
+        let height = self.get_block_height().await?;
+        let Some(cutoff) = height.checked_sub(POSITION_ACTIVITY_RETENTION_BLOCKS) else {
+            return Ok(());
+        };
+
+        let expired: Vec<(u64, _)> = self
+            .nonverifiable_prefix_raw(position_activity::by_height::prefix())
+            .map(|entry| entry.and_then(|(k, _)| position_activity::by_height::parse_key(&k)))
+            .try_take_while(|(activity_height, _)| {
+                futures::future::ready(Ok(*activity_height <= cutoff))
+            })
+            .try_collect()
+            .await?;
+
+        for (activity_height, id) in expired {
+            self.nonverifiable_delete(position_activity::by_height::key(activity_height, &id));
+            self.nonverifiable_delete(
+                position_activity::by_position_and_height(&id, activity_height).into_bytes(),
+            );
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> PositionActivityTracker for T {}
@@ -0,0 +1,55 @@
+use anyhow::Result;
+use async_trait::async_trait;
+use cnidarium::{StateRead, StateWrite};
+use futures::{StreamExt as _, TryStreamExt as _};
+
+use crate::{
+    lp::position::{self, Position},
+    state_key::expiry_queue,
+};
+
+use position::State::*;
+
+#[async_trait]
+pub(crate) trait PositionByExpiryRead: StateRead {
+    /// Returns the ids of opened positions that expire at or before `height`,
+    /// in the order they expire.
+    async fn positions_expiring_by(&self, height: u64) -> Result<Vec<position::Id>> {
+        self.nonverifiable_prefix_raw(expiry_queue::prefix())
+            .map(|entry| entry.and_then(|(k, _)| expiry_queue::parse_key(&k)))
+            .try_take_while(|(expires_at_height, _)| {
+                futures::future::ready(Ok(*expires_at_height <= height))
+            })
+            .map_ok(|(_, id)| id)
+            .try_collect()
+            .await
+    }
+}
+
+impl<T: StateRead + ?Sized> PositionByExpiryRead for T {}
+
+pub(super) trait PositionByExpiryIndex: StateWrite {
+    fn update_position_by_expiry_index(
+        &mut self,
+        position_id: &position::Id,
+        prev_state: &Option<Position>,
+        new_state: &Position,
+    ) -> Result<()> {
+        let Some(expires_at_height) = new_state.expires_at_height else {
+            return Ok(());
+        };
+        let key = expiry_queue::key(expires_at_height, position_id).to_vec();
+
+        // Only opened positions are waiting to expire.
+        match (prev_state.as_ref().map(|p| p.state), new_state.state) {
+            (None, Opened) => self.nonverifiable_put_raw(key, vec![]),
+            (Some(Opened), Opened) => {}
+            (Some(Opened), _) => self.nonverifiable_delete(key),
+            _ => {}
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: StateWrite + ?Sized> PositionByExpiryIndex for T {}
@@ -0,0 +1,371 @@
+//! Offline replay of a block's batch execution against a snapshot of the DEX,
+//! to compare routing parameters against real order books without a live chain.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use anyhow::{Context as _, Result};
+use cnidarium::{StateDelta, StateRead};
+use cnidarium_component::Component as _;
+use futures::{future, TryStreamExt as _};
+use penumbra_sdk_asset::Value;
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_proto::StateReadProto as _;
+use penumbra_sdk_sct::{component::clock::EpochManager as _, epoch::Epoch};
+use serde::{Deserialize, Serialize};
+use tendermint::v0_37::abci;
+
+use crate::{
+    lp::{
+        position::{self, Position},
+        Reserves,
+    },
+    state_key, BatchSwapOutputData, DexParameters, SwapExecution, TradingPair,
+};
+
+use super::{
+    Dex, PositionManager as _, PositionRead as _, StateReadExt as _, StateWriteExt as _,
+    SwapDataWrite as _,
+};
+
+/// The DEX state going into a block's batch execution.
+///
+/// Snapshots are serializable, so that they can be exported once and replayed
+/// any number of times under different parameters.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// The height of the block.
+    pub height: u64,
+    /// The DEX parameters in effect for the block.
+    pub params: DexParameters,
+    /// The positions open at the end of the block, with their reserves going
+    /// into batch execution.
+    pub positions: Vec<Position>,
+    /// The swaps submitted in the block, aggregated per trading pair if the
+    /// snapshot was exported from chain state.
+    pub swaps: Vec<SnapshotSwap>,
+}
+
+/// A swap submitted in a snapshotted block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SnapshotSwap {
+    pub trading_pair: TradingPair,
+    /// The amount of asset 1 to swap for asset 2.
+    pub delta_1: Amount,
+    /// The amount of asset 2 to swap for asset 1.
+    pub delta_2: Amount,
+}
+
+impl Snapshot {
+    /// Exports a snapshot of the block at `height`, from the chain state as of
+    /// the previous block (`prev`) and as of the block itself (`state`).
+    ///
+    /// The swaps are recovered from the block's batch swap outputs, so there is
+    /// one aggregate swap per trading pair. This doesn't affect the replay, since
+    /// batch execution only sees each pair's aggregate flow, but outputs can only
+    /// be compared per pair rather than per swap.
+    ///
+    /// The positions are those still open after the block, so positions opened
+    /// in the block are included and positions closed in it are not. Positions
+    /// that were already open take their reserves from `prev`, as they were
+    /// going into execution; positions opened in the block only exist after
+    /// execution, so they take their reserves from `state`.
+    pub async fn export<S: StateRead>(prev: &S, state: &S, height: u64) -> Result<Self> {
+        let params = prev.get_dex_params().await?;
+        let mut before: BTreeMap<position::Id, Position> = prev
+            .all_positions()
+            .try_filter(|position| future::ready(position.state == position::State::Opened))
+            .map_ok(|position| (position.id(), position))
+            .try_collect()
+            .await
+            .context("failed to read positions before the block")?;
+        let positions = state
+            .all_positions()
+            .try_filter(|position| future::ready(position.state == position::State::Opened))
+            .map_ok(|position| before.remove(&position.id()).unwrap_or(position))
+            .try_collect()
+            .await
+            .context("failed to read positions after the block")?;
+        let swaps = batch_outputs(state, height)
+            .await?
+            .into_values()
+            .map(|bsod| SnapshotSwap {
+                trading_pair: bsod.trading_pair,
+                delta_1: bsod.delta_1,
+                delta_2: bsod.delta_2,
+            })
+            .collect();
+
+        Ok(Self {
+            height,
+            params,
+            positions,
+            swaps,
+        })
+    }
+
+    /// Replays the block's batch execution and arbitrage under `params`, in an
+    /// in-memory state holding only the snapshot.
+    pub async fn replay(&self, params: DexParameters) -> Result<Outcome> {
+        let height = self.height;
+        let mut state = StateDelta::new(());
+        state.put_block_height(height);
+        state.put_block_timestamp(height, tendermint::Time::unix_epoch());
+        state.put_epoch_by_height(
+            height,
+            Epoch {
+                index: 0,
+                start_height: height,
+            },
+        );
+        state.put_dex_params(params);
+
+        for position in self.positions.iter().cloned() {
+            let id = position.id();
+            state
+                .open_position(position)
+                .await
+                .with_context(|| format!("failed to open position {id}"))?;
+        }
+        for swap in &self.swaps {
+            state
+                .accumulate_swap_flow(&swap.trading_pair, (swap.delta_1, swap.delta_2).into())
+                .await
+                .context("failed to accumulate swap flow")?;
+        }
+
+        let mut state = Arc::new(state);
+        Dex::end_block(
+            &mut state,
+            &abci::request::EndBlock {
+                height: height.try_into()?,
+            },
+        )
+        .await;
+
+        Ok(Outcome {
+            batches: batch_outputs(&state, height).await?,
+            arb_execution: state.arb_execution(height).await?,
+            positions: state
+                .all_positions()
+                .map_ok(|position| (position.id(), position))
+                .try_collect()
+                .await?,
+        })
+    }
+}
+
+/// The result of replaying a snapshot.
+#[derive(Debug, Clone)]
+pub struct Outcome {
+    /// The batch swap outputs, by trading pair.
+    pub batches: BTreeMap<TradingPair, BatchSwapOutputData>,
+    /// The arbitrage execution, if any.
+    pub arb_execution: Option<SwapExecution>,
+    /// The positions after execution, by ID.
+    pub positions: BTreeMap<position::Id, Position>,
+}
+
+impl Outcome {
+    /// Returns the output of `swap`, as its pro-rata share of its batch.
+    pub fn swap_output(&self, swap: &SnapshotSwap) -> (Amount, Amount) {
+        match self.batches.get(&swap.trading_pair) {
+            Some(bsod) => bsod.pro_rata_outputs((swap.delta_1, swap.delta_2)),
+            None => (swap.delta_1, swap.delta_2),
+        }
+    }
+
+    /// Returns the surplus captured by arbitrage, if any.
+    pub fn arb_surplus(&self) -> Option<Value> {
+        let execution = self.arb_execution.as_ref()?;
+        Some(Value {
+            asset_id: execution.output.asset_id,
+            amount: execution
+                .output
+                .amount
+                .checked_sub(&execution.input.amount)?,
+        })
+    }
+}
+
+/// The differences between two replays of the same snapshot.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    /// The output of every swap, under both sets of parameters.
+    pub swaps: Vec<SwapComparison>,
+    /// The positions whose reserves ended up differently.
+    pub positions: Vec<PositionComparison>,
+    /// The arbitrage surplus under the baseline parameters.
+    pub baseline_arb_surplus: Option<Value>,
+    /// The arbitrage surplus under the candidate parameters.
+    pub candidate_arb_surplus: Option<Value>,
+}
+
+#[derive(Debug, Clone)]
+pub struct SwapComparison {
+    pub swap: SnapshotSwap,
+    pub baseline: (Amount, Amount),
+    pub candidate: (Amount, Amount),
+}
+
+#[derive(Debug, Clone)]
+pub struct PositionComparison {
+    pub id: position::Id,
+    pub trading_pair: TradingPair,
+    pub initial: Reserves,
+    pub baseline: Reserves,
+    pub candidate: Reserves,
+}
+
+impl Comparison {
+    /// Compares the `baseline` and `candidate` replays of `snapshot`.
+    pub fn new(snapshot: &Snapshot, baseline: &Outcome, candidate: &Outcome) -> Self {
+        let swaps = snapshot
+            .swaps
+            .iter()
+            .map(|swap| SwapComparison {
+                swap: *swap,
+                baseline: baseline.swap_output(swap),
+                candidate: candidate.swap_output(swap),
+            })
+            .collect();
+
+        let positions = snapshot
+            .positions
+            .iter()
+            .filter_map(|position| {
+                let id = position.id();
+                let reserves = |outcome: &Outcome| {
+                    outcome
+                        .positions
+                        .get(&id)
+                        .map(|position| position.reserves.clone())
+                        .unwrap_or_else(|| position.reserves.clone())
+                };
+                let (baseline, candidate) = (reserves(baseline), reserves(candidate));
+                (baseline != candidate).then(|| PositionComparison {
+                    id,
+                    trading_pair: position.phi.pair,
+                    initial: position.reserves.clone(),
+                    baseline,
+                    candidate,
+                })
+            })
+            .collect();
+
+        Self {
+            swaps,
+            positions,
+            baseline_arb_surplus: baseline.arb_surplus(),
+            candidate_arb_surplus: candidate.arb_surplus(),
+        }
+    }
+}
+
+/// Reads the batch swap outputs recorded at `height`, by trading pair.
+async fn batch_outputs<S: StateRead>(
+    state: &S,
+    height: u64,
+) -> Result<BTreeMap<TradingPair, BatchSwapOutputData>> {
+    state
+        .prefix::<BatchSwapOutputData>(&state_key::output_data_at_height(height))
+        .map_ok(|(_, bsod)| (bsod.trading_pair, bsod))
+        .try_collect()
+        .await
+        .context("failed to read batch swap outputs")
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_sdk_asset::asset;
+
+    use super::*;
+    use crate::{component::router::create_buy, DirectedUnitPair};
+
+    #[tokio::test]
+    /// Test that replaying a snapshot fills its swaps, and that comparing a
+    /// replay with itself finds no differences.
+    async fn replay_fills_swaps() -> anyhow::Result<()> {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("penumbra").unwrap();
+        let gn = cache.get_unit("gn").unwrap();
+
+        // A single 1:1 gn:penumbra position, i.e. buy 1 gn at 1 penumbra.
+        let pair = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+        let buy = create_buy(pair.clone(), 1u64.into(), 1u64.into());
+        let trading_pair: TradingPair = pair.into_directed_trading_pair().into();
+        assert_eq!(trading_pair.asset_1(), penumbra.id());
+
+        let snapshot = Snapshot {
+            height: 1,
+            params: DexParameters::default(),
+            positions: vec![buy.clone()],
+            swaps: vec![SnapshotSwap {
+                trading_pair,
+                delta_1: 0u64.into(),
+                delta_2: gn.value(1u32.into()).amount,
+            }],
+        };
+
+        let baseline = snapshot.replay(snapshot.params.clone()).await?;
+        let (lambda_1, lambda_2) = baseline.swap_output(&snapshot.swaps[0]);
+        assert_eq!(lambda_1, penumbra.value(1u32.into()).amount);
+        assert_eq!(lambda_2, 0u64.into());
+        assert_eq!(
+            baseline.positions[&buy.id()].reserves.r2,
+            gn.value(1u32.into()).amount
+        );
+
+        let comparison = Comparison::new(&snapshot, &baseline, &baseline);
+        assert_eq!(comparison.swaps.len(), 1);
+        assert_eq!(comparison.swaps[0].baseline, comparison.swaps[0].candidate);
+        assert!(comparison.positions.is_empty());
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    /// Test that an exported snapshot holds the positions open after the block,
+    /// with the reserves they had going into it.
+    async fn export_takes_positions_open_after_the_block() -> anyhow::Result<()> {
+        let cache = asset::Cache::with_known_assets();
+        let penumbra = cache.get_unit("penumbra").unwrap();
+        let gn = cache.get_unit("gn").unwrap();
+        let pair = DirectedUnitPair::new(gn.clone(), penumbra.clone());
+
+        let kept = create_buy(pair.clone(), 1u64.into(), 1u64.into());
+        let closed = create_buy(pair.clone(), 1u64.into(), 2u64.into());
+        let opened = create_buy(pair.clone(), 1u64.into(), 3u64.into());
+
+        let mut prev = StateDelta::new(());
+        prev.put_block_height(1);
+        prev.put_dex_params(DexParameters::default());
+        prev.open_position(kept.clone()).await?;
+        prev.open_position(closed.clone()).await?;
+
+        // After the block, `kept` has been traded against, `closed` has been
+        // closed, and `opened` has been opened.
+        let mut state = StateDelta::new(());
+        state.put_block_height(2);
+        state.put_dex_params(DexParameters::default());
+        let mut traded = kept.clone();
+        traded.reserves = traded.reserves.flip();
+        state.open_position(traded).await?;
+        state.open_position(closed.clone()).await?;
+        state.close_position_by_id(&closed.id()).await?;
+        state.open_position(opened.clone()).await?;
+
+        let snapshot = Snapshot::export(&prev, &state, 2).await?;
+        let positions: BTreeMap<_, _> = snapshot
+            .positions
+            .into_iter()
+            .map(|position| (position.id(), position))
+            .collect();
+        assert_eq!(positions.len(), 2);
+        assert_eq!(positions[&kept.id()].reserves, kept.reserves);
+        assert_eq!(positions[&opened.id()].reserves, opened.reserves);
+        assert!(!positions.contains_key(&closed.id()));
+
+        Ok(())
+    }
+}
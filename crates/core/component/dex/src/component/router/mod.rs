@@ -4,6 +4,7 @@ mod path;
 mod path_cache;
 mod path_search;
 mod route_and_fill;
+mod split;
 
 use path::Path;
 use path_cache::{PathCache, PathEntry, SharedPathCache};
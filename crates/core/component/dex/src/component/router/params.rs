@@ -10,6 +10,10 @@ pub struct RoutingParams {
     pub price_limit: Option<U128x128>,
     pub fixed_candidates: Arc<Vec<asset::Id>>,
     pub max_hops: usize,
+    /// The maximum number of paths to split a swap across, see [`DexParameters::max_split_paths`].
+    pub max_split_paths: usize,
+    /// Whether to rank paths by their composed price, see [`DexParameters::composed_path_prices`].
+    pub composed_path_prices: bool,
 }
 
 impl RoutingParams {
@@ -45,12 +49,16 @@ impl From<DexParameters> for RoutingParams {
         DexParameters {
             fixed_candidates,
             max_hops,
+            max_split_paths,
+            composed_path_prices,
             ..
         }: DexParameters,
     ) -> Self {
         Self {
             fixed_candidates: Arc::new(fixed_candidates),
             max_hops: max_hops as usize,
+            max_split_paths: max_split_paths as usize,
+            composed_path_prices,
             price_limit: None,
         }
     }
@@ -2,11 +2,11 @@ use anyhow::Result;
 
 use cnidarium::{StateDelta, StateRead};
 use penumbra_sdk_asset::asset;
-use penumbra_sdk_num::fixpoint::U128x128;
+use penumbra_sdk_num::{fixpoint::U128x128, Amount};
 use std::cmp::Ordering;
 use tracing::Instrument;
 
-use crate::{component::PositionRead, DirectedTradingPair};
+use crate::{component::PositionRead, lp::BareTradingFunction, DirectedTradingPair};
 
 /// A path is an ordered sequence of assets, implicitly defining a trading pair,
 /// and a price for trading along that path. It contains a forked view of the
@@ -22,7 +22,20 @@ pub(super) struct Path<S: StateRead + 'static> {
     /// The nodes along the path, implicitly defining the end
     pub nodes: Vec<asset::Id>,
     /// An estimate of the end-to-end effective price along the path
+    ///
+    /// This is the product of the per-hop effective prices, rounded at every
+    /// hop, unless the path composes prices, in which case it is the effective
+    /// price of [`Path::phi`], rounded once for the whole path.
     pub price: U128x128,
+    /// A synthetic trading function composing the best position on each hop,
+    /// oriented to trade the start asset for the end asset, if the path is
+    /// ranked by its composed price (see [`DexParameters::composed_path_prices`]).
+    ///
+    /// [`DexParameters::composed_path_prices`]: crate::DexParameters::composed_path_prices
+    pub phi: Option<BareTradingFunction>,
+    /// An estimate of the output the best position on each hop can provide,
+    /// in units of the end asset, or `None` for the empty path.
+    pub capacity: Option<Amount>,
     /// A forked view of the state after traveling along this path.
     pub state: StateDelta<S>,
     /// A span recording information about the path, for debugging.
@@ -34,13 +47,15 @@ impl<S: StateRead + 'static> Path<S> {
         self.nodes.last().unwrap_or(&self.start)
     }
 
-    pub fn begin(start: asset::Id, state: StateDelta<S>) -> Self {
+    pub fn begin(start: asset::Id, state: StateDelta<S>, composed_prices: bool) -> Self {
         let span = tracing::debug_span!("path", start = ?start);
         span.in_scope(|| tracing::debug!("beginning path"));
         Self {
             start,
             nodes: Vec::new(),
             price: 1u64.into(),
+            phi: composed_prices.then(|| BareTradingFunction::new(0, 1u64.into(), 1u64.into())),
+            capacity: None,
             state,
             span,
         }
@@ -52,6 +67,8 @@ impl<S: StateRead + 'static> Path<S> {
             start: self.start,
             nodes: self.nodes.clone(),
             price: self.price,
+            phi: self.phi.clone(),
+            capacity: self.capacity,
             state: self.state.fork(),
             span: self.span.clone(),
         }
@@ -80,18 +97,45 @@ impl<S: StateRead + 'static> Path<S> {
         self.state
             .deindex_position_by_price(&best_price_lp, &best_price_lp_id);
 
-        // Compute the effective price of a trade in the direction self.end()=>new_end
-        let hop_price = best_price_lp
+        // Orient the position to trade in the direction self.end()=>new_end
+        let hop_phi = best_price_lp
             .phi
             .orient_end(new_end)
-            .expect("position should be contain the end asset")
-            .effective_price();
+            .expect("position should be contain the end asset");
+        let hop_price = hop_phi.effective_price();
 
-        match self.price * hop_price {
-            Ok(path_price) => {
+        // The most the position can provide is its reserves of the new end asset,
+        // further limited by how much of the old end asset the path can deliver.
+        let hop_reserves = best_price_lp
+            .reserves_for(new_end)
+            .expect("position should be contain the end asset");
+        let capacity = match self.capacity {
+            None => hop_reserves,
+            Some(capacity) => hop_phi
+                .convert_to_lambda_2(capacity.into())
+                .ok()
+                .and_then(|lambda_2| Amount::try_from(lambda_2.round_down()).ok())
+                .map_or(hop_reserves, |lambda_2| lambda_2.min(hop_reserves)),
+        };
+
+        let extended = match &self.phi {
+            // Composing the trading functions along the path lets us compute the
+            // end-to-end price with a single rounding, rather than one per hop.
+            Some(phi) => phi
+                .compose(&hop_phi)
+                .map(|phi| (phi.effective_price(), Some(phi))),
+            None => (self.price * hop_price)
+                .map(|path_price| (path_price, None))
+                .map_err(anyhow::Error::from),
+        };
+
+        match extended {
+            Ok((path_price, phi)) => {
                 // Update and return the path.
-                tracing::debug!(%path_price, %hop_price, ?best_price_lp_id, "extended path");
+                tracing::debug!(%path_price, %hop_price, ?capacity, ?best_price_lp_id, "extended path");
                 self.price = path_price;
+                self.phi = phi;
+                self.capacity = Some(capacity);
                 self.nodes.push(new_end);
                 // Create a new span for the extension.  Note: this is a child of
                 // the path span (:path:via:via:via etc), not a child of the current
@@ -100,9 +144,9 @@ impl<S: StateRead + 'static> Path<S> {
                 Ok(Some(self))
             }
             Err(e) => {
-                // If there was an overflow estimating the effective price, we failed
-                // to extend the path.
-                tracing::debug!(?e, "failed to extend path due to overflow");
+                // If there was an overflow estimating the effective price, or we
+                // couldn't compose the hop onto the path, we failed to extend the path.
+                tracing::debug!(?e, "failed to extend path, could not estimate its price");
                 Ok(None)
             }
         }
@@ -66,12 +66,16 @@ pub(super) type SharedPathCache<S> = Arc<Mutex<PathCache<S>>>;
 
 impl<S: StateRead + 'static> PathCache<S> {
     /// Initializes a new PathCache with the identity path for the start asset.
-    pub fn begin(start: asset::Id, state: StateDelta<S>) -> SharedPathCache<S> {
+    pub fn begin(
+        start: asset::Id,
+        state: StateDelta<S>,
+        composed_prices: bool,
+    ) -> SharedPathCache<S> {
         let mut cache = BTreeMap::new();
         cache.insert(
             start,
             PathEntry {
-                path: Path::begin(start, state),
+                path: Path::begin(start, state, composed_prices),
                 active: true,
                 spill: None,
             },
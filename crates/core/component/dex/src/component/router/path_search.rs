@@ -30,6 +30,8 @@ pub trait PathSearch: StateRead + Clone + 'static {
             max_hops,
             fixed_candidates,
             price_limit,
+            composed_path_prices,
+            ..
         } = params;
 
         // Initialize some metrics for calculating time spent on path searching
@@ -46,7 +48,7 @@ pub trait PathSearch: StateRead + Clone + 'static {
         // at the end of routing
         let state = StateDelta::new(self.clone());
 
-        let cache = PathCache::begin(src, state);
+        let cache = PathCache::begin(src, state, composed_path_prices);
         for i in 0..max_hops {
             relax_active_paths(cache.clone(), fixed_candidates.clone()).await?;
             tracing::trace!(i, "finished relaxing all active paths");
@@ -19,7 +19,7 @@ use crate::{
     BatchSwapOutputData, SwapExecution, TradingPair,
 };
 
-use super::fill_route::FillError;
+use super::{fill_route::FillError, split::split_fill};
 
 /// Ties together the routing and filling logic, to process
 /// a block's batch swap flows.
@@ -174,6 +174,26 @@ pub trait RouteAndFill: StateWrite + Sized {
         // An ordered list of execution traces that were used to fill the trade.
         let mut traces: Vec<Vec<Value>> = Vec::new();
 
+        // If split routing is enabled, we start by filling the best paths simultaneously,
+        // and only route what that left unfilled through the loop below.
+        if params.max_split_paths > 1 {
+            if let Some(swap_execution) = split_fill(
+                self,
+                asset_1,
+                asset_2,
+                input,
+                &params,
+                &mut execution_circuit_breaker,
+            )
+            .await?
+            {
+                tracing::debug!(consumed_input = ?swap_execution.input.amount, output = ?swap_execution.output.amount, "filled across split paths");
+                total_output_2 = swap_execution.output.amount;
+                total_unfilled_1 = input - swap_execution.input.amount;
+                traces = swap_execution.traces;
+            }
+        }
+
         // Termination conditions:
         // 1. We have no more `delta_1` remaining
         // 2. A path can no longer be found
@@ -181,6 +201,12 @@ pub trait RouteAndFill: StateWrite + Sized {
         // 4. The execution circuit breaker has been triggered based on the number of path searches and executions
         // 5. An unrecoverable error occurred during the execution of the route.
         loop {
+            // The split fill may have already filled all of the input.
+            if total_unfilled_1.value() == 0 {
+                tracing::debug!("filled all input, exiting route_and_fill");
+                break;
+            }
+
             // Check if we have exceeded the execution circuit breaker limits.
             if execution_circuit_breaker.exceeded_limits() {
                 tracing::debug!("execution circuit breaker triggered, exiting route_and_fill");
@@ -0,0 +1,264 @@
+use std::sync::Arc;
+
+use anyhow::{Context, Result};
+use cnidarium::{StateDelta, StateWrite};
+use penumbra_sdk_asset::{asset, Value};
+use penumbra_sdk_num::{fixpoint::U128x128, Amount};
+use tracing::instrument;
+
+use crate::{component::ExecutionCircuitBreaker, SwapExecution};
+
+use super::{fill_route::FillError, FillRoute, PathSearch, RoutingParams};
+
+/// A run of liquidity along a path that executes at a single price.
+#[derive(Debug, Clone, Copy)]
+struct Chunk {
+    /// The index of the path the chunk belongs to.
+    path: usize,
+    /// The amount of input the chunk can absorb.
+    input: Amount,
+    /// The price of the chunk, in units of input per unit of output.
+    price: U128x128,
+}
+
+/// Fills `input` by splitting it across up to `params.max_split_paths` of the
+/// best paths from `asset_1` to `asset_2`, so that every path is filled up to
+/// the same marginal price.
+///
+/// This proceeds in three phases, each metered by the `execution_circuit_breaker`:
+/// 1. Discovery: on a scratch fork of the state, repeatedly search for the best
+///    path and fill it up to its spill price, collecting the distinct paths found.
+/// 2. Profiling: fill each path on its own scratch fork, recording the price of
+///    each run of liquidity along it.
+/// 3. Execution: allocate the input to the cheapest liquidity across all paths,
+///    then fill each path with its allocation, up to the marginal price.
+///
+/// Paths that share liquidity can make the profiles optimistic, and the circuit
+/// breaker can interrupt any phase, so the returned execution may leave some
+/// input unfilled. Returns `None` if nothing was filled.
+#[instrument(skip(state, input, params, execution_circuit_breaker))]
+pub(super) async fn split_fill<S: StateWrite + 'static>(
+    state: &mut Arc<S>,
+    asset_1: asset::Id,
+    asset_2: asset::Id,
+    input: Amount,
+    params: &RoutingParams,
+    execution_circuit_breaker: &mut ExecutionCircuitBreaker,
+) -> Result<Option<SwapExecution>> {
+    let paths = discover_paths(
+        state,
+        asset_1,
+        asset_2,
+        input,
+        params,
+        execution_circuit_breaker,
+    )
+    .await?;
+    tracing::debug!(?paths, "discovered paths to split across");
+
+    // Profile each path in isolation, against the current state.
+    let mut chunks = Vec::new();
+    for (index, path) in paths.iter().enumerate() {
+        if execution_circuit_breaker.exceeded_limits() {
+            tracing::debug!("execution circuit breaker triggered, stop profiling paths");
+            break;
+        } else {
+            execution_circuit_breaker.increment();
+        }
+
+        let mut probe = StateDelta::new(state.clone());
+        let execution = match probe
+            .fill_route(
+                Value {
+                    asset_id: asset_1,
+                    amount: input,
+                },
+                path,
+                None,
+            )
+            .await
+        {
+            Ok(execution) => execution,
+            Err(e) => {
+                tracing::debug!(?e, ?path, "failed to profile path, skipping it");
+                continue;
+            }
+        };
+
+        // Traces are produced in order of increasing price, and a trace
+        // without output has an infinite price, so we can stop at the first one.
+        chunks.extend(execution.traces.iter().map_while(|trace| {
+            let input = trace.first()?.amount;
+            let output = trace.last()?.amount;
+            let price = U128x128::ratio(input, output).ok()?;
+            Some(Chunk {
+                path: index,
+                input,
+                price,
+            })
+        }));
+    }
+
+    let (allocations, marginal_price) = allocate(chunks, paths.len(), input, params.price_limit);
+    let Some(marginal_price) = marginal_price else {
+        tracing::debug!("no liquidity to split across, exiting split_fill");
+        return Ok(None);
+    };
+    tracing::debug!(?allocations, %marginal_price, "allocated input across paths");
+
+    let mut traces = Vec::new();
+    let mut total_input = Amount::zero();
+    let mut total_output = Amount::zero();
+    for (path, allocation) in paths.iter().zip(allocations) {
+        if allocation == Amount::zero() {
+            continue;
+        }
+
+        if execution_circuit_breaker.exceeded_limits() {
+            tracing::debug!("execution circuit breaker triggered, stop filling paths");
+            break;
+        } else {
+            execution_circuit_breaker.increment();
+        }
+
+        let execution_result = Arc::get_mut(state)
+            .expect("expected state to have no other refs")
+            .fill_route(
+                Value {
+                    asset_id: asset_1,
+                    amount: allocation,
+                },
+                path,
+                Some(marginal_price),
+            )
+            .await;
+
+        let execution = match execution_result {
+            Ok(execution) => execution,
+            Err(FillError::ExecutionOverflow(position_id)) => {
+                // Leave the input for this path to the greedy router, which knows
+                // how to route around the overflowing position.
+                tracing::debug!(culprit = ?position_id, "overflow detected during split execution");
+                continue;
+            }
+            Err(e) => anyhow::bail!("error filling route: {:?}", e),
+        };
+
+        tracing::debug!(?path, consumed_input = ?execution.input.amount, output = ?execution.output.amount, "filled split path");
+        total_input += execution.input.amount;
+        total_output += execution.output.amount;
+        traces.extend(execution.traces);
+    }
+
+    if traces.is_empty() {
+        return Ok(None);
+    }
+
+    Ok(Some(SwapExecution {
+        traces,
+        input: Value {
+            asset_id: asset_1,
+            amount: total_input,
+        },
+        output: Value {
+            asset_id: asset_2,
+            amount: total_output,
+        },
+    }))
+}
+
+/// Collects up to `params.max_split_paths` distinct paths, in the order the
+/// router would visit them, without modifying the state.
+async fn discover_paths<S: StateWrite + 'static>(
+    state: &Arc<S>,
+    asset_1: asset::Id,
+    asset_2: asset::Id,
+    input: Amount,
+    params: &RoutingParams,
+    execution_circuit_breaker: &mut ExecutionCircuitBreaker,
+) -> Result<Vec<Vec<asset::Id>>> {
+    let mut scratch = Arc::new(StateDelta::new(state.clone()));
+    let mut paths: Vec<Vec<asset::Id>> = Vec::new();
+    let mut unfilled = input;
+
+    for _ in 0..params.max_split_paths {
+        if unfilled == Amount::zero() {
+            break;
+        }
+
+        if execution_circuit_breaker.exceeded_limits() {
+            tracing::debug!("execution circuit breaker triggered, stop discovering paths");
+            break;
+        } else {
+            execution_circuit_breaker.increment();
+        }
+
+        let (path, spill_price) = scratch
+            .path_search(asset_1, asset_2, params.clone())
+            .await
+            .context("error finding best path")?;
+
+        let Some(path) = path.filter(|path| !path.is_empty()) else {
+            break;
+        };
+
+        // Consume the path up to the spill price, so that the next search
+        // surfaces the next best path.
+        let Ok(execution) = Arc::get_mut(&mut scratch)
+            .expect("expected scratch state to have no other refs")
+            .fill_route(
+                Value {
+                    asset_id: asset_1,
+                    amount: unfilled,
+                },
+                &path,
+                spill_price,
+            )
+            .await
+        else {
+            break;
+        };
+        unfilled = unfilled - execution.input.amount;
+
+        if !paths.contains(&path) {
+            paths.push(path);
+        }
+    }
+
+    Ok(paths)
+}
+
+/// Allocates `input` to the cheapest chunks of liquidity across all paths,
+/// stopping at the price limit, if any.
+///
+/// Returns the input allocated to each path, along with the price of the most
+/// expensive chunk used, which is the common marginal price of every path.
+fn allocate(
+    mut chunks: Vec<Chunk>,
+    num_paths: usize,
+    input: Amount,
+    price_limit: Option<U128x128>,
+) -> (Vec<Amount>, Option<U128x128>) {
+    // The sort is stable, so chunks of the same path stay in execution order.
+    chunks.sort_by_key(|chunk| chunk.price);
+
+    let mut allocations = vec![Amount::zero(); num_paths];
+    let mut unallocated = input;
+    let mut marginal_price = None;
+
+    for chunk in chunks {
+        if unallocated == Amount::zero() {
+            break;
+        }
+        if price_limit.is_some_and(|limit| chunk.price >= limit) {
+            break;
+        }
+
+        let amount = chunk.input.min(unallocated);
+        allocations[chunk.path] += amount;
+        unallocated = unallocated - amount;
+        marginal_price = Some(chunk.price);
+    }
+
+    (allocations, marginal_price)
+}
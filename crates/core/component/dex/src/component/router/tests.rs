@@ -14,9 +14,9 @@ use crate::lp::SellOrder;
 use crate::DexParameters;
 use crate::{
     component::{
-        router::{FillRoute, HandleBatchSwaps, Path},
+        router::{FillRoute, HandleBatchSwaps, Path, RouteAndFill},
         tests::TempStorageExt,
-        PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        ExecutionCircuitBreaker, PositionManager, PositionRead, StateReadExt, StateWriteExt,
     },
     lp::{
         position::{self, Position},
@@ -71,7 +71,7 @@ async fn path_extension_basic() {
     // Create a new path starting at "gm".
     let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
 
-    let path = Path::begin(gm.id(), state);
+    let path = Path::begin(gm.id(), state, false);
 
     // Extend the path to "gn".
     let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
@@ -139,7 +139,7 @@ async fn path_extension_basic() {
     // Write some test positions without the mispriced position.
     create_test_positions_basic(&mut state, false).await;
 
-    let path = Path::begin(gm.id(), state)
+    let path = Path::begin(gm.id(), state, false)
         .extend_to(gn.id())
         .await
         .expect("extend_to failed")
@@ -165,6 +165,44 @@ async fn path_extension_basic() {
     // TODO: test synthetic liquidity
 }
 
+#[tokio::test(flavor = "multi_thread")]
+/// Checks that paths only carry a composed trading function when ranked by
+/// their composed price, and that both kinds of path estimate the same capacity.
+async fn path_extension_composes_prices_when_enabled() {
+    let _ = tracing_subscriber::fmt::try_init();
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let pusd = asset::Cache::with_known_assets()
+        .get_unit("test_usd")
+        .unwrap();
+
+    let mut paths = Vec::new();
+    for composed_prices in [true, false] {
+        let mut state = StateDelta::new(());
+        state.put_dex_params(DexParameters::default());
+        create_test_positions_basic(&mut state, false).await;
+
+        let path = Path::begin(gm.id(), state, composed_prices)
+            .extend_to(gn.id())
+            .await
+            .expect("extend_to failed")
+            .expect("path to gn not found")
+            .extend_to(pusd.id())
+            .await
+            .expect("extend_to failed")
+            .expect("path to test_usd not found");
+        paths.push(path);
+    }
+    let (multiplied, composed) = (paths.pop().unwrap(), paths.pop().unwrap());
+
+    assert!(multiplied.phi.is_none(), "prices should not be composed");
+    let phi = composed.phi.as_ref().expect("prices should be composed");
+    assert_eq!(composed.price, phi.effective_price());
+
+    assert!(multiplied.capacity.is_some(), "path should have a capacity");
+    assert_eq!(multiplied.capacity, composed.capacity);
+}
+
 async fn create_test_positions_basic<S: StateWrite>(s: &mut S, misprice: bool) {
     let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
 
@@ -1614,7 +1652,7 @@ async fn path_search_testnet_53_1_reproduction() -> anyhow::Result<()> {
     state.open_position(s_c).await.unwrap();
     state.open_position(c_t).await.unwrap();
 
-    let cache = PathCache::begin(penumbra.id(), state.fork());
+    let cache = PathCache::begin(penumbra.id(), state.fork(), false);
     let mut cache_guard = cache.lock();
     let mut identity_path = cache_guard.0.get_mut(&penumbra.id()).unwrap().path.fork();
 
@@ -1785,7 +1823,7 @@ async fn path_search_commutative() -> anyhow::Result<()> {
     state.open_position(c_d).await.unwrap();
     state.open_position(d_t).await.unwrap();
 
-    let cache = PathCache::begin(btc.id(), state.fork());
+    let cache = PathCache::begin(btc.id(), state.fork(), false);
     let mut cache_guard = cache.lock();
     let mut identity_path = cache_guard.0.get_mut(&btc.id()).unwrap().path.fork();
 
@@ -1885,7 +1923,7 @@ async fn path_search_commutative() -> anyhow::Result<()> {
             .iter()
             .map(|index| all_paths[**index].fork())
             .collect_vec();
-        let cache2 = PathCache::begin(btc.id(), state.fork());
+        let cache2 = PathCache::begin(btc.id(), state.fork(), false);
         let mut cache_guard2 = cache2.lock();
         for (i, path) in sequence_of_updates.into_iter().enumerate() {
             tracing::debug!(i, path_price = %path.price);
@@ -1972,7 +2010,7 @@ async fn path_search_unique() -> anyhow::Result<()> {
     state.open_position(a_t).await.unwrap();
     state.open_position(b_t).await.unwrap();
 
-    let cache = PathCache::begin(pen.id(), state.fork());
+    let cache = PathCache::begin(pen.id(), state.fork(), false);
     let mut cache_guard = cache.lock();
     let mut identity_path = cache_guard.0.get_mut(&pen.id()).unwrap().path.fork();
 
@@ -2008,7 +2046,7 @@ async fn path_search_unique() -> anyhow::Result<()> {
             .iter()
             .map(|index| all_paths[**index].fork())
             .collect_vec();
-        let cache2 = PathCache::begin(pen.id(), state.fork());
+        let cache2 = PathCache::begin(pen.id(), state.fork(), false);
         let mut cache_guard2 = cache2.lock();
         for (i, path) in sequence_of_updates.into_iter().enumerate() {
             tracing::debug!(i, path_price = %path.price);
@@ -2175,3 +2213,113 @@ async fn path_compare_node_ids() -> anyhow::Result<()> {
     assert!(path2 < path1);
     Ok(())
 }
+
+/// Opens two routes from gm to penumbra, each made of positions selling one
+/// penumbra at interleaved prices: directly, at 1, 3, 5, 7 and 9 gm each, and
+/// through gn, at 2, 4, 6, 8 and 10 gm each.
+async fn create_interleaved_routes<S: StateWrite>(s: &mut S) {
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    // Sell 100 gn at 1 gm each.
+    s.open_position(create_sell(
+        DirectedUnitPair::new(gn.clone(), gm.clone()),
+        100u64.into(),
+        1u64.into(),
+    ))
+    .await
+    .unwrap();
+
+    for price in 1..=10u64 {
+        let market = if price % 2 == 1 {
+            DirectedUnitPair::new(penumbra.clone(), gm.clone())
+        } else {
+            DirectedUnitPair::new(penumbra.clone(), gn.clone())
+        };
+        s.open_position(create_sell(market, 1u64.into(), price.into()))
+            .await
+            .unwrap();
+    }
+}
+
+/// Routes `input` gm into penumbra against the interleaved routes, returning the output.
+async fn route_interleaved(
+    input: u64,
+    max_split_paths: usize,
+    execution_budget: u32,
+) -> anyhow::Result<Amount> {
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = Arc::new(StateDelta::new(storage.latest_snapshot()));
+    let mut state_tx = state.try_begin_transaction().unwrap();
+    create_interleaved_routes(&mut state_tx).await;
+    state_tx.apply();
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    let mut routing_params = state.routing_params().await?;
+    routing_params.max_split_paths = max_split_paths;
+
+    let execution = state
+        .route_and_fill(
+            gm.id(),
+            penumbra.id(),
+            gm.value(input.into()).amount,
+            routing_params,
+            ExecutionCircuitBreaker::new(execution_budget),
+        )
+        .await?
+        .expect("routes have liquidity");
+
+    assert_eq!(execution.output.asset_id, penumbra.id());
+    Ok(execution.output.amount)
+}
+
+#[tokio::test]
+/// Test that splitting across paths fills more of a swap than greedy routing,
+/// when the liquidity on the best path alternates with the next-best path and
+/// the execution budget is tight.
+async fn split_route_beats_greedy_on_interleaved_paths() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    // Enough gm to buy all ten penumbra, with budget for six operations.
+    let greedy = route_interleaved(55, 0, 6).await?;
+    let split = route_interleaved(55, 2, 6).await?;
+
+    // Greedy routing fills a single position per routing round.
+    assert_eq!(greedy, penumbra.value(6u64.into()).amount);
+    // Split routing fills both paths entirely in two discovery rounds, two
+    // profiling fills, and two executions.
+    assert_eq!(split, penumbra.value(10u64.into()).amount);
+    assert!(split > greedy);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Test that splitting across paths fills each path up to the same marginal
+/// price, matching greedy routing when the execution budget is ample.
+async fn split_route_matches_greedy_with_ample_budget() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let penumbra = asset::Cache::with_known_assets()
+        .get_unit("penumbra")
+        .unwrap();
+
+    // 20 gm buys the penumbra at 1 through 5 gm, and 5/6 of the one at 6 gm.
+    let greedy = route_interleaved(20, 0, 64).await?;
+    let split = route_interleaved(20, 2, 64).await?;
+
+    assert_eq!(greedy, split);
+    assert!(split > penumbra.value(5u64.into()).amount);
+    assert!(split < penumbra.value(6u64.into()).amount);
+
+    Ok(())
+}
@@ -1,4 +1,4 @@
-use std::{pin::Pin, sync::Arc};
+use std::{collections::BTreeMap, pin::Pin, sync::Arc};
 
 use anyhow::Result;
 use async_stream::try_stream;
@@ -7,8 +7,9 @@ use tokio::sync::mpsc;
 use tonic::Status;
 use tracing::instrument;
 
-use cnidarium::{StateDelta, Storage};
+use cnidarium::{StateDelta, StateRead, Storage};
 use penumbra_sdk_asset::{asset, Value};
+use penumbra_sdk_num::fixpoint::U128x128;
 use penumbra_sdk_proto::{
     core::component::dex::v1::{
         query_service_server::QueryService,
@@ -20,26 +21,43 @@ use penumbra_sdk_proto::{
         ArbExecutionRequest, ArbExecutionResponse, ArbExecutionsRequest, ArbExecutionsResponse,
         BatchSwapOutputDataRequest, BatchSwapOutputDataResponse, CandlestickDataRequest,
         CandlestickDataResponse, CandlestickDataStreamRequest, CandlestickDataStreamResponse,
+        LiquidityPositionActivityRequest, LiquidityPositionActivityResponse,
         LiquidityPositionByIdRequest, LiquidityPositionByIdResponse, LiquidityPositionsByIdRequest,
         LiquidityPositionsByIdResponse, LiquidityPositionsByPriceRequest,
         LiquidityPositionsByPriceResponse, LiquidityPositionsRequest, LiquidityPositionsResponse,
-        SimulateTradeRequest, SimulateTradeResponse, SpreadRequest, SpreadResponse,
-        SwapExecutionRequest, SwapExecutionResponse, SwapExecutionsRequest, SwapExecutionsResponse,
+        OrderBookDepthRequest, OrderBookDepthResponse, OrderBookDepthStreamRequest,
+        OrderBookDepthStreamResponse, SimulateTradeRequest, SimulateTradeResponse, SpreadRequest,
+        SpreadResponse, SwapExecutionRequest, SwapExecutionResponse, SwapExecutionsRequest,
+        SwapExecutionsResponse, TimeWeightedAveragePriceRequest, TimeWeightedAveragePriceResponse,
     },
+    event::EventDomainType as _,
     DomainType, StateReadProto,
 };
+use penumbra_sdk_sct::component::clock::EpochRead as _;
 
 use super::ExecutionCircuitBreaker;
 use crate::{
     component::metrics,
+    event::EventPositionExecution,
     lp::position::{self, Position},
-    state_key, CandlestickData, DirectedTradingPair, SwapExecution, TradingPair,
+    order_book::{DepthAggregator, Side},
+    price_impact_bps, state_key, CandlestickData, CandlestickInterval, DirectedTradingPair,
+    OrderBookDepth, SimulatedPositionExecution, SwapExecution, TradingPair,
 };
 
-use super::{chandelier::CandlestickRead, router::RouteAndFill, PositionRead, StateReadExt};
+use super::{
+    chandelier::CandlestickRead, router::RouteAndFill, PositionRead, PriceHistoryRead, StateReadExt,
+};
 
 pub mod stub;
 
+/// The number of levels per side returned by `OrderBookDepth` when the request doesn't say.
+const DEFAULT_DEPTH_LEVELS: usize = 50;
+/// The most levels per side that `OrderBookDepth` will return.
+const MAX_DEPTH_LEVELS: usize = 1_000;
+/// The most positions per side that `OrderBookDepth` will walk.
+const MAX_DEPTH_POSITIONS: usize = 10_000;
+
 // TODO: Hide this and only expose a Router?
 pub struct Server {
     storage: Storage,
@@ -77,6 +95,15 @@ impl QueryService for Server {
             dyn futures::Stream<Item = Result<CandlestickDataStreamResponse, tonic::Status>> + Send,
         >,
     >;
+    type OrderBookDepthStreamStream = Pin<
+        Box<dyn futures::Stream<Item = Result<OrderBookDepthStreamResponse, tonic::Status>> + Send>,
+    >;
+    type LiquidityPositionActivityStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<LiquidityPositionActivityResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
 
     #[instrument(skip(self, request))]
     async fn arb_execution(
@@ -216,13 +243,22 @@ impl QueryService for Server {
         // Limit the number of candlesticks returned to 20,000 (approximately 1 day)
         // to prevent the server from being overwhelmed by a single request.
         let limit = std::cmp::min(request.get_ref().limit, 20_000u64);
-        let start_height = match request.get_ref().start_height {
+        let interval = CandlestickInterval::try_from(request.get_ref().interval)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        check_candlestick_interval(&state, interval).await?;
+        let start_bucket = match request.get_ref().start_height {
             0 => {
-                // If no start height is provided, go `limit` blocks back from now.
-                let current_height = state.version();
-                current_height.saturating_sub(limit)
+                // If no start height is provided, go `limit` intervals back from now.
+                let current_bucket = state
+                    .candlestick_bucket_at_height(interval, state.version())
+                    .await
+                    .map_err(|e| tonic::Status::internal(e.to_string()))?;
+                interval.buckets_before(current_bucket, limit)
             }
-            start_height => start_height,
+            start_height => state
+                .candlestick_bucket_at_height(interval, start_height)
+                .await
+                .map_err(|e| Status::invalid_argument(format!("invalid start_height: {e}")))?,
         };
 
         let pair: DirectedTradingPair = request
@@ -234,7 +270,7 @@ impl QueryService for Server {
             .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
 
         let candlesticks = state
-            .candlesticks(&pair, start_height, limit as usize)
+            .candlesticks_by_interval(&pair, interval, start_bucket, limit as usize)
             .await
             .map_err(|e| tonic::Status::internal(e.to_string()))?;
 
@@ -254,6 +290,9 @@ impl QueryService for Server {
             .ok_or_else(|| Status::invalid_argument("missing trading_pair"))?
             .try_into()
             .map_err(|_| Status::invalid_argument("invalid trading_pair"))?;
+        let interval = CandlestickInterval::try_from(request.get_ref().interval)
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+        check_candlestick_interval(&self.storage.latest_snapshot(), interval).await?;
 
         let (tx_candle, rx_candle) = mpsc::channel::<CandlestickData>(1);
         let storage = self.storage.clone();
@@ -268,7 +307,21 @@ impl QueryService for Server {
                     .expect("channel should be open");
                 let snapshot = rx_state_snapshot.borrow().clone();
                 let height = snapshot.version();
-                match snapshot.get_candlestick(&pair, height).await? {
+                // Rolled-up candles only change when the block had executions for the pair,
+                // in which case we send the in-progress candle of the current interval.
+                let candle = match snapshot.get_candlestick(&pair, height).await? {
+                    Some(candle) if interval == CandlestickInterval::Block => Some(candle),
+                    Some(_) => {
+                        let bucket = snapshot
+                            .candlestick_bucket_at_height(interval, height)
+                            .await?;
+                        snapshot
+                            .get_candlestick_rollup(&pair, interval, bucket)
+                            .await?
+                    }
+                    None => None,
+                };
+                match candle {
                     Some(candle) => tx_candle.send(candle).await?,
                     None => {
                         // If there's no candlestick data, might as well check that
@@ -377,63 +430,7 @@ impl QueryService for Server {
                 tonic::Status::invalid_argument(format!("error parsing trading pair: {:#}", e))
             })?;
 
-        let pair12 = DirectedTradingPair {
-            start: pair.asset_1(),
-            end: pair.asset_2(),
-        };
-        let pair21 = DirectedTradingPair {
-            start: pair.asset_2(),
-            end: pair.asset_1(),
-        };
-        let best_1_to_2_position = state
-            .best_position(&pair12)
-            .await
-            .map_err(|e| {
-                tonic::Status::internal(format!(
-                    "error finding best position for {:?}: {:#}",
-                    pair12, e
-                ))
-            })?
-            .map(|(_, p)| p);
-        let best_2_to_1_position = state
-            .best_position(&pair12)
-            .await
-            .map_err(|e| {
-                tonic::Status::internal(format!(
-                    "error finding best position for {:?}: {:#}",
-                    pair21, e
-                ))
-            })?
-            .map(|(_, p)| p);
-
-        let approx_effective_price_1_to_2 = best_1_to_2_position
-            .as_ref()
-            .map(|p| {
-                p.phi
-                    .orient_start(pair.asset_1())
-                    .expect("position has one end = asset 1")
-                    .effective_price()
-                    .into()
-            })
-            .unwrap_or_default();
-
-        let approx_effective_price_2_to_1 = best_2_to_1_position
-            .as_ref()
-            .map(|p| {
-                p.phi
-                    .orient_start(pair.asset_2())
-                    .expect("position has one end = asset 2")
-                    .effective_price()
-                    .into()
-            })
-            .unwrap_or_default();
-
-        Ok(tonic::Response::new(SpreadResponse {
-            best_1_to_2_position: best_1_to_2_position.map(Into::into),
-            best_2_to_1_position: best_2_to_1_position.map(Into::into),
-            approx_effective_price_1_to_2,
-            approx_effective_price_2_to_1,
-        }))
+        Ok(tonic::Response::new(spread(&state, pair).await?))
     }
 
     #[instrument(skip(self, request))]
@@ -475,6 +472,171 @@ impl QueryService for Server {
         Ok(tonic::Response::new(s.boxed()))
     }
 
+    #[instrument(skip(self, request))]
+    async fn order_book_depth(
+        &self,
+        request: tonic::Request<OrderBookDepthRequest>,
+    ) -> Result<tonic::Response<OrderBookDepthResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| tonic::Status::invalid_argument("missing directed trading pair"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "error parsing directed trading pair: {:#}",
+                    e
+                ))
+            })?;
+        let levels = depth_levels(request.levels);
+        // Check the tick size up front, so that a bad one is reported as such.
+        DepthAggregator::new(Side::Ask, request.tick_size, levels, MAX_DEPTH_POSITIONS)
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        let depth = order_book_depth(&state, pair, request.tick_size, levels)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error aggregating depth: {:#}", e)))?;
+
+        Ok(tonic::Response::new(OrderBookDepthResponse {
+            depth: Some(depth.into()),
+        }))
+    }
+
+    async fn order_book_depth_stream(
+        &self,
+        request: tonic::Request<OrderBookDepthStreamRequest>,
+    ) -> Result<tonic::Response<Self::OrderBookDepthStreamStream>, Status> {
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| tonic::Status::invalid_argument("missing directed trading pair"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "error parsing directed trading pair: {:#}",
+                    e
+                ))
+            })?;
+        let tick_size = request.tick_size;
+        let levels = depth_levels(request.levels);
+        DepthAggregator::new(Side::Ask, tick_size, levels, MAX_DEPTH_POSITIONS)
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        let (tx_depth, rx_depth) = mpsc::channel::<OrderBookDepth>(1);
+        let storage = self.storage.clone();
+        tokio::spawn(async move {
+            let mut rx_state_snapshot = storage.subscribe();
+            loop {
+                let snapshot = rx_state_snapshot.borrow_and_update().clone();
+                let depth = order_book_depth(&snapshot, pair, tick_size, levels).await?;
+                if tx_depth.send(depth).await.is_err() {
+                    // The client has disconnected.
+                    break;
+                }
+                rx_state_snapshot
+                    .changed()
+                    .await
+                    .expect("channel should be open");
+            }
+            Ok::<_, anyhow::Error>(())
+        });
+
+        Ok(tonic::Response::new(
+            tokio_stream::wrappers::ReceiverStream::new(rx_depth)
+                .map(|depth| {
+                    Ok(OrderBookDepthStreamResponse {
+                        depth: Some(depth.into()),
+                    })
+                })
+                .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn time_weighted_average_price(
+        &self,
+        request: tonic::Request<TimeWeightedAveragePriceRequest>,
+    ) -> Result<tonic::Response<TimeWeightedAveragePriceResponse>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let pair: DirectedTradingPair = request
+            .trading_pair
+            .ok_or_else(|| tonic::Status::invalid_argument("missing directed trading pair"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!(
+                    "error parsing directed trading pair: {:#}",
+                    e
+                ))
+            })?;
+
+        let current_height = state
+            .get_block_height()
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error getting block height: {:#}", e)))?;
+        let end_height = match request.end_height {
+            0 => current_height,
+            end_height if end_height > current_height => {
+                return Err(tonic::Status::invalid_argument(format!(
+                    "end height {end_height} is after the current height {current_height}"
+                )))
+            }
+            end_height => end_height,
+        };
+        if request.start_height >= end_height {
+            return Err(tonic::Status::invalid_argument(
+                "start height must be before the end height",
+            ));
+        }
+
+        let price = state
+            .time_weighted_average_price(pair, request.start_height, end_height)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error computing twap: {:#}", e)))?
+            .ok_or_else(|| {
+                tonic::Status::not_found("trading pair had not traded by the start height")
+            })?;
+
+        Ok(tonic::Response::new(TimeWeightedAveragePriceResponse {
+            price: price.into(),
+            end_height,
+        }))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn liquidity_position_activity(
+        &self,
+        request: tonic::Request<LiquidityPositionActivityRequest>,
+    ) -> Result<tonic::Response<Self::LiquidityPositionActivityStream>, Status> {
+        let state = self.storage.latest_snapshot();
+        let request = request.into_inner();
+
+        let id: position::Id = request
+            .position_id
+            .ok_or_else(|| tonic::Status::invalid_argument("missing position id"))?
+            .try_into()
+            .map_err(|e| {
+                tonic::Status::invalid_argument(format!("error parsing position id: {:#}", e))
+            })?;
+
+        let s = state
+            .position_activity(&id, request.start_height)
+            .map_err(|e| {
+                tonic::Status::internal(format!("error retrieving position activity: {:#}", e))
+            })?
+            .map_ok(|activity| LiquidityPositionActivityResponse {
+                activity: Some(activity.into()),
+            })
+            .map_err(|e: anyhow::Error| {
+                tonic::Status::internal(format!("error retrieving position activity: {:#}", e))
+            });
+        Ok(tonic::Response::new(s.boxed()))
+    }
+
     #[instrument(skip(self, request))]
     async fn liquidity_positions(
         &self,
@@ -645,6 +807,10 @@ impl SimulationService for Server {
             .expect("dex parameters are set")
             .max_execution_budget;
 
+        let mid_price = mid_price(&state, input.asset_id, output_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("error finding mid price: {:#}", e)))?;
+
         let mut state_tx = Arc::new(StateDelta::new(state));
         let execution_circuit_breaker = ExecutionCircuitBreaker::new(execution_budget);
 
@@ -686,9 +852,52 @@ impl SimulationService for Server {
             asset_id: input.asset_id,
         };
 
+        // Dry-run the spread the trade would leave behind.
+        let spread_after = spread(&state_tx, TradingPair::new(input.asset_id, output_id)).await?;
+
+        // Recover the positions the trade executed against from the events it emitted.
+        let (state, mut cache) = Arc::try_unwrap(state_tx)
+            .map_err(|_| tonic::Status::internal("simulation state is still shared"))?
+            .flatten();
+        let events = cache
+            .take_events()
+            .iter()
+            .filter_map(|event| EventPositionExecution::try_from_event(event).ok())
+            .collect::<Vec<_>>();
+        let mut fees = BTreeMap::new();
+        for event in &events {
+            let position = state
+                .position_by_id(&event.position_id)
+                .await
+                .map_err(|e| tonic::Status::internal(format!("error fetching position: {:#}", e)))?
+                .ok_or_else(|| tonic::Status::internal("executed position not found"))?;
+            fees.insert(event.position_id, position.phi.component.fee);
+        }
+        let position_executions =
+            SimulatedPositionExecution::from_events(events, |id| fees.get(id).copied()).map_err(
+                |e| tonic::Status::internal(format!("error summarizing executions: {:#}", e)),
+            )?;
+        let lp_fees = SimulatedPositionExecution::total_fees(&position_executions);
+
+        // If nothing was filled there is no price to report, and without a two-sided market
+        // there is no mid price to measure the impact against.
+        let effective_price =
+            U128x128::ratio(swap_execution.input.amount, swap_execution.output.amount)
+                .ok()
+                .map(f64::from);
+        let price_impact_bps = effective_price
+            .zip(mid_price)
+            .map(|(effective_price, mid_price)| price_impact_bps(effective_price, mid_price));
+
         let rsp = tonic::Response::new(SimulateTradeResponse {
             unfilled: Some(unfilled.into()),
             output: Some(swap_execution.into()),
+            position_executions: position_executions.into_iter().map(Into::into).collect(),
+            effective_price,
+            mid_price,
+            price_impact_bps,
+            lp_fees: lp_fees.into_iter().map(Into::into).collect(),
+            spread_after: Some(spread_after),
         });
 
         let duration = start_time.elapsed();
@@ -698,3 +907,183 @@ impl SimulationService for Server {
         Ok(rsp)
     }
 }
+
+/// The best positions for trading across `pair` in either direction, along
+/// with their approximate effective prices.
+async fn spread<S: StateRead>(state: &S, pair: TradingPair) -> Result<SpreadResponse, Status> {
+    let pair12 = DirectedTradingPair {
+        start: pair.asset_1(),
+        end: pair.asset_2(),
+    };
+    let pair21 = DirectedTradingPair {
+        start: pair.asset_2(),
+        end: pair.asset_1(),
+    };
+    let best_1_to_2_position = state
+        .best_position(&pair12)
+        .await
+        .map_err(|e| {
+            tonic::Status::internal(format!(
+                "error finding best position for {:?}: {:#}",
+                pair12, e
+            ))
+        })?
+        .map(|(_, p)| p);
+    let best_2_to_1_position = state
+        .best_position(&pair21)
+        .await
+        .map_err(|e| {
+            tonic::Status::internal(format!(
+                "error finding best position for {:?}: {:#}",
+                pair21, e
+            ))
+        })?
+        .map(|(_, p)| p);
+
+    let approx_effective_price_1_to_2 = best_1_to_2_position
+        .as_ref()
+        .map(|p| {
+            p.phi
+                .orient_start(pair.asset_1())
+                .expect("position has one end = asset 1")
+                .effective_price()
+                .into()
+        })
+        .unwrap_or_default();
+
+    let approx_effective_price_2_to_1 = best_2_to_1_position
+        .as_ref()
+        .map(|p| {
+            p.phi
+                .orient_start(pair.asset_2())
+                .expect("position has one end = asset 2")
+                .effective_price()
+                .into()
+        })
+        .unwrap_or_default();
+
+    Ok(SpreadResponse {
+        best_1_to_2_position: best_1_to_2_position.map(Into::into),
+        best_2_to_1_position: best_2_to_1_position.map(Into::into),
+        approx_effective_price_1_to_2,
+        approx_effective_price_2_to_1,
+    })
+}
+
+/// The midpoint between the best prices to buy and to sell `end` for `start`,
+/// in units of `start` per unit of `end`, if there is liquidity on both sides.
+async fn mid_price<S: StateRead>(
+    state: &S,
+    start: asset::Id,
+    end: asset::Id,
+) -> Result<Option<f64>> {
+    let pair = DirectedTradingPair::new(start, end);
+    let Some((_, ask)) = state.best_position(&pair).await? else {
+        return Ok(None);
+    };
+    let Some((_, bid)) = state.best_position(&pair.flip()).await? else {
+        return Ok(None);
+    };
+
+    let ask = f64::from(
+        ask.phi
+            .orient_start(start)
+            .expect("position has one end = start")
+            .effective_price(),
+    );
+    // The bid is quoted in units of `end` per unit of `start`, so we invert it.
+    let bid = f64::from(
+        bid.phi
+            .orient_start(end)
+            .expect("position has one end = end")
+            .effective_price(),
+    );
+    if bid == 0.0 {
+        return Ok(None);
+    }
+
+    Ok(Some((ask + 1.0 / bid) / 2.0))
+}
+
+/// Check that candlesticks are rolled up over `interval`, so that requests for it can be served.
+async fn check_candlestick_interval<S: StateRead>(
+    state: &S,
+    interval: CandlestickInterval,
+) -> Result<(), Status> {
+    if interval == CandlestickInterval::Block {
+        return Ok(());
+    }
+    let params = state
+        .get_dex_params()
+        .await
+        .map_err(|e| Status::internal(e.to_string()))?;
+    if !params.candlestick_intervals.contains(&interval) {
+        return Err(Status::failed_precondition(format!(
+            "candlesticks are not rolled up over the {} interval",
+            interval.as_str()
+        )));
+    }
+    Ok(())
+}
+
+fn depth_levels(requested: u32) -> usize {
+    match requested {
+        0 => DEFAULT_DEPTH_LEVELS,
+        n => std::cmp::min(n as usize, MAX_DEPTH_LEVELS),
+    }
+}
+
+/// Walk the price index outwards from the spread on both sides of `pair`,
+/// aggregating positions into levels of `tick_size`.
+async fn order_book_depth<S: StateRead>(
+    state: &S,
+    pair: DirectedTradingPair,
+    tick_size: f64,
+    levels: usize,
+) -> Result<OrderBookDepth> {
+    // Asks sell the end asset for the start asset, so they're indexed for trades
+    // from start to end, and their effective price is already in start per end.
+    let mut asks = DepthAggregator::new(Side::Ask, tick_size, levels, MAX_DEPTH_POSITIONS)?;
+    let mut positions = state.positions_by_price(&pair);
+    while let Some((_, position)) = positions.try_next().await? {
+        let phi = position
+            .phi
+            .orient_start(pair.start)
+            .expect("position in the price index is on the pair");
+        let amount = position
+            .reserves_for(pair.end)
+            .expect("position in the price index is on the pair");
+        if !asks.push(phi.effective_price().into(), amount) {
+            break;
+        }
+    }
+
+    // Bids sell the start asset for the end asset: their effective price is in
+    // end per start, so we invert it, and measure them by how much of the end
+    // asset their reserves would buy.
+    let mut bids = DepthAggregator::new(Side::Bid, tick_size, levels, MAX_DEPTH_POSITIONS)?;
+    let mut positions = state.positions_by_price(&pair.flip());
+    while let Some((_, position)) = positions.try_next().await? {
+        let phi = position
+            .phi
+            .orient_start(pair.end)
+            .expect("position in the price index is on the pair");
+        let reserves = position
+            .reserves_for(pair.start)
+            .expect("position in the price index is on the pair");
+        let price: f64 = phi.effective_price().into();
+        let amount = phi
+            .convert_to_delta_1(reserves.into())?
+            .round_down()
+            .try_into()?;
+        if !bids.push(price.recip(), amount) {
+            break;
+        }
+    }
+
+    Ok(OrderBookDepth {
+        height: state.get_block_height().await?,
+        bids: bids.finish(),
+        asks: asks.finish(),
+    })
+}
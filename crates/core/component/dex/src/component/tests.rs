@@ -16,10 +16,11 @@ use crate::{
     component::{
         router::FillRoute,
         router::{create_buy, create_sell, HandleBatchSwaps, RoutingParams},
-        Arbitrage, PositionManager, PositionRead, StateReadExt, StateWriteExt,
+        Arbitrage, InternalDexWrite as _, PositionManager, PositionRead, PriceHistoryRead as _,
+        StateReadExt, StateWriteExt,
     },
     lp::{position::Position, Reserves},
-    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair,
+    BatchSwapOutputData, DirectedTradingPair, DirectedUnitPair, TradingPair,
 };
 
 #[async_trait]
@@ -34,6 +35,7 @@ impl TempStorageExt for TempStorage {
         let mut state = StateDelta::new(self.latest_snapshot());
 
         state.put_block_height(0);
+        state.put_block_timestamp(0, tendermint::Time::unix_epoch());
         state.put_epoch_by_height(
             0,
             penumbra_sdk_sct::epoch::Epoch {
@@ -259,6 +261,82 @@ async fn single_close_on_fill() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Checks that executions against a position and withdrawals from it are
+/// folded into its per-block activity, which is pruned once it is old enough.
+async fn position_activity_is_tracked() -> anyhow::Result<()> {
+    use crate::component::position_manager::activity_tracker::{
+        PositionActivityTracker as _, POSITION_ACTIVITY_RETENTION_BLOCKS,
+    };
+    use crate::component::ValueCircuitBreaker as _;
+    use futures::TryStreamExt as _;
+    use penumbra_sdk_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_block_height(5);
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let position = SellOrder::parse_str("100gm@1gn/100bps")?.into_position(OsRng);
+    let id = position.id();
+    state.open_position(position.clone()).await?;
+
+    // Two fills in the same block are folded together. The inputs are
+    // credited to the VCB as batch swaps would be, so that the position's
+    // reserves can be withdrawn afterwards.
+    let route = [gm.id()];
+    for input in ["30gn", "20gn"] {
+        let input = input.parse::<Value>().unwrap();
+        state.dex_vcb_credit(input).await?;
+        FillRoute::fill_route(&mut state, input, &route, None).await?;
+    }
+    let executed = state.position_by_id(&id).await?.unwrap();
+
+    state.put_block_height(6);
+    state.close_position_by_id(&id).await?;
+    state.withdraw_position(id, 0).await?;
+    storage.commit(state).await?;
+
+    let activity: Vec<_> = storage
+        .latest_snapshot()
+        .position_activity(&id, 0)?
+        .try_collect()
+        .await?;
+    assert_eq!(activity.len(), 2);
+
+    assert_eq!(activity[0].height, 5);
+    assert_eq!(activity[0].executions, 2);
+    assert_eq!(activity[0].prev_reserves, position.reserves);
+    assert_eq!(activity[0].reserves, executed.reserves);
+    // The position keeps 1% of the 50gn it received.
+    assert_eq!(
+        activity[0].fees.r1 + activity[0].fees.r2,
+        "0.5gn".parse::<Value>().unwrap().amount
+    );
+
+    assert_eq!(activity[1].height, 6);
+    assert_eq!(activity[1].executions, 0);
+    assert_eq!(activity[1].withdrawn, executed.reserves);
+    assert_eq!(activity[1].reserves, Reserves::zero());
+
+    // Once the first block falls out of the retention window, only its
+    // activity is pruned.
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_block_height(5 + POSITION_ACTIVITY_RETENTION_BLOCKS);
+    state.prune_position_activity().await?;
+    storage.commit(state).await?;
+
+    let activity: Vec<_> = storage
+        .latest_snapshot()
+        .position_activity(&id, 0)?
+        .try_collect()
+        .await?;
+    assert_eq!(activity.len(), 1);
+    assert_eq!(activity[0].height, 6);
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Builds a simple order book with a two orders, fills against them both,
 /// and checks that one of the orders is auto-closed.
@@ -312,6 +390,66 @@ async fn check_close_on_fill() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+/// Checks that positions are closed once they reach their expiry height, and
+/// that positions can't be opened already expired.
+async fn check_expires_at_height() -> anyhow::Result<()> {
+    use crate::component::position_manager::expiry_index::PositionByExpiryRead as _;
+    use penumbra_sdk_sct::component::clock::EpochManager as _;
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state.put_block_height(5);
+
+    let mut expiring = SellOrder::parse_str("100gm@1gn")?.into_position(OsRng);
+    expiring.expires_at_height = Some(6);
+    let mut later = SellOrder::parse_str("100gm@1.1gn")?.into_position(OsRng);
+    later.expires_at_height = Some(7);
+    let mut stale = SellOrder::parse_str("100gm@1.2gn")?.into_position(OsRng);
+    stale.expires_at_height = Some(4);
+    let forever = SellOrder::parse_str("100gm@1.3gn")?.into_position(OsRng);
+
+    state.open_position(expiring.clone()).await?;
+    state.open_position(later.clone()).await?;
+    state.open_position(forever.clone()).await?;
+    assert!(
+        state.open_position(stale).await.is_err(),
+        "positions can't be opened past their expiry"
+    );
+
+    // Nothing expires before its expiry height.
+    state.close_expired_positions().await?;
+    assert_eq!(
+        state.position_by_id(&expiring.id()).await?.unwrap().state,
+        position::State::Opened
+    );
+
+    state.put_block_height(6);
+    state.close_expired_positions().await?;
+    assert_eq!(
+        state.position_by_id(&expiring.id()).await?.unwrap().state,
+        position::State::Closed
+    );
+    assert_eq!(
+        state.position_by_id(&later.id()).await?.unwrap().state,
+        position::State::Opened
+    );
+
+    // A position closed early is dropped from the expiry index.
+    assert_eq!(state.positions_expiring_by(7).await?, vec![later.id()]);
+    state.close_position_by_id(&later.id()).await?;
+    assert!(state.positions_expiring_by(7).await?.is_empty());
+
+    state.put_block_height(7);
+    state.close_expired_positions().await?;
+    assert_eq!(
+        state.position_by_id(&forever.id()).await?.unwrap().state,
+        position::State::Opened
+    );
+
+    Ok(())
+}
+
 #[tokio::test]
 /// Try to execute against multiple positions, mainly testing that the order-book traversal
 /// is done correctly.
@@ -846,6 +984,8 @@ async fn basic_cycle_arb() -> anyhow::Result<()> {
         max_hops: 4 + 2,
         price_limit: Some(1u64.into()),
         fixed_candidates: Arc::new(vec![penumbra.id(), gm.id(), gn.id()]),
+        max_split_paths: 0,
+        composed_path_prices: false,
     };
     state.arbitrage(penumbra.id(), routing_params).await?;
 
@@ -945,6 +1085,8 @@ async fn reproduce_arbitrage_loop_testnet_53() -> anyhow::Result<()> {
         max_hops: 4 + 2,
         price_limit: Some(1u64.into()),
         fixed_candidates: Arc::new(vec![penumbra.id(), test_usd.id()]),
+        max_split_paths: 0,
+        composed_path_prices: false,
     };
 
     let arb_profit = tokio::time::timeout(
@@ -1145,3 +1287,146 @@ async fn check_routable_asset_ordering() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+/// Test that batch clearing prices accumulate into a time-weighted average price.
+async fn clearing_prices_accumulate_into_twap() -> anyhow::Result<()> {
+    let _ = tracing_subscriber::fmt::try_init();
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+    let gn = asset::Cache::with_known_assets().get_unit("gn").unwrap();
+    let trading_pair = TradingPair::new(gm.id(), gn.id());
+    let forward = DirectedTradingPair::new(trading_pair.asset_1, trading_pair.asset_2);
+
+    // Trade 10 of asset 1 for 20 of asset 2 at height 1, then 10 for 50 at height 3.
+    let mut checkpoint = None;
+    for (height, lambda_2) in [(1u64, 20u32), (3, 50)] {
+        let mut state = StateDelta::new(storage.latest_snapshot());
+        state
+            .record_clearing_prices(&BatchSwapOutputData {
+                delta_1: 10u32.into(),
+                delta_2: 0u32.into(),
+                lambda_1: 0u32.into(),
+                lambda_2: lambda_2.into(),
+                unfilled_1: 0u32.into(),
+                unfilled_2: 0u32.into(),
+                height,
+                trading_pair,
+                sct_position_prefix: Default::default(),
+            })
+            .await?;
+        storage.commit(state).await?;
+        // A consumer in consensus saves the accumulator at the start of its window.
+        if checkpoint.is_none() {
+            checkpoint = storage
+                .latest_snapshot()
+                .price_accumulator(trading_pair)
+                .await?;
+        }
+    }
+
+    let state = storage.latest_snapshot();
+
+    // Blocks 2 and 3 had a price of 2 in effect, and blocks 4 and 5 a price of 5.
+    let checkpoint = checkpoint.expect("pair traded at height 1");
+    let twap = state
+        .time_weighted_average_price_since(forward, &checkpoint, 1, 5)
+        .await?
+        .expect("pair has traded");
+    assert_eq!(f64::from(twap), 3.5);
+
+    // The same average is served from the nonverifiable history.
+    let twap = state
+        .time_weighted_average_price(forward, 1, 5)
+        .await?
+        .expect("pair traded before the start height");
+    assert_eq!(f64::from(twap), 3.5);
+
+    // Past accumulators are looked up from history.
+    let twap = state
+        .time_weighted_average_price(forward, 2, 3)
+        .await?
+        .expect("pair traded before the start height");
+    assert_eq!(f64::from(twap), 2.0);
+
+    let twap = state
+        .time_weighted_average_price(forward.flip(), 1, 3)
+        .await?
+        .expect("pair traded before the start height");
+    assert_eq!(f64::from(twap), 0.5);
+
+    // There's no price before the pair first traded.
+    assert!(state
+        .time_weighted_average_price(forward, 0, 5)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that simulating a trade that fills nothing reports no prices, rather
+/// than prices of zero.
+async fn simulate_trade_without_fills_has_no_prices() -> anyhow::Result<()> {
+    use crate::component::rpc::Server;
+    use penumbra_sdk_proto::core::component::dex::v1::{
+        simulation_service_server::SimulationService as _, SimulateTradeRequest,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+
+    let simulation = Server::new(storage.as_ref().clone())
+        .simulate_trade(tonic::Request::new(SimulateTradeRequest {
+            input: Some("10gn".parse::<Value>().unwrap().into()),
+            output: Some(gm.id().into()),
+            routing: None,
+        }))
+        .await?
+        .into_inner();
+
+    assert_eq!(simulation.effective_price, None);
+    assert_eq!(simulation.mid_price, None);
+    assert_eq!(simulation.price_impact_bps, None);
+
+    Ok(())
+}
+
+#[tokio::test]
+/// Checks that simulating a trade against a one-sided book reports its
+/// effective price, but no mid price or price impact.
+async fn simulate_trade_against_one_sided_book_has_no_mid_price() -> anyhow::Result<()> {
+    use crate::component::rpc::Server;
+    use penumbra_sdk_proto::core::component::dex::v1::{
+        simulation_service_server::SimulationService as _, SimulateTradeRequest,
+    };
+
+    let storage = TempStorage::new().await?.apply_minimal_genesis().await?;
+    let gm = asset::Cache::with_known_assets().get_unit("gm").unwrap();
+
+    // Only sell gm for gn, so there is no bid to take a mid price from.
+    let mut state = StateDelta::new(storage.latest_snapshot());
+    state
+        .open_position(SellOrder::parse_str("100gm@1gn")?.into_position(OsRng))
+        .await?;
+    storage.commit(state).await?;
+
+    let simulation = Server::new(storage.as_ref().clone())
+        .simulate_trade(tonic::Request::new(SimulateTradeRequest {
+            input: Some("10gn".parse::<Value>().unwrap().into()),
+            output: Some(gm.id().into()),
+            routing: None,
+        }))
+        .await?
+        .into_inner();
+
+    let effective_price = simulation
+        .effective_price
+        .expect("a filled trade has an effective price");
+    assert!((effective_price - 1.0).abs() < 1e-6);
+    assert_eq!(simulation.mid_price, None);
+    assert_eq!(simulation.price_impact_bps, None);
+
+    Ok(())
+}
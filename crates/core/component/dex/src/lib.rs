@@ -10,16 +10,22 @@ pub mod state_key;
 mod batch_swap_output_data;
 mod candlestick;
 mod params;
+mod simulation;
 mod swap_execution;
 mod trading_pair;
+mod twap;
 
 pub use batch_swap_output_data::BatchSwapOutputData;
-pub use candlestick::CandlestickData;
+pub use candlestick::{CandlestickData, CandlestickInterval};
+pub use order_book::{DepthLevel, OrderBookDepth};
 pub use params::DexParameters;
+pub use simulation::{price_impact_bps, SimulatedPositionExecution};
 pub use swap_execution::SwapExecution;
 pub use trading_pair::{DirectedTradingPair, DirectedUnitPair, TradingPair, TradingPairVar};
+pub use twap::PriceAccumulator;
 
 pub mod lp;
+pub mod order_book;
 pub mod swap;
 pub mod swap_claim;
 
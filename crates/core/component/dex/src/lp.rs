@@ -1,3 +1,4 @@
+mod activity;
 mod nft;
 mod order;
 mod reserves;
@@ -9,6 +10,7 @@ pub mod plan;
 pub mod position;
 pub mod view;
 
+pub use activity::PositionActivity;
 pub use metadata::PositionMetadata;
 pub use nft::LpNft;
 pub use order::{BuyOrder, SellOrder};
@@ -0,0 +1,185 @@
+use anyhow::{anyhow, Result};
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_proto::{core::component::dex::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use super::{
+    position::{self, Position},
+    Reserves,
+};
+
+/// The changes to a liquidity position's reserves during a single block.
+///
+/// Every execution against a position, reward credited to it and withdrawal
+/// from it during a block is folded into a single record, so that clients can
+/// account for what a position earned without replaying execution events.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionActivity", into = "pb::PositionActivity")]
+pub struct PositionActivity {
+    pub position_id: position::Id,
+    pub height: u64,
+    /// The reserves of the position before its first change in the block.
+    pub prev_reserves: Reserves,
+    /// The reserves of the position after its last change in the block.
+    pub reserves: Reserves,
+    /// The number of executions against the position in the block.
+    pub executions: u64,
+    /// The fees earned by those executions, which are included in `reserves`.
+    pub fees: Reserves,
+    /// The rewards credited to the position in the block.
+    pub rewards: Reserves,
+    /// The reserves withdrawn from the position in the block.
+    pub withdrawn: Reserves,
+}
+
+impl PositionActivity {
+    /// Starts recording the activity of a position at `height`, given its
+    /// reserves going into the block.
+    pub fn new(position_id: position::Id, height: u64, prev_reserves: Reserves) -> Self {
+        Self {
+            position_id,
+            height,
+            reserves: prev_reserves.clone(),
+            prev_reserves,
+            executions: 0,
+            fees: Reserves::zero(),
+            rewards: Reserves::zero(),
+            withdrawn: Reserves::zero(),
+        }
+    }
+
+    /// Records an execution that took the position from `prev_state` to `new_state`.
+    pub fn record_execution(&mut self, prev_state: &Position, new_state: &Position) {
+        let flows = new_state.flows(prev_state);
+        self.executions += 1;
+        self.fees.r1 += flows.fee_1();
+        self.fees.r2 += flows.fee_2();
+        self.reserves = new_state.reserves.clone();
+    }
+
+    /// Records a reward that took the position from `prev_state` to `new_state`.
+    pub fn record_reward(&mut self, prev_state: &Position, new_state: &Position) {
+        let increase = |new: Amount, prev: Amount| new.checked_sub(&prev).unwrap_or_default();
+        self.rewards.r1 += increase(new_state.reserves.r1, prev_state.reserves.r1);
+        self.rewards.r2 += increase(new_state.reserves.r2, prev_state.reserves.r2);
+        self.reserves = new_state.reserves.clone();
+    }
+
+    /// Records the withdrawal of all of the position's reserves.
+    pub fn record_withdrawal(&mut self, withdrawn: &Reserves) {
+        self.withdrawn.r1 += withdrawn.r1;
+        self.withdrawn.r2 += withdrawn.r2;
+        self.reserves = Reserves::zero();
+    }
+}
+
+impl DomainType for PositionActivity {
+    type Proto = pb::PositionActivity;
+}
+
+impl From<PositionActivity> for pb::PositionActivity {
+    fn from(activity: PositionActivity) -> Self {
+        Self {
+            position_id: Some(activity.position_id.into()),
+            height: activity.height,
+            prev_reserves: Some(activity.prev_reserves.into()),
+            reserves: Some(activity.reserves.into()),
+            executions: activity.executions,
+            fees: Some(activity.fees.into()),
+            rewards: Some(activity.rewards.into()),
+            withdrawn: Some(activity.withdrawn.into()),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionActivity> for PositionActivity {
+    type Error = anyhow::Error;
+    fn try_from(activity: pb::PositionActivity) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: activity
+                .position_id
+                .ok_or_else(|| anyhow!("missing position id"))?
+                .try_into()?,
+            height: activity.height,
+            prev_reserves: activity
+                .prev_reserves
+                .ok_or_else(|| anyhow!("missing prev reserves"))?
+                .try_into()?,
+            reserves: activity
+                .reserves
+                .ok_or_else(|| anyhow!("missing reserves"))?
+                .try_into()?,
+            executions: activity.executions,
+            fees: activity
+                .fees
+                .ok_or_else(|| anyhow!("missing fees"))?
+                .try_into()?,
+            rewards: activity
+                .rewards
+                .ok_or_else(|| anyhow!("missing rewards"))?
+                .try_into()?,
+            withdrawn: activity
+                .withdrawn
+                .ok_or_else(|| anyhow!("missing withdrawn reserves"))?
+                .try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_sdk_asset::asset;
+    use rand_core::OsRng;
+
+    use super::*;
+    use crate::DirectedTradingPair;
+
+    #[test]
+    fn folds_block_activity() {
+        let cache = asset::Cache::with_known_assets();
+        let gm = cache.get_unit("gm").expect("gm is a known asset").id();
+        let gn = cache.get_unit("gn").expect("gn is a known asset").id();
+        let pair = DirectedTradingPair::new(gm, gn);
+
+        let mut opened =
+            Position::new(OsRng, pair, 100, 1u64.into(), 1u64.into(), Reserves::zero());
+        opened.reserves = Reserves {
+            r1: 0u64.into(),
+            r2: 1_000u64.into(),
+        };
+        let id = opened.id();
+        let mut activity = PositionActivity::new(id, 7, opened.reserves.clone());
+
+        // Two executions: 500 of asset 1 in, then 300 more.
+        let mut executed = opened.clone();
+        executed.reserves = Reserves {
+            r1: 500u64.into(),
+            r2: 505u64.into(),
+        };
+        activity.record_execution(&opened, &executed);
+        let mut executed_again = executed.clone();
+        executed_again.reserves = Reserves {
+            r1: 800u64.into(),
+            r2: 208u64.into(),
+        };
+        activity.record_execution(&executed, &executed_again);
+
+        let mut rewarded = executed_again.clone();
+        rewarded.reserves.r2 = 258u64.into();
+        activity.record_reward(&executed_again, &rewarded);
+
+        activity.record_withdrawal(&rewarded.reserves);
+
+        assert_eq!(activity.executions, 2);
+        assert_eq!(activity.prev_reserves, opened.reserves);
+        assert_eq!(activity.fees.r1, 8u64.into());
+        assert_eq!(activity.fees.r2, 0u64.into());
+        assert_eq!(activity.rewards.r2, 50u64.into());
+        assert_eq!(activity.withdrawn, rewarded.reserves);
+        assert_eq!(activity.reserves, Reserves::zero());
+
+        let roundtrip = PositionActivity::decode(activity.encode_to_vec().as_slice())
+            .expect("activity roundtrips");
+        assert_eq!(roundtrip, activity);
+    }
+}
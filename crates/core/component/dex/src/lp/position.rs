@@ -35,6 +35,8 @@ pub struct Position {
     /// Set to `true` if a position is a limit-order, meaning that it will be closed after being
     /// filled against.
     pub close_on_fill: bool,
+    /// If set, the position is closed at the end of the block at this height.
+    pub expires_at_height: Option<u64>,
 }
 
 impl std::fmt::Debug for Position {
@@ -96,6 +98,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            expires_at_height: None,
         }
     }
 
@@ -138,6 +141,7 @@ impl Position {
             state: State::Opened,
             reserves,
             close_on_fill: false,
+            expires_at_height: None,
         }
     }
 
@@ -506,6 +510,7 @@ impl From<Position> for pb::Position {
             phi: Some(p.phi.into()),
             nonce: p.nonce.to_vec(),
             close_on_fill: p.close_on_fill,
+            expires_at_height: p.expires_at_height.unwrap_or_default(),
         }
     }
 }
@@ -532,6 +537,10 @@ impl TryFrom<pb::Position> for Position {
                 .try_into()
                 .context("expected 32-byte nonce")?,
             close_on_fill: p.close_on_fill,
+            expires_at_height: match p.expires_at_height {
+                0 => None,
+                height => Some(height),
+            },
         })
     }
 }
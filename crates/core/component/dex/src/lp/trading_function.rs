@@ -1,4 +1,5 @@
 use anyhow::{anyhow, Result};
+use ethnum::U256;
 use penumbra_sdk_asset::{asset, Value};
 use penumbra_sdk_num::{fixpoint::U128x128, Amount};
 use penumbra_sdk_proto::{penumbra::core::component::dex::v1 as pb, DomainType};
@@ -445,11 +446,79 @@ impl BareTradingFunction {
         (U128x128::from(10_000 - self.fee) / U128x128::from(10_000u64)).expect("10_000 != 0")
     }
 
-    /// Compose two trading functions together
-    #[deprecated(note = "this method is not yet implemented")]
-    pub fn compose(&self, _phi: BareTradingFunction) -> BareTradingFunction {
-        unimplemented!()
+    /// Composes this trading function, which trades asset 1 for asset 2, with
+    /// `phi`, which trades asset 2 for some third asset, into a synthetic trading
+    /// function that trades asset 1 directly for the third asset.
+    ///
+    /// Trading through the composition gives the same output as trading through
+    /// `self` and then `phi`, up to the rounding of the intermediate amount. A
+    /// single fee can't express two stacked fees exactly, so the fees are folded
+    /// into the valuations, and the composition has no fee of its own.
+    ///
+    /// The valuations are reduced to lowest terms. If they still don't fit in an
+    /// [`Amount`], they are scaled down together, approximating the composed price.
+    ///
+    /// # Errors
+    /// This method errors if either trading function has a zero valuation or
+    /// charges the whole input as a fee, or if the composed price is too extreme
+    /// to approximate.
+    pub fn compose(&self, phi: &BareTradingFunction) -> Result<BareTradingFunction> {
+        // The composed rate is the product of the fee-adjusted rates:
+        // effective_price_inv = (gamma_1 * p_1 / q_1) * (gamma_2 * p_2 / q_2)
+        let gamma_numerator = |fee: u32| {
+            10_000u128
+                .checked_sub(fee.into())
+                .ok_or_else(|| anyhow!("fee of {fee} bps exceeds 100%"))
+        };
+        let mut numerators = [
+            self.p.value(),
+            phi.p.value(),
+            gamma_numerator(self.fee)?,
+            gamma_numerator(phi.fee)?,
+        ];
+        let mut denominators = [self.q.value(), phi.q.value(), 10_000, 10_000];
+        if numerators.contains(&0) || denominators.contains(&0) {
+            anyhow::bail!("cannot compose trading functions with a zero valuation or gamma");
+        }
+
+        // Cancel common factors before multiplying, to keep the exact product
+        // within range as often as possible.
+        for n in numerators.iter_mut() {
+            for d in denominators.iter_mut() {
+                let g = gcd(*n, *d);
+                *n /= g;
+                *d /= g;
+            }
+        }
+
+        let (mut p, mut q) = (1u128, 1u128);
+        for (n, d) in numerators.into_iter().zip(denominators) {
+            // Multiply in 256 bits, then drop the same number of low bits from
+            // both products so that they fit, preserving their ratio.
+            let wide_p = U256::from(p) * U256::from(n);
+            let wide_q = U256::from(q) * U256::from(d);
+            let shift = 128u32.saturating_sub(wide_p.leading_zeros().min(wide_q.leading_zeros()));
+            (p, q) = ((wide_p >> shift).as_u128(), (wide_q >> shift).as_u128());
+            if p == 0 || q == 0 {
+                anyhow::bail!("composed price is too extreme to approximate");
+            }
+        }
+
+        let g = gcd(p, q);
+        Ok(BareTradingFunction {
+            fee: 0,
+            p: (p / g).into(),
+            q: (q / g).into(),
+        })
+    }
+}
+
+/// Returns the greatest common divisor of `a` and `b`.
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
     }
+    a
 }
 
 impl DomainType for BareTradingFunction {
@@ -489,6 +558,7 @@ mod tests {
     use ark_ff::Zero;
     use decaf377::Fq;
     use penumbra_sdk_asset::asset::Id;
+    use proptest::prelude::*;
 
     use super::*;
 
@@ -712,4 +782,94 @@ mod tests {
         assert_eq!(new_reserves.r1, Amount::zero());
         assert_eq!(new_reserves.r2, Amount::from(50u64));
     }
+
+    #[test]
+    /// Test that composing trading functions multiplies their fee-adjusted prices.
+    fn compose_multiplies_prices() {
+        let phi_1 = BareTradingFunction::new(0, 1u64.into(), 2u64.into());
+        let phi_2 = BareTradingFunction::new(0, 3u64.into(), 1u64.into());
+        assert_eq!(
+            phi_1.compose(&phi_2).unwrap(),
+            BareTradingFunction::new(0, 3u64.into(), 2u64.into())
+        );
+
+        // Fees are folded into the valuations: 0.997 * 0.99 = 0.98703
+        let phi_1 = BareTradingFunction::new(30, 1u64.into(), 1u64.into());
+        let phi_2 = BareTradingFunction::new(100, 1u64.into(), 1u64.into());
+        assert_eq!(
+            phi_1.compose(&phi_2).unwrap(),
+            BareTradingFunction::new(0, 98_703u64.into(), 100_000u64.into())
+        );
+
+        // A valuation of zero can't be composed.
+        let phi_0 = BareTradingFunction::new(0, 0u64.into(), 1u64.into());
+        assert!(phi_0.compose(&phi_1).is_err());
+        assert!(phi_1.compose(&phi_0).is_err());
+    }
+
+    #[test]
+    /// Test that composing trading functions whose exact valuations overflow
+    /// approximates the composed price.
+    fn compose_approximates_large_valuations() {
+        let p = Amount::from(u128::MAX / 3);
+        let phi_1 = BareTradingFunction::new(0, p, 7u64.into());
+        let phi_2 = BareTradingFunction::new(0, 11u64.into(), p - Amount::from(2u64));
+        let composed = phi_1.compose(&phi_2).unwrap();
+        let price: f64 = composed.effective_price().into();
+        assert!((price - 7.0 / 11.0).abs() < 1e-12);
+
+        // Squaring the price can't be approximated, though.
+        assert!(phi_1.compose(&phi_1).is_err());
+    }
+
+    proptest! {
+        #[test]
+        /// Test that filling the composition of two trading functions matches
+        /// filling each of them in sequence, up to the rounding of the intermediate amount.
+        fn compose_matches_sequential_fills(
+            fee_1 in 0u32..5_000,
+            fee_2 in 0u32..5_000,
+            p_1 in 1u64..1_000_000,
+            q_1 in 1u64..1_000_000,
+            p_2 in 1u64..1_000_000,
+            q_2 in 1u64..1_000_000,
+            delta_1 in 0u64..1_000_000_000,
+        ) {
+            let phi_1 = BareTradingFunction::new(fee_1, p_1.into(), q_1.into());
+            let phi_2 = BareTradingFunction::new(fee_2, p_2.into(), q_2.into());
+            let composed = phi_1.compose(&phi_2).unwrap();
+
+            // Make the reserves deep enough that no fill is limited by them.
+            let reserves = Reserves {
+                r1: 0u64.into(),
+                r2: u128::MAX.into(),
+            };
+            let (_, _, lambda_2) = phi_1.fill(delta_1.into(), &reserves).unwrap();
+            let (_, _, sequential_lambda_3) = phi_2.fill(lambda_2, &reserves).unwrap();
+            let (unfilled, _, composed_lambda_3) = composed.fill(delta_1.into(), &reserves).unwrap();
+            prop_assert_eq!(unfilled, Amount::zero());
+
+            // Rounding the intermediate amount down loses at most one unit of
+            // asset 2, worth at most `effective_price_inv` units of asset 3.
+            let slack = f64::from(phi_2.effective_price_inv()).ceil() as u128 + 1;
+            let (sequential, direct) = (sequential_lambda_3.value(), composed_lambda_3.value());
+            prop_assert!(direct + 1 >= sequential);
+            prop_assert!(direct <= sequential + slack);
+        }
+
+        #[test]
+        /// Test that composition is associative, so that the synthetic trading
+        /// function of a path doesn't depend on how its hops are grouped.
+        fn compose_is_associative(
+            fees in prop::array::uniform3(0u32..5_000),
+            valuations in prop::array::uniform6(1u64..1_000_000),
+        ) {
+            let [phi_1, phi_2, phi_3] = [0, 1, 2].map(|i| {
+                BareTradingFunction::new(fees[i], valuations[2 * i].into(), valuations[2 * i + 1].into())
+            });
+            let left = phi_1.compose(&phi_2).unwrap().compose(&phi_3).unwrap();
+            let right = phi_1.compose(&phi_2.compose(&phi_3).unwrap()).unwrap();
+            prop_assert_eq!(left, right);
+        }
+    }
 }
@@ -0,0 +1,291 @@
+use anyhow::{anyhow, Result};
+use penumbra_sdk_num::Amount;
+use serde::{Deserialize, Serialize};
+
+use penumbra_sdk_proto::{core::component::dex::v1 as pb, DomainType};
+
+/// Aggregated liquidity on both sides of a [`DirectedTradingPair`](crate::DirectedTradingPair).
+///
+/// Prices are quoted in units of the pair's `start` asset per unit of its `end`
+/// asset, inclusive of fees, and amounts are denominated in the `end` asset.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "pb::OrderBookDepth", into = "pb::OrderBookDepth")]
+pub struct OrderBookDepth {
+    /// The block height the depth was computed at.
+    pub height: u64,
+    /// Levels of liquidity buying the `end` asset, best (highest) price first.
+    pub bids: Vec<DepthLevel>,
+    /// Levels of liquidity selling the `end` asset, best (lowest) price first.
+    pub asks: Vec<DepthLevel>,
+}
+
+/// The liquidity available at one tick of an [`OrderBookDepth`].
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "pb::DepthLevel", into = "pb::DepthLevel")]
+pub struct DepthLevel {
+    /// The price of this level, a multiple of the requested tick size.
+    pub price: f64,
+    /// The amount of the `end` asset available at this level.
+    pub amount: Amount,
+    /// The amount of the `end` asset available at this level or better.
+    pub cumulative_amount: Amount,
+    /// The number of positions contributing to this level.
+    pub position_count: u32,
+}
+
+/// The smallest tick size a [`DepthAggregator`] accepts.
+///
+/// Prices can be as large as 2^128, so much smaller ticks would overflow the
+/// scaled price to infinity.
+pub const MIN_TICK_SIZE: f64 = 1e-18;
+
+/// Which side of the book a [`DepthAggregator`] is building.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// Prices are visited in descending order, and rounded down to a tick.
+    Bid,
+    /// Prices are visited in ascending order, and rounded up to a tick.
+    Ask,
+}
+
+/// Accumulates liquidity, visited from the best price outwards, into tick-sized levels.
+///
+/// Prices are rounded away from the spread, so that a level never advertises a
+/// better price than the liquidity in it can actually provide.
+///
+/// At most `max_positions` positions are visited, so that a book full of dust
+/// positions can't make a single request walk the whole price index.
+#[derive(Debug, Clone)]
+pub struct DepthAggregator {
+    side: Side,
+    tick_size: f64,
+    max_levels: usize,
+    max_positions: usize,
+    positions: usize,
+    levels: Vec<DepthLevel>,
+    current_tick: Option<f64>,
+}
+
+impl DepthAggregator {
+    pub fn new(
+        side: Side,
+        tick_size: f64,
+        max_levels: usize,
+        max_positions: usize,
+    ) -> Result<Self> {
+        if !(tick_size.is_finite() && tick_size >= MIN_TICK_SIZE) {
+            return Err(anyhow!("tick size must be at least {MIN_TICK_SIZE}"));
+        }
+        Ok(Self {
+            side,
+            tick_size,
+            max_levels,
+            max_positions,
+            positions: 0,
+            levels: Vec::new(),
+            current_tick: None,
+        })
+    }
+
+    /// Add the liquidity of one position at `price`.
+    ///
+    /// Returns `false`, without adding anything, once the liquidity would start
+    /// a level beyond the maximum or `max_positions` have been visited, so the
+    /// caller can stop walking the book. Positions whose price isn't a finite
+    /// number are skipped.
+    pub fn push(&mut self, price: f64, amount: Amount) -> bool {
+        if self.positions >= self.max_positions {
+            return false;
+        }
+        self.positions += 1;
+
+        let scaled = price / self.tick_size;
+        if !scaled.is_finite() {
+            return true;
+        }
+        let tick = match self.side {
+            Side::Bid => scaled.floor(),
+            Side::Ask => scaled.ceil(),
+        };
+
+        let cumulative_before = self
+            .levels
+            .last()
+            .map(|level| level.cumulative_amount)
+            .unwrap_or_default();
+
+        if self.current_tick == Some(tick) {
+            let level = self
+                .levels
+                .last_mut()
+                .expect("a current tick implies a level");
+            level.amount += amount;
+            level.cumulative_amount += amount;
+            level.position_count += 1;
+            return true;
+        }
+
+        if self.levels.len() >= self.max_levels {
+            return false;
+        }
+
+        self.current_tick = Some(tick);
+        self.levels.push(DepthLevel {
+            price: tick * self.tick_size,
+            amount,
+            cumulative_amount: cumulative_before + amount,
+            position_count: 1,
+        });
+        true
+    }
+
+    pub fn finish(self) -> Vec<DepthLevel> {
+        self.levels
+    }
+}
+
+impl DomainType for OrderBookDepth {
+    type Proto = pb::OrderBookDepth;
+}
+
+impl From<OrderBookDepth> for pb::OrderBookDepth {
+    fn from(depth: OrderBookDepth) -> Self {
+        Self {
+            height: depth.height,
+            bids: depth.bids.into_iter().map(Into::into).collect(),
+            asks: depth.asks.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl TryFrom<pb::OrderBookDepth> for OrderBookDepth {
+    type Error = anyhow::Error;
+    fn try_from(depth: pb::OrderBookDepth) -> Result<Self, Self::Error> {
+        Ok(Self {
+            height: depth.height,
+            bids: depth
+                .bids
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+            asks: depth
+                .asks
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<_>>()?,
+        })
+    }
+}
+
+impl DomainType for DepthLevel {
+    type Proto = pb::DepthLevel;
+}
+
+impl From<DepthLevel> for pb::DepthLevel {
+    fn from(level: DepthLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: Some(level.amount.into()),
+            cumulative_amount: Some(level.cumulative_amount.into()),
+            position_count: level.position_count,
+        }
+    }
+}
+
+impl TryFrom<pb::DepthLevel> for DepthLevel {
+    type Error = anyhow::Error;
+    fn try_from(level: pb::DepthLevel) -> Result<Self, Self::Error> {
+        Ok(Self {
+            price: level.price,
+            amount: level
+                .amount
+                .ok_or_else(|| anyhow!("missing amount"))?
+                .try_into()?,
+            cumulative_amount: level
+                .cumulative_amount
+                .ok_or_else(|| anyhow!("missing cumulative amount"))?
+                .try_into()?,
+            position_count: level.position_count,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn levels(
+        side: Side,
+        tick: f64,
+        max: usize,
+        orders: &[(f64, u64)],
+    ) -> Vec<(f64, u64, u64, u32)> {
+        let mut agg = DepthAggregator::new(side, tick, max, usize::MAX).expect("valid tick");
+        for (price, amount) in orders {
+            if !agg.push(*price, (*amount).into()) {
+                break;
+            }
+        }
+        agg.finish()
+            .into_iter()
+            .map(|l| {
+                (
+                    l.price,
+                    u64::try_from(l.amount.value()).expect("fits"),
+                    u64::try_from(l.cumulative_amount.value()).expect("fits"),
+                    l.position_count,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn asks_round_up_and_accumulate() {
+        let asks = levels(
+            Side::Ask,
+            0.5,
+            10,
+            &[(1.0, 10), (1.2, 5), (1.5, 1), (2.1, 7)],
+        );
+        assert_eq!(
+            asks,
+            vec![(1.0, 10, 10, 1), (1.5, 6, 16, 2), (2.5, 7, 23, 1)]
+        );
+    }
+
+    #[test]
+    fn bids_round_down() {
+        let bids = levels(Side::Bid, 0.5, 10, &[(1.9, 3), (1.5, 4), (1.4, 2)]);
+        assert_eq!(bids, vec![(1.5, 7, 7, 2), (1.0, 2, 9, 1)]);
+    }
+
+    #[test]
+    fn stops_at_max_levels() {
+        let asks = levels(Side::Ask, 1.0, 2, &[(1.0, 1), (2.0, 1), (2.0, 1), (3.0, 1)]);
+        assert_eq!(asks, vec![(1.0, 1, 1, 1), (2.0, 2, 3, 2)]);
+    }
+
+    #[test]
+    fn stops_at_max_positions() {
+        let mut agg = DepthAggregator::new(Side::Ask, 1.0, 10, 2).expect("valid tick");
+        assert!(agg.push(1.0, 1u64.into()));
+        assert!(agg.push(1.0, 1u64.into()));
+        assert!(!agg.push(1.0, 1u64.into()));
+        assert_eq!(agg.finish().len(), 1);
+    }
+
+    #[test]
+    fn skips_infinite_prices() {
+        let bids = levels(Side::Bid, 1.0, 10, &[(f64::INFINITY, 5), (2.0, 1)]);
+        assert_eq!(bids, vec![(2.0, 1, 1, 1)]);
+    }
+
+    #[test]
+    fn rejects_bad_tick_sizes() {
+        assert!(DepthAggregator::new(Side::Ask, 0.0, 1, 1).is_err());
+        assert!(DepthAggregator::new(Side::Ask, -1.0, 1, 1).is_err());
+        assert!(DepthAggregator::new(Side::Ask, f64::NAN, 1, 1).is_err());
+        assert!(DepthAggregator::new(Side::Ask, 1e-300, 1, 1).is_err());
+        assert!(DepthAggregator::new(Side::Ask, MIN_TICK_SIZE, 1, 1).is_ok());
+    }
+}
@@ -3,6 +3,8 @@ use penumbra_sdk_proto::penumbra::core::component::dex::v1 as pb;
 use penumbra_sdk_proto::DomainType;
 use serde::{Deserialize, Serialize};
 
+use crate::CandlestickInterval;
+
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(try_from = "pb::DexParameters", into = "pb::DexParameters")]
 /// The configuration parameters for the DEX component.
@@ -12,6 +14,13 @@ pub struct DexParameters {
     pub max_hops: u32,
     pub max_positions_per_pair: u32,
     pub max_execution_budget: u32,
+    pub max_split_paths: u32,
+    /// The intervals candlesticks are rolled up into; an empty list decodes as
+    /// [`CandlestickInterval::DEFAULT_ROLLUPS`].
+    pub candlestick_intervals: Vec<CandlestickInterval>,
+    /// Whether path search ranks paths by the price of the composed trading function along
+    /// them, rather than by the product of their per-hop prices.
+    pub composed_path_prices: bool,
 }
 
 impl DomainType for DexParameters {
@@ -32,6 +41,18 @@ impl TryFrom<pb::DexParameters> for DexParameters {
             max_hops: msg.max_hops,
             max_positions_per_pair: msg.max_positions_per_pair,
             max_execution_budget: msg.max_execution_budget,
+            max_split_paths: msg.max_split_paths,
+            // Parameters stored before candlestick intervals were configurable have none set,
+            // and should keep rolling up the intervals that used to be hardcoded.
+            candlestick_intervals: if msg.candlestick_intervals.is_empty() {
+                CandlestickInterval::DEFAULT_ROLLUPS.to_vec()
+            } else {
+                msg.candlestick_intervals
+                    .into_iter()
+                    .map(CandlestickInterval::try_from)
+                    .collect::<Result<_, _>>()?
+            },
+            composed_path_prices: msg.composed_path_prices,
         })
     }
 }
@@ -48,6 +69,13 @@ impl From<DexParameters> for pb::DexParameters {
             max_hops: params.max_hops,
             max_positions_per_pair: params.max_positions_per_pair,
             max_execution_budget: params.max_execution_budget,
+            max_split_paths: params.max_split_paths,
+            candlestick_intervals: params
+                .candlestick_intervals
+                .into_iter()
+                .map(|interval| pb::CandlestickInterval::from(interval) as i32)
+                .collect(),
+            composed_path_prices: params.composed_path_prices,
         }
     }
 }
@@ -72,6 +100,25 @@ impl Default for DexParameters {
             max_hops: 4,
             max_positions_per_pair: 1_000,
             max_execution_budget: 64,
+            max_split_paths: 0,
+            candlestick_intervals: CandlestickInterval::DEFAULT_ROLLUPS.to_vec(),
+            composed_path_prices: false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn missing_candlestick_intervals_decode_as_the_default_rollups() {
+        let mut proto = pb::DexParameters::from(DexParameters::default());
+        proto.candlestick_intervals.clear();
+        let params = DexParameters::try_from(proto).expect("parameters should decode");
+        assert_eq!(
+            params.candlestick_intervals,
+            CandlestickInterval::DEFAULT_ROLLUPS.to_vec()
+        );
+    }
+}
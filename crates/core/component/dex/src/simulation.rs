@@ -0,0 +1,258 @@
+use std::collections::{btree_map::Entry, BTreeMap};
+
+use anyhow::{anyhow, Result};
+use penumbra_sdk_asset::Value;
+use penumbra_sdk_num::Amount;
+use serde::{Deserialize, Serialize};
+
+use penumbra_sdk_proto::{core::component::dex::v1 as pb, DomainType};
+
+use crate::{event::EventPositionExecution, lp::position, DirectedTradingPair};
+
+/// A position that was executed against during a simulated trade.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::SimulatedPositionExecution",
+    into = "pb::SimulatedPositionExecution"
+)]
+pub struct SimulatedPositionExecution {
+    pub position_id: position::Id,
+    /// The direction the position was traded in, from the asset it received
+    /// to the asset it paid out.
+    pub hop: DirectedTradingPair,
+    /// The amount of `hop.start` the position received, including fees.
+    pub input: Amount,
+    /// The amount of `hop.end` the position paid out.
+    pub output: Amount,
+    /// The fee paid to the position, in units of `hop.start`.
+    pub fee: Amount,
+    /// Whether the trade used up the position's reserves of `hop.end`.
+    pub exhausted: bool,
+}
+
+impl SimulatedPositionExecution {
+    /// Summarizes the execution events emitted while simulating a trade into
+    /// one execution per position, in the order the positions were first used.
+    ///
+    /// `fee_bps` looks up the fee of each position, in basis points.
+    pub fn from_events(
+        events: impl IntoIterator<Item = EventPositionExecution>,
+        fee_bps: impl Fn(&position::Id) -> Option<u32>,
+    ) -> Result<Vec<Self>> {
+        // A position can be executed against several times, so we keep its
+        // reserves before the first execution and after the last one.
+        let mut order = Vec::new();
+        let mut reserves = BTreeMap::new();
+        for event in events {
+            match reserves.entry(event.position_id) {
+                Entry::Occupied(mut entry) => {
+                    let (_first, last) = entry.get_mut();
+                    *last = event;
+                }
+                Entry::Vacant(entry) => {
+                    order.push(event.position_id);
+                    entry.insert((event.clone(), event));
+                }
+            }
+        }
+
+        order
+            .into_iter()
+            .map(|id| {
+                let (first, last) = reserves
+                    .remove(&id)
+                    .expect("every position id has reserves");
+                let pair = last.trading_pair;
+                let (hop, input, output, remaining) = if last.reserves_1 >= first.prev_reserves_1 {
+                    (
+                        DirectedTradingPair::new(pair.asset_1(), pair.asset_2()),
+                        last.reserves_1 - first.prev_reserves_1,
+                        first.prev_reserves_2.checked_sub(&last.reserves_2),
+                        last.reserves_2,
+                    )
+                } else {
+                    (
+                        DirectedTradingPair::new(pair.asset_2(), pair.asset_1()),
+                        last.reserves_2
+                            .checked_sub(&first.prev_reserves_2)
+                            .ok_or_else(|| anyhow!("position {id} lost reserves of both assets"))?,
+                        first.prev_reserves_1.checked_sub(&last.reserves_1),
+                        last.reserves_1,
+                    )
+                };
+                let output = output
+                    .ok_or_else(|| anyhow!("position {id} gained reserves of both assets"))?;
+                let fee_bps = fee_bps(&id).ok_or_else(|| anyhow!("unknown position {id}"))?;
+
+                Ok(Self {
+                    position_id: id,
+                    hop,
+                    input,
+                    output,
+                    fee: fee_paid(input, fee_bps),
+                    exhausted: remaining == Amount::zero(),
+                })
+            })
+            .collect()
+    }
+
+    /// Totals the fees paid across `executions`, per asset.
+    pub fn total_fees<'a>(executions: impl IntoIterator<Item = &'a Self>) -> Vec<Value> {
+        let mut totals = BTreeMap::new();
+        for execution in executions {
+            *totals
+                .entry(execution.hop.start)
+                .or_insert_with(Amount::zero) += execution.fee;
+        }
+        totals
+            .into_iter()
+            .filter(|(_, amount)| *amount != Amount::zero())
+            .map(|(asset_id, amount)| Value { asset_id, amount })
+            .collect()
+    }
+}
+
+/// The part of `input` kept by a position charging `fee_bps`.
+///
+/// Positions only trade `input * (1 - fee_bps / 10_000)`, keeping the rest.
+fn fee_paid(input: Amount, fee_bps: u32) -> Amount {
+    input * Amount::from(fee_bps) / Amount::from(10_000u32)
+}
+
+/// How much worse `effective_price` is than `reference_price`, in basis points.
+///
+/// Prices are in units of input per unit of output, so a positive impact means
+/// the trade paid more than the reference price.
+pub fn price_impact_bps(effective_price: f64, reference_price: f64) -> f64 {
+    if reference_price == 0.0 {
+        return 0.0;
+    }
+    (effective_price / reference_price - 1.0) * 10_000.0
+}
+
+impl DomainType for SimulatedPositionExecution {
+    type Proto = pb::SimulatedPositionExecution;
+}
+
+impl From<SimulatedPositionExecution> for pb::SimulatedPositionExecution {
+    fn from(execution: SimulatedPositionExecution) -> Self {
+        Self {
+            position_id: Some(execution.position_id.into()),
+            hop: Some(execution.hop.into()),
+            input: Some(execution.input.into()),
+            output: Some(execution.output.into()),
+            fee: Some(execution.fee.into()),
+            exhausted: execution.exhausted,
+        }
+    }
+}
+
+impl TryFrom<pb::SimulatedPositionExecution> for SimulatedPositionExecution {
+    type Error = anyhow::Error;
+    fn try_from(execution: pb::SimulatedPositionExecution) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position_id: execution
+                .position_id
+                .ok_or_else(|| anyhow!("missing position id"))?
+                .try_into()?,
+            hop: execution
+                .hop
+                .ok_or_else(|| anyhow!("missing hop"))?
+                .try_into()?,
+            input: execution
+                .input
+                .ok_or_else(|| anyhow!("missing input"))?
+                .try_into()?,
+            output: execution
+                .output
+                .ok_or_else(|| anyhow!("missing output"))?
+                .try_into()?,
+            fee: execution
+                .fee
+                .ok_or_else(|| anyhow!("missing fee"))?
+                .try_into()?,
+            exhausted: execution.exhausted,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_sdk_asset::asset;
+
+    use super::*;
+    use crate::TradingPair;
+
+    fn execution(
+        id: position::Id,
+        pair: TradingPair,
+        prev: (u64, u64),
+        new: (u64, u64),
+    ) -> EventPositionExecution {
+        EventPositionExecution {
+            position_id: id,
+            trading_pair: pair,
+            reserves_1: new.0.into(),
+            reserves_2: new.1.into(),
+            prev_reserves_1: prev.0.into(),
+            prev_reserves_2: prev.1.into(),
+            context: DirectedTradingPair::new(pair.asset_1(), pair.asset_2()),
+        }
+    }
+
+    #[test]
+    fn summarizes_repeated_executions_per_position() {
+        let cache = asset::Cache::with_known_assets();
+        let gm = cache.get_unit("gm").expect("gm is a known asset").id();
+        let gn = cache.get_unit("gn").expect("gn is a known asset").id();
+        let pair = TradingPair::new(gm, gn);
+        let (a, b) = (position::Id([1; 32]), position::Id([2; 32]));
+
+        let events = vec![
+            // Position `a` sells asset 2 twice, ending up empty.
+            execution(a, pair, (0, 100), (40, 60)),
+            // Position `b` sells asset 1.
+            execution(b, pair, (50, 0), (20, 30)),
+            execution(a, pair, (40, 60), (100, 0)),
+        ];
+        let fees = |id: &position::Id| Some(if *id == a { 100 } else { 0 });
+        let executions =
+            SimulatedPositionExecution::from_events(events, fees).expect("events are consistent");
+
+        assert_eq!(
+            executions,
+            vec![
+                SimulatedPositionExecution {
+                    position_id: a,
+                    hop: DirectedTradingPair::new(pair.asset_1(), pair.asset_2()),
+                    input: 100u64.into(),
+                    output: 100u64.into(),
+                    fee: 1u64.into(),
+                    exhausted: true,
+                },
+                SimulatedPositionExecution {
+                    position_id: b,
+                    hop: DirectedTradingPair::new(pair.asset_2(), pair.asset_1()),
+                    input: 30u64.into(),
+                    output: 30u64.into(),
+                    fee: 0u64.into(),
+                    exhausted: false,
+                },
+            ]
+        );
+        assert_eq!(
+            SimulatedPositionExecution::total_fees(&executions),
+            vec![Value {
+                asset_id: pair.asset_1(),
+                amount: 1u64.into(),
+            }]
+        );
+    }
+
+    #[test]
+    fn price_impact_is_relative_to_reference() {
+        assert!((price_impact_bps(1.01, 1.0) - 100.0).abs() < 1e-9);
+        assert_eq!(price_impact_bps(1.0, 1.0), 0.0);
+        assert_eq!(price_impact_bps(1.0, 0.0), 0.0);
+    }
+}
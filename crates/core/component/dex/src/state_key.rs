@@ -57,6 +57,41 @@ pub mod candlesticks {
             format!("{}{}/{}/", prefix(), &pair.start, &pair.end)
         }
     }
+
+    pub mod rollup {
+        use crate::{CandlestickInterval, DirectedTradingPair};
+
+        pub fn prefix() -> &'static str {
+            "dex/candlesticks/rollup/"
+        }
+
+        pub fn by_pair_interval_and_bucket(
+            pair: &DirectedTradingPair,
+            interval: CandlestickInterval,
+            bucket: u64,
+        ) -> String {
+            format!(
+                "{}{}/{}/{}/{bucket:020}",
+                prefix(),
+                interval.as_str(),
+                &pair.start,
+                &pair.end
+            )
+        }
+
+        pub fn by_pair_and_interval(
+            pair: &DirectedTradingPair,
+            interval: CandlestickInterval,
+        ) -> String {
+            format!(
+                "{}{}/{}/{}/",
+                prefix(),
+                interval.as_str(),
+                &pair.start,
+                &pair.end
+            )
+        }
+    }
 }
 
 pub mod block_scoped {
@@ -76,6 +111,10 @@ pub fn output_data(height: u64, trading_pair: TradingPair) -> String {
     )
 }
 
+pub fn output_data_at_height(height: u64) -> String {
+    format!("dex/output/{height:020}/")
+}
+
 pub fn swap_execution(height: u64, trading_pair: DirectedTradingPair) -> String {
     format!(
         "dex/swap_execution/{:020}/{}/{}",
@@ -95,6 +134,88 @@ pub fn arb_executions() -> &'static str {
     "dex/arb_execution/"
 }
 
+pub mod price_accumulator {
+    use crate::TradingPair;
+
+    /// The latest price accumulator for a trading pair, in verifiable storage.
+    pub fn latest(trading_pair: &TradingPair) -> String {
+        format!(
+            "dex/price_accumulator/latest/{}/{}",
+            &trading_pair.asset_1(),
+            &trading_pair.asset_2()
+        )
+    }
+
+    pub fn history_by_pair(trading_pair: &TradingPair) -> String {
+        format!(
+            "dex/price_accumulator/history/{}/{}/",
+            &trading_pair.asset_1(),
+            &trading_pair.asset_2()
+        )
+    }
+
+    /// A past price accumulator, in nonverifiable storage.
+    ///
+    /// Heights are stored inverted, so that a forward range starting at some
+    /// height visits the most recent update at or before it first.
+    pub fn history_by_pair_and_height(trading_pair: &TradingPair, height: u64) -> String {
+        format!(
+            "{}{}",
+            history_by_pair(trading_pair),
+            history_height_key(height)
+        )
+    }
+
+    pub fn history_height_key(height: u64) -> String {
+        format!("{:020}", u64::MAX - height)
+    }
+}
+
+pub mod position_activity {
+    use crate::lp::position;
+
+    pub fn by_position(position_id: &position::Id) -> String {
+        format!("dex/position_activity/{position_id}/")
+    }
+
+    /// The activity of a position during the block at `height`, in nonverifiable storage.
+    pub fn by_position_and_height(position_id: &position::Id, height: u64) -> String {
+        format!("{}{}", by_position(position_id), height_key(height))
+    }
+
+    pub fn height_key(height: u64) -> String {
+        format!("{height:020}")
+    }
+
+    pub(crate) mod by_height {
+        use crate::lp::position;
+        use anyhow::ensure;
+
+        pub(crate) fn prefix() -> &'static [u8] {
+            b"dex/internal/position_activity_by_height/"
+        }
+
+        /// Activity is indexed by its height, big-endian, so that a prefix scan
+        /// visits the oldest activity first.
+        pub(crate) fn key(height: u64, id: &position::Id) -> Vec<u8> {
+            let mut key = prefix().to_vec();
+            key.extend_from_slice(&height.to_be_bytes());
+            key.extend_from_slice(&id.0);
+            key
+        }
+
+        pub(crate) fn parse_key(key: &[u8]) -> anyhow::Result<(u64, position::Id)> {
+            let key = key
+                .strip_prefix(prefix())
+                .ok_or_else(|| anyhow::anyhow!("key is not in the activity index"))?;
+            ensure!(key.len() == 40, "key must have a height and a position id");
+            let height = u64::from_be_bytes(key[0..8].try_into()?);
+            let id = position::Id(key[8..40].try_into()?);
+            Ok((height, id))
+        }
+    }
+}
+
 pub fn swap_flows() -> &'static str {
     "dex/swap_flows"
 }
@@ -371,3 +492,29 @@ pub(crate) mod eviction_queue {
         }
     }
 }
+
+pub(crate) mod expiry_queue {
+    use crate::lp::position;
+    use anyhow::ensure;
+
+    pub(crate) fn prefix() -> &'static [u8] {
+        b"dex/internal/expiry_queue/"
+    }
+
+    /// Positions are keyed by their expiry height, big-endian, so that a prefix
+    /// scan visits them in the order they expire.
+    pub(crate) fn key(expires_at_height: u64, id: &position::Id) -> [u8; 66] {
+        let mut full_key = [0u8; 66];
+        full_key[0..26].copy_from_slice(prefix());
+        full_key[26..34].copy_from_slice(&expires_at_height.to_be_bytes());
+        full_key[34..66].copy_from_slice(&id.0);
+        full_key
+    }
+
+    pub(crate) fn parse_key(key: &[u8]) -> anyhow::Result<(u64, position::Id)> {
+        ensure!(key.len() == 66, "key must be 66 bytes");
+        let height = u64::from_be_bytes(key[26..34].try_into()?);
+        let id = position::Id(key[34..66].try_into()?);
+        Ok((height, id))
+    }
+}
@@ -0,0 +1,225 @@
+use anyhow::{anyhow, Result};
+use penumbra_sdk_num::fixpoint::U128x128;
+use serde::{Deserialize, Serialize};
+
+use penumbra_sdk_proto::{core::component::dex::v1 as pb, DomainType};
+
+use crate::{DirectedTradingPair, TradingPair};
+
+/// Cumulative prices for a [`TradingPair`], in the style of Uniswap V2 price accumulators.
+///
+/// Each block adds the price in effect during that block to the cumulative prices,
+/// where the price in effect is the batch clearing price of the most recent earlier
+/// block in which the pair traded. The time-weighted average price between two
+/// heights is then the difference of the cumulative prices divided by the number
+/// of blocks between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PriceAccumulator", into = "pb::PriceAccumulator")]
+pub struct PriceAccumulator {
+    pub trading_pair: TradingPair,
+    /// The height of the last update.
+    pub height: u64,
+    /// The clearing price of asset 1, in units of asset 2, as of the last update.
+    pub price_1: U128x128,
+    /// The clearing price of asset 2, in units of asset 1, as of the last update.
+    pub price_2: U128x128,
+    /// The sum of the price of asset 1 in effect at each block, up to `height`.
+    pub cumulative_price_1: U128x128,
+    /// The sum of the price of asset 2 in effect at each block, up to `height`.
+    pub cumulative_price_2: U128x128,
+}
+
+impl PriceAccumulator {
+    /// Start accumulating prices from a first observation at `height`.
+    pub fn new(
+        trading_pair: TradingPair,
+        height: u64,
+        (price_1, price_2): (U128x128, U128x128),
+    ) -> Self {
+        Self {
+            trading_pair,
+            height,
+            price_1,
+            price_2,
+            cumulative_price_1: U128x128::default(),
+            cumulative_price_2: U128x128::default(),
+        }
+    }
+
+    /// The cumulative prices of asset 1 and asset 2 as of `height`, which must
+    /// not be before the last update.
+    pub fn cumulative_prices_at(&self, height: u64) -> Result<(U128x128, U128x128)> {
+        let blocks = height.checked_sub(self.height).ok_or_else(|| {
+            anyhow!(
+                "height {height} is before the last update at {}",
+                self.height
+            )
+        })?;
+        let blocks = U128x128::from(blocks);
+        Ok((
+            self.cumulative_price_1
+                .checked_add(&self.price_1.checked_mul(&blocks)?)?,
+            self.cumulative_price_2
+                .checked_add(&self.price_2.checked_mul(&blocks)?)?,
+        ))
+    }
+
+    /// Record the clearing prices observed at `height`.
+    ///
+    /// The new prices only take effect for blocks after `height`.
+    pub fn observe(&self, height: u64, (price_1, price_2): (U128x128, U128x128)) -> Result<Self> {
+        let (cumulative_price_1, cumulative_price_2) = self.cumulative_prices_at(height)?;
+        Ok(Self {
+            trading_pair: self.trading_pair,
+            height,
+            price_1,
+            price_2,
+            cumulative_price_1,
+            cumulative_price_2,
+        })
+    }
+
+    /// The cumulative price of `pair.start`, in units of `pair.end`, as of `height`.
+    pub fn cumulative_price_at(&self, pair: &DirectedTradingPair, height: u64) -> Result<U128x128> {
+        let (cumulative_price_1, cumulative_price_2) = self.cumulative_prices_at(height)?;
+        if *pair == DirectedTradingPair::new(self.trading_pair.asset_1, self.trading_pair.asset_2) {
+            Ok(cumulative_price_1)
+        } else if *pair
+            == DirectedTradingPair::new(self.trading_pair.asset_2, self.trading_pair.asset_1)
+        {
+            Ok(cumulative_price_2)
+        } else {
+            Err(anyhow!("accumulator is not for the requested trading pair"))
+        }
+    }
+
+    /// The time-weighted average price of `pair.start`, in units of `pair.end`,
+    /// over the blocks in `(start_height, end_height]`.
+    ///
+    /// `start` must be the accumulator as of `start_height`, and `end` the
+    /// accumulator as of `end_height`.
+    pub fn time_weighted_average_price(
+        start: &PriceAccumulator,
+        start_height: u64,
+        end: &PriceAccumulator,
+        end_height: u64,
+        pair: &DirectedTradingPair,
+    ) -> Result<U128x128> {
+        if end_height <= start_height {
+            return Err(anyhow!("end height must be after the start height"));
+        }
+        let elapsed = U128x128::from(end_height - start_height);
+        let sum = end
+            .cumulative_price_at(pair, end_height)?
+            .checked_sub(&start.cumulative_price_at(pair, start_height)?)?;
+        Ok(sum.checked_div(&elapsed)?)
+    }
+}
+
+impl DomainType for PriceAccumulator {
+    type Proto = pb::PriceAccumulator;
+}
+
+impl From<PriceAccumulator> for pb::PriceAccumulator {
+    fn from(acc: PriceAccumulator) -> Self {
+        Self {
+            trading_pair: Some(acc.trading_pair.into()),
+            height: acc.height,
+            price_1: acc.price_1.to_bytes().to_vec(),
+            price_2: acc.price_2.to_bytes().to_vec(),
+            cumulative_price_1: acc.cumulative_price_1.to_bytes().to_vec(),
+            cumulative_price_2: acc.cumulative_price_2.to_bytes().to_vec(),
+        }
+    }
+}
+
+impl TryFrom<pb::PriceAccumulator> for PriceAccumulator {
+    type Error = anyhow::Error;
+    fn try_from(acc: pb::PriceAccumulator) -> Result<Self, Self::Error> {
+        Ok(Self {
+            trading_pair: acc
+                .trading_pair
+                .ok_or_else(|| anyhow!("missing trading pair"))?
+                .try_into()?,
+            height: acc.height,
+            price_1: acc.price_1.as_slice().try_into()?,
+            price_2: acc.price_2.as_slice().try_into()?,
+            cumulative_price_1: acc.cumulative_price_1.as_slice().try_into()?,
+            cumulative_price_2: acc.cumulative_price_2.as_slice().try_into()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_sdk_asset::asset;
+
+    use super::*;
+
+    fn pair() -> TradingPair {
+        let gm = asset::Cache::with_known_assets()
+            .get_unit("gm")
+            .expect("gm is a known asset");
+        let gn = asset::Cache::with_known_assets()
+            .get_unit("gn")
+            .expect("gn is a known asset");
+        TradingPair::new(gm.id(), gn.id())
+    }
+
+    fn prices(price_1: u64) -> (U128x128, U128x128) {
+        let price_1 = U128x128::from(price_1);
+        let price_2 = U128x128::ratio(U128x128::from(1u64), price_1).expect("price is nonzero");
+        (price_1, price_2)
+    }
+
+    #[test]
+    fn twap_weights_prices_by_blocks_in_effect() {
+        let pair = pair();
+        let forward = DirectedTradingPair::new(pair.asset_1(), pair.asset_2());
+
+        // Price 2 observed at height 10, then 5 at height 14.
+        let at_10 = PriceAccumulator::new(pair, 10, prices(2));
+        let at_14 = at_10.observe(14, prices(5)).expect("no overflow");
+        assert_eq!(at_14.cumulative_price_1, U128x128::from(8u64));
+
+        // Blocks 11..=14 had price 2 in effect, blocks 15..=18 had price 5.
+        let twap = PriceAccumulator::time_weighted_average_price(&at_10, 10, &at_14, 18, &forward)
+            .expect("valid range");
+        assert_eq!(f64::from(twap), 3.5);
+
+        // Before the second observation takes effect, only the first price counts.
+        let twap = PriceAccumulator::time_weighted_average_price(&at_10, 12, &at_14, 14, &forward)
+            .expect("valid range");
+        assert_eq!(f64::from(twap), 2.0);
+    }
+
+    #[test]
+    fn twap_in_reverse_direction_uses_inverse_prices() {
+        let pair = pair();
+        let reverse = DirectedTradingPair::new(pair.asset_2(), pair.asset_1());
+
+        let at_0 = PriceAccumulator::new(pair, 0, prices(2));
+        let at_1 = at_0.observe(1, prices(4)).expect("no overflow");
+        let twap = PriceAccumulator::time_weighted_average_price(&at_0, 0, &at_1, 2, &reverse)
+            .expect("valid range");
+        assert_eq!(f64::from(twap), 0.375);
+    }
+
+    #[test]
+    fn rejects_empty_ranges_and_stale_heights() {
+        let pair = pair();
+        let forward = DirectedTradingPair::new(pair.asset_1(), pair.asset_2());
+        let acc = PriceAccumulator::new(pair, 5, prices(1));
+        assert!(PriceAccumulator::time_weighted_average_price(&acc, 6, &acc, 6, &forward).is_err());
+        assert!(acc.cumulative_prices_at(4).is_err());
+    }
+
+    #[test]
+    fn round_trips_through_proto() {
+        let acc = PriceAccumulator::new(pair(), 3, prices(7))
+            .observe(9, prices(3))
+            .expect("no overflow");
+        let decoded = PriceAccumulator::decode(acc.encode_to_vec().as_slice()).expect("decodes");
+        assert_eq!(acc, decoded);
+    }
+}
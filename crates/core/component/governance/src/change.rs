@@ -178,6 +178,98 @@ impl ParameterChange {
     }
 }
 
+/// When a [`ScheduledParameterChange`] takes effect.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Activation {
+    /// At the beginning of the block with the given height.
+    Height(u64),
+    /// At the beginning of the first block of the epoch with the given index.
+    Epoch(u64),
+}
+
+impl std::fmt::Display for Activation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Activation::Height(height) => write!(f, "height {height}"),
+            Activation::Epoch(epoch) => write!(f, "epoch {epoch}"),
+        }
+    }
+}
+
+/// A batch of changes to the app parameters, which take effect at an explicit activation
+/// height or epoch rather than as soon as the proposal that carries them passes.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::proposal::ScheduledParameterChange",
+    into = "pb::proposal::ScheduledParameterChange"
+)]
+pub struct ScheduledParameterChange {
+    /// The changes to apply, in order.
+    ///
+    /// Each change is applied independently of the others: if the preconditions of a
+    /// change do not hold at activation time, only that change is skipped.
+    pub changes: Vec<ParameterChange>,
+    /// When the changes take effect.
+    pub activation: Activation,
+}
+
+/// The maximum number of parameter changes a single scheduled change can bundle.
+pub const MAX_SCHEDULED_CHANGES: usize = 16;
+
+impl DomainType for ScheduledParameterChange {
+    type Proto = pb::proposal::ScheduledParameterChange;
+}
+
+impl TryFrom<pb::proposal::ScheduledParameterChange> for ScheduledParameterChange {
+    type Error = anyhow::Error;
+    fn try_from(value: pb::proposal::ScheduledParameterChange) -> Result<Self, Self::Error> {
+        if value.changes.is_empty() {
+            anyhow::bail!("scheduled parameter change must contain at least one change");
+        }
+        if value.changes.len() > MAX_SCHEDULED_CHANGES {
+            anyhow::bail!(
+                "scheduled parameter change can contain at most {MAX_SCHEDULED_CHANGES} changes"
+            );
+        }
+
+        let activation = match (value.activation_height, value.activation_epoch) {
+            (0, 0) => anyhow::bail!("scheduled parameter change is missing an activation"),
+            (height, 0) => Activation::Height(height),
+            (0, epoch) => Activation::Epoch(epoch),
+            (_, _) => anyhow::bail!(
+                "scheduled parameter change must not set both an activation height and epoch"
+            ),
+        };
+
+        Ok(ScheduledParameterChange {
+            changes: value
+                .changes
+                .into_iter()
+                .map(ParameterChange::try_from)
+                .collect::<Result<_, _>>()?,
+            activation,
+        })
+    }
+}
+
+impl From<ScheduledParameterChange> for pb::proposal::ScheduledParameterChange {
+    fn from(value: ScheduledParameterChange) -> Self {
+        let (activation_height, activation_epoch) = match value.activation {
+            Activation::Height(height) => (height, 0),
+            Activation::Epoch(epoch) => (0, epoch),
+        };
+        pb::proposal::ScheduledParameterChange {
+            changes: value
+                .changes
+                .into_iter()
+                .map(pb::proposal::ParameterChange::from)
+                .collect(),
+            activation_height,
+            activation_epoch,
+        }
+    }
+}
+
 fn get_component<'a>(
     app_parameters: &'a mut serde_json::Value,
     change: &EncodedParameter,
@@ -419,4 +511,45 @@ mod tests {
         assert!(satisfied_result.is_ok());
         assert!(unsatisfied_result.is_err());
     }
+
+    #[test]
+    fn scheduled_changes_require_exactly_one_activation() {
+        let change = ParameterChange {
+            preconditions: vec![],
+            changes: vec![super::EncodedParameter {
+                component: "governanceParams".to_string(),
+                key: "proposalVotingBlocks".to_string(),
+                value: r#""17281""#.to_string(),
+            }],
+        };
+        let scheduled = |activation_height, activation_epoch| {
+            ScheduledParameterChange::try_from(pb::proposal::ScheduledParameterChange {
+                changes: vec![change.clone().into()],
+                activation_height,
+                activation_epoch,
+            })
+        };
+
+        assert_eq!(
+            scheduled(100, 0)
+                .expect("height activation is valid")
+                .activation,
+            Activation::Height(100)
+        );
+        assert_eq!(
+            scheduled(0, 7)
+                .expect("epoch activation is valid")
+                .activation,
+            Activation::Epoch(7)
+        );
+        assert!(scheduled(0, 0).is_err());
+        assert!(scheduled(100, 7).is_err());
+
+        let empty = pb::proposal::ScheduledParameterChange {
+            changes: vec![],
+            activation_height: 100,
+            activation_epoch: 0,
+        };
+        assert!(ScheduledParameterChange::try_from(empty).is_err());
+    }
 }
@@ -70,6 +70,13 @@ impl Component for Governance {
     async fn end_epoch<S: StateWrite + 'static>(state: &mut Arc<S>) -> Result<()> {
         let state = Arc::get_mut(state).expect("state should be unique");
         state.tally_delegator_votes(None).await?;
+
+        // Parameter changes scheduled for the next epoch take effect at its first block.
+        let next_height = state.get_block_height().await? + 1;
+        let next_epoch_index = state.get_current_epoch().await?.index + 1;
+        state
+            .activate_scheduled_epoch_param_changes(next_epoch_index, next_height)
+            .await?;
         Ok(())
     }
 }
@@ -3,7 +3,7 @@ use std::str::FromStr;
 
 use anyhow::Context;
 use async_stream::try_stream;
-use cnidarium::Storage;
+use cnidarium::{StateDelta, Storage};
 use futures::{StreamExt, TryStreamExt};
 use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::core::component::governance::v1::AllTalliedDelegatorVotesForProposalRequest;
@@ -16,8 +16,10 @@ use penumbra_sdk_proto::{
     core::component::governance::v1::{
         query_service_server::QueryService, ProposalDataRequest, ProposalDataResponse,
         ProposalInfoRequest, ProposalInfoResponse, ProposalListRequest, ProposalListResponse,
-        ProposalRateDataRequest, ProposalRateDataResponse, ValidatorVotesRequest,
-        ValidatorVotesResponse,
+        ProposalProjectionRequest, ProposalProjectionResponse, ProposalRateDataRequest,
+        ProposalRateDataResponse, ScheduledParameterChangesRequest,
+        ScheduledParameterChangesResponse, ValidatorVoteBreakdownRequest,
+        ValidatorVoteBreakdownResponse, ValidatorVotesRequest, ValidatorVotesResponse,
     },
     StateReadProto,
 };
@@ -26,11 +28,12 @@ use penumbra_sdk_stake::IdentityKey;
 use tonic::Status;
 use tracing::instrument;
 
+use crate::proposal_state::Outcome;
 use crate::state_key;
 use crate::Tally;
 use crate::Vote;
 
-use super::StateReadExt;
+use super::{StateReadExt, StateWriteExt as _};
 
 // TODO: Hide this and only expose a Router?
 pub struct Server {
@@ -410,4 +413,176 @@ impl QueryService for Server {
             .boxed(),
         ))
     }
+
+    type ScheduledParameterChangesStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<ScheduledParameterChangesResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+
+    #[instrument(skip(self, _request))]
+    async fn scheduled_parameter_changes(
+        &self,
+        _request: tonic::Request<ScheduledParameterChangesRequest>,
+    ) -> Result<tonic::Response<Self::ScheduledParameterChangesStream>, Status> {
+        let state = self.storage.latest_snapshot();
+
+        let scheduled_changes = state.scheduled_param_changes().await.map_err(|e| {
+            tonic::Status::internal(format!("unable to fetch scheduled parameter changes: {e}"))
+        })?;
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(scheduled_changes.into_iter().map(
+                |(proposal_id, scheduled, activation_height)| {
+                    Ok(ScheduledParameterChangesResponse {
+                        proposal_id,
+                        scheduled_change: Some(scheduled.into()),
+                        activation_height: activation_height.unwrap_or_default(),
+                    })
+                },
+            ))
+            .boxed(),
+        ))
+    }
+
+    #[instrument(skip(self, request))]
+    async fn proposal_projection(
+        &self,
+        request: tonic::Request<ProposalProjectionRequest>,
+    ) -> Result<tonic::Response<ProposalProjectionResponse>, Status> {
+        let mut state = StateDelta::new(self.storage.latest_snapshot());
+        let proposal_id = request.into_inner().proposal_id;
+
+        let proposal_state = state
+            .proposal_state(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to fetch proposal state: {e}")))?
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!("proposal {} not found", proposal_id))
+            })?;
+        if !proposal_state.is_voting() {
+            return Err(tonic::Status::failed_precondition(format!(
+                "proposal {} is no longer voting",
+                proposal_id
+            )));
+        }
+
+        // Enactment starts with a final tally of the pending delegator votes, so project the
+        // outcome from a throwaway tally of them too.
+        state
+            .tally_delegator_votes(Some(proposal_id))
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to tally votes: {e}")))?;
+
+        let params = state.get_governance_params().await.map_err(|e| {
+            tonic::Status::internal(format!("unable to fetch governance parameters: {e}"))
+        })?;
+        let current_tally = state
+            .current_tally(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to tally votes: {e}")))?;
+        let total_voting_power = state
+            .total_voting_power_at_proposal_start(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to fetch voting power: {e}")))?;
+        let validator_default_tally = state
+            .validator_default_tally(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to fetch voting power: {e}")))?;
+
+        let unvoted_power = total_voting_power.saturating_sub(current_tally.total());
+        let projected_outcome: Outcome<()> =
+            current_tally.outcome(total_voting_power, &params).into();
+        let votes_to_flip = current_tally
+            .votes_to_flip(
+                unvoted_power,
+                validator_default_tally,
+                total_voting_power,
+                &params,
+            )
+            .unwrap_or_default();
+
+        Ok(tonic::Response::new(ProposalProjectionResponse {
+            current_tally: Some(current_tally.into()),
+            projected_outcome: Some(projected_outcome.into()),
+            total_voting_power,
+            unvoted_power,
+            validator_default_power: validator_default_tally.total(),
+            votes_to_flip,
+        }))
+    }
+
+    type ValidatorVoteBreakdownStream = Pin<
+        Box<
+            dyn futures::Stream<Item = Result<ValidatorVoteBreakdownResponse, tonic::Status>>
+                + Send,
+        >,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn validator_vote_breakdown(
+        &self,
+        request: tonic::Request<ValidatorVoteBreakdownRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorVoteBreakdownStream>, Status> {
+        let mut state = StateDelta::new(self.storage.latest_snapshot());
+        let proposal_id = request.into_inner().proposal_id;
+
+        state
+            .proposal_state(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to fetch proposal state: {e}")))?
+            .ok_or_else(|| {
+                tonic::Status::not_found(format!("proposal {} not found", proposal_id))
+            })?;
+
+        // Include the delegator votes which have been cast but not tallied yet, by tallying them
+        // in a throwaway state.
+        state
+            .tally_delegator_votes(Some(proposal_id))
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to tally votes: {e}")))?;
+
+        let validator_powers = state
+            .validator_voting_power_at_proposal_start(proposal_id)
+            .await
+            .map_err(|e| tonic::Status::internal(format!("unable to fetch voting power: {e}")))?;
+        let mut validator_votes = state.validator_votes(proposal_id).await.map_err(|e| {
+            tonic::Status::internal(format!("unable to fetch validator votes: {e}"))
+        })?;
+        let mut delegator_tallies =
+            state
+                .tallied_delegator_votes(proposal_id)
+                .await
+                .map_err(|e| {
+                    tonic::Status::internal(format!("unable to fetch delegator votes: {e}"))
+                })?;
+
+        let breakdown = validator_powers
+            .into_iter()
+            .map(|(identity_key, voting_power)| {
+                let validator_vote = validator_votes.remove(&identity_key);
+                let delegator_tally = delegator_tallies.remove(&identity_key).unwrap_or_default();
+                // Delegators who voted take their share of the validator's voting power with them,
+                // so the validator's vote only counts for what's left over.
+                let mut net_tally = delegator_tally;
+                if let Some(vote) = validator_vote {
+                    net_tally +=
+                        (vote, voting_power.saturating_sub(delegator_tally.total())).into();
+                }
+
+                Ok(ValidatorVoteBreakdownResponse {
+                    identity_key: Some(identity_key.into()),
+                    voting_power,
+                    validator_vote: validator_vote.map(Into::into),
+                    delegator_tally: Some(delegator_tally.into()),
+                    net_tally: Some(net_tally.into()),
+                })
+            })
+            .collect::<Vec<_>>();
+
+        Ok(tonic::Response::new(
+            futures::stream::iter(breakdown).boxed(),
+        ))
+    }
 }
@@ -6,7 +6,7 @@ use std::{
 use anyhow::{Context, Result};
 use async_trait::async_trait;
 use cnidarium::{StateRead, StateWrite};
-use futures::StreamExt;
+use futures::{StreamExt, TryStreamExt};
 use ibc_types::core::client::ClientId;
 use penumbra_sdk_asset::{asset, Value, STAKING_TOKEN_DENOM};
 use penumbra_sdk_ibc::component::ClientStateReadExt as _;
@@ -29,7 +29,7 @@ use tracing::instrument;
 use penumbra_sdk_stake::{rate::RateData, validator};
 
 use crate::{
-    change::ParameterChange,
+    change::{Activation, ParameterChange, ScheduledParameterChange},
     params::GovernanceParameters,
     proposal::{Proposal, ProposalPayload},
     proposal_state::State as ProposalState,
@@ -565,11 +565,95 @@ pub trait StateReadExt: StateRead + penumbra_sdk_stake::StateReadExt {
         Ok(tally)
     }
 
+    /// Add up the voting power currently counted through validator votes, by the vote of each
+    /// validator, which delegators to those validators can still override by casting their own
+    /// votes.
+    async fn validator_default_tally(&self, proposal_id: u64) -> Result<Tally> {
+        let validator_powers = self
+            .validator_voting_power_at_proposal_start(proposal_id)
+            .await?;
+        let validator_votes = self.validator_votes(proposal_id).await?;
+        let delegator_tallies = self.tallied_delegator_votes(proposal_id).await?;
+
+        let mut tally = Tally::default();
+        for (validator, power) in validator_powers.into_iter() {
+            let Some(vote) = validator_votes.get(&validator) else {
+                continue;
+            };
+            let delegated = delegator_tallies
+                .get(&validator)
+                .map(Tally::total)
+                .unwrap_or_default();
+            tally += (*vote, power.saturating_sub(delegated)).into();
+        }
+
+        Ok(tally)
+    }
+
     /// Gets the parameter changes scheduled for the given height, if any.
     async fn param_changes_for_height(&self, height: u64) -> Result<Option<ParameterChange>> {
         self.get(&state_key::param_changes_for_height(height)).await
     }
 
+    /// Gets the scheduled parameter changes that activate at the given height, ordered by the
+    /// id of the proposal that scheduled them.
+    async fn scheduled_param_changes_for_height(
+        &self,
+        height: u64,
+    ) -> Result<Vec<(u64, ScheduledParameterChange)>> {
+        self.prefix::<ScheduledParameterChange>(&state_key::scheduled_param_changes::all_at_height(
+            height,
+        ))
+        .map(|entry| {
+            let (key, scheduled) = entry?;
+            Ok::<_, anyhow::Error>((parse_last_key_segment(&key)?, scheduled))
+        })
+        .try_collect()
+        .await
+    }
+
+    /// Gets all the scheduled parameter changes that have not been applied yet.
+    ///
+    /// Each entry contains the id of the proposal that scheduled the change, the change itself,
+    /// and the height at which it will be applied, if it is known yet: the height of a change
+    /// scheduled for an epoch is only known once the previous epoch ends.
+    async fn scheduled_param_changes(
+        &self,
+    ) -> Result<Vec<(u64, ScheduledParameterChange, Option<u64>)>> {
+        let by_height_prefix = state_key::scheduled_param_changes::all_by_height();
+        let mut changes: Vec<_> = self
+            .prefix::<ScheduledParameterChange>(by_height_prefix)
+            .map(|entry| {
+                let (key, scheduled) = entry?;
+                let height = key
+                    .strip_prefix(by_height_prefix)
+                    .and_then(|suffix| suffix.split('/').next())
+                    .context("scheduled parameter change key has a height")?
+                    .parse::<u64>()?;
+                Ok::<_, anyhow::Error>((parse_last_key_segment(&key)?, scheduled, Some(height)))
+            })
+            .try_collect()
+            .await?;
+
+        let by_epoch: Vec<_> = self
+            .prefix::<ScheduledParameterChange>(state_key::scheduled_param_changes::all_by_epoch())
+            .map(|entry| {
+                let (key, scheduled) = entry?;
+                Ok::<_, anyhow::Error>((parse_last_key_segment(&key)?, scheduled, None))
+            })
+            .try_collect()
+            .await?;
+        changes.extend(by_epoch);
+
+        Ok(changes)
+    }
+
+    /// Returns true if scheduled parameter changes were applied in this block.
+    fn scheduled_param_changes_applied(&self) -> bool {
+        self.object_get::<()>(state_key::scheduled_param_changes::applied())
+            .is_some()
+    }
+
     /// Check if any proposal is started in this block.
     fn proposal_started(&self) -> bool {
         self.object_get::<()>(state_key::proposal_started())
@@ -586,6 +670,15 @@ pub trait StateReadExt: StateRead + penumbra_sdk_stake::StateReadExt {
 
 impl<T: StateRead + penumbra_sdk_stake::StateReadExt + ?Sized> StateReadExt for T {}
 
+/// Parses the proposal id at the end of a scheduled parameter change key.
+fn parse_last_key_segment(key: &str) -> Result<u64> {
+    key.rsplit('/')
+        .next()
+        .context("scheduled parameter change key has a proposal id")?
+        .parse()
+        .context("scheduled parameter change key ends with a proposal id")
+}
+
 #[async_trait]
 pub trait StateWriteExt: StateWrite + penumbra_sdk_ibc::component::ConnectionStateWriteExt {
     /// Writes the provided governance parameters to the JMT.
@@ -685,6 +778,101 @@ pub trait StateWriteExt: StateWrite + penumbra_sdk_ibc::component::ConnectionSta
         self.object_put(state_key::proposal_started(), ());
     }
 
+    /// Queue the parameter changes of a passed proposal until their activation.
+    ///
+    /// Fails if the activation has already been reached by the time the proposal passes.
+    async fn schedule_param_changes(
+        &mut self,
+        proposal_id: u64,
+        scheduled: ScheduledParameterChange,
+    ) -> Result<()> {
+        let next_height = self.get_block_height().await? + 1;
+        let current_epoch = self.get_current_epoch().await?;
+
+        match scheduled.activation {
+            Activation::Epoch(epoch_index) => {
+                anyhow::ensure!(
+                    epoch_index > current_epoch.index,
+                    "activation epoch {epoch_index} has already started (current epoch is {})",
+                    current_epoch.index,
+                );
+                self.put(
+                    state_key::scheduled_param_changes::by_epoch(epoch_index, proposal_id),
+                    scheduled,
+                );
+            }
+            Activation::Height(height) => {
+                anyhow::ensure!(
+                    height >= next_height,
+                    "activation height {height} has already been reached (next height is {next_height})",
+                );
+                self.put(
+                    state_key::scheduled_param_changes::by_height(height, proposal_id),
+                    scheduled,
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolve the changes scheduled for epochs up to `epoch_index` to the given height, which
+    /// must be the first height of that epoch.
+    async fn activate_scheduled_epoch_param_changes(
+        &mut self,
+        epoch_index: u64,
+        height: u64,
+    ) -> Result<()> {
+        let by_epoch: Vec<(String, ScheduledParameterChange)> = self
+            .prefix(state_key::scheduled_param_changes::all_by_epoch())
+            .try_collect()
+            .await?;
+
+        for (key, scheduled) in by_epoch {
+            let Activation::Epoch(activation_epoch) = scheduled.activation else {
+                anyhow::bail!("change scheduled by epoch has no activation epoch");
+            };
+            if activation_epoch > epoch_index {
+                continue;
+            }
+            let proposal_id = parse_last_key_segment(&key)?;
+            tracing::info!(
+                proposal_id,
+                activation_epoch,
+                height,
+                "scheduling parameter change for the start of the epoch"
+            );
+            self.delete(key);
+            self.put(
+                state_key::scheduled_param_changes::by_height(height, proposal_id),
+                scheduled,
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Remove and return the scheduled parameter changes that activate at the given height,
+    /// ordered by the id of the proposal that scheduled them.
+    async fn take_scheduled_param_changes_for_height(
+        &mut self,
+        height: u64,
+    ) -> Result<Vec<(u64, ScheduledParameterChange)>> {
+        let changes = self.scheduled_param_changes_for_height(height).await?;
+        for (proposal_id, _) in &changes {
+            self.delete(state_key::scheduled_param_changes::by_height(
+                height,
+                *proposal_id,
+            ));
+        }
+        Ok(changes)
+    }
+
+    /// Record in the object store that scheduled parameter changes were applied in this block.
+    fn mark_scheduled_param_changes_applied(&mut self) {
+        self.object_put(state_key::scheduled_param_changes::applied(), ());
+    }
+
     /// Store the proposal deposit amount.
     fn put_deposit_amount(&mut self, proposal_id: u64, amount: Amount) {
         self.put(state_key::proposal_deposit_amount(proposal_id), amount);
@@ -933,6 +1121,21 @@ pub trait StateWriteExt: StateWrite + penumbra_sdk_ibc::component::ConnectionSta
                 let unfrozen_client = client_state.unfrozen();
                 self.put_client(client_id, unfrozen_client);
             }
+            ProposalPayload::ScheduledParameterChange(scheduled) => {
+                tracing::info!(
+                    activation = %scheduled.activation,
+                    num_changes = scheduled.changes.len(),
+                    "scheduled parameter change proposal passed, queueing for activation"
+                );
+                // Voters relied on the activation, so the changes are not applied late: the
+                // proposal fails to enact if its activation was reached while it was voted on.
+                if let Err(error) = self
+                    .schedule_param_changes(proposal_id, scheduled.clone())
+                    .await
+                {
+                    return Ok(Err(error));
+                }
+            }
         }
         Ok(Ok(()))
     }
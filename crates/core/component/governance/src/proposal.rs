@@ -4,7 +4,7 @@ use ibc_types::core::client::ClientId;
 use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 
-use crate::change::ParameterChange;
+use crate::change::{ParameterChange, ScheduledParameterChange};
 use penumbra_sdk_proto::{penumbra::core::component::governance::v1 as pb, DomainType};
 
 /// A governance proposal.
@@ -73,6 +73,9 @@ impl From<Proposal> for pb::Proposal {
                     client_id: client_id.into(),
                 },
             )),
+            ProposalPayload::ScheduledParameterChange(scheduled) => {
+                Some(Payload::ScheduledParameterChange(scheduled.into()))
+            }
         };
         proposal.payload = payload;
         proposal
@@ -164,6 +167,9 @@ impl TryFrom<pb::Proposal> for Proposal {
                         client_id: unfreeze_ibc_client.client_id,
                     }
                 }
+                Payload::ScheduledParameterChange(scheduled) => {
+                    ProposalPayload::ScheduledParameterChange(scheduled.try_into()?)
+                }
             },
         })
     }
@@ -225,6 +231,8 @@ pub enum ProposalKind {
     FreezeIbcClient,
     /// A proposal to unfreeze an IBC client.
     UnfreezeIbcClient,
+    /// A parameter change proposal with an explicit activation height or epoch.
+    ScheduledParameterChange,
 }
 
 impl From<ProposalKind> for pb::ProposalKind {
@@ -237,6 +245,7 @@ impl From<ProposalKind> for pb::ProposalKind {
             ProposalKind::UpgradePlan => pb::ProposalKind::UpgradePlan,
             ProposalKind::FreezeIbcClient => pb::ProposalKind::FreezeIbcClient,
             ProposalKind::UnfreezeIbcClient => pb::ProposalKind::UnfreezeIbcClient,
+            ProposalKind::ScheduledParameterChange => pb::ProposalKind::ScheduledParameterChange,
         }
     }
 }
@@ -254,6 +263,7 @@ impl TryFrom<pb::ProposalKind> for ProposalKind {
             pb::ProposalKind::UpgradePlan => ProposalKind::UpgradePlan,
             pb::ProposalKind::FreezeIbcClient => ProposalKind::FreezeIbcClient,
             pb::ProposalKind::UnfreezeIbcClient => ProposalKind::UnfreezeIbcClient,
+            pb::ProposalKind::ScheduledParameterChange => ProposalKind::ScheduledParameterChange,
         };
         Ok(kind)
     }
@@ -269,6 +279,7 @@ impl FromStr for ProposalKind {
             "parameter_change" => Ok(ProposalKind::ParameterChange),
             "community_pool_spend" => Ok(ProposalKind::CommunityPoolSpend),
             "upgrade_plan" => Ok(ProposalKind::UpgradePlan),
+            "scheduled_parameter_change" => Ok(ProposalKind::ScheduledParameterChange),
             _ => Err(anyhow::anyhow!("invalid proposal kind: {}", s)),
         }
     }
@@ -285,6 +296,7 @@ impl Proposal {
             ProposalPayload::UpgradePlan { .. } => ProposalKind::UpgradePlan,
             ProposalPayload::FreezeIbcClient { .. } => ProposalKind::FreezeIbcClient,
             ProposalPayload::UnfreezeIbcClient { .. } => ProposalKind::UnfreezeIbcClient,
+            ProposalPayload::ScheduledParameterChange(_) => ProposalKind::ScheduledParameterChange,
         }
     }
 }
@@ -331,6 +343,9 @@ pub enum ProposalPayload {
         /// The identifier of the client to unfreeze.
         client_id: String,
     },
+    /// A scheduled parameter change proposal bundles changes to the app parameters, which
+    /// take effect at an explicit activation height or epoch once the proposal has passed.
+    ScheduledParameterChange(ScheduledParameterChange),
 }
 
 /// A TOML-serializable version of `ProposalPayload`, meant for human consumption.
@@ -344,6 +359,7 @@ pub enum ProposalPayloadToml {
     UpgradePlan { height: u64 },
     FreezeIbcClient { client_id: String },
     UnfreezeIbcClient { client_id: String },
+    ScheduledParameterChange(ScheduledParameterChange),
 }
 
 impl TryFrom<ProposalPayloadToml> for ProposalPayload {
@@ -377,6 +393,9 @@ impl TryFrom<ProposalPayloadToml> for ProposalPayload {
             ProposalPayloadToml::UnfreezeIbcClient { client_id } => {
                 ProposalPayload::UnfreezeIbcClient { client_id }
             }
+            ProposalPayloadToml::ScheduledParameterChange(scheduled) => {
+                ProposalPayload::ScheduledParameterChange(scheduled)
+            }
         })
     }
 }
@@ -406,6 +425,9 @@ impl From<ProposalPayload> for ProposalPayloadToml {
             ProposalPayload::UnfreezeIbcClient { client_id } => {
                 ProposalPayloadToml::UnfreezeIbcClient { client_id }
             }
+            ProposalPayload::ScheduledParameterChange(scheduled) => {
+                ProposalPayloadToml::ScheduledParameterChange(scheduled)
+            }
         }
     }
 }
@@ -428,6 +450,10 @@ impl ProposalPayload {
         matches!(self, ProposalPayload::ParameterChange { .. })
     }
 
+    pub fn is_scheduled_parameter_change(&self) -> bool {
+        matches!(self, ProposalPayload::ScheduledParameterChange { .. })
+    }
+
     pub fn is_community_pool_spend(&self) -> bool {
         matches!(self, ProposalPayload::CommunityPoolSpend { .. })
     }
@@ -124,6 +124,33 @@ pub fn param_changes_for_height(block_height: u64) -> String {
     format!("governance/param_changes/{block_height}/")
 }
 
+pub mod scheduled_param_changes {
+    pub fn by_height(block_height: u64, proposal_id: u64) -> String {
+        format!("{}{block_height:020}/{proposal_id:020}", all_by_height())
+    }
+
+    pub fn all_at_height(block_height: u64) -> String {
+        // Note: this has to be the prefix of the `by_height` function above.
+        format!("{}{block_height:020}/", all_by_height())
+    }
+
+    pub fn all_by_height() -> &'static str {
+        "governance/scheduled_param_changes/by_height/"
+    }
+
+    pub fn by_epoch(epoch_index: u64, proposal_id: u64) -> String {
+        format!("{}{epoch_index:020}/{proposal_id:020}", all_by_epoch())
+    }
+
+    pub fn all_by_epoch() -> &'static str {
+        "governance/scheduled_param_changes/by_epoch/"
+    }
+
+    pub fn applied() -> &'static str {
+        "governance/scheduled_param_changes/applied"
+    }
+}
+
 pub fn proposal_started() -> &'static str {
     "governance/proposal_started"
 }
@@ -189,6 +189,94 @@ impl Tally {
         // the emergency condition of 1/3 majority of voting power
         Ratio::new(self.yes, total_voting_power) > Ratio::new(1, 3)
     }
+
+    /// The smallest amount of voting power which would change whether this tally passes: `yes`
+    /// votes for a tally which would not pass, and `no` votes for one which would.
+    ///
+    /// The votes can be cast with the `available` voting power which has not been cast yet, or by
+    /// delegators overriding the `overridable` voting power cast by their validators. An override
+    /// counts on both sides: it removes the power from the validator's vote as well as adding it
+    /// to the delegator's.
+    ///
+    /// Returns `None` if casting all of this voting power would not change the outcome.
+    pub fn votes_to_flip(
+        self,
+        available: u64,
+        overridable: Tally,
+        total_voting_power: u64,
+        params: &GovernanceParameters,
+    ) -> Option<u64> {
+        let passes = self.outcome(total_voting_power, params).is_pass();
+        let vote = if passes { Vote::No } else { Vote::Yes };
+        let overridable_against = if passes {
+            overridable.yes
+        } else {
+            overridable.no
+        };
+
+        // Overrides don't add to the total voting power cast, so only new votes can help a tally
+        // reach quorum: use those first, as many as needed.
+        let quorum_votes = if passes {
+            0
+        } else {
+            smallest_satisfying(available, |power| {
+                (self + Tally::from((vote, power))).meets_quorum(total_voting_power, params)
+            })
+            .unwrap_or(available)
+        };
+
+        // Past quorum, overriding votes on the other side moves the tally the most, followed by
+        // overriding abstentions and finally casting new votes.
+        let flipped = |power: u64| {
+            let mut remaining = power;
+            let mut take = |limit: u64| {
+                let taken = remaining.min(limit);
+                remaining -= taken;
+                taken
+            };
+            take(quorum_votes);
+            let overridden_against = take(overridable_against);
+            let overridden_abstain = take(overridable.abstain);
+
+            let mut tally = self + Tally::from((vote, power));
+            if passes {
+                tally.yes -= overridden_against;
+            } else {
+                tally.no -= overridden_against;
+            }
+            tally.abstain -= overridden_abstain;
+            tally.outcome(total_voting_power, params).is_pass() != passes
+        };
+
+        // Each additional vote moves the outcome monotonically towards flipping, so we can binary
+        // search for the smallest amount which flips it.
+        smallest_satisfying(
+            available + overridable_against + overridable.abstain,
+            flipped,
+        )
+    }
+}
+
+/// Binary search for the smallest value in `0..=max` satisfying a monotonic predicate.
+fn smallest_satisfying(max: u64, predicate: impl Fn(u64) -> bool) -> Option<u64> {
+    if !predicate(max) {
+        return None;
+    }
+    if predicate(0) {
+        return Some(0);
+    }
+
+    let (mut low, mut high) = (0, max);
+    while high - low > 1 {
+        let mid = low + (high - low) / 2;
+        if predicate(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Some(high)
 }
 
 /// This is a ratio of two `u64` values, intended to be used solely in governance parameters and
@@ -280,3 +368,43 @@ impl From<pb::Ratio> for Ratio {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn votes_to_flip_finds_smallest_amount() {
+        let params = GovernanceParameters::default();
+        let tally = Tally::from((Vote::Yes, 10));
+
+        // With a 40% quorum, 30 more `yes` votes out of 100 are needed for the proposal to pass.
+        assert_eq!(tally.outcome(100, &params), Outcome::Fail);
+        assert_eq!(
+            tally.votes_to_flip(90, Tally::default(), 100, &params),
+            Some(30)
+        );
+        assert_eq!(
+            tally.votes_to_flip(29, Tally::default(), 100, &params),
+            None
+        );
+        assert_eq!(tally.votes_to_flip(0, Tally::default(), 100, &params), None);
+    }
+
+    #[test]
+    fn votes_to_flip_counts_overrides_on_both_sides() {
+        let params = GovernanceParameters::default();
+        let tally = Tally::from((Vote::Yes, 60));
+
+        // 60 more `no` votes would be needed to fail the proposal, but only 40 are unvoted.
+        assert_eq!(tally.outcome(100, &params), Outcome::Pass);
+        assert_eq!(
+            tally.votes_to_flip(40, Tally::default(), 100, &params),
+            None
+        );
+
+        // If delegators can override their validators' `yes` votes, each override removes a `yes`
+        // as well as adding a `no`, so 30 of them are enough.
+        assert_eq!(tally.votes_to_flip(40, tally, 100, &params), Some(30));
+    }
+}
@@ -1,7 +1,7 @@
 use crate::{
     component::{
         action_handler::ActionHandler, validator_handler::ValidatorDataRead,
-        validator_handler::ValidatorManager,
+        validator_handler::ValidatorManager, StateReadExt as _,
     },
     rate::RateData,
     validator,
@@ -11,6 +11,7 @@ use async_trait::async_trait;
 use cnidarium::StateWrite;
 use decaf377_rdsa::VerificationKey;
 use penumbra_sdk_proto::DomainType;
+use penumbra_sdk_sct::component::clock::EpochRead;
 
 #[async_trait]
 impl ActionHandler for validator::Definition {
@@ -119,13 +120,54 @@ impl ActionHandler for validator::Definition {
         /* ------------ execution ----------- */
         // If the validator is already defined, we update the definition.
         // Otherwise, we add the new validator and "prime" its state.
-        if prev_definition.is_some() {
-            state
-                .update_validator_definition(new_validator.clone())
-                .await
-                .context(
-                    "should be able to update validator during validator definition execution",
-                )?;
+        if let Some(prev_validator) = &prev_definition {
+            let old_commission_bps = prev_validator.funding_streams.total_rate_bps() as u64;
+            let new_commission_bps = new_validator.funding_streams.total_rate_bps() as u64;
+
+            if new_commission_bps > old_commission_bps {
+                // Commission increases are rate limited, and only take effect after a delay,
+                // so that delegators have a chance to undelegate before they apply.
+                let stake_params = state.get_stake_params().await?;
+                let max_increase_bps = stake_params.max_commission_increase_bps;
+                ensure!(
+                    max_increase_bps == 0
+                        || new_commission_bps - old_commission_bps <= max_increase_bps,
+                    "validator commission can increase by at most {}bps per epoch (from {}bps to {}bps)",
+                    max_increase_bps,
+                    old_commission_bps,
+                    new_commission_bps,
+                );
+
+                let current_epoch = state.get_current_epoch().await?;
+                let pending = validator::PendingCommissionIncrease {
+                    funding_streams: new_validator.funding_streams.clone(),
+                    effective_epoch_index: current_epoch
+                        .index
+                        .saturating_add(stake_params.commission_increase_delay_epochs),
+                };
+
+                // The rest of the definition is applied right away, but the validator
+                // keeps its current funding streams until the increase takes effect.
+                let mut definition = new_validator.clone();
+                definition.funding_streams = prev_validator.funding_streams.clone();
+                state
+                    .update_validator_definition(definition)
+                    .await
+                    .context(
+                        "should be able to update validator during validator definition execution",
+                    )?;
+                state.announce_commission_increase(&new_validator.identity_key, pending);
+            } else {
+                state
+                    .update_validator_definition(new_validator.clone())
+                    .await
+                    .context(
+                        "should be able to update validator during validator definition execution",
+                    )?;
+                // A definition that does not increase the commission supersedes any
+                // increase announced previously.
+                state.cancel_commission_increase(&new_validator.identity_key);
+            }
         } else {
             let validator_key = new_validator.identity_key;
 
@@ -1,5 +1,6 @@
 use crate::{
     component::{
+        history::ValidatorHistoryWrite,
         stake::{
             ConsensusIndexRead, ConsensusIndexWrite, ConsensusUpdateWrite, InternalStakingData,
             RateDataWrite,
@@ -9,9 +10,11 @@ use crate::{
         },
         SlashingData,
     },
-    rate::BaseRateData,
-    state_key, validator, CurrentConsensusKeys, FundingStreams, IdentityKey, Penalty, StateReadExt,
-    StateWriteExt, BPS_SQUARED_SCALING_FACTOR,
+    rate::{BaseRateData, RateData},
+    state_key,
+    validator::{self, EpochSummary},
+    CurrentConsensusKeys, FundingStreams, IdentityKey, Penalty, StateReadExt, StateWriteExt,
+    BPS_SQUARED_SCALING_FACTOR,
 };
 use anyhow::{Context, Result};
 use async_trait::async_trait;
@@ -80,6 +83,13 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
             "collected delegation changes for the epoch"
         );
 
+        // Apply the commission increases that are due, so that the new funding
+        // streams are used to compute the rates for the upcoming epoch. We keep
+        // the replaced ones around to describe the epoch that is ending.
+        let mut replaced_funding_streams = self
+            .apply_pending_commission_increases(epoch_to_end.index)
+            .await?;
+
         // Compute and set the chain base rate for the upcoming epoch.
         let next_base_rate = self.process_chain_base_rate().await?;
 
@@ -120,6 +130,8 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
                 .remove(validator_identity)
                 .unwrap_or_else(Amount::zero);
 
+            let ended_epoch_funding_streams = replaced_funding_streams.remove(validator_identity);
+
             if let Some(rewards) = self
                 .process_validator(
                     validator_identity,
@@ -127,6 +139,7 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
                     next_base_rate.clone(),
                     total_delegations,
                     total_undelegations,
+                    ended_epoch_funding_streams,
                 )
                 .await
                 .map_err(|e| {
@@ -166,6 +179,7 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         next_base_rate: BaseRateData,
         total_delegations: Amount,
         total_undelegations: Amount,
+        ended_epoch_funding_streams: Option<FundingStreams>,
     ) -> Result<Option<(IdentityKey, FundingStreams, Amount)>> {
         let validator = self.get_validator_definition(&validator_identity).await?.ok_or_else(|| {
             anyhow::anyhow!("validator (identity={}) is in consensus index but its definition was not found in the JMT", &validator_identity)
@@ -257,6 +271,20 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
             "calculated validator's voting power for the upcoming epoch"
         );
 
+        // Before overwriting it, record a summary of the validator's performance
+        // over the epoch we are ending, to serve historical queries. If its
+        // commission was just increased, the summary uses the previous one.
+        let ended_epoch_funding_streams =
+            ended_epoch_funding_streams.unwrap_or_else(|| validator.funding_streams.clone());
+        self.record_validator_epoch_summary(
+            &validator.identity_key,
+            &ended_epoch_funding_streams,
+            validator_state,
+            epoch_to_end,
+            prev_validator_rate_with_penalty.clone(),
+        )
+        .await?;
+
         // Update the state of the validator within the validator set
         // with the newly starting epoch's calculated voting rate and power.
         self.set_validator_rate_data(&validator.identity_key, next_validator_rate.clone());
@@ -315,6 +343,52 @@ pub trait EpochHandler: StateWriteExt + ConsensusIndexRead {
         Ok(reward_queue_entry)
     }
 
+    /// Record the [`EpochSummary`] of a validator for the epoch we are ending,
+    /// given the funding streams that were in effect during that epoch.
+    async fn record_validator_epoch_summary(
+        &mut self,
+        identity_key: &IdentityKey,
+        funding_streams: &FundingStreams,
+        validator_state: validator::State,
+        epoch_to_end: Epoch,
+        rate_data: RateData,
+    ) -> Result<()> {
+        let voting_power = self
+            .get_validator_power(identity_key)
+            .await?
+            .unwrap_or_else(Amount::zero);
+
+        let commission_bps = funding_streams
+            .iter()
+            .map(|stream| stream.rate_bps() as u32)
+            .sum();
+
+        let end_height = self.get_block_height().await?;
+        let epoch_heights = epoch_to_end.start_height..=end_height;
+        let (missed_blocks, tracked_blocks) = self
+            .get_validator_uptime(identity_key)
+            .await?
+            .map(|uptime| {
+                (
+                    uptime.num_missed_blocks_in(epoch_heights.clone()),
+                    uptime.num_tracked_blocks_in(epoch_heights),
+                )
+            })
+            .unwrap_or_default();
+
+        self.record_epoch_summary(EpochSummary {
+            identity_key: *identity_key,
+            epoch_index: epoch_to_end.index,
+            state: validator_state,
+            rate_data,
+            voting_power,
+            commission_bps,
+            missed_blocks,
+            tracked_blocks,
+        });
+        Ok(())
+    }
+
     /// Compute and return the chain base rate ("L1BOR").
     async fn process_chain_base_rate(&mut self) -> Result<BaseRateData> {
         // We are transitioning to the next epoch, so the "current" base rate in
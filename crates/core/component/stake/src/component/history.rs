@@ -0,0 +1,50 @@
+use std::{ops::Bound, pin::Pin};
+
+use anyhow::Result;
+use cnidarium::{StateRead, StateWrite};
+use futures::{Stream, StreamExt, TryStreamExt};
+use penumbra_sdk_proto::{DomainType, StateWriteProto};
+
+use crate::{state_key, validator::EpochSummary, IdentityKey};
+
+/// Provide access to the per-epoch performance summaries of validators.
+///
+/// The summaries are kept in nonverifiable storage, since they are only
+/// used to serve queries and are never read during execution.
+pub trait ValidatorHistoryRead: StateRead {
+    /// Returns a stream of the [`EpochSummary`] entries recorded for a validator,
+    /// from the epoch at `start_epoch_index` up to and including `end_epoch_index`,
+    /// or up to the latest recorded epoch if `end_epoch_index` is `None`.
+    fn validator_history(
+        &self,
+        identity_key: &IdentityKey,
+        start_epoch_index: u64,
+        end_epoch_index: Option<u64>,
+    ) -> Result<Pin<Box<dyn Stream<Item = Result<EpochSummary>> + Send + 'static>>> {
+        let prefix = state_key::history::prefix(identity_key);
+        let start = Bound::Included(state_key::history::epoch_key(start_epoch_index).into_bytes());
+        let end = match end_epoch_index {
+            Some(end) => Bound::Included(state_key::history::epoch_key(end).into_bytes()),
+            None => Bound::Unbounded,
+        };
+
+        Ok(self
+            .nonverifiable_range_raw(Some(prefix.as_bytes()), (start, end))?
+            .and_then(|(_, v)| async move { EpochSummary::decode(v.as_ref()) })
+            .boxed())
+    }
+}
+
+impl<T: StateRead + ?Sized> ValidatorHistoryRead for T {}
+
+pub(crate) trait ValidatorHistoryWrite: StateWrite {
+    /// Record the performance summary of a validator for the epoch it describes.
+    fn record_epoch_summary(&mut self, summary: EpochSummary) {
+        self.nonverifiable_put(
+            state_key::history::for_id_in_epoch(&summary.identity_key, summary.epoch_index).into(),
+            summary,
+        );
+    }
+}
+
+impl<T: StateWrite + ?Sized> ValidatorHistoryWrite for T {}
@@ -1,11 +1,13 @@
 pub mod action_handler;
 mod epoch_handler;
+mod history;
 pub mod metrics;
 pub mod rpc;
 pub mod stake;
 pub mod validator_handler;
 
 pub use self::{
+    history::ValidatorHistoryRead,
     metrics::register_metrics,
     stake::{ConsensusIndexRead, SlashingData, Staking, StateReadExt, StateWriteExt},
     validator_handler::validator_store::ValidatorPoolDeposit,
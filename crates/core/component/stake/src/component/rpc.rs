@@ -16,7 +16,9 @@ use tap::{TapFallible, TapOptional};
 use tonic::Status;
 use tracing::{error_span, instrument, Instrument, Span};
 
-use super::{validator_handler::ValidatorDataRead, ConsensusIndexRead, SlashingData};
+use super::{
+    validator_handler::ValidatorDataRead, ConsensusIndexRead, SlashingData, ValidatorHistoryRead,
+};
 use crate::validator::{Info, State};
 
 // TODO: Hide this and only expose a Router?
@@ -234,4 +236,46 @@ impl QueryService for Server {
             None => Err(Status::not_found("validator uptime not found")),
         }
     }
+
+    type ValidatorHistoryStream = Pin<
+        Box<dyn futures::Stream<Item = Result<ValidatorHistoryResponse, tonic::Status>> + Send>,
+    >;
+
+    #[instrument(skip(self, request))]
+    async fn validator_history(
+        &self,
+        request: tonic::Request<ValidatorHistoryRequest>,
+    ) -> Result<tonic::Response<Self::ValidatorHistoryStream>, Status> {
+        use futures::TryStreamExt;
+
+        let state = self.storage.latest_snapshot();
+        let ValidatorHistoryRequest {
+            identity_key,
+            start_epoch_index,
+            end_epoch_index,
+        } = request.into_inner();
+
+        let identity_key = identity_key
+            .ok_or_else(|| Status::invalid_argument("missing identity key"))?
+            .try_into()
+            .map_err(|_| Status::invalid_argument("invalid identity key"))?;
+
+        if matches!(end_epoch_index, Some(end) if end < start_epoch_index) {
+            return Err(Status::invalid_argument(
+                "end epoch index must not be less than start epoch index",
+            ));
+        }
+
+        let stream = state
+            .validator_history(&identity_key, start_epoch_index, end_epoch_index)
+            .map_err(|e| Status::internal(format!("error getting validator history: {e}")))?
+            .map_ok(|summary| ValidatorHistoryResponse {
+                summary: Some(summary.into()),
+            })
+            .map_err(|e: anyhow::Error| format!("error getting validator history: {e}"))
+            .map_err(Status::unavailable)
+            .boxed();
+
+        Ok(tonic::Response::new(stream))
+    }
 }
@@ -14,17 +14,19 @@ use {
         validator::{
             self,
             BondingState::*,
+            PendingCommissionIncrease,
             State::{self, *},
             Validator,
         },
-        DelegationToken, IdentityKey, Penalty, Uptime,
+        DelegationToken, FundingStreams, IdentityKey, Penalty, Uptime,
     },
-    anyhow::{ensure, Result},
+    anyhow::{ensure, Context, Result},
     async_trait::async_trait,
     cnidarium::StateWrite,
+    futures::TryStreamExt,
     penumbra_sdk_asset::asset,
     penumbra_sdk_num::Amount,
-    penumbra_sdk_proto::{DomainType as _, StateWriteProto},
+    penumbra_sdk_proto::{DomainType as _, StateReadProto, StateWriteProto},
     penumbra_sdk_sct::component::{
         clock::{EpochManager, EpochRead},
         StateReadExt as _,
@@ -615,6 +617,81 @@ pub trait ValidatorManager: StateWrite {
         Ok(())
     }
 
+    /// Record a commission increase announced by the validator, replacing any
+    /// increase it announced previously.
+    fn announce_commission_increase(
+        &mut self,
+        identity_key: &IdentityKey,
+        pending: PendingCommissionIncrease,
+    ) {
+        tracing::debug!(
+            validator_identity = %identity_key,
+            effective_epoch_index = pending.effective_epoch_index,
+            "announcing commission increase"
+        );
+        self.put(
+            state_key::validators::pending_commission_increase::by_id(identity_key),
+            pending,
+        );
+    }
+
+    /// Cancel the commission increase announced by the validator, if any.
+    fn cancel_commission_increase(&mut self, identity_key: &IdentityKey) {
+        self.delete(state_key::validators::pending_commission_increase::by_id(
+            identity_key,
+        ));
+    }
+
+    /// Apply the announced commission increases that take effect at the end of
+    /// the epoch with index `epoch_index`, by updating the funding streams of the
+    /// corresponding validator definitions.
+    ///
+    /// Returns the funding streams that were replaced, which were in effect
+    /// during the epoch that is ending.
+    #[instrument(skip(self))]
+    async fn apply_pending_commission_increases(
+        &mut self,
+        epoch_index: u64,
+    ) -> Result<BTreeMap<IdentityKey, FundingStreams>> {
+        let prefix = state_key::validators::pending_commission_increase::prefix();
+        let pending_increases: Vec<(String, PendingCommissionIncrease)> =
+            self.prefix(prefix).try_collect().await?;
+        let mut replaced = BTreeMap::new();
+
+        for (key, pending) in pending_increases {
+            if pending.effective_epoch_index > epoch_index {
+                continue;
+            }
+
+            let identity_key: IdentityKey = key
+                .strip_prefix(prefix)
+                .context("pending commission increase key has the expected prefix")?
+                .parse()?;
+
+            self.delete(key);
+
+            let Some(mut validator) = self.get_validator_definition(&identity_key).await? else {
+                tracing::warn!(validator_identity = %identity_key, "pending commission increase for an unknown validator");
+                continue;
+            };
+
+            tracing::debug!(
+                validator_identity = %identity_key,
+                new_commission_bps = pending.funding_streams.total_rate_bps(),
+                "applying commission increase"
+            );
+            let previous =
+                std::mem::replace(&mut validator.funding_streams, pending.funding_streams);
+            replaced.insert(identity_key, previous);
+            self.put(
+                state_key::validators::definitions::by_id(&identity_key),
+                validator,
+            );
+        }
+
+        Ok(replaced)
+    }
+
     /// Update the validator pool's bonding state.
     #[instrument(skip(self))]
     async fn process_validator_pool_state(
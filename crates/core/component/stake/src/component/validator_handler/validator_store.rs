@@ -28,12 +28,15 @@ pub trait ValidatorDataRead: StateRead {
         let validator = self.get_validator_definition(identity_key).await?;
         let status = self.get_validator_status(identity_key).await?;
         let rate_data = self.get_validator_rate(identity_key).await?;
+        let pending_commission_increase =
+            self.get_pending_commission_increase(identity_key).await?;
 
         match (validator, status, rate_data) {
             (Some(validator), Some(status), Some(rate_data)) => Ok(Some(validator::Info {
                 validator,
                 status,
                 rate_data,
+                pending_commission_increase,
             })),
             _ => Ok(None),
         }
@@ -124,6 +127,18 @@ pub trait ValidatorDataRead: StateRead {
             .await
     }
 
+    /// Returns the commission increase announced by the validator, if it
+    /// has not taken effect yet.
+    async fn get_pending_commission_increase(
+        &self,
+        identity_key: &IdentityKey,
+    ) -> Result<Option<validator::PendingCommissionIncrease>> {
+        self.get(&state_key::validators::pending_commission_increase::by_id(
+            identity_key,
+        ))
+        .await
+    }
+
     fn get_validator_uptime(
         &self,
         identity_key: &IdentityKey,
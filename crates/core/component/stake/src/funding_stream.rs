@@ -193,6 +193,11 @@ impl FundingStreams {
     pub fn len(&self) -> usize {
         self.funding_streams.len()
     }
+
+    /// Returns the total commission of the funding streams, in basis points.
+    pub fn total_rate_bps(&self) -> u16 {
+        self.funding_streams.iter().map(|fs| fs.rate_bps()).sum()
+    }
 }
 
 impl TryFrom<Vec<FundingStream>> for FundingStreams {
@@ -80,6 +80,15 @@ pub mod validators {
         }
     }
 
+    pub mod pending_commission_increase {
+        pub fn prefix() -> &'static str {
+            "staking/validators/data/pending_commission_increase/"
+        }
+        pub fn by_id(id: &crate::IdentityKey) -> String {
+            format!("{}{id}", prefix())
+        }
+    }
+
     pub mod last_disabled {
         pub fn by_id(id: &crate::IdentityKey) -> String {
             format!("staking/validators/data/last_disabled/{id}")
@@ -138,6 +147,25 @@ pub mod penalty {
     }
 }
 
+pub mod history {
+    use crate::IdentityKey;
+
+    pub fn prefix(id: &IdentityKey) -> String {
+        format!("staking/history/{id}/")
+    }
+
+    /// The summary of a validator's performance in the epoch with the given
+    /// index, in nonverifiable storage.
+    pub fn for_id_in_epoch(id: &IdentityKey, epoch_index: u64) -> String {
+        format!("{}{}", prefix(id), epoch_key(epoch_index))
+    }
+
+    pub fn epoch_key(epoch_index: u64) -> String {
+        // Padded so that the lex order agrees with the numeric order on epochs.
+        format!("{epoch_index:010}")
+    }
+}
+
 pub mod consensus_update {
     pub fn consensus_keys() -> &'static str {
         "staking/cometbft_data/consensus_keys"
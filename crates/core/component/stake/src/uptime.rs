@@ -1,3 +1,5 @@
+use std::ops::RangeInclusive;
+
 use bitvec::prelude::*;
 
 use penumbra_sdk_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
@@ -98,6 +100,26 @@ impl Uptime {
         })
     }
 
+    /// Counts the heights in `heights` that are covered by the window.
+    pub fn num_tracked_blocks_in(&self, heights: RangeInclusive<u64>) -> u64 {
+        let window_len = self.signatures.len() as u64;
+        let earliest_height = self.as_of_block_height.saturating_sub(window_len - 1);
+        let start = (*heights.start()).max(earliest_height);
+        let end = (*heights.end()).min(self.as_of_block_height);
+        if start > end {
+            0
+        } else {
+            end - start + 1
+        }
+    }
+
+    /// Counts the missed blocks over the window whose height is in `heights`.
+    pub fn num_missed_blocks_in(&self, heights: RangeInclusive<u64>) -> u64 {
+        self.missed_blocks()
+            .filter(|height| heights.contains(height))
+            .count() as u64
+    }
+
     /// Returns the block height up to which this tracker has recorded.
     pub fn as_of_height(&self) -> u64 {
         self.as_of_block_height
@@ -184,6 +206,25 @@ mod tests {
         assert_eq!(missed_blocks, vec![1]);
     }
 
+    #[test]
+    fn counts_blocks_in_range() {
+        let window = 10;
+        let mut uptime = Uptime::new(0, window);
+
+        // Miss every odd block, up to height 25.
+        for h in 1..=25u64 {
+            uptime.mark_height_as_signed(h, h % 2 == 0).unwrap();
+        }
+
+        // The window covers heights 16..=25.
+        assert_eq!(uptime.num_tracked_blocks_in(1..=25), 10);
+        assert_eq!(uptime.num_missed_blocks_in(1..=25), 5);
+        assert_eq!(uptime.num_tracked_blocks_in(20..=30), 6);
+        assert_eq!(uptime.num_missed_blocks_in(20..=30), 3);
+        assert_eq!(uptime.num_tracked_blocks_in(1..=15), 0);
+        assert_eq!(uptime.num_missed_blocks_in(1..=15), 0);
+    }
+
     proptest! {
         /// Ensure that the `Uptime` struct simulates a fixed size queue of (height, signed) tuples,
         /// and that the `missed_blocks` iterator returns the correct missed blocks.
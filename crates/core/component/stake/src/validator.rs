@@ -10,13 +10,17 @@ use crate::{DelegationToken, FundingStream, FundingStreams, GovernanceKey, Ident
 
 mod bonding;
 mod definition;
+mod epoch_summary;
 mod info;
+mod pending_commission;
 mod state;
 mod status;
 
 pub use bonding::State as BondingState;
 pub use definition::Definition;
+pub use epoch_summary::EpochSummary;
 pub use info::Info;
+pub use pending_commission::PendingCommissionIncrease;
 pub use state::State;
 pub use status::Status;
 
@@ -0,0 +1,90 @@
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{rate::RateData, validator::State, IdentityKey};
+
+/// A summary of a validator's performance over a single epoch, recorded when
+/// the epoch ends.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::ValidatorEpochSummary",
+    into = "pb::ValidatorEpochSummary"
+)]
+pub struct EpochSummary {
+    /// The validator's identity.
+    pub identity_key: IdentityKey,
+    /// The index of the summarized epoch.
+    pub epoch_index: u64,
+    /// The validator's state at the end of the epoch.
+    pub state: State,
+    /// The validator's rate data during the epoch, including any penalty recorded in it.
+    pub rate_data: RateData,
+    /// The validator's voting power during the epoch.
+    pub voting_power: Amount,
+    /// The total rate of the validator's funding streams during the epoch, in basis points.
+    pub commission_bps: u32,
+    /// The number of blocks of the epoch that the validator failed to sign.
+    pub missed_blocks: u64,
+    /// The number of blocks of the epoch covered by the validator's uptime window.
+    pub tracked_blocks: u64,
+}
+
+impl EpochSummary {
+    /// The fraction of the tracked blocks of the epoch that the validator signed,
+    /// or `None` if no blocks of the epoch were tracked.
+    pub fn signed_ratio(&self) -> Option<f64> {
+        if self.tracked_blocks == 0 {
+            return None;
+        }
+        let signed = self.tracked_blocks.saturating_sub(self.missed_blocks);
+        Some(signed as f64 / self.tracked_blocks as f64)
+    }
+}
+
+impl DomainType for EpochSummary {
+    type Proto = pb::ValidatorEpochSummary;
+}
+
+impl From<EpochSummary> for pb::ValidatorEpochSummary {
+    fn from(s: EpochSummary) -> Self {
+        pb::ValidatorEpochSummary {
+            identity_key: Some(s.identity_key.into()),
+            epoch_index: s.epoch_index,
+            state: Some(s.state.into()),
+            rate_data: Some(s.rate_data.into()),
+            voting_power: Some(s.voting_power.into()),
+            commission_bps: s.commission_bps,
+            missed_blocks: s.missed_blocks,
+            tracked_blocks: s.tracked_blocks,
+        }
+    }
+}
+
+impl TryFrom<pb::ValidatorEpochSummary> for EpochSummary {
+    type Error = anyhow::Error;
+    fn try_from(s: pb::ValidatorEpochSummary) -> Result<Self, Self::Error> {
+        Ok(EpochSummary {
+            identity_key: s
+                .identity_key
+                .ok_or_else(|| anyhow::anyhow!("missing identity key field in proto"))?
+                .try_into()?,
+            epoch_index: s.epoch_index,
+            state: s
+                .state
+                .ok_or_else(|| anyhow::anyhow!("missing state field in proto"))?
+                .try_into()?,
+            rate_data: s
+                .rate_data
+                .ok_or_else(|| anyhow::anyhow!("missing rate data field in proto"))?
+                .try_into()?,
+            voting_power: s
+                .voting_power
+                .ok_or_else(|| anyhow::anyhow!("missing voting power field in proto"))?
+                .try_into()?,
+            commission_bps: s.commission_bps,
+            missed_blocks: s.missed_blocks,
+            tracked_blocks: s.tracked_blocks,
+        })
+    }
+}
@@ -4,7 +4,7 @@ use penumbra_sdk_proto::{
 };
 use serde::{Deserialize, Serialize};
 
-use super::{Status, Validator};
+use super::{PendingCommissionIncrease, Status, Validator};
 use crate::rate::RateData;
 
 #[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
@@ -13,6 +13,8 @@ pub struct Info {
     pub validator: Validator,
     pub status: Status,
     pub rate_data: RateData,
+    /// A commission increase announced by the validator that has not taken effect yet.
+    pub pending_commission_increase: Option<PendingCommissionIncrease>,
 }
 
 impl DomainType for Info {
@@ -25,6 +27,7 @@ impl From<Info> for pb::ValidatorInfo {
             validator: Some(v.validator.into()),
             status: Some(v.status.into()),
             rate_data: Some(v.rate_data.into()),
+            pending_commission_increase: v.pending_commission_increase.map(Into::into),
         }
     }
 }
@@ -53,6 +56,10 @@ impl TryFrom<pb::ValidatorInfo> for Info {
                 .rate_data
                 .ok_or_else(|| anyhow::anyhow!("missing rate_data field in proto"))?
                 .try_into()?,
+            pending_commission_increase: v
+                .pending_commission_increase
+                .map(TryInto::try_into)
+                .transpose()?,
         })
     }
 }
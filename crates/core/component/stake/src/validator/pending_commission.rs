@@ -0,0 +1,49 @@
+use penumbra_sdk_proto::{penumbra::core::component::stake::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+use crate::{FundingStream, FundingStreams};
+
+/// A commission increase announced by a validator, that has not taken effect yet.
+///
+/// Increases of a validator's total commission are not applied when the validator
+/// uploads a new definition, but at the end of the epoch at `effective_epoch_index`,
+/// so that delegators have time to react to them.
+#[derive(Debug, PartialEq, Eq, Clone, Serialize, Deserialize)]
+#[serde(
+    try_from = "pb::PendingCommissionIncrease",
+    into = "pb::PendingCommissionIncrease"
+)]
+pub struct PendingCommissionIncrease {
+    /// The funding streams the validator will use once the increase takes effect.
+    pub funding_streams: FundingStreams,
+    /// The index of the epoch at the end of which the increase takes effect.
+    pub effective_epoch_index: u64,
+}
+
+impl DomainType for PendingCommissionIncrease {
+    type Proto = pb::PendingCommissionIncrease;
+}
+
+impl From<PendingCommissionIncrease> for pb::PendingCommissionIncrease {
+    fn from(p: PendingCommissionIncrease) -> Self {
+        pb::PendingCommissionIncrease {
+            funding_streams: p.funding_streams.into_iter().map(Into::into).collect(),
+            effective_epoch_index: p.effective_epoch_index,
+        }
+    }
+}
+
+impl TryFrom<pb::PendingCommissionIncrease> for PendingCommissionIncrease {
+    type Error = anyhow::Error;
+    fn try_from(p: pb::PendingCommissionIncrease) -> Result<Self, Self::Error> {
+        Ok(PendingCommissionIncrease {
+            funding_streams: p
+                .funding_streams
+                .into_iter()
+                .map(TryInto::try_into)
+                .collect::<Result<Vec<FundingStream>, _>>()?
+                .try_into()?,
+            effective_epoch_index: p.effective_epoch_index,
+        })
+    }
+}
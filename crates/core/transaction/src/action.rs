@@ -2,6 +2,9 @@ use anyhow::anyhow;
 use penumbra_sdk_auction::auction::dutch::actions::{
     ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
 };
+use penumbra_sdk_auction::auction::twap::actions::{
+    ActionTwapAuctionEnd, ActionTwapAuctionSchedule, ActionTwapAuctionWithdraw,
+};
 use penumbra_sdk_txhash::{EffectHash, EffectingData};
 use std::convert::{TryFrom, TryInto};
 
@@ -45,6 +48,9 @@ pub enum Action {
     ActionDutchAuctionSchedule(ActionDutchAuctionSchedule),
     ActionDutchAuctionEnd(ActionDutchAuctionEnd),
     ActionDutchAuctionWithdraw(ActionDutchAuctionWithdraw),
+    ActionTwapAuctionSchedule(ActionTwapAuctionSchedule),
+    ActionTwapAuctionEnd(ActionTwapAuctionEnd),
+    ActionTwapAuctionWithdraw(ActionTwapAuctionWithdraw),
     ActionLiquidityTournamentVote(
         penumbra_sdk_funding::liquidity_tournament::ActionLiquidityTournamentVote,
     ),
@@ -77,6 +83,9 @@ impl EffectingData for Action {
             Action::ActionDutchAuctionSchedule(a) => a.effect_hash(),
             Action::ActionDutchAuctionEnd(a) => a.effect_hash(),
             Action::ActionDutchAuctionWithdraw(a) => a.effect_hash(),
+            Action::ActionTwapAuctionSchedule(a) => a.effect_hash(),
+            Action::ActionTwapAuctionEnd(a) => a.effect_hash(),
+            Action::ActionTwapAuctionWithdraw(a) => a.effect_hash(),
             Action::ActionLiquidityTournamentVote(a) => a.effect_hash(),
         }
     }
@@ -129,6 +138,13 @@ impl Action {
             Action::ActionDutchAuctionWithdraw(_) => {
                 tracing::info_span!("ActionDutchAuctionWithdraw", ?idx)
             }
+            Action::ActionTwapAuctionSchedule(_) => {
+                tracing::info_span!("ActionTwapAuctionSchedule", ?idx)
+            }
+            Action::ActionTwapAuctionEnd(_) => tracing::info_span!("ActionTwapAuctionEnd", ?idx),
+            Action::ActionTwapAuctionWithdraw(_) => {
+                tracing::info_span!("ActionTwapAuctionWithdraw", ?idx)
+            }
             Action::ActionLiquidityTournamentVote(_) => {
                 tracing::info_span!("ActionLiquidityTournamentVote", ?idx)
             }
@@ -162,6 +178,9 @@ impl Action {
             Action::ActionDutchAuctionSchedule(_) => 53,
             Action::ActionDutchAuctionEnd(_) => 54,
             Action::ActionDutchAuctionWithdraw(_) => 55,
+            Action::ActionTwapAuctionSchedule(_) => 56,
+            Action::ActionTwapAuctionEnd(_) => 57,
+            Action::ActionTwapAuctionWithdraw(_) => 58,
             Action::ActionLiquidityTournamentVote(_) => 70,
         }
     }
@@ -196,6 +215,9 @@ impl IsAction for Action {
             Action::ActionDutchAuctionSchedule(action) => action.balance_commitment(),
             Action::ActionDutchAuctionEnd(action) => action.balance_commitment(),
             Action::ActionDutchAuctionWithdraw(action) => action.balance_commitment(),
+            Action::ActionTwapAuctionSchedule(action) => action.balance_commitment(),
+            Action::ActionTwapAuctionEnd(action) => action.balance_commitment(),
+            Action::ActionTwapAuctionWithdraw(action) => action.balance_commitment(),
             Action::ActionLiquidityTournamentVote(action) => action.balance_commitment(),
         }
     }
@@ -226,6 +248,9 @@ impl IsAction for Action {
             Action::ActionDutchAuctionSchedule(x) => x.view_from_perspective(txp),
             Action::ActionDutchAuctionEnd(x) => x.view_from_perspective(txp),
             Action::ActionDutchAuctionWithdraw(x) => x.view_from_perspective(txp),
+            Action::ActionTwapAuctionSchedule(x) => x.view_from_perspective(txp),
+            Action::ActionTwapAuctionEnd(x) => x.view_from_perspective(txp),
+            Action::ActionTwapAuctionWithdraw(x) => x.view_from_perspective(txp),
             Action::ActionLiquidityTournamentVote(x) => x.view_from_perspective(txp),
         }
     }
@@ -310,6 +335,15 @@ impl From<Action> for pb::Action {
             Action::ActionDutchAuctionWithdraw(inner) => pb::Action {
                 action: Some(pb::action::Action::ActionDutchAuctionWithdraw(inner.into())),
             },
+            Action::ActionTwapAuctionSchedule(inner) => pb::Action {
+                action: Some(pb::action::Action::ActionTwapAuctionSchedule(inner.into())),
+            },
+            Action::ActionTwapAuctionEnd(inner) => pb::Action {
+                action: Some(pb::action::Action::ActionTwapAuctionEnd(inner.into())),
+            },
+            Action::ActionTwapAuctionWithdraw(inner) => pb::Action {
+                action: Some(pb::action::Action::ActionTwapAuctionWithdraw(inner.into())),
+            },
             Action::ActionLiquidityTournamentVote(inner) => pb::Action {
                 action: Some(pb::action::Action::ActionLiquidityTournamentVote(
                     inner.into(),
@@ -389,6 +423,15 @@ impl TryFrom<pb::Action> for Action {
             pb::action::Action::ActionDutchAuctionWithdraw(inner) => {
                 Ok(Action::ActionDutchAuctionWithdraw(inner.try_into()?))
             }
+            pb::action::Action::ActionTwapAuctionSchedule(inner) => {
+                Ok(Action::ActionTwapAuctionSchedule(inner.try_into()?))
+            }
+            pb::action::Action::ActionTwapAuctionEnd(inner) => {
+                Ok(Action::ActionTwapAuctionEnd(inner.try_into()?))
+            }
+            pb::action::Action::ActionTwapAuctionWithdraw(inner) => {
+                Ok(Action::ActionTwapAuctionWithdraw(inner.try_into()?))
+            }
             pb::action::Action::ActionLiquidityTournamentVote(inner) => {
                 Ok(Action::ActionLiquidityTournamentVote(inner.try_into()?))
             }
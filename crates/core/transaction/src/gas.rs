@@ -1,6 +1,9 @@
 use penumbra_sdk_auction::auction::dutch::actions::{
     ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
 };
+use penumbra_sdk_auction::auction::twap::actions::{
+    ActionTwapAuctionEnd, ActionTwapAuctionSchedule, ActionTwapAuctionWithdraw,
+};
 use penumbra_sdk_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_sdk_dex::{
     lp::plan::PositionOpenPlan, PositionClose, PositionOpen, PositionWithdraw, Swap, SwapClaim,
@@ -272,6 +275,23 @@ fn dutch_auction_schedule_gas_cost(dutch_action_schedule: &ActionDutchAuctionSch
     }
 }
 
+fn twap_auction_schedule_gas_cost(twap_action_schedule: &ActionTwapAuctionSchedule) -> Gas {
+    Gas {
+        // penumbra.core.asset.v1.Value `input` = 48 bytes
+        // penumbra.core.asset.v1.AssetId `output_id` = 32 bytes
+        // penumbra.core.num.v1.Amount `min_output` = 16 bytes
+        // uint64 `start_height` = 8 bytes
+        // uint64 `end_height` = 8 bytes
+        // uint64 `step_count` = 8 bytes
+        // bytes `nonce` = 32 bytes
+        block_space: 152,
+        compact_block_space: 0,
+        verification: 50,
+        // Like Dutch auctions, each step opens and closes a position.
+        execution: 2 * twap_action_schedule.description.step_count * (10 + 10),
+    }
+}
+
 fn dutch_auction_end_gas_cost() -> Gas {
     Gas {
         // AuctionId `auction_id` = 32 bytes
@@ -359,6 +379,10 @@ impl GasCost for ActionPlan {
             ActionPlan::ActionDutchAuctionSchedule(das) => das.gas_cost(),
             ActionPlan::ActionDutchAuctionEnd(_) => dutch_auction_end_gas_cost(),
             ActionPlan::ActionDutchAuctionWithdraw(_) => dutch_auction_withdraw_gas_cost(),
+            ActionPlan::ActionTwapAuctionSchedule(tas) => tas.gas_cost(),
+            // TWAP auction end and withdrawals have the same shape as their Dutch counterparts.
+            ActionPlan::ActionTwapAuctionEnd(_) => dutch_auction_end_gas_cost(),
+            ActionPlan::ActionTwapAuctionWithdraw(_) => dutch_auction_withdraw_gas_cost(),
 
             ActionPlan::Delegate(d) => d.gas_cost(),
             ActionPlan::Undelegate(u) => u.gas_cost(),
@@ -412,6 +436,15 @@ impl GasCost for Action {
             Action::ActionDutchAuctionWithdraw(action_dutch_auction_withdraw) => {
                 action_dutch_auction_withdraw.gas_cost()
             }
+            Action::ActionTwapAuctionSchedule(action_twap_auction_schedule) => {
+                action_twap_auction_schedule.gas_cost()
+            }
+            Action::ActionTwapAuctionEnd(action_twap_auction_end) => {
+                action_twap_auction_end.gas_cost()
+            }
+            Action::ActionTwapAuctionWithdraw(action_twap_auction_withdraw) => {
+                action_twap_auction_withdraw.gas_cost()
+            }
             Action::ActionLiquidityTournamentVote(action_liquidity_tournament_vote) => {
                 action_liquidity_tournament_vote.gas_cost()
             }
@@ -716,6 +749,24 @@ impl GasCost for ActionDutchAuctionWithdraw {
     }
 }
 
+impl GasCost for ActionTwapAuctionSchedule {
+    fn gas_cost(&self) -> Gas {
+        twap_auction_schedule_gas_cost(&self)
+    }
+}
+
+impl GasCost for ActionTwapAuctionEnd {
+    fn gas_cost(&self) -> Gas {
+        dutch_auction_end_gas_cost()
+    }
+}
+
+impl GasCost for ActionTwapAuctionWithdraw {
+    fn gas_cost(&self) -> Gas {
+        dutch_auction_withdraw_gas_cost()
+    }
+}
+
 impl GasCost for ActionLiquidityTournamentVote {
     fn gas_cost(&self) -> Gas {
         liquidity_tournament_vote_gas_cost()
@@ -5,6 +5,10 @@ use penumbra_sdk_auction::auction::dutch::actions::{
     view::{ActionDutchAuctionScheduleView, ActionDutchAuctionWithdrawView},
     ActionDutchAuctionEnd, ActionDutchAuctionSchedule, ActionDutchAuctionWithdraw,
 };
+use penumbra_sdk_auction::auction::twap::actions::{
+    view::{ActionTwapAuctionScheduleView, ActionTwapAuctionWithdrawView},
+    ActionTwapAuctionEnd, ActionTwapAuctionSchedule, ActionTwapAuctionWithdraw,
+};
 use penumbra_sdk_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_sdk_dex::{
     lp::{
@@ -517,6 +521,46 @@ impl IsAction for ActionDutchAuctionWithdraw {
     }
 }
 
+impl IsAction for ActionTwapAuctionSchedule {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, txp: &TransactionPerspective) -> ActionView {
+        let view = ActionTwapAuctionScheduleView {
+            action: self.to_owned(),
+            auction_id: self.description.id(),
+            input_metadata: txp.denoms.get_by_id(self.description.input.asset_id),
+            output_metadata: txp.denoms.get_by_id(self.description.output_id),
+        };
+        ActionView::ActionTwapAuctionSchedule(view)
+    }
+}
+
+impl IsAction for ActionTwapAuctionEnd {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance().commit(Fr::zero())
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        ActionView::ActionTwapAuctionEnd(self.to_owned())
+    }
+}
+
+impl IsAction for ActionTwapAuctionWithdraw {
+    fn balance_commitment(&self) -> balance::Commitment {
+        self.balance_commitment()
+    }
+
+    fn view_from_perspective(&self, _txp: &TransactionPerspective) -> ActionView {
+        let view = ActionTwapAuctionWithdrawView {
+            action: self.to_owned(),
+            reserves: vec![],
+        };
+        ActionView::ActionTwapAuctionWithdraw(view)
+    }
+}
+
 impl IsAction for ActionLiquidityTournamentVote {
     fn balance_commitment(&self) -> balance::Commitment {
         self.balance_commitment()
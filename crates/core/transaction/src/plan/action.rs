@@ -7,6 +7,9 @@ use penumbra_sdk_asset::Balance;
 use penumbra_sdk_auction::auction::dutch::actions::ActionDutchAuctionEnd;
 use penumbra_sdk_auction::auction::dutch::actions::ActionDutchAuctionSchedule;
 use penumbra_sdk_auction::auction::dutch::actions::ActionDutchAuctionWithdrawPlan;
+use penumbra_sdk_auction::auction::twap::actions::ActionTwapAuctionEnd;
+use penumbra_sdk_auction::auction::twap::actions::ActionTwapAuctionSchedule;
+use penumbra_sdk_auction::auction::twap::actions::ActionTwapAuctionWithdrawPlan;
 use penumbra_sdk_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_sdk_dex::PositionOpen;
 use penumbra_sdk_dex::{
@@ -83,6 +86,9 @@ pub enum ActionPlan {
     ActionDutchAuctionSchedule(ActionDutchAuctionSchedule),
     ActionDutchAuctionEnd(ActionDutchAuctionEnd),
     ActionDutchAuctionWithdraw(ActionDutchAuctionWithdrawPlan),
+    ActionTwapAuctionSchedule(ActionTwapAuctionSchedule),
+    ActionTwapAuctionEnd(ActionTwapAuctionEnd),
+    ActionTwapAuctionWithdraw(ActionTwapAuctionWithdrawPlan),
 
     ActionLiquidityTournamentVote(ActionLiquidityTournamentVotePlan),
 }
@@ -168,6 +174,9 @@ impl ActionPlan {
             ActionDutchAuctionWithdraw(plan) => {
                 Action::ActionDutchAuctionWithdraw(plan.to_action())
             }
+            ActionTwapAuctionSchedule(plan) => Action::ActionTwapAuctionSchedule(plan.clone()),
+            ActionTwapAuctionEnd(plan) => Action::ActionTwapAuctionEnd(plan.clone()),
+            ActionTwapAuctionWithdraw(plan) => Action::ActionTwapAuctionWithdraw(plan.to_action()),
             ActionLiquidityTournamentVote(plan) => {
                 let note_commitment = plan.staked_note.commit();
                 let auth_path = witness_data
@@ -210,6 +219,9 @@ impl ActionPlan {
             ActionPlan::ActionDutchAuctionSchedule(_) => 53,
             ActionPlan::ActionDutchAuctionEnd(_) => 54,
             ActionPlan::ActionDutchAuctionWithdraw(_) => 55,
+            ActionPlan::ActionTwapAuctionSchedule(_) => 56,
+            ActionPlan::ActionTwapAuctionEnd(_) => 57,
+            ActionPlan::ActionTwapAuctionWithdraw(_) => 58,
             ActionPlan::ActionLiquidityTournamentVote(_) => 70,
         }
     }
@@ -239,6 +251,9 @@ impl ActionPlan {
             ActionDutchAuctionSchedule(action) => action.balance(),
             ActionDutchAuctionEnd(action) => action.balance(),
             ActionDutchAuctionWithdraw(action) => action.balance(),
+            ActionTwapAuctionSchedule(action) => action.balance(),
+            ActionTwapAuctionEnd(action) => action.balance(),
+            ActionTwapAuctionWithdraw(action) => action.balance(),
 
             // None of these contribute to transaction balance:
             IbcAction(_)
@@ -276,6 +291,9 @@ impl ActionPlan {
             ActionDutchAuctionSchedule(_) => Fr::zero(),
             ActionDutchAuctionEnd(_) => Fr::zero(),
             ActionDutchAuctionWithdraw(_) => Fr::zero(),
+            ActionTwapAuctionSchedule(_) => Fr::zero(),
+            ActionTwapAuctionEnd(_) => Fr::zero(),
+            ActionTwapAuctionWithdraw(_) => Fr::zero(),
             ActionLiquidityTournamentVote(_) => Fr::zero(),
         }
     }
@@ -309,6 +327,9 @@ impl ActionPlan {
             ActionDutchAuctionSchedule(plan) => plan.effect_hash(),
             ActionDutchAuctionEnd(plan) => plan.effect_hash(),
             ActionDutchAuctionWithdraw(plan) => plan.to_action().effect_hash(),
+            ActionTwapAuctionSchedule(plan) => plan.effect_hash(),
+            ActionTwapAuctionEnd(plan) => plan.effect_hash(),
+            ActionTwapAuctionWithdraw(plan) => plan.to_action().effect_hash(),
             ActionLiquidityTournamentVote(plan) => plan.to_body(fvk).effect_hash(),
         }
     }
@@ -448,6 +469,24 @@ impl From<ActionDutchAuctionWithdrawPlan> for ActionPlan {
     }
 }
 
+impl From<ActionTwapAuctionSchedule> for ActionPlan {
+    fn from(inner: ActionTwapAuctionSchedule) -> ActionPlan {
+        ActionPlan::ActionTwapAuctionSchedule(inner)
+    }
+}
+
+impl From<ActionTwapAuctionEnd> for ActionPlan {
+    fn from(inner: ActionTwapAuctionEnd) -> ActionPlan {
+        ActionPlan::ActionTwapAuctionEnd(inner)
+    }
+}
+
+impl From<ActionTwapAuctionWithdrawPlan> for ActionPlan {
+    fn from(inner: ActionTwapAuctionWithdrawPlan) -> ActionPlan {
+        ActionPlan::ActionTwapAuctionWithdraw(inner)
+    }
+}
+
 impl From<ProposalWithdraw> for ActionPlan {
     fn from(inner: ProposalWithdraw) -> ActionPlan {
         ActionPlan::ProposalWithdraw(inner)
@@ -559,6 +598,21 @@ impl From<ActionPlan> for pb_t::ActionPlan {
                     inner.into(),
                 )),
             },
+            ActionPlan::ActionTwapAuctionSchedule(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ActionTwapAuctionSchedule(
+                    inner.into(),
+                )),
+            },
+            ActionPlan::ActionTwapAuctionEnd(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ActionTwapAuctionEnd(
+                    inner.into(),
+                )),
+            },
+            ActionPlan::ActionTwapAuctionWithdraw(inner) => pb_t::ActionPlan {
+                action: Some(pb_t::action_plan::Action::ActionTwapAuctionWithdraw(
+                    inner.into(),
+                )),
+            },
             ActionPlan::ActionLiquidityTournamentVote(inner) => pb_t::ActionPlan {
                 action: Some(pb_t::action_plan::Action::ActionLiquidityTournamentVote(
                     inner.into(),
@@ -654,6 +708,15 @@ impl TryFrom<pb_t::ActionPlan> for ActionPlan {
             pb_t::action_plan::Action::ActionDutchAuctionWithdraw(inner) => {
                 Ok(ActionPlan::ActionDutchAuctionWithdraw(inner.try_into()?))
             }
+            pb_t::action_plan::Action::ActionTwapAuctionSchedule(inner) => {
+                Ok(ActionPlan::ActionTwapAuctionSchedule(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::ActionTwapAuctionEnd(inner) => {
+                Ok(ActionPlan::ActionTwapAuctionEnd(inner.try_into()?))
+            }
+            pb_t::action_plan::Action::ActionTwapAuctionWithdraw(inner) => {
+                Ok(ActionPlan::ActionTwapAuctionWithdraw(inner.try_into()?))
+            }
             pb_t::action_plan::Action::Ics20Withdrawal(inner) => {
                 Ok(ActionPlan::Ics20Withdrawal(inner.try_into()?))
             }
@@ -276,6 +276,9 @@ impl Transaction {
                 | Action::ActionDutchAuctionSchedule(_)
                 | Action::ActionDutchAuctionEnd(_)
                 | Action::ActionDutchAuctionWithdraw(_)
+                | Action::ActionTwapAuctionSchedule(_)
+                | Action::ActionTwapAuctionEnd(_)
+                | Action::ActionTwapAuctionWithdraw(_)
                 | Action::ActionLiquidityTournamentVote(_) => {}
             }
         }
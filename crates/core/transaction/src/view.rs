@@ -705,6 +705,9 @@ mod test {
             ActionPlan::ActionDutchAuctionSchedule(_) => None,
             ActionPlan::ActionDutchAuctionEnd(_) => None,
             ActionPlan::ActionDutchAuctionWithdraw(_) => None,
+            ActionPlan::ActionTwapAuctionSchedule(_) => None,
+            ActionPlan::ActionTwapAuctionEnd(_) => None,
+            ActionPlan::ActionTwapAuctionWithdraw(_) => None,
             ActionPlan::IbcAction(_) => todo!(),
             ActionPlan::ActionLiquidityTournamentVote(_) => todo!(),
         }
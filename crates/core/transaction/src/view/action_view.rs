@@ -2,6 +2,10 @@ use penumbra_sdk_auction::auction::dutch::{
     actions::view::{ActionDutchAuctionScheduleView, ActionDutchAuctionWithdrawView},
     ActionDutchAuctionEnd,
 };
+use penumbra_sdk_auction::auction::twap::{
+    actions::view::{ActionTwapAuctionScheduleView, ActionTwapAuctionWithdrawView},
+    ActionTwapAuctionEnd,
+};
 use penumbra_sdk_community_pool::{CommunityPoolDeposit, CommunityPoolOutput, CommunityPoolSpend};
 use penumbra_sdk_dex::{
     lp::{
@@ -57,6 +61,9 @@ pub enum ActionView {
     ActionDutchAuctionSchedule(ActionDutchAuctionScheduleView),
     ActionDutchAuctionEnd(ActionDutchAuctionEnd),
     ActionDutchAuctionWithdraw(ActionDutchAuctionWithdrawView),
+    ActionTwapAuctionSchedule(ActionTwapAuctionScheduleView),
+    ActionTwapAuctionEnd(ActionTwapAuctionEnd),
+    ActionTwapAuctionWithdraw(ActionTwapAuctionWithdrawView),
     ActionLiquidityTournamentVote(ActionLiquidityTournamentVoteView),
 }
 
@@ -109,6 +116,13 @@ impl TryFrom<pbt::ActionView> for ActionView {
                 AV::ActionDutchAuctionWithdraw(x) => {
                     ActionView::ActionDutchAuctionWithdraw(x.try_into()?)
                 }
+                AV::ActionTwapAuctionSchedule(x) => {
+                    ActionView::ActionTwapAuctionSchedule(x.try_into()?)
+                }
+                AV::ActionTwapAuctionEnd(x) => ActionView::ActionTwapAuctionEnd(x.try_into()?),
+                AV::ActionTwapAuctionWithdraw(x) => {
+                    ActionView::ActionTwapAuctionWithdraw(x.try_into()?)
+                }
                 AV::ActionLiquidityTournamentVote(x) => {
                     ActionView::ActionLiquidityTournamentVote(x.try_into()?)
                 }
@@ -151,6 +165,9 @@ impl From<ActionView> for pbt::ActionView {
                 ActionView::ActionDutchAuctionWithdraw(x) => {
                     AV::ActionDutchAuctionWithdraw(x.into())
                 }
+                ActionView::ActionTwapAuctionSchedule(x) => AV::ActionTwapAuctionSchedule(x.into()),
+                ActionView::ActionTwapAuctionEnd(x) => AV::ActionTwapAuctionEnd(x.into()),
+                ActionView::ActionTwapAuctionWithdraw(x) => AV::ActionTwapAuctionWithdraw(x.into()),
                 ActionView::ActionLiquidityTournamentVote(x) => {
                     AV::ActionLiquidityTournamentVote(x.into())
                 }
@@ -190,6 +207,9 @@ impl From<ActionView> for Action {
             ActionView::ActionDutchAuctionWithdraw(x) => {
                 Action::ActionDutchAuctionWithdraw(x.into())
             }
+            ActionView::ActionTwapAuctionSchedule(x) => Action::ActionTwapAuctionSchedule(x.into()),
+            ActionView::ActionTwapAuctionEnd(x) => Action::ActionTwapAuctionEnd(x),
+            ActionView::ActionTwapAuctionWithdraw(x) => Action::ActionTwapAuctionWithdraw(x.into()),
             ActionView::ActionLiquidityTournamentVote(x) => {
                 Action::ActionLiquidityTournamentVote(x.into())
             }
@@ -491,6 +491,7 @@ fn position_strategy() -> impl Strategy<Value = Position> {
             phi,
             nonce: [0u8; 32],
             close_on_fill: true,
+            expires_at_height: None,
         })
 }
 
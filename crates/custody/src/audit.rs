@@ -0,0 +1,631 @@
+//! A custody middleware that records every authorization decision in a
+//! tamper-evident, hash-chained audit log.
+//!
+//! The log is a file of newline-delimited JSON [`Entry`] records.  Each entry
+//! commits to the hash of the entry before it, so removing, reordering, or
+//! editing any entry breaks the chain from that point on, which can be detected
+//! with [`verify`].
+//!
+//! Removing entries from the end of the log leaves the rest of the chain
+//! intact, so the number of entries and the hash of the last one are also kept
+//! in a separate [`Head`] file next to the log, which the log must extend.
+
+use std::{
+    io::{BufRead, BufReader, Write},
+    path::{Path, PathBuf},
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use anyhow::Context;
+use penumbra_sdk_asset::asset::Cache;
+use penumbra_sdk_keys::FullViewingKey;
+use penumbra_sdk_proto::custody::v1::{self as pb, AuthorizeResponse};
+use penumbra_sdk_transaction::TransactionPlan;
+use serde::{Deserialize, Serialize};
+use tokio::sync::Mutex;
+use tonic::{async_trait, Request, Response, Status};
+
+use crate::{
+    render, AuthorizeRequest, AuthorizeValidatorDefinitionRequest, AuthorizeValidatorVoteRequest,
+    PreAuthorization,
+};
+
+/// The hash of the (nonexistent) entry preceding the first entry in a log.
+const GENESIS_HASH: [u8; 32] = [0u8; 32];
+
+/// A single record in the audit log.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Entry {
+    #[serde(flatten)]
+    pub body: EntryBody,
+    /// The hex-encoded hash of this entry, committing to the body and the
+    /// previous entry's hash.
+    pub hash: String,
+}
+
+/// The contents of an audit log entry, excluding its own hash.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct EntryBody {
+    /// The position of this entry in the log, starting at zero.
+    pub index: u64,
+    /// The time the decision was recorded, in seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// The hex-encoded hash of the preceding entry.
+    pub previous: String,
+    /// A summary of what was asked to be signed.
+    pub request: RequestSummary,
+    /// The pre-authorizations attached to the request, if any.
+    pub pre_authorizations: Vec<PreAuthorizationSummary>,
+    /// Whether the inner custody service authorized the request.
+    pub outcome: Outcome,
+}
+
+impl EntryBody {
+    fn hash(&self) -> anyhow::Result<[u8; 32]> {
+        let previous = parse_hash(&self.previous)?;
+        let body = serde_json::to_vec(self)?;
+        let hash = blake2b_simd::Params::new()
+            .personal(b"PenumbraCustAud")
+            .hash_length(32)
+            .to_state()
+            .update(&previous)
+            .update(&body)
+            .finalize();
+        Ok(hash.as_bytes().try_into().expect("hash length is 32 bytes"))
+    }
+}
+
+/// A summary of a signing request, recorded in the audit log.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum RequestSummary {
+    Transaction {
+        /// The hex-encoded effect hash of the planned transaction.
+        effect_hash: String,
+        chain_id: String,
+        fee: String,
+        /// A human-readable description of each action in the plan, in plan
+        /// order, as rendered by [`render::action`].
+        actions: Vec<String>,
+        /// The outputs of the plan, with their destination addresses.
+        destinations: Vec<Destination>,
+    },
+    ValidatorDefinition {
+        identity_key: String,
+        name: String,
+        sequence_number: u32,
+    },
+    ValidatorVote {
+        identity_key: String,
+        proposal: u64,
+        vote: String,
+    },
+}
+
+/// An output of a transaction plan.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Destination {
+    pub address: String,
+    pub value: String,
+}
+
+/// The identity of a pre-authorization attached to a request.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "method")]
+pub enum PreAuthorizationSummary {
+    Ed25519 {
+        /// The hex-encoded verification key of the pre-authorizing signer.
+        vk: String,
+    },
+}
+
+impl From<&PreAuthorization> for PreAuthorizationSummary {
+    fn from(pre_auth: &PreAuthorization) -> Self {
+        match pre_auth {
+            PreAuthorization::Ed25519(ed) => PreAuthorizationSummary::Ed25519 {
+                vk: hex::encode(ed.vk.as_bytes()),
+            },
+        }
+    }
+}
+
+/// The decision made by the inner custody service.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(tag = "decision", rename_all = "snake_case")]
+pub enum Outcome {
+    Approved,
+    Denied { reason: String },
+}
+
+impl<T> From<&Result<T, Status>> for Outcome {
+    fn from(result: &Result<T, Status>) -> Self {
+        match result {
+            Ok(_) => Outcome::Approved,
+            Err(status) => Outcome::Denied {
+                reason: status.message().to_string(),
+            },
+        }
+    }
+}
+
+impl RequestSummary {
+    /// Summarize a transaction plan, using the FVK to compute its effect hash.
+    pub fn transaction(plan: &TransactionPlan, fvk: &FullViewingKey) -> anyhow::Result<Self> {
+        let cache = Cache::with_known_assets();
+        Ok(RequestSummary::Transaction {
+            effect_hash: hex::encode(plan.effect_hash(fvk)?.as_bytes()),
+            chain_id: plan.transaction_parameters.chain_id.clone(),
+            fee: plan.transaction_parameters.fee.0.format(&cache),
+            actions: plan
+                .actions
+                .iter()
+                .map(|action| render::action(action, &cache, Some(fvk)))
+                .collect(),
+            destinations: plan
+                .output_plans()
+                .map(|output| Destination {
+                    address: output.dest_address.to_string(),
+                    value: output.value.format(&cache),
+                })
+                .collect(),
+        })
+    }
+}
+
+/// The head of an audit log, persisted next to it: the number of entries in the
+/// log and the hash of the last one.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct Head {
+    pub count: u64,
+    /// The hex-encoded hash of the last entry.
+    pub hash: String,
+}
+
+impl Head {
+    /// The path of the head file for the audit log at `path`.
+    pub fn path(log: &Path) -> PathBuf {
+        let mut path = log.as_os_str().to_owned();
+        path.push(".head");
+        path.into()
+    }
+
+    fn read(log: &Path) -> anyhow::Result<Option<Self>> {
+        let path = Self::path(log);
+        if !path.exists() {
+            return Ok(None);
+        }
+        let contents = std::fs::read_to_string(&path)
+            .with_context(|| format!("failed to read audit log head {}", path.display()))?;
+        Ok(Some(serde_json::from_str(&contents).with_context(
+            || format!("audit log head {} is malformed", path.display()),
+        )?))
+    }
+
+    /// Atomically replace the head file for the audit log at `log`.
+    fn write(&self, log: &Path) -> anyhow::Result<()> {
+        let path = Self::path(log);
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        let tmp = PathBuf::from(tmp);
+
+        let mut file = std::fs::File::create(&tmp)
+            .with_context(|| format!("failed to write audit log head {}", tmp.display()))?;
+        file.write_all(serde_json::to_string(self)?.as_bytes())?;
+        file.sync_data()?;
+        std::fs::rename(&tmp, &path)
+            .with_context(|| format!("failed to write audit log head {}", path.display()))?;
+        Ok(())
+    }
+}
+
+fn parse_hash(hex_hash: &str) -> anyhow::Result<[u8; 32]> {
+    hex::decode(hex_hash)?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("hash must be 32 bytes"))
+}
+
+/// Verify the hash chain of the audit log at `path`, returning the number of entries.
+///
+/// Fails on the first entry whose index, back-link, or hash does not match, or
+/// if the log does not extend the [`Head`] recorded next to it.
+pub fn verify(path: impl AsRef<Path>) -> anyhow::Result<u64> {
+    let (count, _) = read_chain(path.as_ref())?;
+    Ok(count)
+}
+
+/// Reads and checks the whole log, returning the number of entries and the hash
+/// of the last entry.
+fn read_chain(path: &Path) -> anyhow::Result<(u64, [u8; 32])> {
+    let file = std::fs::File::open(path)
+        .with_context(|| format!("failed to open audit log {}", path.display()))?;
+    let head = Head::read(path)?;
+
+    let mut count = 0u64;
+    let mut previous = GENESIS_HASH;
+    // The hash at the position recorded in the head, once the chain reaches it.
+    let mut at_head = None;
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let entry: Entry = serde_json::from_str(&line)
+            .with_context(|| format!("audit log entry {count} is malformed"))?;
+
+        anyhow::ensure!(
+            entry.body.index == count,
+            "audit log entry {count} has index {}",
+            entry.body.index
+        );
+        anyhow::ensure!(
+            parse_hash(&entry.body.previous)? == previous,
+            "audit log entry {count} does not link to the previous entry"
+        );
+        let hash = entry.body.hash()?;
+        anyhow::ensure!(
+            parse_hash(&entry.hash)? == hash,
+            "audit log entry {count} has been modified"
+        );
+
+        previous = hash;
+        count += 1;
+        if head.as_ref().is_some_and(|head| head.count == count) {
+            at_head = Some(hash);
+        }
+    }
+
+    // The log may run ahead of its head, if writing the head was interrupted,
+    // but it must always contain the entry the head points to.
+    match head {
+        Some(head) if head.count > 0 => {
+            anyhow::ensure!(
+                count >= head.count,
+                "audit log has {count} entries, but its head records {}: entries have been removed from the end of the log",
+                head.count
+            );
+            anyhow::ensure!(
+                at_head == Some(parse_hash(&head.hash)?),
+                "audit log entry {} does not match the hash recorded in its head",
+                head.count - 1
+            );
+        }
+        _ => anyhow::ensure!(
+            count == 0,
+            "audit log has {count} entries, but no head at {}",
+            Head::path(path).display()
+        ),
+    }
+
+    Ok((count, previous))
+}
+
+/// The append-only end of an audit log file.
+struct Writer {
+    path: PathBuf,
+    next_index: u64,
+    previous: [u8; 32],
+}
+
+impl Writer {
+    /// Open the log at `path`, verifying any existing entries.
+    ///
+    /// Fails if the log is missing but its head records entries, since starting
+    /// a new chain would silently discard them.
+    fn open(path: PathBuf) -> anyhow::Result<Self> {
+        let (next_index, previous) = if path.exists() {
+            read_chain(&path).context("existing audit log failed verification")?
+        } else {
+            if let Some(head) = Head::read(&path)? {
+                anyhow::ensure!(
+                    head.count == 0,
+                    "audit log {} is missing, but its head records {} entries",
+                    path.display(),
+                    head.count
+                );
+            }
+            (0, GENESIS_HASH)
+        };
+
+        Ok(Self {
+            path,
+            next_index,
+            previous,
+        })
+    }
+
+    fn append(
+        &mut self,
+        request: RequestSummary,
+        pre_authorizations: &[PreAuthorization],
+        outcome: Outcome,
+    ) -> anyhow::Result<()> {
+        let body = EntryBody {
+            index: self.next_index,
+            timestamp: SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs(),
+            previous: hex::encode(self.previous),
+            request,
+            pre_authorizations: pre_authorizations.iter().map(Into::into).collect(),
+            outcome,
+        };
+        let hash = body.hash()?;
+        let entry = Entry {
+            body,
+            hash: hex::encode(hash),
+        };
+
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+
+        let mut file = std::fs::OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .with_context(|| format!("failed to open audit log {}", self.path.display()))?;
+        file.write_all(line.as_bytes())?;
+        file.sync_data()?;
+
+        self.next_index += 1;
+        self.previous = hash;
+
+        Head {
+            count: self.next_index,
+            hash: hex::encode(hash),
+        }
+        .write(&self.path)
+    }
+}
+
+/// A handle to an audit log file, which may be shared by several [`AuditLog`] services.
+///
+/// Sharing a handle (rather than opening the same file twice) keeps a single
+/// hash chain when, e.g., spend and governance custody are audited together.
+#[derive(Clone)]
+pub struct Log {
+    writer: Arc<Mutex<Writer>>,
+}
+
+impl Log {
+    /// Open the audit log at `path`, creating it if it does not exist.
+    ///
+    /// If the log already exists, its hash chain is verified before use.
+    pub fn open(path: impl Into<PathBuf>) -> anyhow::Result<Self> {
+        Ok(Self {
+            writer: Arc::new(Mutex::new(Writer::open(path.into())?)),
+        })
+    }
+
+    async fn record<T>(
+        &self,
+        request: RequestSummary,
+        pre_authorizations: &[PreAuthorization],
+        result: &Result<T, Status>,
+    ) -> Result<(), Status> {
+        self.writer
+            .lock()
+            .await
+            .append(request, pre_authorizations, result.into())
+            .map_err(|e| Status::internal(format!("failed to write audit log: {e:#}")))
+    }
+}
+
+/// A custody service that forwards every request to an inner custody service,
+/// recording each authorization decision in an audit [`Log`].
+///
+/// Requests that fail to parse never reach the inner service, and are not
+/// recorded, since no decision was made about them.
+pub struct AuditLog<S> {
+    inner: S,
+    fvk: FullViewingKey,
+    log: Log,
+}
+
+impl<S> AuditLog<S> {
+    /// Wrap `inner`, appending its decisions to `log`.
+    ///
+    /// The `fvk` is used to compute the effect hashes of transaction plans.
+    pub fn new(inner: S, fvk: FullViewingKey, log: Log) -> Self {
+        Self { inner, fvk, log }
+    }
+}
+
+#[async_trait]
+impl<S: pb::custody_service_server::CustodyService> pb::custody_service_server::CustodyService
+    for AuditLog<S>
+{
+    async fn authorize(
+        &self,
+        request: Request<pb::AuthorizeRequest>,
+    ) -> Result<Response<AuthorizeResponse>, Status> {
+        let parsed: AuthorizeRequest = request
+            .get_ref()
+            .clone()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        let summary = RequestSummary::transaction(&parsed.plan, &self.fvk)
+            .map_err(|e| Status::invalid_argument(format!("{e:#}")))?;
+
+        let result = self.inner.authorize(request).await;
+        self.log
+            .record(summary, &parsed.pre_authorizations, &result)
+            .await?;
+        result
+    }
+
+    async fn authorize_validator_definition(
+        &self,
+        request: Request<pb::AuthorizeValidatorDefinitionRequest>,
+    ) -> Result<Response<pb::AuthorizeValidatorDefinitionResponse>, Status> {
+        let parsed: AuthorizeValidatorDefinitionRequest = request
+            .get_ref()
+            .clone()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        let definition = &parsed.validator_definition;
+        let summary = RequestSummary::ValidatorDefinition {
+            identity_key: definition.identity_key.to_string(),
+            name: definition.name.clone(),
+            sequence_number: definition.sequence_number,
+        };
+
+        let result = self.inner.authorize_validator_definition(request).await;
+        self.log
+            .record(summary, &parsed.pre_authorizations, &result)
+            .await?;
+        result
+    }
+
+    async fn authorize_validator_vote(
+        &self,
+        request: Request<pb::AuthorizeValidatorVoteRequest>,
+    ) -> Result<Response<pb::AuthorizeValidatorVoteResponse>, Status> {
+        let parsed: AuthorizeValidatorVoteRequest = request
+            .get_ref()
+            .clone()
+            .try_into()
+            .map_err(|e: anyhow::Error| Status::invalid_argument(e.to_string()))?;
+        let vote = &parsed.validator_vote;
+        let summary = RequestSummary::ValidatorVote {
+            identity_key: vote.identity_key.to_string(),
+            proposal: vote.proposal,
+            vote: vote.vote.to_string(),
+        };
+
+        let result = self.inner.authorize_validator_vote(request).await;
+        self.log
+            .record(summary, &parsed.pre_authorizations, &result)
+            .await?;
+        result
+    }
+
+    async fn export_full_viewing_key(
+        &self,
+        request: Request<pb::ExportFullViewingKeyRequest>,
+    ) -> Result<Response<pb::ExportFullViewingKeyResponse>, Status> {
+        self.inner.export_full_viewing_key(request).await
+    }
+
+    async fn confirm_address(
+        &self,
+        request: Request<pb::ConfirmAddressRequest>,
+    ) -> Result<Response<pb::ConfirmAddressResponse>, Status> {
+        self.inner.confirm_address(request).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vote(proposal: u64) -> RequestSummary {
+        RequestSummary::ValidatorVote {
+            identity_key: "penumbravalid1test".to_string(),
+            proposal,
+            vote: "yes".to_string(),
+        }
+    }
+
+    #[test]
+    fn chain_round_trip() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit.jsonl");
+
+        let mut writer = Writer::open(path.clone())?;
+        writer.append(vote(1), &[], Outcome::Approved)?;
+        writer.append(
+            vote(2),
+            &[],
+            Outcome::Denied {
+                reason: "not allowed".to_string(),
+            },
+        )?;
+        assert_eq!(verify(&path)?, 2);
+
+        // Reopening the log continues the existing chain.
+        let mut writer = Writer::open(path.clone())?;
+        writer.append(vote(3), &[], Outcome::Approved)?;
+        assert_eq!(verify(&path)?, 3);
+        Ok(())
+    }
+
+    #[test]
+    fn tampering_is_detected() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit.jsonl");
+
+        let mut writer = Writer::open(path.clone())?;
+        writer.append(vote(1), &[], Outcome::Approved)?;
+        writer.append(vote(2), &[], Outcome::Approved)?;
+        writer.append(vote(3), &[], Outcome::Approved)?;
+
+        let contents = std::fs::read_to_string(&path)?;
+
+        // Editing an entry breaks its hash.
+        let edited = contents.replacen("\"proposal\":2", "\"proposal\":4", 1);
+        std::fs::write(&path, &edited)?;
+        assert!(verify(&path).is_err());
+
+        // Dropping an entry breaks the chain.
+        let dropped = contents
+            .lines()
+            .enumerate()
+            .filter(|(i, _)| *i != 1)
+            .map(|(_, line)| format!("{line}\n"))
+            .collect::<String>();
+        std::fs::write(&path, &dropped)?;
+        assert!(verify(&path).is_err());
+
+        // A tampered log is refused when opening for writing.
+        assert!(Writer::open(path).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn truncation_is_detected() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit.jsonl");
+
+        let mut writer = Writer::open(path.clone())?;
+        writer.append(vote(1), &[], Outcome::Approved)?;
+        writer.append(vote(2), &[], Outcome::Approved)?;
+        let head = std::fs::read_to_string(Head::path(&path))?;
+        writer.append(vote(3), &[], Outcome::Approved)?;
+
+        // A log that runs ahead of its head, as after an interrupted write, is
+        // still accepted.
+        std::fs::write(Head::path(&path), &head)?;
+        assert_eq!(verify(&path)?, 3);
+
+        // Removing the last entry leaves an intact chain, but falls short of the head.
+        writer.append(vote(4), &[], Outcome::Approved)?;
+        let contents = std::fs::read_to_string(&path)?;
+        let truncated = contents
+            .lines()
+            .take(3)
+            .map(|line| format!("{line}\n"))
+            .collect::<String>();
+        std::fs::write(&path, &truncated)?;
+        assert!(verify(&path).is_err());
+
+        // As does removing the head along with the entries.
+        std::fs::remove_file(Head::path(&path))?;
+        assert!(verify(&path).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn deleting_the_log_is_detected() -> anyhow::Result<()> {
+        let dir = tempfile::tempdir()?;
+        let path = dir.path().join("audit.jsonl");
+
+        let mut writer = Writer::open(path.clone())?;
+        writer.append(vote(1), &[], Outcome::Approved)?;
+        let head = std::fs::read_to_string(Head::path(&path))?;
+
+        // Deleting the log must not start a new chain over the recorded head.
+        std::fs::remove_file(&path)?;
+        assert!(Writer::open(path.clone()).is_err());
+        assert_eq!(std::fs::read_to_string(Head::path(&path))?, head);
+        Ok(())
+    }
+}
@@ -1,9 +1,17 @@
+use std::{
+    path::PathBuf,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use anyhow::Context;
 use penumbra_sdk_proto::custody::v1::{self as pb, AuthorizeResponse};
 use rand_core::OsRng;
 use serde::{Deserialize, Serialize};
 use serde_with::{formats::Uppercase, hex::Hex};
-use tokio::sync::OnceCell;
+use tokio::sync::Mutex;
 use tonic::{async_trait, Request, Response, Status};
+use zeroize::Zeroizing;
 
 use crate::{soft_kms, terminal::Terminal, threshold};
 
@@ -14,6 +22,7 @@ mod encryption {
         ChaCha20Poly1305,
     };
     use rand_core::CryptoRngCore;
+    use serde::{Deserialize, Serialize};
 
     /// Represents a password that has been validated for length, and won't cause argon2 errors
     #[derive(Clone, Copy)]
@@ -36,30 +45,190 @@ mod encryption {
     }
 
     // These can be recomputed from the library, at the cost of importing 25 billion traits.
-    const SALT_SIZE: usize = 32;
+    pub const SALT_SIZE: usize = 32;
     const TAG_SIZE: usize = 16;
-    const KEY_SIZE: usize = 32;
+    pub const KEY_SIZE: usize = 32;
+    const NONCE_SIZE: usize = 12;
+
+    /// The cost parameters of the Argon2id key derivation.
+    #[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+    pub struct KdfParams {
+        /// Memory cost, in KiB.
+        pub m_cost: u32,
+        /// Number of iterations.
+        pub t_cost: u32,
+        /// Degree of parallelism.
+        pub p_cost: u32,
+    }
+
+    impl Default for KdfParams {
+        /// Default from the crate, but hardcoded so it doesn't change under us, and following https://datatracker.ietf.org/doc/html/rfc9106.
+        fn default() -> Self {
+            Self {
+                m_cost: 1 << 21,
+                t_cost: 1,
+                p_cost: 4,
+            }
+        }
+    }
 
-    fn derive_key(salt: &[u8; SALT_SIZE], password: Password<'_>) -> [u8; KEY_SIZE] {
+    impl KdfParams {
+        /// The smallest memory cost accepted by [`KdfParams::validate`], in KiB (64 MiB).
+        pub const MIN_M_COST: u32 = 1 << 16;
+        /// The largest memory cost accepted by [`KdfParams::validate`], in KiB (4 GiB).
+        pub const MAX_M_COST: u32 = 1 << 22;
+        /// The largest number of iterations accepted by [`KdfParams::validate`].
+        pub const MAX_T_COST: u32 = 64;
+        /// The largest degree of parallelism accepted by [`KdfParams::validate`].
+        pub const MAX_P_COST: u32 = 16;
+
+        /// Check that these parameters are accepted by Argon2, and within bounds that keep
+        /// unlocking both meaningfully expensive and possible on ordinary hardware.
+        pub fn validate(&self) -> anyhow::Result<()> {
+            argon2::Params::new(self.m_cost, self.t_cost, self.p_cost, Some(KEY_SIZE))
+                .map_err(|e| anyhow!("invalid key derivation parameters: {e}"))?;
+            anyhow::ensure!(
+                (Self::MIN_M_COST..=Self::MAX_M_COST).contains(&self.m_cost),
+                "memory cost must be between {} and {} KiB",
+                Self::MIN_M_COST,
+                Self::MAX_M_COST
+            );
+            anyhow::ensure!(
+                self.t_cost <= Self::MAX_T_COST,
+                "number of iterations must be at most {}",
+                Self::MAX_T_COST
+            );
+            anyhow::ensure!(
+                self.p_cost <= Self::MAX_P_COST,
+                "degree of parallelism must be at most {}",
+                Self::MAX_P_COST
+            );
+            Ok(())
+        }
+
+        /// Whether deriving a key with these parameters is cheaper than with `other`.
+        pub fn is_weaker_than(&self, other: &KdfParams) -> bool {
+            self.m_cost < other.m_cost || self.t_cost < other.t_cost
+        }
+
+        fn to_bytes(self) -> [u8; 12] {
+            let mut bytes = [0u8; 12];
+            bytes[0..4].copy_from_slice(&self.m_cost.to_le_bytes());
+            bytes[4..8].copy_from_slice(&self.t_cost.to_le_bytes());
+            bytes[8..12].copy_from_slice(&self.p_cost.to_le_bytes());
+            bytes
+        }
+    }
+
+    pub fn derive_key(
+        salt: &[u8; SALT_SIZE],
+        secret: &[u8],
+        params: &KdfParams,
+    ) -> anyhow::Result<[u8; KEY_SIZE]> {
+        anyhow::ensure!(secret.len() < argon2::MAX_PWD_LEN, "secret too long");
+        let argon2_params =
+            argon2::Params::new(params.m_cost, params.t_cost, params.p_cost, Some(KEY_SIZE))
+                .map_err(|e| anyhow!("invalid key derivation parameters: {e}"))?;
         let mut key = [0u8; KEY_SIZE];
-        // The only reason this function should fail is because of incorrect static parameters
-        // we've chosen, since we've validated the length of the password.
-        argon2::Argon2::hash_password_into(
-            // Default from the crate, but hardcoded so it doesn't change under us, and following https://datatracker.ietf.org/doc/html/rfc9106.
-            &argon2::Argon2::new(
-                argon2::Algorithm::Argon2id,
-                argon2::Version::V0x13,
-                argon2::Params::new(1 << 21, 1, 4, Some(KEY_SIZE))
-                    .expect("the parameters should be valid"),
-            ),
-            password.0.as_bytes(),
-            salt,
-            &mut key,
+        argon2::Argon2::new(
+            argon2::Algorithm::Argon2id,
+            argon2::Version::V0x13,
+            argon2_params,
         )
-        .expect("password hashing should not fail with a small enough password");
-        key
+        .hash_password_into(secret, salt, &mut key)
+        .map_err(|e| anyhow!("key derivation failed: {e}"))?;
+        Ok(key)
+    }
+
+    /// Encrypt `data` under `key`, returning `tag || ciphertext`.
+    fn seal(key: &[u8; KEY_SIZE], nonce: &[u8; NONCE_SIZE], ad: &[u8], data: &[u8]) -> Vec<u8> {
+        let mut sealed = Vec::with_capacity(TAG_SIZE + data.len());
+        sealed.extend_from_slice(&[0u8; TAG_SIZE]);
+        sealed.extend_from_slice(data);
+        let tag = ChaCha20Poly1305::new(&(*key).into())
+            .encrypt_in_place_detached(&(*nonce).into(), ad, &mut sealed[TAG_SIZE..])
+            .expect("ChaCha20Poly1305 encryption should not fail");
+        sealed[0..TAG_SIZE].copy_from_slice(&tag);
+        sealed
     }
 
+    /// The inverse of [`seal`].
+    fn open(
+        key: &[u8; KEY_SIZE],
+        nonce: &[u8; NONCE_SIZE],
+        ad: &[u8],
+        sealed: &[u8],
+    ) -> anyhow::Result<Vec<u8>> {
+        anyhow::ensure!(sealed.len() >= TAG_SIZE, "provided ciphertext is too short");
+        let (tag, message) = sealed.split_at(TAG_SIZE);
+        let mut message = message.to_owned();
+        ChaCha20Poly1305::new(&(*key).into())
+            .decrypt_in_place_detached(&(*nonce).into(), ad, &mut message, tag.into())
+            .map_err(|_| anyhow!("failed to decrypt ciphertext"))?;
+        Ok(message)
+    }
+
+    /// Wrap a data key under a key derived from `secret`, returning the fresh salt and the wrapped key.
+    pub fn wrap_key(
+        rng: &mut impl CryptoRngCore,
+        secret: &[u8],
+        params: &KdfParams,
+        data_key: &[u8; KEY_SIZE],
+    ) -> anyhow::Result<([u8; SALT_SIZE], Vec<u8>)> {
+        // Each wrapping uses a fresh salt, and so a fresh key, so a fixed nonce is safe.
+        // The salt and parameters are bound as associated data, so that they can't be
+        // swapped out from under the wrapped key.
+        let mut salt = [0u8; SALT_SIZE];
+        rng.fill_bytes(&mut salt);
+        let kek = derive_key(&salt, secret, params)?;
+        let ad = [salt.as_slice(), &params.to_bytes()].concat();
+        Ok((salt, seal(&kek, &Default::default(), &ad, data_key)))
+    }
+
+    /// The inverse of [`wrap_key`].
+    pub fn unwrap_key(
+        secret: &[u8],
+        params: &KdfParams,
+        salt: &[u8],
+        wrapped_key: &[u8],
+    ) -> anyhow::Result<[u8; KEY_SIZE]> {
+        let salt: &[u8; SALT_SIZE] = salt.try_into().map_err(|_| anyhow!("invalid salt"))?;
+        let kek = derive_key(salt, secret, params)?;
+        let ad = [salt.as_slice(), &params.to_bytes()].concat();
+        open(&kek, &Default::default(), &ad, wrapped_key)?
+            .try_into()
+            .map_err(|_| anyhow!("wrapped key has the wrong length"))
+    }
+
+    /// Encrypt `data` under a data key, returning `nonce || tag || ciphertext`.
+    pub fn encrypt_with_key(
+        rng: &mut impl CryptoRngCore,
+        key: &[u8; KEY_SIZE],
+        data: &[u8],
+    ) -> Vec<u8> {
+        let mut nonce = [0u8; NONCE_SIZE];
+        rng.fill_bytes(&mut nonce);
+        let mut ciphertext = nonce.to_vec();
+        ciphertext.extend(seal(key, &nonce, &[], data));
+        ciphertext
+    }
+
+    /// The inverse of [`encrypt_with_key`].
+    pub fn decrypt_with_key(key: &[u8; KEY_SIZE], data: &[u8]) -> anyhow::Result<Vec<u8>> {
+        anyhow::ensure!(data.len() >= NONCE_SIZE, "provided ciphertext is too short");
+        let (nonce, sealed) = data.split_at(NONCE_SIZE);
+        open(
+            key,
+            nonce.try_into().expect("nonce is the right length"),
+            &[],
+            sealed,
+        )
+    }
+
+    /// Encrypt `data` in the original, single-password format.
+    ///
+    /// New configs use key slots instead, so this is only needed to test reading old configs.
+    #[cfg(test)]
     pub fn encrypt(rng: &mut impl CryptoRngCore, password: Password<'_>, data: &[u8]) -> Vec<u8> {
         // The scheme here is that we derive a new salt, used that to derive a new unique key
         // from the password, then store the salt alongside the ciphertext, and its tag.
@@ -71,7 +240,10 @@ mod encryption {
             rng.fill_bytes(&mut out);
             out
         };
-        let key = derive_key(&salt, password);
+        // The only reason this should fail is because of incorrect static parameters
+        // we've chosen, since we've validated the length of the password.
+        let key = derive_key(&salt, password.0.as_bytes(), &KdfParams::default())
+            .expect("password hashing should not fail with a small enough password");
 
         let mut ciphertext = Vec::with_capacity(TAG_SIZE + salt.len() + data.len());
         ciphertext.extend_from_slice(&[0u8; TAG_SIZE]);
@@ -99,8 +271,9 @@ mod encryption {
         let salt = &header[TAG_SIZE..TAG_SIZE + SALT_SIZE];
         let key = derive_key(
             &salt.try_into().expect("salt is the right length"),
-            password,
-        );
+            password.0.as_bytes(),
+            &KdfParams::default(),
+        )?;
         ChaCha20Poly1305::new(&key.into())
             .decrypt_in_place_detached(&Default::default(), &salt, &mut message, tag.into())
             .map_err(|_| anyhow!("failed to decrypt ciphertext"))?;
@@ -135,7 +308,11 @@ mod encryption {
     }
 }
 
-use encryption::{decrypt, encrypt};
+use encryption::{
+    decrypt, decrypt_with_key, encrypt_with_key, unwrap_key, wrap_key, Password, KEY_SIZE,
+};
+
+pub use encryption::KdfParams;
 
 /// The actual inner configuration used for an encrypted configuration.
 #[derive(Serialize, Deserialize)]
@@ -154,77 +331,430 @@ impl InnerConfig {
     }
 }
 
+/// A secret that can unlock a [`KeySlot`].
+#[derive(Clone, Copy)]
+pub enum Secret<'a> {
+    /// A password typed in by the user.
+    Password(&'a str),
+    /// The contents of a keyfile.
+    Keyfile(&'a [u8]),
+}
+
+impl<'a> Secret<'a> {
+    fn kind(&self) -> SlotKind {
+        match self {
+            Secret::Password(_) => SlotKind::Password,
+            Secret::Keyfile(_) => SlotKind::Keyfile,
+        }
+    }
+
+    fn as_bytes(&self) -> &'a [u8] {
+        match *self {
+            Secret::Password(password) => password.as_bytes(),
+            Secret::Keyfile(contents) => contents,
+        }
+    }
+}
+
+/// The kind of secret a [`KeySlot`] is unlocked with.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SlotKind {
+    Password,
+    Keyfile,
+}
+
+/// One way of unlocking an encrypted config: a copy of the data key, wrapped
+/// under a key derived from a password or keyfile.
+#[serde_as]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct KeySlot {
+    /// The kind of secret that unlocks this slot.
+    pub kind: SlotKind,
+    /// The key derivation parameters this slot was wrapped with.
+    pub kdf: KdfParams,
+    #[serde_as(as = "Hex<Uppercase>")]
+    salt: Vec<u8>,
+    #[serde_as(as = "Hex<Uppercase>")]
+    wrapped_key: Vec<u8>,
+}
+
+impl KeySlot {
+    fn new(secret: Secret<'_>, kdf: KdfParams, data_key: &[u8; KEY_SIZE]) -> anyhow::Result<Self> {
+        let (salt, wrapped_key) = wrap_key(&mut OsRng, secret.as_bytes(), &kdf, data_key)?;
+        Ok(Self {
+            kind: secret.kind(),
+            kdf,
+            salt: salt.to_vec(),
+            wrapped_key,
+        })
+    }
+
+    fn unlock(&self, secret: Secret<'_>) -> anyhow::Result<[u8; KEY_SIZE]> {
+        anyhow::ensure!(self.kind == secret.kind(), "wrong kind of secret for slot");
+        unwrap_key(secret.as_bytes(), &self.kdf, &self.salt, &self.wrapped_key)
+    }
+}
+
 /// The configuration for the encrypted custody backend.
 ///
 /// This holds a blob of encrypted data that needs to be further deserialized into another config.
+///
+/// The blob is encrypted under a random data key, and each [`KeySlot`] holds a
+/// copy of that data key wrapped under a password or keyfile, so that unlock
+/// methods can be added, changed, or removed without touching the blob itself.
+/// Configs created before key slots existed hold the blob encrypted directly
+/// under a single password, and are migrated to key slots on their first change.
 #[serde_as]
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct Config {
+    /// The inner config encrypted directly under a single password (the original format).
     #[serde_as(as = "Hex<Uppercase>")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     data: Vec<u8>,
+    /// The key derivation cost used for new slots, and which weaker slots are
+    /// upgraded to when they're unlocked.
+    #[serde(default, skip_serializing_if = "is_default")]
+    pub kdf: KdfParams,
+    /// If set, unlock with the contents of this file rather than prompting for a password.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub keyfile: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    slots: Vec<KeySlot>,
+    /// The inner config, encrypted under the data key.
+    #[serde_as(as = "Hex<Uppercase>")]
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    payload: Vec<u8>,
+}
+
+/// The result of unlocking an encrypted [`Config`].
+pub struct Unlocked {
+    /// The decrypted inner config.
+    pub inner: InnerConfig,
+    /// If the slot used to unlock the config was weaker than the configured
+    /// [`KdfParams`] (or the config was in the original format), an updated
+    /// config that should be persisted in place of the old one.
+    pub upgraded: Option<Config>,
 }
 
 impl Config {
     /// Create a config from an inner config, with the actual params, and an encryption password.
     pub fn create(password: &str, inner: InnerConfig) -> anyhow::Result<Self> {
-        let password = password.try_into()?;
+        Self::create_with_kdf(Secret::Password(password), KdfParams::default(), inner)
+    }
+
+    /// Create a config from an inner config, with a single slot unlocked by `secret`,
+    /// using the given key derivation cost.
+    pub fn create_with_kdf(
+        secret: Secret<'_>,
+        kdf: KdfParams,
+        inner: InnerConfig,
+    ) -> anyhow::Result<Self> {
+        let mut data_key = [0u8; KEY_SIZE];
+        rand_core::RngCore::fill_bytes(&mut OsRng, &mut data_key);
         Ok(Self {
-            data: encrypt(&mut OsRng, password, &inner.to_bytes()?),
+            data: Vec::new(),
+            kdf,
+            keyfile: None,
+            slots: vec![KeySlot::new(secret, kdf, &data_key)?],
+            payload: encrypt_with_key(&mut OsRng, &data_key, &inner.to_bytes()?),
         })
     }
 
-    fn decrypt(self, password: &str) -> anyhow::Result<InnerConfig> {
-        let decrypted_data = decrypt(password.try_into()?, &self.data)?;
-        Ok(InnerConfig::from_bytes(&decrypted_data)?)
+    /// The key slots of this config.
+    ///
+    /// This is empty for configs in the original, single-password format.
+    pub fn slots(&self) -> &[KeySlot] {
+        &self.slots
+    }
+
+    fn is_legacy(&self) -> bool {
+        !self.data.is_empty()
+    }
+
+    /// Find the slot that `secret` unlocks, returning its index and the data key.
+    fn unlock_slot(&self, secret: Secret<'_>) -> anyhow::Result<(usize, [u8; KEY_SIZE])> {
+        self.slots
+            .iter()
+            .enumerate()
+            .filter(|(_, slot)| slot.kind == secret.kind())
+            .find_map(|(index, slot)| slot.unlock(secret).ok().map(|key| (index, key)))
+            .ok_or_else(|| anyhow::anyhow!("no key slot could be unlocked with this secret"))
+    }
+
+    /// Convert a config in the original format to the key slot format, keeping
+    /// the password as the only slot.
+    fn migrate_legacy(&mut self, secret: Secret<'_>) -> anyhow::Result<()> {
+        if !self.is_legacy() {
+            return Ok(());
+        }
+        let Secret::Password(password) = secret else {
+            anyhow::bail!("configs in the original format can only be unlocked with a password");
+        };
+        let inner = InnerConfig::from_bytes(&decrypt(Password::new(password)?, &self.data)?)?;
+        let keyfile = self.keyfile.take();
+        *self = Self::create_with_kdf(secret, self.kdf, inner)?;
+        self.keyfile = keyfile;
+        Ok(())
+    }
+
+    fn decrypt(&self, secret: Secret<'_>) -> anyhow::Result<InnerConfig> {
+        // The decrypted bytes contain the spend key, so wipe them once parsed.
+        let plaintext = if self.is_legacy() {
+            let Secret::Password(password) = secret else {
+                anyhow::bail!(
+                    "configs in the original format can only be unlocked with a password"
+                );
+            };
+            Zeroizing::new(decrypt(Password::new(password)?, &self.data)?)
+        } else {
+            let (_, data_key) = self.unlock_slot(secret)?;
+            let data_key = Zeroizing::new(data_key);
+            Zeroizing::new(decrypt_with_key(&data_key, &self.payload)?)
+        };
+        InnerConfig::from_bytes(&plaintext)
+    }
+
+    /// Decrypt the inner config, upgrading the unlocked slot if needed.
+    ///
+    /// A failed upgrade is only logged, so that unusable [`KdfParams`] can't
+    /// prevent unlocking the config.
+    pub fn open(&self, secret: Secret<'_>) -> anyhow::Result<Unlocked> {
+        let inner = self.decrypt(secret)?;
+        let mut upgraded = self.clone();
+        let upgraded = match upgraded.upgrade(secret) {
+            Ok(true) => Some(upgraded),
+            Ok(false) => None,
+            Err(e) => {
+                tracing::warn!(?e, "failed to upgrade encrypted custody key slot");
+                None
+            }
+        };
+        Ok(Unlocked { inner, upgraded })
+    }
+
+    /// Re-wrap the slot unlocked by `secret` with the configured [`KdfParams`],
+    /// if it was wrapped with weaker ones, returning whether anything changed.
+    ///
+    /// Configs in the original format are always migrated to key slots.
+    pub fn upgrade(&mut self, secret: Secret<'_>) -> anyhow::Result<bool> {
+        if self.is_legacy() {
+            self.migrate_legacy(secret)?;
+            return Ok(true);
+        }
+        let (index, data_key) = self.unlock_slot(secret)?;
+        if !self.slots[index].kdf.is_weaker_than(&self.kdf) {
+            return Ok(false);
+        }
+        self.slots[index] = KeySlot::new(secret, self.kdf, &data_key)?;
+        Ok(true)
+    }
+
+    /// Add a new slot unlocked by `new`, authorized by an `existing` secret.
+    pub fn add_slot(&mut self, existing: Secret<'_>, new: Secret<'_>) -> anyhow::Result<()> {
+        self.migrate_legacy(existing)?;
+        let (_, data_key) = self.unlock_slot(existing)?;
+        self.slots.push(KeySlot::new(new, self.kdf, &data_key)?);
+        Ok(())
+    }
+
+    /// Replace the slot unlocked by `old` with one unlocked by `new`.
+    pub fn change_secret(&mut self, old: Secret<'_>, new: Secret<'_>) -> anyhow::Result<()> {
+        self.migrate_legacy(old)?;
+        let (index, data_key) = self.unlock_slot(old)?;
+        self.slots[index] = KeySlot::new(new, self.kdf, &data_key)?;
+        Ok(())
+    }
+
+    /// Change the password of the slot unlocked by `old` to `new`.
+    pub fn change_password(&mut self, old: &str, new: &str) -> anyhow::Result<()> {
+        self.change_secret(Secret::Password(old), Secret::Password(new))
+    }
+
+    /// Remove the slot at `index`, authorized by an `existing` secret.
+    ///
+    /// The last remaining slot can't be removed.
+    pub fn remove_slot(&mut self, existing: Secret<'_>, index: usize) -> anyhow::Result<()> {
+        self.migrate_legacy(existing)?;
+        self.unlock_slot(existing)?;
+        anyhow::ensure!(index < self.slots.len(), "no key slot {index}");
+        anyhow::ensure!(self.slots.len() > 1, "cannot remove the last key slot");
+        self.slots.remove(index);
+        Ok(())
+    }
+
+    /// Read the configured keyfile, if any.
+    pub fn read_keyfile(&self) -> anyhow::Result<Option<Vec<u8>>> {
+        self.keyfile
+            .as_ref()
+            .map(|path| {
+                std::fs::read(path)
+                    .with_context(|| format!("failed to read keyfile {}", path.display()))
+            })
+            .transpose()
     }
 
     // Attempt to convert this to a threshold config, if possible
-    pub fn convert_to_threshold(self, password: &str) -> anyhow::Result<Option<threshold::Config>> {
-        match self.decrypt(password)? {
+    pub fn convert_to_threshold(
+        self,
+        secret: Secret<'_>,
+    ) -> anyhow::Result<Option<threshold::Config>> {
+        match self.decrypt(secret)? {
             InnerConfig::SoftKms(_) => Ok(None),
             InnerConfig::Threshold(c) => Ok(Some(c)),
         }
     }
 }
 
+/// Helper function for Serde serialization, allowing us to skip serialization
+/// of default config values.
+fn is_default<T: Default + Eq>(value: &T) -> bool {
+    *value == T::default()
+}
+
+type UpgradeHook = Box<dyn Fn(&Config) -> anyhow::Result<()> + Send + Sync>;
+
+/// An unlocked inner custody service, and when it was last used.
+struct Session {
+    service: Arc<dyn pb::custody_service_server::CustodyService>,
+    last_used: Instant,
+}
+
 /// Represents a custody service that uses an encrypted configuration.
 ///
 /// This service wraps either the threshold or solo custody service.
+///
+/// The config is unlocked on first use, and the unlocked service is kept until
+/// it has been idle for longer than the session timeout, if one is set. Once
+/// the timeout passes, the unlocked service is dropped in the background, even
+/// if no further requests arrive.
 pub struct Encrypted<T> {
-    config: Config,
+    config: Mutex<Config>,
     terminal: T,
-    inner: OnceCell<anyhow::Result<Box<dyn pb::custody_service_server::CustodyService>>>,
+    session_timeout: Option<Duration>,
+    on_upgrade: Option<UpgradeHook>,
+    session: Arc<Mutex<Option<Session>>>,
 }
 
 impl<T: Terminal + Clone + Send + Sync + 'static> Encrypted<T> {
     /// Create a new encrypted config, using the terminal to ask for a password
     pub fn new(config: Config, terminal: T) -> Self {
         Self {
-            config,
+            config: Mutex::new(config),
             terminal,
-            inner: Default::default(),
+            session_timeout: None,
+            on_upgrade: None,
+            session: Default::default(),
         }
     }
 
-    async fn get_inner(&self) -> Result<&dyn pb::custody_service_server::CustodyService, Status> {
-        Ok(self
-            .inner
-            .get_or_init(|| async {
-                let password = self.terminal.get_password().await?;
+    /// Lock the config again after it has been idle for `timeout`, so that the
+    /// next request prompts for the secret.
+    pub fn with_session_timeout(mut self, timeout: Duration) -> Self {
+        self.session_timeout = Some(timeout);
+        self
+    }
 
-                let inner = self.config.clone().decrypt(&password)?;
-                let out: Box<dyn pb::custody_service_server::CustodyService> = match inner {
-                    InnerConfig::SoftKms(c) => Box::new(soft_kms::SoftKms::new(c)),
-                    InnerConfig::Threshold(c) => {
-                        Box::new(threshold::Threshold::new(c, self.terminal.clone()))
-                    }
-                };
-                Ok(out)
-            })
+    /// Upgrade weaker key slots when they're unlocked, calling `hook` to
+    /// persist the upgraded config.
+    ///
+    /// Without a hook, slots are never upgraded, since the upgrade would be lost.
+    pub fn with_upgrade_hook(
+        mut self,
+        hook: impl Fn(&Config) -> anyhow::Result<()> + Send + Sync + 'static,
+    ) -> Self {
+        self.on_upgrade = Some(Box::new(hook));
+        self
+    }
+
+    async fn unlock(&self) -> anyhow::Result<Arc<dyn pb::custody_service_server::CustodyService>> {
+        let mut config = self.config.lock().await;
+
+        let password;
+        let keyfile = config.read_keyfile()?.map(Zeroizing::new);
+        let secret = match &keyfile {
+            Some(contents) => Secret::Keyfile(contents),
+            None => {
+                password = Zeroizing::new(self.terminal.get_password().await?);
+                Secret::Password(&password)
+            }
+        };
+
+        let unlocked = config.open(secret)?;
+        if let (Some(upgraded), Some(hook)) = (unlocked.upgraded, &self.on_upgrade) {
+            match hook(&upgraded) {
+                Ok(()) => {
+                    tracing::info!("upgraded encrypted custody key slot");
+                    *config = upgraded;
+                }
+                Err(e) => tracing::warn!(?e, "failed to persist upgraded custody config"),
+            }
+        }
+
+        let out: Arc<dyn pb::custody_service_server::CustodyService> = match unlocked.inner {
+            InnerConfig::SoftKms(c) => Arc::new(soft_kms::SoftKms::new(c)),
+            InnerConfig::Threshold(c) => {
+                Arc::new(threshold::Threshold::new(c, self.terminal.clone()))
+            }
+        };
+        Ok(out)
+    }
+
+    async fn get_inner(
+        &self,
+    ) -> Result<Arc<dyn pb::custody_service_server::CustodyService>, Status> {
+        let mut session = self.session.lock().await;
+
+        if let Some(current) = session.as_mut() {
+            let expired = self
+                .session_timeout
+                .is_some_and(|timeout| current.last_used.elapsed() >= timeout);
+            if !expired {
+                current.last_used = Instant::now();
+                return Ok(current.service.clone());
+            }
+            tracing::info!("encrypted custody session timed out, locking");
+            *session = None;
+        }
+
+        let service = self
+            .unlock()
             .await
-            .as_ref()
-            .map_err(|e| Status::unauthenticated(format!("failed to initialize custody {e}")))?
-            .as_ref())
+            .map_err(|e| Status::unauthenticated(format!("failed to initialize custody {e}")))?;
+        *session = Some(Session {
+            service: service.clone(),
+            last_used: Instant::now(),
+        });
+        if let Some(timeout) = self.session_timeout {
+            self.spawn_expiry(timeout);
+        }
+        Ok(service)
+    }
+
+    /// Drop the unlocked session once it has been idle for `timeout`, without
+    /// waiting for the next request to notice.
+    fn spawn_expiry(&self, timeout: Duration) {
+        let session = Arc::downgrade(&self.session);
+        tokio::spawn(async move {
+            let mut wait = timeout;
+            loop {
+                tokio::time::sleep(wait).await;
+                let Some(session) = session.upgrade() else {
+                    return;
+                };
+                let mut session = session.lock().await;
+                let Some(current) = session.as_ref() else {
+                    return;
+                };
+                let idle = current.last_used.elapsed();
+                if idle >= timeout {
+                    tracing::info!("encrypted custody session timed out, locking");
+                    *session = None;
+                    return;
+                }
+                // The session was used since we last checked, so wait out the rest of its timeout.
+                wait = timeout - idle;
+            }
+        });
     }
 }
 
@@ -276,3 +806,180 @@ impl<T: Terminal + Clone + Send + Sync + 'static> pb::custody_service_server::Cu
         self.get_inner().await?.confirm_address(request).await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use penumbra_sdk_keys::keys::{Bip44Path, SeedPhrase, SpendKey};
+
+    use super::*;
+
+    /// Cheap parameters, so the tests don't spend gigabytes of memory on key derivation.
+    const TEST_KDF: KdfParams = KdfParams {
+        m_cost: 64,
+        t_cost: 1,
+        p_cost: 1,
+    };
+
+    fn inner() -> (SpendKey, InnerConfig) {
+        let seed_phrase = SeedPhrase::generate(OsRng);
+        let spend_key = SpendKey::from_seed_phrase_bip44(seed_phrase, &Bip44Path::new(0));
+        (spend_key.clone(), InnerConfig::SoftKms(spend_key.into()))
+    }
+
+    fn spend_key_of(config: &Config, secret: Secret<'_>) -> anyhow::Result<SpendKey> {
+        match config.decrypt(secret)? {
+            InnerConfig::SoftKms(c) => Ok(c.spend_key),
+            InnerConfig::Threshold(_) => anyhow::bail!("expected a soft kms config"),
+        }
+    }
+
+    #[test]
+    fn slots_unlock_independently() -> anyhow::Result<()> {
+        let (spend_key, inner) = inner();
+        let mut config = Config::create_with_kdf(Secret::Password("first"), TEST_KDF, inner)?;
+        let keyfile = b"keyfile contents";
+
+        config.add_slot(Secret::Password("first"), Secret::Password("second"))?;
+        config.add_slot(Secret::Password("second"), Secret::Keyfile(keyfile))?;
+        assert_eq!(config.slots().len(), 3);
+
+        for secret in [
+            Secret::Password("first"),
+            Secret::Password("second"),
+            Secret::Keyfile(keyfile),
+        ] {
+            assert_eq!(spend_key_of(&config, secret)?, spend_key);
+        }
+        assert!(config.decrypt(Secret::Password("third")).is_err());
+        // A keyfile's contents don't work when typed in as a password.
+        assert!(config
+            .decrypt(Secret::Password("keyfile contents"))
+            .is_err());
+
+        config.remove_slot(Secret::Password("second"), 0)?;
+        assert!(config.decrypt(Secret::Password("first")).is_err());
+        assert_eq!(
+            spend_key_of(&config, Secret::Password("second"))?,
+            spend_key
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn change_password_keeps_payload() -> anyhow::Result<()> {
+        let (spend_key, inner) = inner();
+        let mut config = Config::create_with_kdf(Secret::Password("old"), TEST_KDF, inner)?;
+        let payload = config.payload.clone();
+
+        config.change_password("old", "new")?;
+        assert_eq!(config.payload, payload);
+        assert!(config.decrypt(Secret::Password("old")).is_err());
+        assert_eq!(spend_key_of(&config, Secret::Password("new"))?, spend_key);
+
+        assert!(config.change_password("old", "newer").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn last_slot_cannot_be_removed() -> anyhow::Result<()> {
+        let (_, inner) = inner();
+        let mut config = Config::create_with_kdf(Secret::Password("only"), TEST_KDF, inner)?;
+        assert!(config.remove_slot(Secret::Password("only"), 0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn weaker_slots_are_upgraded_on_open() -> anyhow::Result<()> {
+        let (spend_key, inner) = inner();
+        let mut config = Config::create_with_kdf(Secret::Password("password"), TEST_KDF, inner)?;
+        assert!(config
+            .open(Secret::Password("password"))?
+            .upgraded
+            .is_none());
+
+        config.kdf = KdfParams {
+            m_cost: 128,
+            ..TEST_KDF
+        };
+        let upgraded = config
+            .open(Secret::Password("password"))?
+            .upgraded
+            .expect("slot should be upgraded");
+        assert_eq!(upgraded.slots()[0].kdf, upgraded.kdf);
+        assert_eq!(
+            spend_key_of(&upgraded, Secret::Password("password"))?,
+            spend_key
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn failed_upgrades_do_not_prevent_unlocking() -> anyhow::Result<()> {
+        let (spend_key, inner) = inner();
+        let mut config = Config::create_with_kdf(Secret::Password("password"), TEST_KDF, inner)?;
+
+        // Argon2 rejects these parameters, so the slot can't be upgraded to them.
+        config.kdf = KdfParams {
+            m_cost: 128,
+            p_cost: 0,
+            ..TEST_KDF
+        };
+        assert!(config.kdf.validate().is_err());
+        let unlocked = config.open(Secret::Password("password"))?;
+        assert!(unlocked.upgraded.is_none());
+        assert_eq!(
+            spend_key_of(&config, Secret::Password("password"))?,
+            spend_key
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn kdf_params_are_bounded() {
+        assert!(KdfParams::default().validate().is_ok());
+        assert!(TEST_KDF.validate().is_err());
+        assert!(KdfParams {
+            m_cost: KdfParams::MAX_M_COST + 1,
+            ..KdfParams::default()
+        }
+        .validate()
+        .is_err());
+        assert!(KdfParams {
+            t_cost: 0,
+            ..KdfParams::default()
+        }
+        .validate()
+        .is_err());
+    }
+
+    #[test]
+    fn original_format_migrates_to_slots() -> anyhow::Result<()> {
+        let (spend_key, inner) = inner();
+        let mut config = Config {
+            data: encryption::encrypt(&mut OsRng, "old".try_into()?, &inner.to_bytes()?),
+            kdf: TEST_KDF,
+            keyfile: None,
+            slots: Vec::new(),
+            payload: Vec::new(),
+        };
+        assert!(config.slots().is_empty());
+
+        config.change_password("old", "new")?;
+        assert!(config.data.is_empty());
+        assert_eq!(config.slots().len(), 1);
+        assert_eq!(spend_key_of(&config, Secret::Password("new"))?, spend_key);
+        Ok(())
+    }
+
+    #[test]
+    fn toml_round_trip() -> anyhow::Result<()> {
+        let (_, inner) = inner();
+        let mut config = Config::create_with_kdf(Secret::Password("password"), TEST_KDF, inner)?;
+        config.keyfile = Some("/media/usb/penumbra.key".into());
+
+        let encoded = toml::to_string_pretty(&config)?;
+        let decoded: Config = toml::from_str(&encoded)?;
+        assert_eq!(config, decoded);
+        Ok(())
+    }
+}
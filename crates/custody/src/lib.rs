@@ -15,9 +15,11 @@ mod pre_auth;
 mod request;
 mod terminal;
 
+pub mod audit;
 pub mod encrypted;
 pub mod null_kms;
 pub mod policy;
+pub mod render;
 pub mod soft_kms;
 pub mod threshold;
 
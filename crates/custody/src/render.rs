@@ -0,0 +1,636 @@
+//! Human-readable summaries of signing requests.
+//!
+//! Every custody backend that asks a person to approve a request, and the
+//! [`audit`](crate::audit) log that records it, should describe the request the
+//! same way, so that what someone approves is what ends up in the log.  The
+//! functions in this module produce plain text, without any terminal styling,
+//! so that they can be used in any of those places.
+
+use penumbra_sdk_asset::{
+    asset::{self, Cache},
+    Value, STAKING_TOKEN_ASSET_ID,
+};
+use penumbra_sdk_dex::lp::position::Position;
+use penumbra_sdk_governance::ValidatorVoteBody;
+use penumbra_sdk_keys::{Address, FullViewingKey};
+use penumbra_sdk_stake::{validator::Validator, FundingStream};
+use penumbra_sdk_transaction::{ActionPlan, TransactionPlan};
+
+use crate::threshold::SigningRequest;
+
+/// Render any signing request as a multi-line summary.
+pub fn signing_request(
+    request: &SigningRequest,
+    cache: &Cache,
+    fvk: Option<&FullViewingKey>,
+) -> String {
+    match request {
+        SigningRequest::TransactionPlan(plan) => transaction_plan(plan, cache, fvk),
+        SigningRequest::ValidatorDefinition(validator) => validator_definition(validator),
+        SigningRequest::ValidatorVote(vote) => validator_vote(vote),
+    }
+}
+
+/// Render a transaction plan as a multi-line summary.
+///
+/// Outputs and spends are grouped by address, with the values moving to or
+/// from each address summed per asset; every other action gets its own line.
+/// If a full viewing key is supplied, addresses it controls are shown by
+/// account rather than in full.
+pub fn transaction_plan(
+    plan: &TransactionPlan,
+    cache: &Cache,
+    fvk: Option<&FullViewingKey>,
+) -> String {
+    let mut sends = Grouped::default();
+    let mut spends = Grouped::default();
+    let mut other_actions = Vec::new();
+
+    for action_plan in &plan.actions {
+        match action_plan {
+            ActionPlan::Output(output) => {
+                sends.add(address(&output.dest_address, fvk), output.value)
+            }
+            ActionPlan::Spend(spend) => {
+                spends.add(address(&spend.note.address(), fvk), spend.note.value())
+            }
+            other => other_actions.push(action(other, cache, fvk)),
+        }
+    }
+
+    let mut lines = vec![format!(
+        "Chain ID: {}",
+        plan.transaction_parameters.chain_id
+    )];
+    if !sends.0.is_empty() {
+        lines.push("Sends:".to_string());
+        lines.extend(
+            sends
+                .0
+                .iter()
+                .map(|(to, values)| format!("  {} to {}", values_str(values, cache), to)),
+        );
+    }
+    if !other_actions.is_empty() {
+        lines.push("Actions:".to_string());
+        lines.extend(other_actions.into_iter().map(|line| format!("  {line}")));
+    }
+    if !spends.0.is_empty() {
+        lines.push("Spends:".to_string());
+        lines.extend(
+            spends
+                .0
+                .iter()
+                .map(|(from, values)| format!("  {} from {}", values_str(values, cache), from)),
+        );
+    }
+    if let Some(memo) = &plan.memo {
+        if !memo.plaintext.text().is_empty() {
+            lines.push(format!("Memo: {}", memo.plaintext.text()));
+        }
+    }
+    lines.push(format!(
+        "Fee: {}",
+        plan.transaction_parameters.fee.0.format(cache)
+    ));
+    if plan.transaction_parameters.expiry_height != 0 {
+        lines.push(format!(
+            "Expires after height {}",
+            plan.transaction_parameters.expiry_height
+        ));
+    }
+
+    lines.join("\n") + "\n"
+}
+
+/// Render a single planned action as one line.
+pub fn action(action: &ActionPlan, cache: &Cache, fvk: Option<&FullViewingKey>) -> String {
+    let staking_value = |amount| {
+        Value {
+            amount,
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+        .format(cache)
+    };
+
+    match action {
+        ActionPlan::Spend(spend) => format!(
+            "Spend {} from {}",
+            spend.note.value().format(cache),
+            address(&spend.note.address(), fvk)
+        ),
+        ActionPlan::Output(output) => format!(
+            "Send {} to {}",
+            output.value.format(cache),
+            address(&output.dest_address, fvk)
+        ),
+        ActionPlan::Swap(swap) => {
+            let plaintext = &swap.swap_plaintext;
+            let pair = &plaintext.trading_pair;
+            let (input, output_id) = if plaintext.delta_1_i.value() != 0 {
+                (
+                    Value {
+                        amount: plaintext.delta_1_i,
+                        asset_id: pair.asset_1(),
+                    },
+                    pair.asset_2(),
+                )
+            } else {
+                (
+                    Value {
+                        amount: plaintext.delta_2_i,
+                        asset_id: pair.asset_2(),
+                    },
+                    pair.asset_1(),
+                )
+            };
+            format!(
+                "Swap {} for {} at the batch clearing price, with no price limit, claimable by {} (claim fee {})",
+                input.format(cache),
+                asset_name(&output_id, cache),
+                address(&plaintext.claim_address, fvk),
+                plaintext.claim_fee.0.format(cache)
+            )
+        }
+        ActionPlan::SwapClaim(claim) => {
+            let plaintext = &claim.swap_plaintext;
+            let (lambda_1, lambda_2) = claim
+                .output_data
+                .pro_rata_outputs((plaintext.delta_1_i, plaintext.delta_2_i));
+            let outputs = [
+                Value {
+                    amount: lambda_1,
+                    asset_id: plaintext.trading_pair.asset_1(),
+                },
+                Value {
+                    amount: lambda_2,
+                    asset_id: plaintext.trading_pair.asset_2(),
+                },
+            ]
+            .into_iter()
+            .filter(|value| value.amount.value() != 0)
+            .collect::<Vec<_>>();
+            format!(
+                "Claim {} from the swap at height {} to {}",
+                values_str(&outputs, cache),
+                claim.output_data.height,
+                address(&plaintext.claim_address, fvk)
+            )
+        }
+        ActionPlan::PositionOpen(open) => {
+            let mut line = format!(
+                "Open liquidity position {}",
+                position_orders(&open.position, cache)
+            );
+            if open.position.close_on_fill {
+                line.push_str(", closing once filled");
+            }
+            line
+        }
+        ActionPlan::PositionClose(close) => {
+            format!("Close liquidity position {}", close.position_id)
+        }
+        ActionPlan::PositionWithdraw(withdraw) => {
+            let reserves = [
+                Value {
+                    amount: withdraw.reserves.r1,
+                    asset_id: withdraw.pair.asset_1(),
+                },
+                Value {
+                    amount: withdraw.reserves.r2,
+                    asset_id: withdraw.pair.asset_2(),
+                },
+            ];
+            format!(
+                "Withdraw {} from liquidity position {}",
+                values_str(&reserves, cache),
+                withdraw.position_id
+            )
+        }
+        ActionPlan::Delegate(delegate) => format!(
+            "Delegate {} to validator {}",
+            staking_value(delegate.unbonded_amount),
+            delegate.validator_identity
+        ),
+        ActionPlan::Undelegate(undelegate) => format!(
+            "Undelegate {} from validator {}",
+            staking_value(undelegate.unbonded_amount),
+            undelegate.validator_identity
+        ),
+        ActionPlan::UndelegateClaim(claim) => format!(
+            "Claim {} of unbonded stake from validator {}, less any slashing penalty",
+            staking_value(claim.unbonding_amount),
+            claim.validator_identity
+        ),
+        ActionPlan::ValidatorDefinition(definition) => format!(
+            "Upload definition #{} for validator {} ({})",
+            definition.validator.sequence_number,
+            definition.validator.name,
+            definition.validator.identity_key
+        ),
+        ActionPlan::Ics20Withdrawal(withdrawal) => {
+            let unit = withdrawal.denom.best_unit_for(withdrawal.amount);
+            let mut line = format!(
+                "Withdraw {}{} over IBC to {} via {}",
+                unit.format_value(withdrawal.amount),
+                unit,
+                withdrawal.destination_chain_address,
+                withdrawal.source_channel
+            );
+            if !withdrawal.ics20_memo.is_empty() {
+                line.push_str(&format!(" (IBC memo: {})", withdrawal.ics20_memo));
+            }
+            line
+        }
+        ActionPlan::IbcAction(_) => "Relay an IBC message".to_string(),
+        ActionPlan::ProposalSubmit(submit) => format!(
+            "Submit governance proposal #{} \"{}\" with a deposit of {}",
+            submit.proposal.id,
+            submit.proposal.title,
+            staking_value(submit.deposit_amount)
+        ),
+        ActionPlan::ProposalWithdraw(withdraw) => format!(
+            "Withdraw governance proposal #{}: {}",
+            withdraw.proposal, withdraw.reason
+        ),
+        ActionPlan::DelegatorVote(vote) => format!(
+            "Vote {} on governance proposal #{} with {} of voting power",
+            vote.vote,
+            vote.proposal,
+            staking_value(vote.unbonded_amount)
+        ),
+        ActionPlan::ValidatorVote(vote) => format!(
+            "Vote {} on governance proposal #{} as validator {}",
+            vote.body.vote, vote.body.proposal, vote.body.identity_key
+        ),
+        ActionPlan::ProposalDepositClaim(claim) => format!(
+            "Claim the deposit of {} for governance proposal #{}",
+            staking_value(claim.deposit_amount),
+            claim.proposal
+        ),
+        ActionPlan::CommunityPoolSpend(spend) => {
+            format!(
+                "Spend {} from the community pool",
+                spend.value.format(cache)
+            )
+        }
+        ActionPlan::CommunityPoolOutput(output) => format!(
+            "Send {} from the community pool to {}",
+            output.value.format(cache),
+            address(&output.address, fvk)
+        ),
+        ActionPlan::CommunityPoolDeposit(deposit) => format!(
+            "Deposit {} into the community pool",
+            deposit.value.format(cache)
+        ),
+        ActionPlan::ActionDutchAuctionSchedule(schedule) => {
+            let description = &schedule.description;
+            let output = |amount| {
+                Value {
+                    amount,
+                    asset_id: description.output_id,
+                }
+                .format(cache)
+            };
+            format!(
+                "Auction {} for between {} and {}, from height {} to {} in {} steps",
+                description.input.format(cache),
+                output(description.min_output),
+                output(description.max_output),
+                description.start_height,
+                description.end_height,
+                description.step_count
+            )
+        }
+        ActionPlan::ActionDutchAuctionEnd(end) => format!("End auction {}", end.auction_id),
+        ActionPlan::ActionDutchAuctionWithdraw(withdraw) => format!(
+            "Withdraw {} from auction {}",
+            values_str(&[withdraw.reserves_input, withdraw.reserves_output], cache),
+            withdraw.auction_id
+        ),
+        ActionPlan::ActionTwapAuctionSchedule(schedule) => {
+            let description = &schedule.description;
+            format!(
+                "Auction {} for at least {}, from height {} to {} in {} tranches",
+                description.input.format(cache),
+                Value {
+                    amount: description.min_output,
+                    asset_id: description.output_id,
+                }
+                .format(cache),
+                description.start_height,
+                description.end_height,
+                description.step_count
+            )
+        }
+        ActionPlan::ActionTwapAuctionEnd(end) => format!("End auction {}", end.auction_id),
+        ActionPlan::ActionTwapAuctionWithdraw(withdraw) => format!(
+            "Withdraw {} from auction {}",
+            values_str(&[withdraw.reserves_input, withdraw.reserves_output], cache),
+            withdraw.auction_id
+        ),
+        ActionPlan::ActionLiquidityTournamentVote(vote) => format!(
+            "Vote for {} in the liquidity tournament with {}, with rewards sent to {}",
+            vote.incentivized.denom,
+            vote.staked_note.value().format(cache),
+            address(&vote.rewards_recipient, fvk)
+        ),
+    }
+}
+
+fn validator_definition(validator: &Validator) -> String {
+    let mut lines = vec![
+        format!("Validator definition #{}", validator.sequence_number),
+        format!("Name: {}", validator.name),
+        format!("Identity key: {}", validator.identity_key),
+        format!("Website: {}", validator.website),
+        format!("Description: {}", validator.description),
+        format!("Enabled: {}", if validator.enabled { "yes" } else { "no" }),
+        "Funding streams:".to_string(),
+    ];
+    lines.extend(validator.funding_streams.iter().map(|stream| match stream {
+        FundingStream::ToAddress { address, rate_bps } => {
+            format!("  {rate_bps}bps to {address}")
+        }
+        FundingStream::ToCommunityPool { rate_bps } => {
+            format!("  {rate_bps}bps to the community pool")
+        }
+    }));
+    lines.join("\n") + "\n"
+}
+
+fn validator_vote(vote: &ValidatorVoteBody) -> String {
+    let mut lines = vec![
+        format!(
+            "Vote {} on governance proposal #{}",
+            vote.vote, vote.proposal
+        ),
+        format!("Validator: {}", vote.identity_key),
+    ];
+    if !vote.reason.0.is_empty() {
+        lines.push(format!("Reason: {}", vote.reason.0));
+    }
+    lines.join("\n") + "\n"
+}
+
+/// Describe the orders a liquidity position makes, with their implied prices.
+fn position_orders(position: &Position, cache: &Cache) -> String {
+    if let Some(Ok(order)) = position
+        .interpret_as_sell()
+        .map(|order| order.format(cache))
+    {
+        return format!("selling {order}");
+    }
+    if let Some((first, second)) = position.interpret_as_mixed() {
+        if let (Ok(first), Ok(second)) = (first.format(cache), second.format(cache)) {
+            return format!("selling {first} and {second}");
+        }
+    }
+    // Fall back to the raw trading function if the assets aren't in the cache.
+    format!(
+        "with reserves {} and a {}bps fee",
+        values_str(&[position.reserves_1(), position.reserves_2()], cache),
+        position.phi.component.fee
+    )
+}
+
+fn address(address: &Address, fvk: Option<&FullViewingKey>) -> String {
+    match fvk.and_then(|fvk| fvk.address_index(address)) {
+        Some(index) if index.is_ephemeral() => {
+            format!("[account {} (one-time address)]", index.account)
+        }
+        Some(index) => format!("[account {}]", index.account),
+        None => address.to_string(),
+    }
+}
+
+fn asset_name(id: &asset::Id, cache: &Cache) -> String {
+    cache
+        .get(id)
+        .map(|metadata| metadata.default_unit().to_string())
+        .unwrap_or_else(|| id.to_string())
+}
+
+fn values_str(values: &[Value], cache: &Cache) -> String {
+    values
+        .iter()
+        .map(|value| value.format(cache))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Values moving to or from each address, in order of first appearance.
+#[derive(Default)]
+struct Grouped(Vec<(String, Vec<Value>)>);
+
+impl Grouped {
+    fn add(&mut self, address: String, value: Value) {
+        let index = match self.0.iter().position(|(existing, _)| *existing == address) {
+            Some(index) => index,
+            None => {
+                self.0.push((address, Vec::new()));
+                self.0.len() - 1
+            }
+        };
+        let values = &mut self.0[index].1;
+        match values
+            .iter_mut()
+            .find(|existing| existing.asset_id == value.asset_id)
+        {
+            Some(existing) => existing.amount += value.amount,
+            None => values.push(value),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{path::Path, str::FromStr};
+
+    use ibc_types::core::{channel::ChannelId, client::Height as IbcHeight};
+
+    use penumbra_sdk_asset::STAKING_TOKEN_DENOM;
+    use penumbra_sdk_dex::{
+        lp::{plan::PositionOpenPlan, SellOrder},
+        swap::{SwapPlaintext, SwapPlan},
+        TradingPair,
+    };
+    use penumbra_sdk_fee::Fee;
+    use penumbra_sdk_keys::test_keys;
+    use penumbra_sdk_sct::epoch::Epoch;
+    use penumbra_sdk_shielded_pool::{Ics20Withdrawal, Note, OutputPlan, SpendPlan};
+    use penumbra_sdk_stake::{Delegate, IdentityKey, Undelegate};
+    use penumbra_sdk_transaction::{memo::MemoPlaintext, plan::MemoPlan, TransactionParameters};
+    use rand_core::OsRng;
+
+    use super::*;
+
+    const VALIDATOR: &str =
+        "penumbravalid1jzcc6vsm29am9ggs8z0d7s9jk9uf8tfrqg7hglc9ufs7r23nu5yqtw77ex";
+
+    /// Compare a rendering against its golden file in `testdata/render`.
+    ///
+    /// Run with `UPDATE_GOLDEN=1` to rewrite the golden files after an
+    /// intentional change to the rendering.
+    fn check_golden(name: &str, rendered: &str) {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("testdata/render")
+            .join(format!("{name}.txt"));
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::write(&path, rendered).expect("can write golden file");
+            return;
+        }
+        let expected = std::fs::read_to_string(&path).expect("golden file exists");
+        assert_eq!(
+            rendered, expected,
+            "rendering of {name} changed; rerun with UPDATE_GOLDEN=1 if this is intended"
+        );
+    }
+
+    fn penumbra(amount: u64) -> Value {
+        Value {
+            amount: amount.into(),
+            asset_id: *STAKING_TOKEN_ASSET_ID,
+        }
+    }
+
+    fn plan(actions: Vec<ActionPlan>, fee: Fee, expiry_height: u64) -> TransactionPlan {
+        TransactionPlan {
+            actions,
+            transaction_parameters: TransactionParameters {
+                expiry_height,
+                chain_id: "penumbra-testnet-1".to_string(),
+                fee,
+            },
+            detection_data: None,
+            memo: None,
+        }
+    }
+
+    /// Send 7penumbra from `own` to `recipient`, with the change going back to `own`.
+    fn send_plan(own: &Address, recipient: &Address) -> anyhow::Result<TransactionPlan> {
+        let note = Note::generate(&mut OsRng, own, penumbra(10_000_000));
+        let mut plan = plan(
+            vec![
+                SpendPlan::new(&mut OsRng, note, 0u64.into()).into(),
+                OutputPlan::new(&mut OsRng, penumbra(7_000_000), recipient.clone()).into(),
+                OutputPlan::new(&mut OsRng, penumbra(2_980_000), own.clone()).into(),
+            ],
+            Fee::from_staking_token_amount(20_000u64.into()),
+            0,
+        );
+        plan.memo = Some(MemoPlan::new(
+            &mut OsRng,
+            MemoPlaintext::new(own.clone(), "thanks for lunch".to_string())?,
+        ));
+        Ok(plan)
+    }
+
+    #[test]
+    fn send() -> anyhow::Result<()> {
+        let cache = Cache::with_known_assets();
+        let plan = send_plan(&test_keys::ADDRESS_0, &test_keys::ADDRESS_1)?;
+        check_golden("send", &transaction_plan(&plan, &cache, None));
+        Ok(())
+    }
+
+    #[test]
+    fn send_with_fvk() -> anyhow::Result<()> {
+        let cache = Cache::with_known_assets();
+        let fvk = &*test_keys::FULL_VIEWING_KEY;
+        let plan = send_plan(
+            &fvk.payment_address(0u32.into()).0,
+            &fvk.payment_address(1u32.into()).0,
+        )?;
+        check_golden("send_with_fvk", &transaction_plan(&plan, &cache, Some(fvk)));
+        Ok(())
+    }
+
+    #[test]
+    fn dex() -> anyhow::Result<()> {
+        let cache = Cache::with_known_assets();
+        let gm = cache.get_unit("gm").expect("gm is a known asset").id();
+        let pair = TradingPair::new(*STAKING_TOKEN_ASSET_ID, gm);
+        let (delta_1, delta_2) = if pair.asset_1() == *STAKING_TOKEN_ASSET_ID {
+            (10_000_000u64.into(), 0u64.into())
+        } else {
+            (0u64.into(), 10_000_000u64.into())
+        };
+        let swap = SwapPlaintext::new(
+            &mut OsRng,
+            pair,
+            delta_1,
+            delta_2,
+            Fee::default(),
+            test_keys::ADDRESS_0.clone(),
+        );
+        let position = SellOrder::parse_str("100penumbra@1.2gm/10bps")?.into_position(OsRng);
+
+        let plan = plan(
+            vec![
+                SwapPlan::new(&mut OsRng, swap).into(),
+                ActionPlan::PositionOpen(PositionOpenPlan {
+                    position,
+                    metadata: None,
+                }),
+            ],
+            Fee::default(),
+            1000,
+        );
+        check_golden("dex", &transaction_plan(&plan, &cache, None));
+        Ok(())
+    }
+
+    #[test]
+    fn staking() -> anyhow::Result<()> {
+        let cache = Cache::with_known_assets();
+        let validator_identity: IdentityKey = VALIDATOR.parse()?;
+        let plan = plan(
+            vec![
+                ActionPlan::Delegate(Delegate {
+                    validator_identity,
+                    epoch_index: 2,
+                    unbonded_amount: 5_000_000u64.into(),
+                    delegation_amount: 5_000_000u64.into(),
+                }),
+                ActionPlan::Undelegate(Undelegate {
+                    validator_identity,
+                    from_epoch: Epoch {
+                        index: 2,
+                        start_height: 100,
+                    },
+                    unbonded_amount: 2_500_000u64.into(),
+                    delegation_amount: 2_500_000u64.into(),
+                }),
+            ],
+            Fee::default(),
+            0,
+        );
+        check_golden("staking", &transaction_plan(&plan, &cache, None));
+        Ok(())
+    }
+
+    #[test]
+    fn ibc_withdrawal() -> anyhow::Result<()> {
+        let cache = Cache::with_known_assets();
+        let plan = plan(
+            vec![ActionPlan::Ics20Withdrawal(Ics20Withdrawal {
+                amount: 1_500_000u64.into(),
+                denom: STAKING_TOKEN_DENOM.clone(),
+                destination_chain_address: "osmo1qqqsyqcyq5rqwzqfpg9scrgwpugpzysn7hzdtn"
+                    .to_string(),
+                return_address: test_keys::ADDRESS_0.clone(),
+                timeout_height: IbcHeight::new(1, 1000)?,
+                timeout_time: 0,
+                source_channel: ChannelId::from_str("channel-4")?,
+                use_compat_address: false,
+                ics20_memo: "forward via channel-1".to_string(),
+                use_transparent_address: false,
+            })],
+            Fee::from_staking_token_amount(20_000u64.into()),
+            0,
+        );
+        check_golden("ibc_withdrawal", &transaction_plan(&plan, &cache, None));
+        Ok(())
+    }
+}
@@ -105,6 +105,49 @@ impl ::prost::Name for AuctionStateByIdsResponse {
         "/penumbra.core.component.auction.v1.AuctionStateByIdsResponse".into()
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActiveAuctionsRequest {
+    /// If set, only return auctions selling this asset.
+    #[prost(message, optional, tag = "1")]
+    pub input_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// If set, only return auctions buying this asset.
+    #[prost(message, optional, tag = "2")]
+    pub output_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+}
+impl ::prost::Name for ActiveAuctionsRequest {
+    const NAME: &'static str = "ActiveAuctionsRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActiveAuctionsRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActiveAuctionsRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActiveAuctionsResponse {
+    /// The auction ID of the returned auction.
+    #[prost(message, optional, tag = "1")]
+    pub id: ::core::option::Option<AuctionId>,
+    /// The state of the returned auction.
+    #[prost(message, optional, tag = "2")]
+    pub auction: ::core::option::Option<::pbjson_types::Any>,
+    /// The state of any DEX positions relevant to the returned auction.
+    ///
+    /// Could be empty, depending on the auction state.
+    #[prost(message, repeated, tag = "3")]
+    pub positions: ::prost::alloc::vec::Vec<super::super::dex::v1::Position>,
+}
+impl ::prost::Name for ActiveAuctionsResponse {
+    const NAME: &'static str = "ActiveAuctionsResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActiveAuctionsResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActiveAuctionsResponse".into()
+    }
+}
 /// A unique identifier for an auction, obtained from hashing a domain separator
 /// along with the immutable part of an auction description.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -539,6 +582,305 @@ impl ::prost::Name for EventValueCircuitBreakerDebit {
         "/penumbra.core.component.auction.v1.EventValueCircuitBreakerDebit".into()
     }
 }
+/// Describes a gradual-release (TWAP) auction using programmatic liquidity on the DEX.
+///
+/// The input is released in `step_count` even tranches between `start_height`
+/// and `end_height`, each offered at the floor price implied by `min_output`.
+/// Any unsold part of a tranche is carried over to the next one.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwapAuctionDescription {
+    /// The value the seller wishes to auction.
+    #[prost(message, optional, tag = "1")]
+    pub input: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The asset ID of the target asset the seller wishes to acquire.
+    #[prost(message, optional, tag = "2")]
+    pub output_id: ::core::option::Option<super::super::super::asset::v1::AssetId>,
+    /// The minimum output the seller is willing to receive for the whole input.
+    ///
+    /// This implicitly defines the floor price for every tranche.
+    #[prost(message, optional, tag = "3")]
+    pub min_output: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The block height at which the first tranche is released.
+    #[prost(uint64, tag = "4")]
+    pub start_height: u64,
+    /// The block height at which the auction ends.
+    #[prost(uint64, tag = "5")]
+    pub end_height: u64,
+    /// The number of tranches the input is released in.
+    ///
+    /// `end_height - start_height` must be a multiple of `step_count`.
+    #[prost(uint64, tag = "6")]
+    pub step_count: u64,
+    /// A random nonce used to allow identical auctions to have
+    /// distinct auction IDs.
+    #[prost(bytes = "vec", tag = "7")]
+    pub nonce: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for TwapAuctionDescription {
+    const NAME: &'static str = "TwapAuctionDescription";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.TwapAuctionDescription".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.TwapAuctionDescription".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwapAuctionState {
+    /// The sequence number of the auction state.
+    ///
+    /// TWAP auctions move from:
+    /// 0 (opened) => 1 (closed) => n (withdrawn)
+    #[prost(uint64, tag = "1")]
+    pub seq: u64,
+    /// If present, the current position controlled by this auction.
+    #[prost(message, optional, tag = "2")]
+    pub current_position: ::core::option::Option<super::super::dex::v1::PositionId>,
+    /// If present, the next trigger height to release a tranche.
+    #[prost(uint64, tag = "3")]
+    pub next_trigger: u64,
+    /// The amount of the input asset directly owned by the auction.
+    ///
+    /// The auction may also own the input asset indirectly,
+    /// via the reserves of `current_position` if it exists.
+    #[prost(message, optional, tag = "4")]
+    pub input_reserves: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of the output asset directly owned by the auction.
+    ///
+    /// The auction may also own the output asset indirectly,
+    /// via the reserves of `current_position` if it exists.
+    #[prost(message, optional, tag = "5")]
+    pub output_reserves: ::core::option::Option<super::super::super::num::v1::Amount>,
+}
+impl ::prost::Name for TwapAuctionState {
+    const NAME: &'static str = "TwapAuctionState";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.TwapAuctionState".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.TwapAuctionState".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TwapAuction {
+    /// The immutable data describing the auction and its auction ID.
+    #[prost(message, optional, tag = "1")]
+    pub description: ::core::option::Option<TwapAuctionDescription>,
+    /// The mutable data describing the auction's execution.
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<TwapAuctionState>,
+}
+impl ::prost::Name for TwapAuction {
+    const NAME: &'static str = "TwapAuction";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.TwapAuction".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.TwapAuction".into()
+    }
+}
+/// Initiates a TWAP auction using protocol-controlled liquidity.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionTwapAuctionSchedule {
+    #[prost(message, optional, tag = "1")]
+    pub description: ::core::option::Option<TwapAuctionDescription>,
+}
+impl ::prost::Name for ActionTwapAuctionSchedule {
+    const NAME: &'static str = "ActionTwapAuctionSchedule";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActionTwapAuctionSchedule".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActionTwapAuctionSchedule".into()
+    }
+}
+/// Terminate the TWAP auction associated with the specified `auction_id`
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionTwapAuctionEnd {
+    /// The auction to end.
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+}
+impl ::prost::Name for ActionTwapAuctionEnd {
+    const NAME: &'static str = "ActionTwapAuctionEnd";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActionTwapAuctionEnd".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActionTwapAuctionEnd".into()
+    }
+}
+/// Withdraw funds from the ended TWAP auction associated with the specified `auction_id`
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionTwapAuctionWithdraw {
+    /// The auction to withdraw funds from.
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    /// The sequence number of the withdrawal.
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
+    /// A transparent (zero blinding factor) commitment to the
+    /// auction's final reserves.
+    ///
+    /// The chain will check this commitment by recomputing it
+    /// with the on-chain state.
+    #[prost(message, optional, tag = "3")]
+    pub reserves_commitment: ::core::option::Option<
+        super::super::super::asset::v1::BalanceCommitment,
+    >,
+}
+impl ::prost::Name for ActionTwapAuctionWithdraw {
+    const NAME: &'static str = "ActionTwapAuctionWithdraw";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActionTwapAuctionWithdraw".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActionTwapAuctionWithdraw".into()
+    }
+}
+/// A plan to a `ActionTwapAuctionWithdraw` which contains both private and public data.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionTwapAuctionWithdrawPlan {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    #[prost(uint64, tag = "2")]
+    pub seq: u64,
+    #[prost(message, optional, tag = "3")]
+    pub reserves_input: ::core::option::Option<super::super::super::asset::v1::Value>,
+    #[prost(message, optional, tag = "4")]
+    pub reserves_output: ::core::option::Option<super::super::super::asset::v1::Value>,
+}
+impl ::prost::Name for ActionTwapAuctionWithdrawPlan {
+    const NAME: &'static str = "ActionTwapAuctionWithdrawPlan";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawPlan".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawPlan".into()
+    }
+}
+/// An `ActionTwapAuctionSchedule` augmented with additional metadata.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionTwapAuctionScheduleView {
+    #[prost(message, optional, tag = "1")]
+    pub action: ::core::option::Option<ActionTwapAuctionSchedule>,
+    #[prost(message, optional, tag = "2")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    #[prost(message, optional, tag = "3")]
+    pub input_metadata: ::core::option::Option<super::super::super::asset::v1::Metadata>,
+    #[prost(message, optional, tag = "4")]
+    pub output_metadata: ::core::option::Option<
+        super::super::super::asset::v1::Metadata,
+    >,
+}
+impl ::prost::Name for ActionTwapAuctionScheduleView {
+    const NAME: &'static str = "ActionTwapAuctionScheduleView";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActionTwapAuctionScheduleView".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActionTwapAuctionScheduleView".into()
+    }
+}
+/// An `ActionTwapAuctionWithdraw` augmented with additional metadata.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ActionTwapAuctionWithdrawView {
+    #[prost(message, optional, tag = "1")]
+    pub action: ::core::option::Option<ActionTwapAuctionWithdraw>,
+    /// A sequence of values that sum together to the provided
+    /// reserves commitment.
+    #[prost(message, repeated, tag = "2")]
+    pub reserves: ::prost::alloc::vec::Vec<super::super::super::asset::v1::ValueView>,
+}
+impl ::prost::Name for ActionTwapAuctionWithdrawView {
+    const NAME: &'static str = "ActionTwapAuctionWithdrawView";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawView".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawView".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventTwapAuctionScheduled {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    #[prost(message, optional, tag = "2")]
+    pub description: ::core::option::Option<TwapAuctionDescription>,
+}
+impl ::prost::Name for EventTwapAuctionScheduled {
+    const NAME: &'static str = "EventTwapAuctionScheduled";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.EventTwapAuctionScheduled".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.EventTwapAuctionScheduled".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventTwapAuctionUpdated {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<TwapAuctionState>,
+}
+impl ::prost::Name for EventTwapAuctionUpdated {
+    const NAME: &'static str = "EventTwapAuctionUpdated";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.EventTwapAuctionUpdated".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.EventTwapAuctionUpdated".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventTwapAuctionEnded {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<TwapAuctionState>,
+    /// The reason the auction ended.
+    #[prost(enumeration = "event_dutch_auction_ended::Reason", tag = "3")]
+    pub reason: i32,
+}
+impl ::prost::Name for EventTwapAuctionEnded {
+    const NAME: &'static str = "EventTwapAuctionEnded";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.EventTwapAuctionEnded".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.EventTwapAuctionEnded".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EventTwapAuctionWithdrawn {
+    #[prost(message, optional, tag = "1")]
+    pub auction_id: ::core::option::Option<AuctionId>,
+    #[prost(message, optional, tag = "2")]
+    pub state: ::core::option::Option<TwapAuctionState>,
+}
+impl ::prost::Name for EventTwapAuctionWithdrawn {
+    const NAME: &'static str = "EventTwapAuctionWithdrawn";
+    const PACKAGE: &'static str = "penumbra.core.component.auction.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.auction.v1.EventTwapAuctionWithdrawn".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.auction.v1.EventTwapAuctionWithdrawn".into()
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -692,6 +1034,36 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Stream the currently active auctions, optionally filtered by asset pair.
+        pub async fn active_auctions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ActiveAuctionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ActiveAuctionsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.auction.v1.QueryService/ActiveAuctions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.auction.v1.QueryService",
+                        "ActiveAuctions",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -733,6 +1105,20 @@ pub mod query_service_server {
             tonic::Response<Self::AuctionStateByIdsStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ActiveAuctions method.
+        type ActiveAuctionsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ActiveAuctionsResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Stream the currently active auctions, optionally filtered by asset pair.
+        async fn active_auctions(
+            &self,
+            request: tonic::Request<super::ActiveAuctionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ActiveAuctionsStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the auction component.
     #[derive(Debug)]
@@ -905,6 +1291,52 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.auction.v1.QueryService/ActiveAuctions" => {
+                    #[allow(non_camel_case_types)]
+                    struct ActiveAuctionsSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::ActiveAuctionsRequest>
+                    for ActiveAuctionsSvc<T> {
+                        type Response = super::ActiveAuctionsResponse;
+                        type ResponseStream = T::ActiveAuctionsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ActiveAuctionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::active_auctions(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ActiveAuctionsSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
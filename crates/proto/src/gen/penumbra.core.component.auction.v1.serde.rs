@@ -738,7 +738,7 @@ impl<'de> serde::Deserialize<'de> for ActionDutchAuctionWithdrawView {
         deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActionDutchAuctionWithdrawView", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuctionId {
+impl serde::Serialize for ActionTwapAuctionEnd {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -746,31 +746,30 @@ impl serde::Serialize for AuctionId {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.inner.is_empty() {
+        if self.auction_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionId", len)?;
-        if !self.inner.is_empty() {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionEnd", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuctionId {
+impl<'de> serde::Deserialize<'de> for ActionTwapAuctionEnd {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "inner",
+            "auction_id",
+            "auctionId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Inner,
+            AuctionId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -793,7 +792,7 @@ impl<'de> serde::Deserialize<'de> for AuctionId {
                         E: serde::de::Error,
                     {
                         match value {
-                            "inner" => Ok(GeneratedField::Inner),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -803,41 +802,39 @@ impl<'de> serde::Deserialize<'de> for AuctionId {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuctionId;
+            type Value = ActionTwapAuctionEnd;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionId")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActionTwapAuctionEnd")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionId, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionTwapAuctionEnd, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut inner__ = None;
+                let mut auction_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Inner => {
-                            if inner__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inner"));
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
                             }
-                            inner__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
-                            ;
+                            auction_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuctionId {
-                    inner: inner__.unwrap_or_default(),
+                Ok(ActionTwapAuctionEnd {
+                    auction_id: auction_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionId", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionEnd", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuctionNft {
+impl serde::Serialize for ActionTwapAuctionSchedule {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -845,39 +842,29 @@ impl serde::Serialize for AuctionNft {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.id.is_some() {
-            len += 1;
-        }
-        if self.seq != 0 {
+        if self.description.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionNft", len)?;
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
-        }
-        if self.seq != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionSchedule", len)?;
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuctionNft {
+impl<'de> serde::Deserialize<'de> for ActionTwapAuctionSchedule {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
-            "seq",
+            "description",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
-            Seq,
+            Description,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -900,8 +887,7 @@ impl<'de> serde::Deserialize<'de> for AuctionNft {
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
-                            "seq" => Ok(GeneratedField::Seq),
+                            "description" => Ok(GeneratedField::Description),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -911,71 +897,96 @@ impl<'de> serde::Deserialize<'de> for AuctionNft {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuctionNft;
+            type Value = ActionTwapAuctionSchedule;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionNft")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActionTwapAuctionSchedule")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionNft, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionTwapAuctionSchedule, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
-                let mut seq__ = None;
+                let mut description__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
-                            }
-                            id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Seq => {
-                            if seq__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("seq"));
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
                             }
-                            seq__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
+                            description__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuctionNft {
-                    id: id__,
-                    seq: seq__.unwrap_or_default(),
+                Ok(ActionTwapAuctionSchedule {
+                    description: description__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionNft", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionSchedule", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuctionParameters {
+impl serde::Serialize for ActionTwapAuctionScheduleView {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let len = 0;
-        let struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionParameters", len)?;
+        let mut len = 0;
+        if self.action.is_some() {
+            len += 1;
+        }
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.input_metadata.is_some() {
+            len += 1;
+        }
+        if self.output_metadata.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionScheduleView", len)?;
+        if let Some(v) = self.action.as_ref() {
+            struct_ser.serialize_field("action", v)?;
+        }
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.input_metadata.as_ref() {
+            struct_ser.serialize_field("inputMetadata", v)?;
+        }
+        if let Some(v) = self.output_metadata.as_ref() {
+            struct_ser.serialize_field("outputMetadata", v)?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuctionParameters {
+impl<'de> serde::Deserialize<'de> for ActionTwapAuctionScheduleView {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "action",
+            "auction_id",
+            "auctionId",
+            "input_metadata",
+            "inputMetadata",
+            "output_metadata",
+            "outputMetadata",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Action,
+            AuctionId,
+            InputMetadata,
+            OutputMetadata,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -997,7 +1008,13 @@ impl<'de> serde::Deserialize<'de> for AuctionParameters {
                     where
                         E: serde::de::Error,
                     {
-                            Ok(GeneratedField::__SkipField__)
+                        match value {
+                            "action" => Ok(GeneratedField::Action),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "inputMetadata" | "input_metadata" => Ok(GeneratedField::InputMetadata),
+                            "outputMetadata" | "output_metadata" => Ok(GeneratedField::OutputMetadata),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -1005,27 +1022,63 @@ impl<'de> serde::Deserialize<'de> for AuctionParameters {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuctionParameters;
+            type Value = ActionTwapAuctionScheduleView;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionParameters")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActionTwapAuctionScheduleView")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionParameters, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionTwapAuctionScheduleView, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                while map_.next_key::<GeneratedField>()?.is_some() {
-                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                let mut action__ = None;
+                let mut auction_id__ = None;
+                let mut input_metadata__ = None;
+                let mut output_metadata__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Action => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("action"));
+                            }
+                            action__ = map_.next_value()?;
+                        }
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::InputMetadata => {
+                            if input_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputMetadata"));
+                            }
+                            input_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputMetadata => {
+                            if output_metadata__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputMetadata"));
+                            }
+                            output_metadata__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
                 }
-                Ok(AuctionParameters {
+                Ok(ActionTwapAuctionScheduleView {
+                    action: action__,
+                    auction_id: auction_id__,
+                    input_metadata: input_metadata__,
+                    output_metadata: output_metadata__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionParameters", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionScheduleView", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuctionStateByIdRequest {
+impl serde::Serialize for ActionTwapAuctionWithdraw {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1033,29 +1086,49 @@ impl serde::Serialize for AuctionStateByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.id.is_some() {
+        if self.auction_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdRequest", len)?;
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
+        if self.seq != 0 {
+            len += 1;
+        }
+        if self.reserves_commitment.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionWithdraw", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if let Some(v) = self.reserves_commitment.as_ref() {
+            struct_ser.serialize_field("reservesCommitment", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuctionStateByIdRequest {
+impl<'de> serde::Deserialize<'de> for ActionTwapAuctionWithdraw {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
+            "auction_id",
+            "auctionId",
+            "seq",
+            "reserves_commitment",
+            "reservesCommitment",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
+            AuctionId,
+            Seq,
+            ReservesCommitment,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1078,7 +1151,9 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "reservesCommitment" | "reserves_commitment" => Ok(GeneratedField::ReservesCommitment),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1088,39 +1163,57 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuctionStateByIdRequest;
+            type Value = ActionTwapAuctionWithdraw;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdRequest")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActionTwapAuctionWithdraw")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionTwapAuctionWithdraw, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
+                let mut auction_id__ = None;
+                let mut seq__ = None;
+                let mut reserves_commitment__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
                             }
-                            id__ = map_.next_value()?;
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ReservesCommitment => {
+                            if reserves_commitment__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesCommitment"));
+                            }
+                            reserves_commitment__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuctionStateByIdRequest {
-                    id: id__,
+                Ok(ActionTwapAuctionWithdraw {
+                    auction_id: auction_id__,
+                    seq: seq__.unwrap_or_default(),
+                    reserves_commitment: reserves_commitment__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionWithdraw", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuctionStateByIdResponse {
+impl serde::Serialize for ActionTwapAuctionWithdrawPlan {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1128,37 +1221,58 @@ impl serde::Serialize for AuctionStateByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.auction.is_some() {
+        if self.auction_id.is_some() {
             len += 1;
         }
-        if !self.positions.is_empty() {
+        if self.seq != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdResponse", len)?;
-        if let Some(v) = self.auction.as_ref() {
-            struct_ser.serialize_field("auction", v)?;
+        if self.reserves_input.is_some() {
+            len += 1;
         }
-        if !self.positions.is_empty() {
-            struct_ser.serialize_field("positions", &self.positions)?;
+        if self.reserves_output.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawPlan", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if let Some(v) = self.reserves_input.as_ref() {
+            struct_ser.serialize_field("reservesInput", v)?;
+        }
+        if let Some(v) = self.reserves_output.as_ref() {
+            struct_ser.serialize_field("reservesOutput", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuctionStateByIdResponse {
+impl<'de> serde::Deserialize<'de> for ActionTwapAuctionWithdrawPlan {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "auction",
-            "positions",
+            "auction_id",
+            "auctionId",
+            "seq",
+            "reserves_input",
+            "reservesInput",
+            "reserves_output",
+            "reservesOutput",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Auction,
-            Positions,
+            AuctionId,
+            Seq,
+            ReservesInput,
+            ReservesOutput,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1181,8 +1295,10 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "auction" => Ok(GeneratedField::Auction),
-                            "positions" => Ok(GeneratedField::Positions),
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "reservesInput" | "reserves_input" => Ok(GeneratedField::ReservesInput),
+                            "reservesOutput" | "reserves_output" => Ok(GeneratedField::ReservesOutput),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1192,47 +1308,65 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuctionStateByIdResponse;
+            type Value = ActionTwapAuctionWithdrawPlan;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdResponse")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawPlan")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionTwapAuctionWithdrawPlan, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut auction__ = None;
-                let mut positions__ = None;
+                let mut auction_id__ = None;
+                let mut seq__ = None;
+                let mut reserves_input__ = None;
+                let mut reserves_output__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Auction => {
-                            if auction__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auction"));
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
                             }
-                            auction__ = map_.next_value()?;
+                            auction_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Positions => {
-                            if positions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positions"));
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
                             }
-                            positions__ = Some(map_.next_value()?);
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ReservesInput => {
+                            if reserves_input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesInput"));
+                            }
+                            reserves_input__ = map_.next_value()?;
+                        }
+                        GeneratedField::ReservesOutput => {
+                            if reserves_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reservesOutput"));
+                            }
+                            reserves_output__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuctionStateByIdResponse {
-                    auction: auction__,
-                    positions: positions__.unwrap_or_default(),
+                Ok(ActionTwapAuctionWithdrawPlan {
+                    auction_id: auction_id__,
+                    seq: seq__.unwrap_or_default(),
+                    reserves_input: reserves_input__,
+                    reserves_output: reserves_output__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawPlan", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuctionStateByIdsRequest {
+impl serde::Serialize for ActionTwapAuctionWithdrawView {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1240,29 +1374,37 @@ impl serde::Serialize for AuctionStateByIdsRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.id.is_empty() {
+        if self.action.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsRequest", len)?;
-        if !self.id.is_empty() {
-            struct_ser.serialize_field("id", &self.id)?;
+        if !self.reserves.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawView", len)?;
+        if let Some(v) = self.action.as_ref() {
+            struct_ser.serialize_field("action", v)?;
+        }
+        if !self.reserves.is_empty() {
+            struct_ser.serialize_field("reserves", &self.reserves)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuctionStateByIdsRequest {
+impl<'de> serde::Deserialize<'de> for ActionTwapAuctionWithdrawView {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
+            "action",
+            "reserves",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
+            Action,
+            Reserves,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1285,7 +1427,8 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdsRequest {
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
+                            "action" => Ok(GeneratedField::Action),
+                            "reserves" => Ok(GeneratedField::Reserves),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1295,39 +1438,47 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdsRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuctionStateByIdsRequest;
+            type Value = ActionTwapAuctionWithdrawView;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdsRequest")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawView")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdsRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActionTwapAuctionWithdrawView, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
+                let mut action__ = None;
+                let mut reserves__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
+                        GeneratedField::Action => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("action"));
                             }
-                            id__ = Some(map_.next_value()?);
+                            action__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuctionStateByIdsRequest {
-                    id: id__.unwrap_or_default(),
+                Ok(ActionTwapAuctionWithdrawView {
+                    action: action__,
+                    reserves: reserves__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActionTwapAuctionWithdrawView", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for AuctionStateByIdsResponse {
+impl serde::Serialize for ActiveAuctionsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1335,45 +1486,39 @@ impl serde::Serialize for AuctionStateByIdsResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.id.is_some() {
-            len += 1;
-        }
-        if self.auction.is_some() {
+        if self.input_id.is_some() {
             len += 1;
         }
-        if !self.positions.is_empty() {
+        if self.output_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsResponse", len)?;
-        if let Some(v) = self.id.as_ref() {
-            struct_ser.serialize_field("id", v)?;
-        }
-        if let Some(v) = self.auction.as_ref() {
-            struct_ser.serialize_field("auction", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActiveAuctionsRequest", len)?;
+        if let Some(v) = self.input_id.as_ref() {
+            struct_ser.serialize_field("inputId", v)?;
         }
-        if !self.positions.is_empty() {
-            struct_ser.serialize_field("positions", &self.positions)?;
+        if let Some(v) = self.output_id.as_ref() {
+            struct_ser.serialize_field("outputId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for AuctionStateByIdsResponse {
+impl<'de> serde::Deserialize<'de> for ActiveAuctionsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "id",
-            "auction",
-            "positions",
+            "input_id",
+            "inputId",
+            "output_id",
+            "outputId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Id,
-            Auction,
-            Positions,
+            InputId,
+            OutputId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1396,9 +1541,8 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdsResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "id" => Ok(GeneratedField::Id),
-                            "auction" => Ok(GeneratedField::Auction),
-                            "positions" => Ok(GeneratedField::Positions),
+                            "inputId" | "input_id" => Ok(GeneratedField::InputId),
+                            "outputId" | "output_id" => Ok(GeneratedField::OutputId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1408,55 +1552,47 @@ impl<'de> serde::Deserialize<'de> for AuctionStateByIdsResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = AuctionStateByIdsResponse;
+            type Value = ActiveAuctionsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdsResponse")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActiveAuctionsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdsResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActiveAuctionsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut id__ = None;
-                let mut auction__ = None;
-                let mut positions__ = None;
+                let mut input_id__ = None;
+                let mut output_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Id => {
-                            if id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("id"));
-                            }
-                            id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Auction => {
-                            if auction__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auction"));
+                        GeneratedField::InputId => {
+                            if input_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputId"));
                             }
-                            auction__ = map_.next_value()?;
+                            input_id__ = map_.next_value()?;
                         }
-                        GeneratedField::Positions => {
-                            if positions__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positions"));
+                        GeneratedField::OutputId => {
+                            if output_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputId"));
                             }
-                            positions__ = Some(map_.next_value()?);
+                            output_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(AuctionStateByIdsResponse {
-                    id: id__,
-                    auction: auction__,
-                    positions: positions__.unwrap_or_default(),
+                Ok(ActiveAuctionsRequest {
+                    input_id: input_id__,
+                    output_id: output_id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActiveAuctionsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DutchAuction {
+impl serde::Serialize for ActiveAuctionsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1464,37 +1600,45 @@ impl serde::Serialize for DutchAuction {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.description.is_some() {
+        if self.id.is_some() {
             len += 1;
         }
-        if self.state.is_some() {
+        if self.auction.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.DutchAuction", len)?;
-        if let Some(v) = self.description.as_ref() {
-            struct_ser.serialize_field("description", v)?;
+        if !self.positions.is_empty() {
+            len += 1;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.ActiveAuctionsResponse", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
+        }
+        if let Some(v) = self.auction.as_ref() {
+            struct_ser.serialize_field("auction", v)?;
+        }
+        if !self.positions.is_empty() {
+            struct_ser.serialize_field("positions", &self.positions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DutchAuction {
+impl<'de> serde::Deserialize<'de> for ActiveAuctionsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "description",
-            "state",
+            "id",
+            "auction",
+            "positions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Description,
-            State,
+            Id,
+            Auction,
+            Positions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1517,8 +1661,9 @@ impl<'de> serde::Deserialize<'de> for DutchAuction {
                         E: serde::de::Error,
                     {
                         match value {
-                            "description" => Ok(GeneratedField::Description),
-                            "state" => Ok(GeneratedField::State),
+                            "id" => Ok(GeneratedField::Id),
+                            "auction" => Ok(GeneratedField::Auction),
+                            "positions" => Ok(GeneratedField::Positions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1528,47 +1673,55 @@ impl<'de> serde::Deserialize<'de> for DutchAuction {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DutchAuction;
+            type Value = ActiveAuctionsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.DutchAuction")
+                formatter.write_str("struct penumbra.core.component.auction.v1.ActiveAuctionsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuction, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ActiveAuctionsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut description__ = None;
-                let mut state__ = None;
+                let mut id__ = None;
+                let mut auction__ = None;
+                let mut positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Description => {
-                            if description__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("description"));
-                            }
-                            description__ = map_.next_value()?;
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Auction => {
+                            if auction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auction"));
                             }
-                            state__ = map_.next_value()?;
+                            auction__ = map_.next_value()?;
+                        }
+                        GeneratedField::Positions => {
+                            if positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positions"));
+                            }
+                            positions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DutchAuction {
-                    description: description__,
-                    state: state__,
+                Ok(ActiveAuctionsResponse {
+                    id: id__,
+                    auction: auction__,
+                    positions: positions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuction", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.ActiveAuctionsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DutchAuctionDescription {
+impl serde::Serialize for AuctionId {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1576,99 +1729,31 @@ impl serde::Serialize for DutchAuctionDescription {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.input.is_some() {
-            len += 1;
-        }
-        if self.output_id.is_some() {
-            len += 1;
-        }
-        if self.max_output.is_some() {
-            len += 1;
-        }
-        if self.min_output.is_some() {
-            len += 1;
-        }
-        if self.start_height != 0 {
-            len += 1;
-        }
-        if self.end_height != 0 {
-            len += 1;
-        }
-        if self.step_count != 0 {
-            len += 1;
-        }
-        if !self.nonce.is_empty() {
+        if !self.inner.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.DutchAuctionDescription", len)?;
-        if let Some(v) = self.input.as_ref() {
-            struct_ser.serialize_field("input", v)?;
-        }
-        if let Some(v) = self.output_id.as_ref() {
-            struct_ser.serialize_field("outputId", v)?;
-        }
-        if let Some(v) = self.max_output.as_ref() {
-            struct_ser.serialize_field("maxOutput", v)?;
-        }
-        if let Some(v) = self.min_output.as_ref() {
-            struct_ser.serialize_field("minOutput", v)?;
-        }
-        if self.start_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
-        }
-        if self.end_height != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
-        }
-        if self.step_count != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("stepCount", ToString::to_string(&self.step_count).as_str())?;
-        }
-        if !self.nonce.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionId", len)?;
+        if !self.inner.is_empty() {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+            struct_ser.serialize_field("inner", pbjson::private::base64::encode(&self.inner).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DutchAuctionDescription {
+impl<'de> serde::Deserialize<'de> for AuctionId {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "input",
-            "output_id",
-            "outputId",
-            "max_output",
-            "maxOutput",
-            "min_output",
-            "minOutput",
-            "start_height",
-            "startHeight",
-            "end_height",
-            "endHeight",
-            "step_count",
-            "stepCount",
-            "nonce",
+            "inner",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Input,
-            OutputId,
-            MaxOutput,
-            MinOutput,
-            StartHeight,
-            EndHeight,
-            StepCount,
-            Nonce,
+            Inner,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1691,14 +1776,7 @@ impl<'de> serde::Deserialize<'de> for DutchAuctionDescription {
                         E: serde::de::Error,
                     {
                         match value {
-                            "input" => Ok(GeneratedField::Input),
-                            "outputId" | "output_id" => Ok(GeneratedField::OutputId),
-                            "maxOutput" | "max_output" => Ok(GeneratedField::MaxOutput),
-                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
-                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
-                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
-                            "stepCount" | "step_count" => Ok(GeneratedField::StepCount),
-                            "nonce" => Ok(GeneratedField::Nonce),
+                            "inner" => Ok(GeneratedField::Inner),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1708,79 +1786,24 @@ impl<'de> serde::Deserialize<'de> for DutchAuctionDescription {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DutchAuctionDescription;
+            type Value = AuctionId;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.DutchAuctionDescription")
+                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionId")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionDescription, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionId, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut input__ = None;
-                let mut output_id__ = None;
-                let mut max_output__ = None;
-                let mut min_output__ = None;
-                let mut start_height__ = None;
-                let mut end_height__ = None;
-                let mut step_count__ = None;
-                let mut nonce__ = None;
+                let mut inner__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Input => {
-                            if input__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("input"));
-                            }
-                            input__ = map_.next_value()?;
-                        }
-                        GeneratedField::OutputId => {
-                            if output_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputId"));
-                            }
-                            output_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::MaxOutput => {
-                            if max_output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("maxOutput"));
-                            }
-                            max_output__ = map_.next_value()?;
-                        }
-                        GeneratedField::MinOutput => {
-                            if min_output__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("minOutput"));
-                            }
-                            min_output__ = map_.next_value()?;
-                        }
-                        GeneratedField::StartHeight => {
-                            if start_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("startHeight"));
-                            }
-                            start_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::EndHeight => {
-                            if end_height__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("endHeight"));
-                            }
-                            end_height__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::StepCount => {
-                            if step_count__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("stepCount"));
-                            }
-                            step_count__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::Nonce => {
-                            if nonce__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nonce"));
+                        GeneratedField::Inner => {
+                            if inner__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inner"));
                             }
-                            nonce__ = 
+                            inner__ = 
                                 Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
@@ -1789,22 +1812,15 @@ impl<'de> serde::Deserialize<'de> for DutchAuctionDescription {
                         }
                     }
                 }
-                Ok(DutchAuctionDescription {
-                    input: input__,
-                    output_id: output_id__,
-                    max_output: max_output__,
-                    min_output: min_output__,
-                    start_height: start_height__.unwrap_or_default(),
-                    end_height: end_height__.unwrap_or_default(),
-                    step_count: step_count__.unwrap_or_default(),
-                    nonce: nonce__.unwrap_or_default(),
+                Ok(AuctionId {
+                    inner: inner__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuctionDescription", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionId", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for DutchAuctionState {
+impl serde::Serialize for AuctionNft {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -1812,69 +1828,39 @@ impl serde::Serialize for DutchAuctionState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.seq != 0 {
-            len += 1;
-        }
-        if self.current_position.is_some() {
-            len += 1;
-        }
-        if self.next_trigger != 0 {
+        if self.id.is_some() {
             len += 1;
         }
-        if self.input_reserves.is_some() {
+        if self.seq != 0 {
             len += 1;
         }
-        if self.output_reserves.is_some() {
-            len += 1;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionNft", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.DutchAuctionState", len)?;
         if self.seq != 0 {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
         }
-        if let Some(v) = self.current_position.as_ref() {
-            struct_ser.serialize_field("currentPosition", v)?;
-        }
-        if self.next_trigger != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("nextTrigger", ToString::to_string(&self.next_trigger).as_str())?;
-        }
-        if let Some(v) = self.input_reserves.as_ref() {
-            struct_ser.serialize_field("inputReserves", v)?;
-        }
-        if let Some(v) = self.output_reserves.as_ref() {
-            struct_ser.serialize_field("outputReserves", v)?;
-        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for DutchAuctionState {
+impl<'de> serde::Deserialize<'de> for AuctionNft {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
+            "id",
             "seq",
-            "current_position",
-            "currentPosition",
-            "next_trigger",
-            "nextTrigger",
-            "input_reserves",
-            "inputReserves",
-            "output_reserves",
-            "outputReserves",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
+            Id,
             Seq,
-            CurrentPosition,
-            NextTrigger,
-            InputReserves,
-            OutputReserves,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1897,11 +1883,8 @@ impl<'de> serde::Deserialize<'de> for DutchAuctionState {
                         E: serde::de::Error,
                     {
                         match value {
+                            "id" => Ok(GeneratedField::Id),
                             "seq" => Ok(GeneratedField::Seq),
-                            "currentPosition" | "current_position" => Ok(GeneratedField::CurrentPosition),
-                            "nextTrigger" | "next_trigger" => Ok(GeneratedField::NextTrigger),
-                            "inputReserves" | "input_reserves" => Ok(GeneratedField::InputReserves),
-                            "outputReserves" | "output_reserves" => Ok(GeneratedField::OutputReserves),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1911,23 +1894,26 @@ impl<'de> serde::Deserialize<'de> for DutchAuctionState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = DutchAuctionState;
+            type Value = AuctionNft;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.DutchAuctionState")
+                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionNft")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionNft, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
+                let mut id__ = None;
                 let mut seq__ = None;
-                let mut current_position__ = None;
-                let mut next_trigger__ = None;
-                let mut input_reserves__ = None;
-                let mut output_reserves__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
+                            }
+                            id__ = map_.next_value()?;
+                        }
                         GeneratedField::Seq => {
                             if seq__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("seq"));
@@ -1936,99 +1922,43 @@ impl<'de> serde::Deserialize<'de> for DutchAuctionState {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::CurrentPosition => {
-                            if current_position__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("currentPosition"));
-                            }
-                            current_position__ = map_.next_value()?;
-                        }
-                        GeneratedField::NextTrigger => {
-                            if next_trigger__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nextTrigger"));
-                            }
-                            next_trigger__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
-                            ;
-                        }
-                        GeneratedField::InputReserves => {
-                            if input_reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("inputReserves"));
-                            }
-                            input_reserves__ = map_.next_value()?;
-                        }
-                        GeneratedField::OutputReserves => {
-                            if output_reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("outputReserves"));
-                            }
-                            output_reserves__ = map_.next_value()?;
-                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(DutchAuctionState {
+                Ok(AuctionNft {
+                    id: id__,
                     seq: seq__.unwrap_or_default(),
-                    current_position: current_position__,
-                    next_trigger: next_trigger__.unwrap_or_default(),
-                    input_reserves: input_reserves__,
-                    output_reserves: output_reserves__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuctionState", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionNft", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventDutchAuctionEnded {
+impl serde::Serialize for AuctionParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.auction_id.is_some() {
-            len += 1;
-        }
-        if self.state.is_some() {
-            len += 1;
-        }
-        if self.reason != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionEnded", len)?;
-        if let Some(v) = self.auction_id.as_ref() {
-            struct_ser.serialize_field("auctionId", v)?;
-        }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
-        }
-        if self.reason != 0 {
-            let v = event_dutch_auction_ended::Reason::try_from(self.reason)
-                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.reason)))?;
-            struct_ser.serialize_field("reason", &v)?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionParameters", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventDutchAuctionEnded {
+impl<'de> serde::Deserialize<'de> for AuctionParameters {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "auction_id",
-            "auctionId",
-            "state",
-            "reason",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AuctionId,
-            State,
-            Reason,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2050,12 +1980,7 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionEnded {
                     where
                         E: serde::de::Error,
                     {
-                        match value {
-                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
-                            "state" => Ok(GeneratedField::State),
-                            "reason" => Ok(GeneratedField::Reason),
-                            _ => Ok(GeneratedField::__SkipField__),
-                        }
+                            Ok(GeneratedField::__SkipField__)
                     }
                 }
                 deserializer.deserialize_identifier(GeneratedVisitor)
@@ -2063,132 +1988,27 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionEnded {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventDutchAuctionEnded;
+            type Value = AuctionParameters;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionEnded")
+                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionParameters")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionEnded, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionParameters, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut auction_id__ = None;
-                let mut state__ = None;
-                let mut reason__ = None;
-                while let Some(k) = map_.next_key()? {
-                    match k {
-                        GeneratedField::AuctionId => {
-                            if auction_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auctionId"));
-                            }
-                            auction_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
-                            }
-                            state__ = map_.next_value()?;
-                        }
-                        GeneratedField::Reason => {
-                            if reason__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reason"));
-                            }
-                            reason__ = Some(map_.next_value::<event_dutch_auction_ended::Reason>()? as i32);
-                        }
-                        GeneratedField::__SkipField__ => {
-                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
-                        }
-                    }
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                 }
-                Ok(EventDutchAuctionEnded {
-                    auction_id: auction_id__,
-                    state: state__,
-                    reason: reason__.unwrap_or_default(),
+                Ok(AuctionParameters {
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionEnded", FIELDS, GeneratedVisitor)
-    }
-}
-impl serde::Serialize for event_dutch_auction_ended::Reason {
-    #[allow(deprecated)]
-    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
-    where
-        S: serde::Serializer,
-    {
-        let variant = match self {
-            Self::Unspecified => "REASON_UNSPECIFIED",
-            Self::Expired => "REASON_EXPIRED",
-            Self::Filled => "REASON_FILLED",
-            Self::ClosedByOwner => "REASON_CLOSED_BY_OWNER",
-        };
-        serializer.serialize_str(variant)
-    }
-}
-impl<'de> serde::Deserialize<'de> for event_dutch_auction_ended::Reason {
-    #[allow(deprecated)]
-    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
-    where
-        D: serde::Deserializer<'de>,
-    {
-        const FIELDS: &[&str] = &[
-            "REASON_UNSPECIFIED",
-            "REASON_EXPIRED",
-            "REASON_FILLED",
-            "REASON_CLOSED_BY_OWNER",
-        ];
-
-        struct GeneratedVisitor;
-
-        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = event_dutch_auction_ended::Reason;
-
-            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                write!(formatter, "expected one of: {:?}", &FIELDS)
-            }
-
-            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
-                    })
-            }
-
-            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                i32::try_from(v)
-                    .ok()
-                    .and_then(|x| x.try_into().ok())
-                    .ok_or_else(|| {
-                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
-                    })
-            }
-
-            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
-            where
-                E: serde::de::Error,
-            {
-                match value {
-                    "REASON_UNSPECIFIED" => Ok(event_dutch_auction_ended::Reason::Unspecified),
-                    "REASON_EXPIRED" => Ok(event_dutch_auction_ended::Reason::Expired),
-                    "REASON_FILLED" => Ok(event_dutch_auction_ended::Reason::Filled),
-                    "REASON_CLOSED_BY_OWNER" => Ok(event_dutch_auction_ended::Reason::ClosedByOwner),
-                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
-                }
-            }
-        }
-        deserializer.deserialize_any(GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionParameters", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventDutchAuctionScheduled {
+impl serde::Serialize for AuctionStateByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2196,38 +2016,29 @@ impl serde::Serialize for EventDutchAuctionScheduled {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.auction_id.is_some() {
-            len += 1;
-        }
-        if self.description.is_some() {
+        if self.id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionScheduled", len)?;
-        if let Some(v) = self.auction_id.as_ref() {
-            struct_ser.serialize_field("auctionId", v)?;
-        }
-        if let Some(v) = self.description.as_ref() {
-            struct_ser.serialize_field("description", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdRequest", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventDutchAuctionScheduled {
+impl<'de> serde::Deserialize<'de> for AuctionStateByIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "auction_id",
-            "auctionId",
-            "description",
+            "id",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AuctionId,
-            Description,
+            Id,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2250,8 +2061,7 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionScheduled {
                         E: serde::de::Error,
                     {
                         match value {
-                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
-                            "description" => Ok(GeneratedField::Description),
+                            "id" => Ok(GeneratedField::Id),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2261,47 +2071,39 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionScheduled {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventDutchAuctionScheduled;
+            type Value = AuctionStateByIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionScheduled")
+                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionScheduled, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut auction_id__ = None;
-                let mut description__ = None;
+                let mut id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AuctionId => {
-                            if auction_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            auction_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::Description => {
-                            if description__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("description"));
-                            }
-                            description__ = map_.next_value()?;
+                            id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventDutchAuctionScheduled {
-                    auction_id: auction_id__,
-                    description: description__,
+                Ok(AuctionStateByIdRequest {
+                    id: id__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionScheduled", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventDutchAuctionUpdated {
+impl serde::Serialize for AuctionStateByIdResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2309,38 +2111,37 @@ impl serde::Serialize for EventDutchAuctionUpdated {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.auction_id.is_some() {
+        if self.auction.is_some() {
             len += 1;
         }
-        if self.state.is_some() {
+        if !self.positions.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionUpdated", len)?;
-        if let Some(v) = self.auction_id.as_ref() {
-            struct_ser.serialize_field("auctionId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdResponse", len)?;
+        if let Some(v) = self.auction.as_ref() {
+            struct_ser.serialize_field("auction", v)?;
         }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        if !self.positions.is_empty() {
+            struct_ser.serialize_field("positions", &self.positions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventDutchAuctionUpdated {
+impl<'de> serde::Deserialize<'de> for AuctionStateByIdResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "auction_id",
-            "auctionId",
-            "state",
+            "auction",
+            "positions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AuctionId,
-            State,
+            Auction,
+            Positions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2363,8 +2164,8 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionUpdated {
                         E: serde::de::Error,
                     {
                         match value {
-                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
-                            "state" => Ok(GeneratedField::State),
+                            "auction" => Ok(GeneratedField::Auction),
+                            "positions" => Ok(GeneratedField::Positions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2374,47 +2175,47 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionUpdated {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventDutchAuctionUpdated;
+            type Value = AuctionStateByIdResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionUpdated")
+                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionUpdated, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut auction_id__ = None;
-                let mut state__ = None;
+                let mut auction__ = None;
+                let mut positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AuctionId => {
-                            if auction_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                        GeneratedField::Auction => {
+                            if auction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auction"));
                             }
-                            auction_id__ = map_.next_value()?;
+                            auction__ = map_.next_value()?;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Positions => {
+                            if positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positions"));
                             }
-                            state__ = map_.next_value()?;
+                            positions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventDutchAuctionUpdated {
-                    auction_id: auction_id__,
-                    state: state__,
+                Ok(AuctionStateByIdResponse {
+                    auction: auction__,
+                    positions: positions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionUpdated", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventDutchAuctionWithdrawn {
+impl serde::Serialize for AuctionStateByIdsRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2422,38 +2223,29 @@ impl serde::Serialize for EventDutchAuctionWithdrawn {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.auction_id.is_some() {
-            len += 1;
-        }
-        if self.state.is_some() {
+        if !self.id.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionWithdrawn", len)?;
-        if let Some(v) = self.auction_id.as_ref() {
-            struct_ser.serialize_field("auctionId", v)?;
-        }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsRequest", len)?;
+        if !self.id.is_empty() {
+            struct_ser.serialize_field("id", &self.id)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventDutchAuctionWithdrawn {
+impl<'de> serde::Deserialize<'de> for AuctionStateByIdsRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "auction_id",
-            "auctionId",
-            "state",
+            "id",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AuctionId,
-            State,
+            Id,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2476,8 +2268,7 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionWithdrawn {
                         E: serde::de::Error,
                     {
                         match value {
-                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
-                            "state" => Ok(GeneratedField::State),
+                            "id" => Ok(GeneratedField::Id),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2487,47 +2278,39 @@ impl<'de> serde::Deserialize<'de> for EventDutchAuctionWithdrawn {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventDutchAuctionWithdrawn;
+            type Value = AuctionStateByIdsRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionWithdrawn")
+                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdsRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionWithdrawn, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdsRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut auction_id__ = None;
-                let mut state__ = None;
+                let mut id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AuctionId => {
-                            if auction_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("auctionId"));
-                            }
-                            auction_id__ = map_.next_value()?;
-                        }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            state__ = map_.next_value()?;
+                            id__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventDutchAuctionWithdrawn {
-                    auction_id: auction_id__,
-                    state: state__,
+                Ok(AuctionStateByIdsRequest {
+                    id: id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionWithdrawn", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventValueCircuitBreakerCredit {
+impl serde::Serialize for AuctionStateByIdsResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2535,48 +2318,45 @@ impl serde::Serialize for EventValueCircuitBreakerCredit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.asset_id.is_some() {
+        if self.id.is_some() {
             len += 1;
         }
-        if self.previous_balance.is_some() {
+        if self.auction.is_some() {
             len += 1;
         }
-        if self.new_balance.is_some() {
+        if !self.positions.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerCredit", len)?;
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsResponse", len)?;
+        if let Some(v) = self.id.as_ref() {
+            struct_ser.serialize_field("id", v)?;
         }
-        if let Some(v) = self.previous_balance.as_ref() {
-            struct_ser.serialize_field("previousBalance", v)?;
+        if let Some(v) = self.auction.as_ref() {
+            struct_ser.serialize_field("auction", v)?;
         }
-        if let Some(v) = self.new_balance.as_ref() {
-            struct_ser.serialize_field("newBalance", v)?;
+        if !self.positions.is_empty() {
+            struct_ser.serialize_field("positions", &self.positions)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
+impl<'de> serde::Deserialize<'de> for AuctionStateByIdsResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset_id",
-            "assetId",
-            "previous_balance",
-            "previousBalance",
-            "new_balance",
-            "newBalance",
+            "id",
+            "auction",
+            "positions",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AssetId,
-            PreviousBalance,
-            NewBalance,
+            Id,
+            Auction,
+            Positions,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2599,9 +2379,9 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
-                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            "id" => Ok(GeneratedField::Id),
+                            "auction" => Ok(GeneratedField::Auction),
+                            "positions" => Ok(GeneratedField::Positions),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2611,55 +2391,55 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventValueCircuitBreakerCredit;
+            type Value = AuctionStateByIdsResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.EventValueCircuitBreakerCredit")
+                formatter.write_str("struct penumbra.core.component.auction.v1.AuctionStateByIdsResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerCredit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<AuctionStateByIdsResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset_id__ = None;
-                let mut previous_balance__ = None;
-                let mut new_balance__ = None;
+                let mut id__ = None;
+                let mut auction__ = None;
+                let mut positions__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
+                        GeneratedField::Id => {
+                            if id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("id"));
                             }
-                            asset_id__ = map_.next_value()?;
+                            id__ = map_.next_value()?;
                         }
-                        GeneratedField::PreviousBalance => {
-                            if previous_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                        GeneratedField::Auction => {
+                            if auction__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auction"));
                             }
-                            previous_balance__ = map_.next_value()?;
+                            auction__ = map_.next_value()?;
                         }
-                        GeneratedField::NewBalance => {
-                            if new_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                        GeneratedField::Positions => {
+                            if positions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positions"));
                             }
-                            new_balance__ = map_.next_value()?;
+                            positions__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventValueCircuitBreakerCredit {
-                    asset_id: asset_id__,
-                    previous_balance: previous_balance__,
-                    new_balance: new_balance__,
+                Ok(AuctionStateByIdsResponse {
+                    id: id__,
+                    auction: auction__,
+                    positions: positions__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerCredit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.AuctionStateByIdsResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for EventValueCircuitBreakerDebit {
+impl serde::Serialize for DutchAuction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2667,38 +2447,1712 @@ impl serde::Serialize for EventValueCircuitBreakerDebit {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.asset_id.is_some() {
-            len += 1;
-        }
-        if self.previous_balance.is_some() {
+        if self.description.is_some() {
             len += 1;
         }
-        if self.new_balance.is_some() {
+        if self.state.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerDebit", len)?;
-        if let Some(v) = self.asset_id.as_ref() {
-            struct_ser.serialize_field("assetId", v)?;
-        }
-        if let Some(v) = self.previous_balance.as_ref() {
-            struct_ser.serialize_field("previousBalance", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.DutchAuction", len)?;
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
         }
-        if let Some(v) = self.new_balance.as_ref() {
-            struct_ser.serialize_field("newBalance", v)?;
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
+impl<'de> serde::Deserialize<'de> for DutchAuction {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "asset_id",
-            "assetId",
-            "previous_balance",
+            "description",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Description,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "description" => Ok(GeneratedField::Description),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.DutchAuction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut description__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuction {
+                    description: description__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DutchAuctionDescription {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output_id.is_some() {
+            len += 1;
+        }
+        if self.max_output.is_some() {
+            len += 1;
+        }
+        if self.min_output.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.step_count != 0 {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.DutchAuctionDescription", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output_id.as_ref() {
+            struct_ser.serialize_field("outputId", v)?;
+        }
+        if let Some(v) = self.max_output.as_ref() {
+            struct_ser.serialize_field("maxOutput", v)?;
+        }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if self.step_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stepCount", ToString::to_string(&self.step_count).as_str())?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuctionDescription {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "input",
+            "output_id",
+            "outputId",
+            "max_output",
+            "maxOutput",
+            "min_output",
+            "minOutput",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "step_count",
+            "stepCount",
+            "nonce",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Input,
+            OutputId,
+            MaxOutput,
+            MinOutput,
+            StartHeight,
+            EndHeight,
+            StepCount,
+            Nonce,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "input" => Ok(GeneratedField::Input),
+                            "outputId" | "output_id" => Ok(GeneratedField::OutputId),
+                            "maxOutput" | "max_output" => Ok(GeneratedField::MaxOutput),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "stepCount" | "step_count" => Ok(GeneratedField::StepCount),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuctionDescription;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.DutchAuctionDescription")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionDescription, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut input__ = None;
+                let mut output_id__ = None;
+                let mut max_output__ = None;
+                let mut min_output__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut step_count__ = None;
+                let mut nonce__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputId => {
+                            if output_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputId"));
+                            }
+                            output_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::MaxOutput => {
+                            if max_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxOutput"));
+                            }
+                            max_output__ = map_.next_value()?;
+                        }
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
+                            }
+                            min_output__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StepCount => {
+                            if step_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stepCount"));
+                            }
+                            step_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuctionDescription {
+                    input: input__,
+                    output_id: output_id__,
+                    max_output: max_output__,
+                    min_output: min_output__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    step_count: step_count__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuctionDescription", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DutchAuctionState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.seq != 0 {
+            len += 1;
+        }
+        if self.current_position.is_some() {
+            len += 1;
+        }
+        if self.next_trigger != 0 {
+            len += 1;
+        }
+        if self.input_reserves.is_some() {
+            len += 1;
+        }
+        if self.output_reserves.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.DutchAuctionState", len)?;
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if let Some(v) = self.current_position.as_ref() {
+            struct_ser.serialize_field("currentPosition", v)?;
+        }
+        if self.next_trigger != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("nextTrigger", ToString::to_string(&self.next_trigger).as_str())?;
+        }
+        if let Some(v) = self.input_reserves.as_ref() {
+            struct_ser.serialize_field("inputReserves", v)?;
+        }
+        if let Some(v) = self.output_reserves.as_ref() {
+            struct_ser.serialize_field("outputReserves", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DutchAuctionState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "seq",
+            "current_position",
+            "currentPosition",
+            "next_trigger",
+            "nextTrigger",
+            "input_reserves",
+            "inputReserves",
+            "output_reserves",
+            "outputReserves",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Seq,
+            CurrentPosition,
+            NextTrigger,
+            InputReserves,
+            OutputReserves,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "seq" => Ok(GeneratedField::Seq),
+                            "currentPosition" | "current_position" => Ok(GeneratedField::CurrentPosition),
+                            "nextTrigger" | "next_trigger" => Ok(GeneratedField::NextTrigger),
+                            "inputReserves" | "input_reserves" => Ok(GeneratedField::InputReserves),
+                            "outputReserves" | "output_reserves" => Ok(GeneratedField::OutputReserves),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DutchAuctionState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.DutchAuctionState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DutchAuctionState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut seq__ = None;
+                let mut current_position__ = None;
+                let mut next_trigger__ = None;
+                let mut input_reserves__ = None;
+                let mut output_reserves__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
+                            }
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CurrentPosition => {
+                            if current_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentPosition"));
+                            }
+                            current_position__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextTrigger => {
+                            if next_trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextTrigger"));
+                            }
+                            next_trigger__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InputReserves => {
+                            if input_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputReserves"));
+                            }
+                            input_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputReserves => {
+                            if output_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputReserves"));
+                            }
+                            output_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DutchAuctionState {
+                    seq: seq__.unwrap_or_default(),
+                    current_position: current_position__,
+                    next_trigger: next_trigger__.unwrap_or_default(),
+                    input_reserves: input_reserves__,
+                    output_reserves: output_reserves__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.DutchAuctionState", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionEnded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reason != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionEnded", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if self.reason != 0 {
+            let v = event_dutch_auction_ended::Reason::try_from(self.reason)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.reason)))?;
+            struct_ser.serialize_field("reason", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionEnded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            Reason,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionEnded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionEnded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionEnded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value::<event_dutch_auction_ended::Reason>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionEnded {
+                    auction_id: auction_id__,
+                    state: state__,
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionEnded", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for event_dutch_auction_ended::Reason {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "REASON_UNSPECIFIED",
+            Self::Expired => "REASON_EXPIRED",
+            Self::Filled => "REASON_FILLED",
+            Self::ClosedByOwner => "REASON_CLOSED_BY_OWNER",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for event_dutch_auction_ended::Reason {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "REASON_UNSPECIFIED",
+            "REASON_EXPIRED",
+            "REASON_FILLED",
+            "REASON_CLOSED_BY_OWNER",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = event_dutch_auction_ended::Reason;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "REASON_UNSPECIFIED" => Ok(event_dutch_auction_ended::Reason::Unspecified),
+                    "REASON_EXPIRED" => Ok(event_dutch_auction_ended::Reason::Expired),
+                    "REASON_FILLED" => Ok(event_dutch_auction_ended::Reason::Filled),
+                    "REASON_CLOSED_BY_OWNER" => Ok(event_dutch_auction_ended::Reason::ClosedByOwner),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionScheduled {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.description.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionScheduled", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionScheduled {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "description",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            Description,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "description" => Ok(GeneratedField::Description),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionScheduled;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionScheduled")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionScheduled, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut description__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionScheduled {
+                    auction_id: auction_id__,
+                    description: description__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionScheduled", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionUpdated", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionUpdated {
+                    auction_id: auction_id__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventDutchAuctionWithdrawn {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionWithdrawn", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventDutchAuctionWithdrawn {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventDutchAuctionWithdrawn;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventDutchAuctionWithdrawn")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventDutchAuctionWithdrawn, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventDutchAuctionWithdrawn {
+                    auction_id: auction_id__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventDutchAuctionWithdrawn", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventTwapAuctionEnded {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reason != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionEnded", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if self.reason != 0 {
+            let v = event_dutch_auction_ended::Reason::try_from(self.reason)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.reason)))?;
+            struct_ser.serialize_field("reason", &v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventTwapAuctionEnded {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+            "reason",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            Reason,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            "reason" => Ok(GeneratedField::Reason),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventTwapAuctionEnded;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventTwapAuctionEnded")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventTwapAuctionEnded, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                let mut reason__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reason => {
+                            if reason__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reason"));
+                            }
+                            reason__ = Some(map_.next_value::<event_dutch_auction_ended::Reason>()? as i32);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventTwapAuctionEnded {
+                    auction_id: auction_id__,
+                    state: state__,
+                    reason: reason__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionEnded", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventTwapAuctionScheduled {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.description.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionScheduled", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventTwapAuctionScheduled {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "description",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            Description,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "description" => Ok(GeneratedField::Description),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventTwapAuctionScheduled;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventTwapAuctionScheduled")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventTwapAuctionScheduled, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut description__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventTwapAuctionScheduled {
+                    auction_id: auction_id__,
+                    description: description__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionScheduled", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventTwapAuctionUpdated {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionUpdated", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventTwapAuctionUpdated {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventTwapAuctionUpdated;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventTwapAuctionUpdated")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventTwapAuctionUpdated, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventTwapAuctionUpdated {
+                    auction_id: auction_id__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionUpdated", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventTwapAuctionWithdrawn {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.auction_id.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionWithdrawn", len)?;
+        if let Some(v) = self.auction_id.as_ref() {
+            struct_ser.serialize_field("auctionId", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventTwapAuctionWithdrawn {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "auction_id",
+            "auctionId",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AuctionId,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "auctionId" | "auction_id" => Ok(GeneratedField::AuctionId),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventTwapAuctionWithdrawn;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventTwapAuctionWithdrawn")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventTwapAuctionWithdrawn, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut auction_id__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AuctionId => {
+                            if auction_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("auctionId"));
+                            }
+                            auction_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventTwapAuctionWithdrawn {
+                    auction_id: auction_id__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventTwapAuctionWithdrawn", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventValueCircuitBreakerCredit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.previous_balance.is_some() {
+            len += 1;
+        }
+        if self.new_balance.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerCredit", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.previous_balance.as_ref() {
+            struct_ser.serialize_field("previousBalance", v)?;
+        }
+        if let Some(v) = self.new_balance.as_ref() {
+            struct_ser.serialize_field("newBalance", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerCredit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "previous_balance",
+            "previousBalance",
+            "new_balance",
+            "newBalance",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            AssetId,
+            PreviousBalance,
+            NewBalance,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
+                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventValueCircuitBreakerCredit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventValueCircuitBreakerCredit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerCredit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut previous_balance__ = None;
+                let mut new_balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::PreviousBalance => {
+                            if previous_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                            }
+                            previous_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewBalance => {
+                            if new_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                            }
+                            new_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventValueCircuitBreakerCredit {
+                    asset_id: asset_id__,
+                    previous_balance: previous_balance__,
+                    new_balance: new_balance__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerCredit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for EventValueCircuitBreakerDebit {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.asset_id.is_some() {
+            len += 1;
+        }
+        if self.previous_balance.is_some() {
+            len += 1;
+        }
+        if self.new_balance.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerDebit", len)?;
+        if let Some(v) = self.asset_id.as_ref() {
+            struct_ser.serialize_field("assetId", v)?;
+        }
+        if let Some(v) = self.previous_balance.as_ref() {
+            struct_ser.serialize_field("previousBalance", v)?;
+        }
+        if let Some(v) = self.new_balance.as_ref() {
+            struct_ser.serialize_field("newBalance", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "asset_id",
+            "assetId",
+            "previous_balance",
             "previousBalance",
             "new_balance",
             "newBalance",
@@ -2706,9 +4160,390 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            AssetId,
-            PreviousBalance,
-            NewBalance,
+            AssetId,
+            PreviousBalance,
+            NewBalance,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
+                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
+                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = EventValueCircuitBreakerDebit;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.EventValueCircuitBreakerDebit")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerDebit, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut asset_id__ = None;
+                let mut previous_balance__ = None;
+                let mut new_balance__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::AssetId => {
+                            if asset_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("assetId"));
+                            }
+                            asset_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::PreviousBalance => {
+                            if previous_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                            }
+                            previous_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::NewBalance => {
+                            if new_balance__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                            }
+                            new_balance__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(EventValueCircuitBreakerDebit {
+                    asset_id: asset_id__,
+                    previous_balance: previous_balance__,
+                    new_balance: new_balance__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerDebit", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for GenesisContent {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.params.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.GenesisContent", len)?;
+        if let Some(v) = self.params.as_ref() {
+            struct_ser.serialize_field("params", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for GenesisContent {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "params",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Params,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "params" => Ok(GeneratedField::Params),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = GenesisContent;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.GenesisContent")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut params__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Params => {
+                            if params__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("params"));
+                            }
+                            params__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(GenesisContent {
+                    params: params__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.GenesisContent", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TwapAuction {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.description.is_some() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.TwapAuction", len)?;
+        if let Some(v) = self.description.as_ref() {
+            struct_ser.serialize_field("description", v)?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TwapAuction {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "description",
+            "state",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Description,
+            State,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "description" => Ok(GeneratedField::Description),
+                            "state" => Ok(GeneratedField::State),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TwapAuction;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.auction.v1.TwapAuction")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TwapAuction, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut description__ = None;
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Description => {
+                            if description__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("description"));
+                            }
+                            description__ = map_.next_value()?;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TwapAuction {
+                    description: description__,
+                    state: state__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.TwapAuction", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TwapAuctionDescription {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output_id.is_some() {
+            len += 1;
+        }
+        if self.min_output.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        if self.step_count != 0 {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.TwapAuctionDescription", len)?;
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output_id.as_ref() {
+            struct_ser.serialize_field("outputId", v)?;
+        }
+        if let Some(v) = self.min_output.as_ref() {
+            struct_ser.serialize_field("minOutput", v)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        if self.step_count != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("stepCount", ToString::to_string(&self.step_count).as_str())?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TwapAuctionDescription {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "input",
+            "output_id",
+            "outputId",
+            "min_output",
+            "minOutput",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+            "step_count",
+            "stepCount",
+            "nonce",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Input,
+            OutputId,
+            MinOutput,
+            StartHeight,
+            EndHeight,
+            StepCount,
+            Nonce,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2731,9 +4566,13 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
                         E: serde::de::Error,
                     {
                         match value {
-                            "assetId" | "asset_id" => Ok(GeneratedField::AssetId),
-                            "previousBalance" | "previous_balance" => Ok(GeneratedField::PreviousBalance),
-                            "newBalance" | "new_balance" => Ok(GeneratedField::NewBalance),
+                            "input" => Ok(GeneratedField::Input),
+                            "outputId" | "output_id" => Ok(GeneratedField::OutputId),
+                            "minOutput" | "min_output" => Ok(GeneratedField::MinOutput),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            "stepCount" | "step_count" => Ok(GeneratedField::StepCount),
+                            "nonce" => Ok(GeneratedField::Nonce),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2743,55 +4582,95 @@ impl<'de> serde::Deserialize<'de> for EventValueCircuitBreakerDebit {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = EventValueCircuitBreakerDebit;
+            type Value = TwapAuctionDescription;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.EventValueCircuitBreakerDebit")
+                formatter.write_str("struct penumbra.core.component.auction.v1.TwapAuctionDescription")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<EventValueCircuitBreakerDebit, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TwapAuctionDescription, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut asset_id__ = None;
-                let mut previous_balance__ = None;
-                let mut new_balance__ = None;
+                let mut input__ = None;
+                let mut output_id__ = None;
+                let mut min_output__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                let mut step_count__ = None;
+                let mut nonce__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::AssetId => {
-                            if asset_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("assetId"));
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
                             }
-                            asset_id__ = map_.next_value()?;
+                            input__ = map_.next_value()?;
                         }
-                        GeneratedField::PreviousBalance => {
-                            if previous_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("previousBalance"));
+                        GeneratedField::OutputId => {
+                            if output_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputId"));
                             }
-                            previous_balance__ = map_.next_value()?;
+                            output_id__ = map_.next_value()?;
                         }
-                        GeneratedField::NewBalance => {
-                            if new_balance__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("newBalance"));
+                        GeneratedField::MinOutput => {
+                            if min_output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("minOutput"));
                             }
-                            new_balance__ = map_.next_value()?;
+                            min_output__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::StepCount => {
+                            if step_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("stepCount"));
+                            }
+                            step_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(EventValueCircuitBreakerDebit {
-                    asset_id: asset_id__,
-                    previous_balance: previous_balance__,
-                    new_balance: new_balance__,
+                Ok(TwapAuctionDescription {
+                    input: input__,
+                    output_id: output_id__,
+                    min_output: min_output__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                    step_count: step_count__.unwrap_or_default(),
+                    nonce: nonce__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.EventValueCircuitBreakerDebit", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.TwapAuctionDescription", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for GenesisContent {
+impl serde::Serialize for TwapAuctionState {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -2799,29 +4678,69 @@ impl serde::Serialize for GenesisContent {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.params.is_some() {
+        if self.seq != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.GenesisContent", len)?;
-        if let Some(v) = self.params.as_ref() {
-            struct_ser.serialize_field("params", v)?;
+        if self.current_position.is_some() {
+            len += 1;
+        }
+        if self.next_trigger != 0 {
+            len += 1;
+        }
+        if self.input_reserves.is_some() {
+            len += 1;
+        }
+        if self.output_reserves.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.auction.v1.TwapAuctionState", len)?;
+        if self.seq != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("seq", ToString::to_string(&self.seq).as_str())?;
+        }
+        if let Some(v) = self.current_position.as_ref() {
+            struct_ser.serialize_field("currentPosition", v)?;
+        }
+        if self.next_trigger != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("nextTrigger", ToString::to_string(&self.next_trigger).as_str())?;
+        }
+        if let Some(v) = self.input_reserves.as_ref() {
+            struct_ser.serialize_field("inputReserves", v)?;
+        }
+        if let Some(v) = self.output_reserves.as_ref() {
+            struct_ser.serialize_field("outputReserves", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for GenesisContent {
+impl<'de> serde::Deserialize<'de> for TwapAuctionState {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "params",
+            "seq",
+            "current_position",
+            "currentPosition",
+            "next_trigger",
+            "nextTrigger",
+            "input_reserves",
+            "inputReserves",
+            "output_reserves",
+            "outputReserves",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Params,
+            Seq,
+            CurrentPosition,
+            NextTrigger,
+            InputReserves,
+            OutputReserves,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -2844,7 +4763,11 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
                         E: serde::de::Error,
                     {
                         match value {
-                            "params" => Ok(GeneratedField::Params),
+                            "seq" => Ok(GeneratedField::Seq),
+                            "currentPosition" | "current_position" => Ok(GeneratedField::CurrentPosition),
+                            "nextTrigger" | "next_trigger" => Ok(GeneratedField::NextTrigger),
+                            "inputReserves" | "input_reserves" => Ok(GeneratedField::InputReserves),
+                            "outputReserves" | "output_reserves" => Ok(GeneratedField::OutputReserves),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -2854,35 +4777,71 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = GenesisContent;
+            type Value = TwapAuctionState;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.auction.v1.GenesisContent")
+                formatter.write_str("struct penumbra.core.component.auction.v1.TwapAuctionState")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<GenesisContent, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TwapAuctionState, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut params__ = None;
+                let mut seq__ = None;
+                let mut current_position__ = None;
+                let mut next_trigger__ = None;
+                let mut input_reserves__ = None;
+                let mut output_reserves__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Params => {
-                            if params__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("params"));
+                        GeneratedField::Seq => {
+                            if seq__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("seq"));
                             }
-                            params__ = map_.next_value()?;
+                            seq__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CurrentPosition => {
+                            if current_position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentPosition"));
+                            }
+                            current_position__ = map_.next_value()?;
+                        }
+                        GeneratedField::NextTrigger => {
+                            if next_trigger__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nextTrigger"));
+                            }
+                            next_trigger__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::InputReserves => {
+                            if input_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("inputReserves"));
+                            }
+                            input_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::OutputReserves => {
+                            if output_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("outputReserves"));
+                            }
+                            output_reserves__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(GenesisContent {
-                    params: params__,
+                Ok(TwapAuctionState {
+                    seq: seq__.unwrap_or_default(),
+                    current_position: current_position__,
+                    next_trigger: next_trigger__.unwrap_or_default(),
+                    input_reserves: input_reserves__,
+                    output_reserves: output_reserves__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.auction.v1.GenesisContent", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.auction.v1.TwapAuctionState", FIELDS, GeneratedVisitor)
     }
 }
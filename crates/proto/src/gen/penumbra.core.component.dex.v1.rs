@@ -641,6 +641,9 @@ pub struct Position {
     /// / immediately after being filled.
     #[prost(bool, tag = "5")]
     pub close_on_fill: bool,
+    /// If nonzero, the position is closed at the end of the block at this height.
+    #[prost(uint64, tag = "6")]
+    pub expires_at_height: u64,
 }
 impl ::prost::Name for Position {
     const NAME: &'static str = "Position";
@@ -1497,6 +1500,32 @@ pub struct SimulateTradeResponse {
     /// Estimated input amount that will not be swapped due to liquidity
     #[prost(message, optional, tag = "2")]
     pub unfilled: ::core::option::Option<super::super::super::asset::v1::Value>,
+    /// The positions the trade executed against, in the order they were first used.
+    #[prost(message, repeated, tag = "3")]
+    pub position_executions: ::prost::alloc::vec::Vec<SimulatedPositionExecution>,
+    /// The effective price of the trade, in base units of the input asset per
+    /// base unit of the output asset.
+    ///
+    /// Unset if no part of the trade could be filled.
+    #[prost(double, optional, tag = "4")]
+    pub effective_price: ::core::option::Option<f64>,
+    /// The mid price of the output asset before the trade, in the same units.
+    ///
+    /// Unset if the input and output assets don't have a two-sided market.
+    #[prost(double, optional, tag = "5")]
+    pub mid_price: ::core::option::Option<f64>,
+    /// How much worse the effective price is than the mid price, in basis points.
+    ///
+    /// Unset if either the effective price or the mid price is unset.
+    #[prost(double, optional, tag = "6")]
+    pub price_impact_bps: ::core::option::Option<f64>,
+    /// The fees paid to liquidity providers, totaled per asset.
+    #[prost(message, repeated, tag = "7")]
+    pub lp_fees: ::prost::alloc::vec::Vec<super::super::super::asset::v1::Value>,
+    /// The spread between the input and output assets after the trade, with
+    /// asset 1 and asset 2 in the canonical order of the trading pair.
+    #[prost(message, optional, tag = "8")]
+    pub spread_after: ::core::option::Option<SpreadResponse>,
 }
 impl ::prost::Name for SimulateTradeResponse {
     const NAME: &'static str = "SimulateTradeResponse";
@@ -1508,6 +1537,39 @@ impl ::prost::Name for SimulateTradeResponse {
         "/penumbra.core.component.dex.v1.SimulateTradeResponse".into()
     }
 }
+/// A position executed against during a simulated trade.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SimulatedPositionExecution {
+    /// The position that was executed against.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The direction the position was traded in, from the asset it received to
+    /// the asset it paid out.
+    #[prost(message, optional, tag = "2")]
+    pub hop: ::core::option::Option<DirectedTradingPair>,
+    /// The amount of the hop's start asset the position received, including fees.
+    #[prost(message, optional, tag = "3")]
+    pub input: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of the hop's end asset the position paid out.
+    #[prost(message, optional, tag = "4")]
+    pub output: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The fee paid to the position, in units of the hop's start asset.
+    #[prost(message, optional, tag = "5")]
+    pub fee: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// Whether the trade used up the position's reserves of the hop's end asset.
+    #[prost(bool, tag = "6")]
+    pub exhausted: bool,
+}
+impl ::prost::Name for SimulatedPositionExecution {
+    const NAME: &'static str = "SimulatedPositionExecution";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.SimulatedPositionExecution".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.SimulatedPositionExecution".into()
+    }
+}
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct EventSwap {
     /// The trading pair to swap.
@@ -1867,6 +1929,27 @@ pub struct DexParameters {
     /// for a single pair
     #[prost(uint32, tag = "5")]
     pub max_execution_budget: u32,
+    /// The maximum number of paths a single batch swap is split across.
+    ///
+    /// If greater than one, the router fills the top paths simultaneously,
+    /// equalizing their marginal prices, before falling back to filling the
+    /// best path one at a time. If zero or one, only the latter is used.
+    #[prost(uint32, tag = "6")]
+    pub max_split_paths: u32,
+    /// The intervals, other than per block, that candlesticks are rolled up into.
+    ///
+    /// Rolled-up candlesticks are kept in nonverifiable storage, so changing this
+    /// only changes which intervals are recorded from then on.
+    ///
+    /// If empty, the default intervals (minute, hour, day and epoch) are used.
+    #[prost(enumeration = "CandlestickInterval", repeated, tag = "7")]
+    pub candlestick_intervals: ::prost::alloc::vec::Vec<i32>,
+    /// Whether path search ranks candidate paths by composing the trading
+    /// functions of the best position on each hop, which rounds the path price
+    /// once, rather than by multiplying the per-hop prices, which rounds at every
+    /// hop. Off unless enabled, since it changes which routes are chosen.
+    #[prost(bool, tag = "8")]
+    pub composed_path_prices: bool,
 }
 impl ::prost::Name for DexParameters {
     const NAME: &'static str = "DexParameters";
@@ -1897,6 +1980,9 @@ impl ::prost::Name for GenesisContent {
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct CandlestickData {
     /// The height of the candlestick data.
+    ///
+    /// For candles rolled up over an interval, this is the first height in the
+    /// interval that had any executions.
     #[prost(uint64, tag = "1")]
     pub height: u64,
     /// The first observed price during the block execution.
@@ -1945,8 +2031,15 @@ pub struct CandlestickDataRequest {
     /// The height to start the query from.
     ///
     /// If this is unset (= 0), the server will return the most recent data points.
+    ///
+    /// For rolled-up intervals, data starts from the interval containing this height.
     #[prost(uint64, tag = "3")]
     pub start_height: u64,
+    /// The interval to aggregate candlesticks over.
+    ///
+    /// If this is unspecified, one data point is returned per block.
+    #[prost(enumeration = "CandlestickInterval", tag = "4")]
+    pub interval: i32,
 }
 impl ::prost::Name for CandlestickDataRequest {
     const NAME: &'static str = "CandlestickDataRequest";
@@ -1979,6 +2072,12 @@ pub struct CandlestickDataStreamRequest {
     /// The directed trading pair to subscribe to.
     #[prost(message, optional, tag = "1")]
     pub pair: ::core::option::Option<DirectedTradingPair>,
+    /// The interval to aggregate candlesticks over.
+    ///
+    /// For rolled-up intervals, the in-progress candle is sent after every block
+    /// that updates it.
+    #[prost(enumeration = "CandlestickInterval", tag = "2")]
+    pub interval: i32,
 }
 impl ::prost::Name for CandlestickDataStreamRequest {
     const NAME: &'static str = "CandlestickDataStreamRequest";
@@ -2006,6 +2105,337 @@ impl ::prost::Name for CandlestickDataStreamResponse {
         "/penumbra.core.component.dex.v1.CandlestickDataStreamResponse".into()
     }
 }
+/// Aggregated liquidity on both sides of a directed trading pair.
+///
+/// Prices are quoted in units of the pair's start asset per unit of its end
+/// asset, inclusive of fees, and amounts are denominated in the end asset.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBookDepth {
+    /// The block height the depth was computed at.
+    #[prost(uint64, tag = "1")]
+    pub height: u64,
+    /// Levels of liquidity buying the end asset, best (highest) price first.
+    #[prost(message, repeated, tag = "2")]
+    pub bids: ::prost::alloc::vec::Vec<DepthLevel>,
+    /// Levels of liquidity selling the end asset, best (lowest) price first.
+    #[prost(message, repeated, tag = "3")]
+    pub asks: ::prost::alloc::vec::Vec<DepthLevel>,
+}
+impl ::prost::Name for OrderBookDepth {
+    const NAME: &'static str = "OrderBookDepth";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.OrderBookDepth".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.OrderBookDepth".into()
+    }
+}
+/// The liquidity available at one price level of the order book.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct DepthLevel {
+    /// The price of this level, a multiple of the requested tick size.
+    ///
+    /// Bids are rounded down and asks are rounded up to the tick.
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    /// The amount of the end asset available at this level.
+    #[prost(message, optional, tag = "2")]
+    pub amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The amount of the end asset available at this level or better.
+    #[prost(message, optional, tag = "3")]
+    pub cumulative_amount: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The number of positions contributing to this level.
+    #[prost(uint32, tag = "4")]
+    pub position_count: u32,
+}
+impl ::prost::Name for DepthLevel {
+    const NAME: &'static str = "DepthLevel";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.DepthLevel".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.DepthLevel".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBookDepthRequest {
+    /// The directed trading pair to aggregate liquidity for.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The width of each price level.
+    ///
+    /// This must be at least 1e-18.
+    #[prost(double, tag = "2")]
+    pub tick_size: f64,
+    /// The maximum number of levels to return on each side.
+    ///
+    /// If this is unset (= 0), the server will pick a default; it may also clamp
+    /// this to a maximum value. The server also stops walking the book after a
+    /// fixed number of positions, so deep books may return fewer levels.
+    #[prost(uint32, tag = "3")]
+    pub levels: u32,
+}
+impl ::prost::Name for OrderBookDepthRequest {
+    const NAME: &'static str = "OrderBookDepthRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.OrderBookDepthRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.OrderBookDepthRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBookDepthResponse {
+    #[prost(message, optional, tag = "1")]
+    pub depth: ::core::option::Option<OrderBookDepth>,
+}
+impl ::prost::Name for OrderBookDepthResponse {
+    const NAME: &'static str = "OrderBookDepthResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.OrderBookDepthResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.OrderBookDepthResponse".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBookDepthStreamRequest {
+    /// The directed trading pair to aggregate liquidity for.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The width of each price level.
+    ///
+    /// This must be at least 1e-18.
+    #[prost(double, tag = "2")]
+    pub tick_size: f64,
+    /// The maximum number of levels to return on each side.
+    #[prost(uint32, tag = "3")]
+    pub levels: u32,
+}
+impl ::prost::Name for OrderBookDepthStreamRequest {
+    const NAME: &'static str = "OrderBookDepthStreamRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.OrderBookDepthStreamRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.OrderBookDepthStreamRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct OrderBookDepthStreamResponse {
+    /// The order book depth as of the latest block.
+    #[prost(message, optional, tag = "1")]
+    pub depth: ::core::option::Option<OrderBookDepth>,
+}
+impl ::prost::Name for OrderBookDepthStreamResponse {
+    const NAME: &'static str = "OrderBookDepthStreamResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.OrderBookDepthStreamResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.OrderBookDepthStreamResponse".into()
+    }
+}
+/// Cumulative price accumulators for a trading pair, updated in blocks where
+/// the pair's batch swap had executions.
+///
+/// Prices and cumulative prices are `U128x128` fixed-point numbers, encoded as
+/// 32 big-endian bytes.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PriceAccumulator {
+    /// The trading pair the accumulator is for.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<TradingPair>,
+    /// The height of the last update.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The clearing price of asset 1, in units of asset 2, as of the last update.
+    #[prost(bytes = "vec", tag = "3")]
+    pub price_1: ::prost::alloc::vec::Vec<u8>,
+    /// The clearing price of asset 2, in units of asset 1, as of the last update.
+    #[prost(bytes = "vec", tag = "4")]
+    pub price_2: ::prost::alloc::vec::Vec<u8>,
+    /// The sum of the price of asset 1 in effect at each block, up to `height`.
+    #[prost(bytes = "vec", tag = "5")]
+    pub cumulative_price_1: ::prost::alloc::vec::Vec<u8>,
+    /// The sum of the price of asset 2 in effect at each block, up to `height`.
+    #[prost(bytes = "vec", tag = "6")]
+    pub cumulative_price_2: ::prost::alloc::vec::Vec<u8>,
+}
+impl ::prost::Name for PriceAccumulator {
+    const NAME: &'static str = "PriceAccumulator";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.PriceAccumulator".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.PriceAccumulator".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePriceRequest {
+    /// The directed trading pair to price.
+    ///
+    /// The price is given in units of the end asset per unit of the start asset.
+    #[prost(message, optional, tag = "1")]
+    pub trading_pair: ::core::option::Option<DirectedTradingPair>,
+    /// The height to start averaging from, exclusive.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+    /// The height to average up to, inclusive.
+    ///
+    /// If this is unset (= 0), the current height is used.
+    #[prost(uint64, tag = "3")]
+    pub end_height: u64,
+}
+impl ::prost::Name for TimeWeightedAveragePriceRequest {
+    const NAME: &'static str = "TimeWeightedAveragePriceRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest".into()
+    }
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct TimeWeightedAveragePriceResponse {
+    /// The time-weighted average price over the requested range.
+    #[prost(double, tag = "1")]
+    pub price: f64,
+    /// The height the average was taken up to.
+    #[prost(uint64, tag = "2")]
+    pub end_height: u64,
+}
+impl ::prost::Name for TimeWeightedAveragePriceResponse {
+    const NAME: &'static str = "TimeWeightedAveragePriceResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse".into()
+    }
+}
+/// The changes to a liquidity position's reserves during a single block.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionActivity {
+    /// The position the activity is for.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// The height of the block.
+    #[prost(uint64, tag = "2")]
+    pub height: u64,
+    /// The reserves of the position before its first change in the block.
+    #[prost(message, optional, tag = "3")]
+    pub prev_reserves: ::core::option::Option<Reserves>,
+    /// The reserves of the position after its last change in the block.
+    #[prost(message, optional, tag = "4")]
+    pub reserves: ::core::option::Option<Reserves>,
+    /// The number of executions against the position in the block.
+    #[prost(uint64, tag = "5")]
+    pub executions: u64,
+    /// The fees earned by those executions, which are included in `reserves`.
+    #[prost(message, optional, tag = "6")]
+    pub fees: ::core::option::Option<Reserves>,
+    /// The rewards credited to the position in the block.
+    #[prost(message, optional, tag = "7")]
+    pub rewards: ::core::option::Option<Reserves>,
+    /// The reserves withdrawn from the position in the block.
+    #[prost(message, optional, tag = "8")]
+    pub withdrawn: ::core::option::Option<Reserves>,
+}
+impl ::prost::Name for PositionActivity {
+    const NAME: &'static str = "PositionActivity";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.PositionActivity".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.PositionActivity".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPositionActivityRequest {
+    /// The position to get activity for.
+    #[prost(message, optional, tag = "1")]
+    pub position_id: ::core::option::Option<PositionId>,
+    /// Only return activity at or after this height.
+    #[prost(uint64, tag = "2")]
+    pub start_height: u64,
+}
+impl ::prost::Name for LiquidityPositionActivityRequest {
+    const NAME: &'static str = "LiquidityPositionActivityRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.LiquidityPositionActivityRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.LiquidityPositionActivityRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct LiquidityPositionActivityResponse {
+    #[prost(message, optional, tag = "1")]
+    pub activity: ::core::option::Option<PositionActivity>,
+}
+impl ::prost::Name for LiquidityPositionActivityResponse {
+    const NAME: &'static str = "LiquidityPositionActivityResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.dex.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.dex.v1.LiquidityPositionActivityResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.dex.v1.LiquidityPositionActivityResponse".into()
+    }
+}
+/// The interval over which a candlestick aggregates executions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum CandlestickInterval {
+    /// One candlestick per block.
+    Unspecified = 0,
+    /// One candlestick per minute of block time.
+    Minute = 1,
+    /// One candlestick per hour of block time.
+    Hour = 2,
+    /// One candlestick per day of block time.
+    Day = 3,
+    /// One candlestick per epoch.
+    Epoch = 4,
+}
+impl CandlestickInterval {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Unspecified => "CANDLESTICK_INTERVAL_UNSPECIFIED",
+            Self::Minute => "CANDLESTICK_INTERVAL_MINUTE",
+            Self::Hour => "CANDLESTICK_INTERVAL_HOUR",
+            Self::Day => "CANDLESTICK_INTERVAL_DAY",
+            Self::Epoch => "CANDLESTICK_INTERVAL_EPOCH",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "CANDLESTICK_INTERVAL_UNSPECIFIED" => Some(Self::Unspecified),
+            "CANDLESTICK_INTERVAL_MINUTE" => Some(Self::Minute),
+            "CANDLESTICK_INTERVAL_HOUR" => Some(Self::Hour),
+            "CANDLESTICK_INTERVAL_DAY" => Some(Self::Day),
+            "CANDLESTICK_INTERVAL_EPOCH" => Some(Self::Epoch),
+            _ => None,
+        }
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod query_service_client {
@@ -2469,6 +2899,137 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Get the liquidity on both sides of a trading pair, aggregated into price levels.
+        ///
+        /// This walks the same price index as `LiquidityPositionsByPrice`, so clients
+        /// don't need to aggregate individual positions themselves.
+        pub async fn order_book_depth(
+            &mut self,
+            request: impl tonic::IntoRequest<super::OrderBookDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrderBookDepthResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/OrderBookDepth",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "OrderBookDepth",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Subscribe to aggregated order book depth, updated once per block.
+        pub async fn order_book_depth_stream(
+            &mut self,
+            request: impl tonic::IntoRequest<super::OrderBookDepthStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::OrderBookDepthStreamResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/OrderBookDepthStream",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "OrderBookDepthStream",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Get the time-weighted average price of a trading pair between two heights.
+        ///
+        /// The price in effect during a block is the batch clearing price of the most
+        /// recent earlier block in which the pair traded.
+        pub async fn time_weighted_average_price(
+            &mut self,
+            request: impl tonic::IntoRequest<super::TimeWeightedAveragePriceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePriceResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/TimeWeightedAveragePrice",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "TimeWeightedAveragePrice",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Stream the per-block activity of a liquidity position: executions against
+        /// it, rewards credited to it, and withdrawals from it.
+        ///
+        /// Activity is kept in nonverifiable storage, so a node that joined the
+        /// network via state sync only has activity since it joined. It is pruned
+        /// after about 30 days, so clients should fetch and keep it before then.
+        pub async fn liquidity_position_activity(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LiquidityPositionActivityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::LiquidityPositionActivityResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionActivity",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.dex.v1.QueryService",
+                        "LiquidityPositionActivity",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated client implementations.
@@ -2762,6 +3323,61 @@ pub mod query_service_server {
             tonic::Response<Self::CandlestickDataStreamStream>,
             tonic::Status,
         >;
+        /// Get the liquidity on both sides of a trading pair, aggregated into price levels.
+        ///
+        /// This walks the same price index as `LiquidityPositionsByPrice`, so clients
+        /// don't need to aggregate individual positions themselves.
+        async fn order_book_depth(
+            &self,
+            request: tonic::Request<super::OrderBookDepthRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::OrderBookDepthResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the OrderBookDepthStream method.
+        type OrderBookDepthStreamStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::OrderBookDepthStreamResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Subscribe to aggregated order book depth, updated once per block.
+        async fn order_book_depth_stream(
+            &self,
+            request: tonic::Request<super::OrderBookDepthStreamRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::OrderBookDepthStreamStream>,
+            tonic::Status,
+        >;
+        /// Get the time-weighted average price of a trading pair between two heights.
+        ///
+        /// The price in effect during a block is the batch clearing price of the most
+        /// recent earlier block in which the pair traded.
+        async fn time_weighted_average_price(
+            &self,
+            request: tonic::Request<super::TimeWeightedAveragePriceRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::TimeWeightedAveragePriceResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the LiquidityPositionActivity method.
+        type LiquidityPositionActivityStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::LiquidityPositionActivityResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Stream the per-block activity of a liquidity position: executions against
+        /// it, rewards credited to it, and withdrawals from it.
+        ///
+        /// Activity is kept in nonverifiable storage, so a node that joined the
+        /// network via state sync only has activity since it joined. It is pruned
+        /// after about 30 days, so clients should fetch and keep it before then.
+        async fn liquidity_position_activity(
+            &self,
+            request: tonic::Request<super::LiquidityPositionActivityRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::LiquidityPositionActivityStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the DEX component.
     #[derive(Debug)]
@@ -3410,6 +4026,192 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.dex.v1.QueryService/OrderBookDepth" => {
+                    #[allow(non_camel_case_types)]
+                    struct OrderBookDepthSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::OrderBookDepthRequest>
+                    for OrderBookDepthSvc<T> {
+                        type Response = super::OrderBookDepthResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::OrderBookDepthRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::order_book_depth(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = OrderBookDepthSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/OrderBookDepthStream" => {
+                    #[allow(non_camel_case_types)]
+                    struct OrderBookDepthStreamSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::OrderBookDepthStreamRequest>
+                    for OrderBookDepthStreamSvc<T> {
+                        type Response = super::OrderBookDepthStreamResponse;
+                        type ResponseStream = T::OrderBookDepthStreamStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::OrderBookDepthStreamRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::order_book_depth_stream(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = OrderBookDepthStreamSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/TimeWeightedAveragePrice" => {
+                    #[allow(non_camel_case_types)]
+                    struct TimeWeightedAveragePriceSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::TimeWeightedAveragePriceRequest>
+                    for TimeWeightedAveragePriceSvc<T> {
+                        type Response = super::TimeWeightedAveragePriceResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::TimeWeightedAveragePriceRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::time_weighted_average_price(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = TimeWeightedAveragePriceSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.dex.v1.QueryService/LiquidityPositionActivity" => {
+                    #[allow(non_camel_case_types)]
+                    struct LiquidityPositionActivitySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::LiquidityPositionActivityRequest>
+                    for LiquidityPositionActivitySvc<T> {
+                        type Response = super::LiquidityPositionActivityResponse;
+                        type ResponseStream = T::LiquidityPositionActivityStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LiquidityPositionActivityRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::liquidity_position_activity(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LiquidityPositionActivitySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
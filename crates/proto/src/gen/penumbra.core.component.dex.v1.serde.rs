@@ -1297,6 +1297,9 @@ impl serde::Serialize for CandlestickDataRequest {
         if self.start_height != 0 {
             len += 1;
         }
+        if self.interval != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CandlestickDataRequest", len)?;
         if let Some(v) = self.pair.as_ref() {
             struct_ser.serialize_field("pair", v)?;
@@ -1311,6 +1314,11 @@ impl serde::Serialize for CandlestickDataRequest {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
         }
+        if self.interval != 0 {
+            let v = CandlestickInterval::try_from(self.interval)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.interval)))?;
+            struct_ser.serialize_field("interval", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1325,6 +1333,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
             "limit",
             "start_height",
             "startHeight",
+            "interval",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1332,6 +1341,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
             Pair,
             Limit,
             StartHeight,
+            Interval,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1357,6 +1367,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                             "pair" => Ok(GeneratedField::Pair),
                             "limit" => Ok(GeneratedField::Limit),
                             "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "interval" => Ok(GeneratedField::Interval),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1379,6 +1390,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                 let mut pair__ = None;
                 let mut limit__ = None;
                 let mut start_height__ = None;
+                let mut interval__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Pair => {
@@ -1403,6 +1415,12 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::Interval => {
+                            if interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interval"));
+                            }
+                            interval__ = Some(map_.next_value::<CandlestickInterval>()? as i32);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1412,6 +1430,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataRequest {
                     pair: pair__,
                     limit: limit__.unwrap_or_default(),
                     start_height: start_height__.unwrap_or_default(),
+                    interval: interval__.unwrap_or_default(),
                 })
             }
         }
@@ -1524,10 +1543,18 @@ impl serde::Serialize for CandlestickDataStreamRequest {
         if self.pair.is_some() {
             len += 1;
         }
+        if self.interval != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.CandlestickDataStreamRequest", len)?;
         if let Some(v) = self.pair.as_ref() {
             struct_ser.serialize_field("pair", v)?;
         }
+        if self.interval != 0 {
+            let v = CandlestickInterval::try_from(self.interval)
+                .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", self.interval)))?;
+            struct_ser.serialize_field("interval", &v)?;
+        }
         struct_ser.end()
     }
 }
@@ -1539,11 +1566,13 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamRequest {
     {
         const FIELDS: &[&str] = &[
             "pair",
+            "interval",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Pair,
+            Interval,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1567,6 +1596,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamRequest {
                     {
                         match value {
                             "pair" => Ok(GeneratedField::Pair),
+                            "interval" => Ok(GeneratedField::Interval),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1587,6 +1617,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamRequest {
                     V: serde::de::MapAccess<'de>,
             {
                 let mut pair__ = None;
+                let mut interval__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Pair => {
@@ -1595,6 +1626,12 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamRequest {
                             }
                             pair__ = map_.next_value()?;
                         }
+                        GeneratedField::Interval => {
+                            if interval__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("interval"));
+                            }
+                            interval__ = Some(map_.next_value::<CandlestickInterval>()? as i32);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1602,6 +1639,7 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamRequest {
                 }
                 Ok(CandlestickDataStreamRequest {
                     pair: pair__,
+                    interval: interval__.unwrap_or_default(),
                 })
             }
         }
@@ -1703,6 +1741,238 @@ impl<'de> serde::Deserialize<'de> for CandlestickDataStreamResponse {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.CandlestickDataStreamResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for CandlestickInterval {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let variant = match self {
+            Self::Unspecified => "CANDLESTICK_INTERVAL_UNSPECIFIED",
+            Self::Minute => "CANDLESTICK_INTERVAL_MINUTE",
+            Self::Hour => "CANDLESTICK_INTERVAL_HOUR",
+            Self::Day => "CANDLESTICK_INTERVAL_DAY",
+            Self::Epoch => "CANDLESTICK_INTERVAL_EPOCH",
+        };
+        serializer.serialize_str(variant)
+    }
+}
+impl<'de> serde::Deserialize<'de> for CandlestickInterval {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "CANDLESTICK_INTERVAL_UNSPECIFIED",
+            "CANDLESTICK_INTERVAL_MINUTE",
+            "CANDLESTICK_INTERVAL_HOUR",
+            "CANDLESTICK_INTERVAL_DAY",
+            "CANDLESTICK_INTERVAL_EPOCH",
+        ];
+
+        struct GeneratedVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = CandlestickInterval;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                write!(formatter, "expected one of: {:?}", &FIELDS)
+            }
+
+            fn visit_i64<E>(self, v: i64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Signed(v), &self)
+                    })
+            }
+
+            fn visit_u64<E>(self, v: u64) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                i32::try_from(v)
+                    .ok()
+                    .and_then(|x| x.try_into().ok())
+                    .ok_or_else(|| {
+                        serde::de::Error::invalid_value(serde::de::Unexpected::Unsigned(v), &self)
+                    })
+            }
+
+            fn visit_str<E>(self, value: &str) -> std::result::Result<Self::Value, E>
+            where
+                E: serde::de::Error,
+            {
+                match value {
+                    "CANDLESTICK_INTERVAL_UNSPECIFIED" => Ok(CandlestickInterval::Unspecified),
+                    "CANDLESTICK_INTERVAL_MINUTE" => Ok(CandlestickInterval::Minute),
+                    "CANDLESTICK_INTERVAL_HOUR" => Ok(CandlestickInterval::Hour),
+                    "CANDLESTICK_INTERVAL_DAY" => Ok(CandlestickInterval::Day),
+                    "CANDLESTICK_INTERVAL_EPOCH" => Ok(CandlestickInterval::Epoch),
+                    _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
+                }
+            }
+        }
+        deserializer.deserialize_any(GeneratedVisitor)
+    }
+}
+impl serde::Serialize for DepthLevel {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.price != 0. {
+            len += 1;
+        }
+        if self.amount.is_some() {
+            len += 1;
+        }
+        if self.cumulative_amount.is_some() {
+            len += 1;
+        }
+        if self.position_count != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DepthLevel", len)?;
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if let Some(v) = self.amount.as_ref() {
+            struct_ser.serialize_field("amount", v)?;
+        }
+        if let Some(v) = self.cumulative_amount.as_ref() {
+            struct_ser.serialize_field("cumulativeAmount", v)?;
+        }
+        if self.position_count != 0 {
+            struct_ser.serialize_field("positionCount", &self.position_count)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for DepthLevel {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "price",
+            "amount",
+            "cumulative_amount",
+            "cumulativeAmount",
+            "position_count",
+            "positionCount",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Price,
+            Amount,
+            CumulativeAmount,
+            PositionCount,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "price" => Ok(GeneratedField::Price),
+                            "amount" => Ok(GeneratedField::Amount),
+                            "cumulativeAmount" | "cumulative_amount" => Ok(GeneratedField::CumulativeAmount),
+                            "positionCount" | "position_count" => Ok(GeneratedField::PositionCount),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = DepthLevel;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.DepthLevel")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<DepthLevel, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut price__ = None;
+                let mut amount__ = None;
+                let mut cumulative_amount__ = None;
+                let mut position_count__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Amount => {
+                            if amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("amount"));
+                            }
+                            amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::CumulativeAmount => {
+                            if cumulative_amount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativeAmount"));
+                            }
+                            cumulative_amount__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionCount => {
+                            if position_count__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionCount"));
+                            }
+                            position_count__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(DepthLevel {
+                    price: price__.unwrap_or_default(),
+                    amount: amount__,
+                    cumulative_amount: cumulative_amount__,
+                    position_count: position_count__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.DepthLevel", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for DexParameters {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -1726,6 +1996,15 @@ impl serde::Serialize for DexParameters {
         if self.max_execution_budget != 0 {
             len += 1;
         }
+        if self.max_split_paths != 0 {
+            len += 1;
+        }
+        if !self.candlestick_intervals.is_empty() {
+            len += 1;
+        }
+        if self.composed_path_prices {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.DexParameters", len)?;
         if self.is_enabled {
             struct_ser.serialize_field("isEnabled", &self.is_enabled)?;
@@ -1742,6 +2021,19 @@ impl serde::Serialize for DexParameters {
         if self.max_execution_budget != 0 {
             struct_ser.serialize_field("maxExecutionBudget", &self.max_execution_budget)?;
         }
+        if self.max_split_paths != 0 {
+            struct_ser.serialize_field("maxSplitPaths", &self.max_split_paths)?;
+        }
+        if !self.candlestick_intervals.is_empty() {
+            let v = self.candlestick_intervals.iter().cloned().map(|v| {
+                CandlestickInterval::try_from(v)
+                    .map_err(|_| serde::ser::Error::custom(format!("Invalid variant {}", v)))
+                }).collect::<std::result::Result<Vec<_>, _>>()?;
+            struct_ser.serialize_field("candlestickIntervals", &v)?;
+        }
+        if self.composed_path_prices {
+            struct_ser.serialize_field("composedPathPrices", &self.composed_path_prices)?;
+        }
         struct_ser.end()
     }
 }
@@ -1762,6 +2054,12 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
             "maxPositionsPerPair",
             "max_execution_budget",
             "maxExecutionBudget",
+            "max_split_paths",
+            "maxSplitPaths",
+            "candlestick_intervals",
+            "candlestickIntervals",
+            "composed_path_prices",
+            "composedPathPrices",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -1771,6 +2069,9 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
             MaxHops,
             MaxPositionsPerPair,
             MaxExecutionBudget,
+            MaxSplitPaths,
+            CandlestickIntervals,
+            ComposedPathPrices,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -1798,6 +2099,9 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                             "maxHops" | "max_hops" => Ok(GeneratedField::MaxHops),
                             "maxPositionsPerPair" | "max_positions_per_pair" => Ok(GeneratedField::MaxPositionsPerPair),
                             "maxExecutionBudget" | "max_execution_budget" => Ok(GeneratedField::MaxExecutionBudget),
+                            "maxSplitPaths" | "max_split_paths" => Ok(GeneratedField::MaxSplitPaths),
+                            "candlestickIntervals" | "candlestick_intervals" => Ok(GeneratedField::CandlestickIntervals),
+                            "composedPathPrices" | "composed_path_prices" => Ok(GeneratedField::ComposedPathPrices),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -1822,6 +2126,9 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                 let mut max_hops__ = None;
                 let mut max_positions_per_pair__ = None;
                 let mut max_execution_budget__ = None;
+                let mut max_split_paths__ = None;
+                let mut candlestick_intervals__ = None;
+                let mut composed_path_prices__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::IsEnabled => {
@@ -1860,6 +2167,26 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MaxSplitPaths => {
+                            if max_split_paths__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxSplitPaths"));
+                            }
+                            max_split_paths__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CandlestickIntervals => {
+                            if candlestick_intervals__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("candlestickIntervals"));
+                            }
+                            candlestick_intervals__ = Some(map_.next_value::<Vec<CandlestickInterval>>()?.into_iter().map(|x| x as i32).collect());
+                        }
+                        GeneratedField::ComposedPathPrices => {
+                            if composed_path_prices__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("composedPathPrices"));
+                            }
+                            composed_path_prices__ = Some(map_.next_value()?);
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -1871,6 +2198,9 @@ impl<'de> serde::Deserialize<'de> for DexParameters {
                     max_hops: max_hops__.unwrap_or_default(),
                     max_positions_per_pair: max_positions_per_pair__.unwrap_or_default(),
                     max_execution_budget: max_execution_budget__.unwrap_or_default(),
+                    max_split_paths: max_split_paths__.unwrap_or_default(),
+                    candlestick_intervals: candlestick_intervals__.unwrap_or_default(),
+                    composed_path_prices: composed_path_prices__.unwrap_or_default(),
                 })
             }
         }
@@ -4006,7 +4336,7 @@ impl<'de> serde::Deserialize<'de> for GenesisContent {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.GenesisContent", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdRequest {
+impl serde::Serialize for LiquidityPositionActivityRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4017,14 +4347,22 @@ impl serde::Serialize for LiquidityPositionByIdRequest {
         if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
+        if self.start_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionActivityRequest", len)?;
         if let Some(v) = self.position_id.as_ref() {
             struct_ser.serialize_field("positionId", v)?;
         }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionActivityRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4033,11 +4371,14 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
         const FIELDS: &[&str] = &[
             "position_id",
             "positionId",
+            "start_height",
+            "startHeight",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             PositionId,
+            StartHeight,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4061,6 +4402,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                     {
                         match value {
                             "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4070,17 +4412,18 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdRequest;
+            type Value = LiquidityPositionActivityRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionActivityRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionActivityRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
                 let mut position_id__ = None;
+                let mut start_height__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::PositionId => {
@@ -4089,20 +4432,29 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
                             }
                             position_id__ = map_.next_value()?;
                         }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdRequest {
+                Ok(LiquidityPositionActivityRequest {
                     position_id: position_id__,
+                    start_height: start_height__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionActivityRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionByIdResponse {
+impl serde::Serialize for LiquidityPositionActivityResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4110,29 +4462,29 @@ impl serde::Serialize for LiquidityPositionByIdResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.data.is_some() {
+        if self.activity.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
-        if let Some(v) = self.data.as_ref() {
-            struct_ser.serialize_field("data", v)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionActivityResponse", len)?;
+        if let Some(v) = self.activity.as_ref() {
+            struct_ser.serialize_field("activity", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionActivityResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "data",
+            "activity",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Data,
+            Activity,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4155,7 +4507,7 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "data" => Ok(GeneratedField::Data),
+                            "activity" => Ok(GeneratedField::Activity),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4165,39 +4517,39 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionByIdResponse;
+            type Value = LiquidityPositionActivityResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionActivityResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionActivityResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut data__ = None;
+                let mut activity__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Data => {
-                            if data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("data"));
+                        GeneratedField::Activity => {
+                            if activity__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activity"));
                             }
-                            data__ = map_.next_value()?;
+                            activity__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionByIdResponse {
-                    data: data__,
+                Ok(LiquidityPositionActivityResponse {
+                    activity: activity__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionActivityResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for LiquidityPositionsByIdRequest {
+impl serde::Serialize for LiquidityPositionByIdRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4205,17 +4557,17 @@ impl serde::Serialize for LiquidityPositionsByIdRequest {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if !self.position_id.is_empty() {
+        if self.position_id.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
-        if !self.position_id.is_empty() {
-            struct_ser.serialize_field("positionId", &self.position_id)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -4261,13 +4613,13 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = LiquidityPositionsByIdRequest;
+            type Value = LiquidityPositionByIdRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -4278,16 +4630,207 @@ impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
                             if position_id__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            position_id__ = Some(map_.next_value()?);
+                            position_id__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(LiquidityPositionsByIdRequest {
-                    position_id: position_id__.unwrap_or_default(),
-                })
+                Ok(LiquidityPositionByIdRequest {
+                    position_id: position_id__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionByIdResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", len)?;
+        if let Some(v) = self.data.as_ref() {
+            struct_ser.serialize_field("data", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionByIdResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "data",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Data,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "data" => Ok(GeneratedField::Data),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionByIdResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionByIdResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionByIdResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Data => {
+                            if data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("data"));
+                            }
+                            data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionByIdResponse {
+                    data: data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionByIdResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for LiquidityPositionsByIdRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.position_id.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", len)?;
+        if !self.position_id.is_empty() {
+            struct_ser.serialize_field("positionId", &self.position_id)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for LiquidityPositionsByIdRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = LiquidityPositionsByIdRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<LiquidityPositionsByIdRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(LiquidityPositionsByIdRequest {
+                    position_id: position_id__.unwrap_or_default(),
+                })
             }
         }
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.LiquidityPositionsByIdRequest", FIELDS, GeneratedVisitor)
@@ -4921,7 +5464,7 @@ impl<'de> serde::Deserialize<'de> for LpNft {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.LpNft", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Position {
+impl serde::Serialize for OrderBookDepth {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -4929,64 +5472,47 @@ impl serde::Serialize for Position {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.phi.is_some() {
-            len += 1;
-        }
-        if !self.nonce.is_empty() {
-            len += 1;
-        }
-        if self.state.is_some() {
+        if self.height != 0 {
             len += 1;
         }
-        if self.reserves.is_some() {
+        if !self.bids.is_empty() {
             len += 1;
         }
-        if self.close_on_fill {
+        if !self.asks.is_empty() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
-        if let Some(v) = self.phi.as_ref() {
-            struct_ser.serialize_field("phi", v)?;
-        }
-        if !self.nonce.is_empty() {
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.OrderBookDepth", len)?;
+        if self.height != 0 {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
-        }
-        if let Some(v) = self.state.as_ref() {
-            struct_ser.serialize_field("state", v)?;
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
         }
-        if let Some(v) = self.reserves.as_ref() {
-            struct_ser.serialize_field("reserves", v)?;
+        if !self.bids.is_empty() {
+            struct_ser.serialize_field("bids", &self.bids)?;
         }
-        if self.close_on_fill {
-            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
+        if !self.asks.is_empty() {
+            struct_ser.serialize_field("asks", &self.asks)?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Position {
+impl<'de> serde::Deserialize<'de> for OrderBookDepth {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "phi",
-            "nonce",
-            "state",
-            "reserves",
-            "close_on_fill",
-            "closeOnFill",
+            "height",
+            "bids",
+            "asks",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Phi,
-            Nonce,
-            State,
-            Reserves,
-            CloseOnFill,
+            Height,
+            Bids,
+            Asks,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5009,11 +5535,9 @@ impl<'de> serde::Deserialize<'de> for Position {
                         E: serde::de::Error,
                     {
                         match value {
-                            "phi" => Ok(GeneratedField::Phi),
-                            "nonce" => Ok(GeneratedField::Nonce),
-                            "state" => Ok(GeneratedField::State),
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "height" => Ok(GeneratedField::Height),
+                            "bids" => Ok(GeneratedField::Bids),
+                            "asks" => Ok(GeneratedField::Asks),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5023,42 +5547,877 @@ impl<'de> serde::Deserialize<'de> for Position {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = Position;
+            type Value = OrderBookDepth;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
+                formatter.write_str("struct penumbra.core.component.dex.v1.OrderBookDepth")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderBookDepth, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut phi__ = None;
-                let mut nonce__ = None;
-                let mut state__ = None;
-                let mut reserves__ = None;
-                let mut close_on_fill__ = None;
+                let mut height__ = None;
+                let mut bids__ = None;
+                let mut asks__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Phi => {
-                            if phi__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("phi"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            phi__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Bids => {
+                            if bids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("bids"));
+                            }
+                            bids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Asks => {
+                            if asks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("asks"));
+                            }
+                            asks__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OrderBookDepth {
+                    height: height__.unwrap_or_default(),
+                    bids: bids__.unwrap_or_default(),
+                    asks: asks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.OrderBookDepth", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OrderBookDepthRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.tick_size != 0. {
+            len += 1;
+        }
+        if self.levels != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.OrderBookDepthRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.tick_size != 0. {
+            struct_ser.serialize_field("tickSize", &self.tick_size)?;
+        }
+        if self.levels != 0 {
+            struct_ser.serialize_field("levels", &self.levels)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderBookDepthRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "tick_size",
+            "tickSize",
+            "levels",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            TickSize,
+            Levels,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "tickSize" | "tick_size" => Ok(GeneratedField::TickSize),
+                            "levels" => Ok(GeneratedField::Levels),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderBookDepthRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.OrderBookDepthRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderBookDepthRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut tick_size__ = None;
+                let mut levels__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::TickSize => {
+                            if tick_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tickSize"));
+                            }
+                            tick_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Levels => {
+                            if levels__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("levels"));
+                            }
+                            levels__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OrderBookDepthRequest {
+                    trading_pair: trading_pair__,
+                    tick_size: tick_size__.unwrap_or_default(),
+                    levels: levels__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.OrderBookDepthRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OrderBookDepthResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.depth.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.OrderBookDepthResponse", len)?;
+        if let Some(v) = self.depth.as_ref() {
+            struct_ser.serialize_field("depth", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderBookDepthResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "depth",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Depth,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "depth" => Ok(GeneratedField::Depth),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderBookDepthResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.OrderBookDepthResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderBookDepthResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut depth__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Depth => {
+                            if depth__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depth"));
+                            }
+                            depth__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OrderBookDepthResponse {
+                    depth: depth__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.OrderBookDepthResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OrderBookDepthStreamRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.tick_size != 0. {
+            len += 1;
+        }
+        if self.levels != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.OrderBookDepthStreamRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.tick_size != 0. {
+            struct_ser.serialize_field("tickSize", &self.tick_size)?;
+        }
+        if self.levels != 0 {
+            struct_ser.serialize_field("levels", &self.levels)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderBookDepthStreamRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "tick_size",
+            "tickSize",
+            "levels",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            TickSize,
+            Levels,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "tickSize" | "tick_size" => Ok(GeneratedField::TickSize),
+                            "levels" => Ok(GeneratedField::Levels),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderBookDepthStreamRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.OrderBookDepthStreamRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderBookDepthStreamRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut tick_size__ = None;
+                let mut levels__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::TickSize => {
+                            if tick_size__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tickSize"));
+                            }
+                            tick_size__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Levels => {
+                            if levels__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("levels"));
+                            }
+                            levels__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OrderBookDepthStreamRequest {
+                    trading_pair: trading_pair__,
+                    tick_size: tick_size__.unwrap_or_default(),
+                    levels: levels__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.OrderBookDepthStreamRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for OrderBookDepthStreamResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.depth.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.OrderBookDepthStreamResponse", len)?;
+        if let Some(v) = self.depth.as_ref() {
+            struct_ser.serialize_field("depth", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for OrderBookDepthStreamResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "depth",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Depth,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "depth" => Ok(GeneratedField::Depth),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = OrderBookDepthStreamResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.OrderBookDepthStreamResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<OrderBookDepthStreamResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut depth__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Depth => {
+                            if depth__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("depth"));
+                            }
+                            depth__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(OrderBookDepthStreamResponse {
+                    depth: depth__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.OrderBookDepthStreamResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Position {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.phi.is_some() {
+            len += 1;
+        }
+        if !self.nonce.is_empty() {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.close_on_fill {
+            len += 1;
+        }
+        if self.expires_at_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.Position", len)?;
+        if let Some(v) = self.phi.as_ref() {
+            struct_ser.serialize_field("phi", v)?;
+        }
+        if !self.nonce.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("nonce", pbjson::private::base64::encode(&self.nonce).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.close_on_fill {
+            struct_ser.serialize_field("closeOnFill", &self.close_on_fill)?;
+        }
+        if self.expires_at_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("expiresAtHeight", ToString::to_string(&self.expires_at_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Position {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "phi",
+            "nonce",
+            "state",
+            "reserves",
+            "close_on_fill",
+            "closeOnFill",
+            "expires_at_height",
+            "expiresAtHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Phi,
+            Nonce,
+            State,
+            Reserves,
+            CloseOnFill,
+            ExpiresAtHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "phi" => Ok(GeneratedField::Phi),
+                            "nonce" => Ok(GeneratedField::Nonce),
+                            "state" => Ok(GeneratedField::State),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "closeOnFill" | "close_on_fill" => Ok(GeneratedField::CloseOnFill),
+                            "expiresAtHeight" | "expires_at_height" => Ok(GeneratedField::ExpiresAtHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = Position;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.Position")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<Position, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut phi__ = None;
+                let mut nonce__ = None;
+                let mut state__ = None;
+                let mut reserves__ = None;
+                let mut close_on_fill__ = None;
+                let mut expires_at_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Phi => {
+                            if phi__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("phi"));
+                            }
+                            phi__ = map_.next_value()?;
+                        }
+                        GeneratedField::Nonce => {
+                            if nonce__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("nonce"));
+                            }
+                            nonce__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::CloseOnFill => {
+                            if close_on_fill__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                            }
+                            close_on_fill__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ExpiresAtHeight => {
+                            if expires_at_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("expiresAtHeight"));
+                            }
+                            expires_at_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(Position {
+                    phi: phi__,
+                    nonce: nonce__.unwrap_or_default(),
+                    state: state__,
+                    reserves: reserves__,
+                    close_on_fill: close_on_fill__.unwrap_or_default(),
+                    expires_at_height: expires_at_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionActivity {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if self.prev_reserves.is_some() {
+            len += 1;
+        }
+        if self.reserves.is_some() {
+            len += 1;
+        }
+        if self.executions != 0 {
+            len += 1;
+        }
+        if self.fees.is_some() {
+            len += 1;
+        }
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        if self.withdrawn.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PositionActivity", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if let Some(v) = self.prev_reserves.as_ref() {
+            struct_ser.serialize_field("prevReserves", v)?;
+        }
+        if let Some(v) = self.reserves.as_ref() {
+            struct_ser.serialize_field("reserves", v)?;
+        }
+        if self.executions != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("executions", ToString::to_string(&self.executions).as_str())?;
+        }
+        if let Some(v) = self.fees.as_ref() {
+            struct_ser.serialize_field("fees", v)?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        if let Some(v) = self.withdrawn.as_ref() {
+            struct_ser.serialize_field("withdrawn", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionActivity {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "height",
+            "prev_reserves",
+            "prevReserves",
+            "reserves",
+            "executions",
+            "fees",
+            "rewards",
+            "withdrawn",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Height,
+            PrevReserves,
+            Reserves,
+            Executions,
+            Fees,
+            Rewards,
+            Withdrawn,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "height" => Ok(GeneratedField::Height),
+                            "prevReserves" | "prev_reserves" => Ok(GeneratedField::PrevReserves),
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "executions" => Ok(GeneratedField::Executions),
+                            "fees" => Ok(GeneratedField::Fees),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            "withdrawn" => Ok(GeneratedField::Withdrawn),
+                            _ => Ok(GeneratedField::__SkipField__),
                         }
-                        GeneratedField::Nonce => {
-                            if nonce__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("nonce"));
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionActivity;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionActivity")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionActivity, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut height__ = None;
+                let mut prev_reserves__ = None;
+                let mut reserves__ = None;
+                let mut executions__ = None;
+                let mut fees__ = None;
+                let mut rewards__ = None;
+                let mut withdrawn__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
                             }
-                            nonce__ = 
-                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
+                            }
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::State => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("state"));
+                        GeneratedField::PrevReserves => {
+                            if prev_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("prevReserves"));
                             }
-                            state__ = map_.next_value()?;
+                            prev_reserves__ = map_.next_value()?;
                         }
                         GeneratedField::Reserves => {
                             if reserves__.is_some() {
@@ -5066,27 +6425,50 @@ impl<'de> serde::Deserialize<'de> for Position {
                             }
                             reserves__ = map_.next_value()?;
                         }
-                        GeneratedField::CloseOnFill => {
-                            if close_on_fill__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("closeOnFill"));
+                        GeneratedField::Executions => {
+                            if executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executions"));
                             }
-                            close_on_fill__ = Some(map_.next_value()?);
+                            executions__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Fees => {
+                            if fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees"));
+                            }
+                            fees__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::Withdrawn => {
+                            if withdrawn__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawn"));
+                            }
+                            withdrawn__ = map_.next_value()?;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(Position {
-                    phi: phi__,
-                    nonce: nonce__.unwrap_or_default(),
-                    state: state__,
+                Ok(PositionActivity {
+                    position_id: position_id__,
+                    height: height__.unwrap_or_default(),
+                    prev_reserves: prev_reserves__,
                     reserves: reserves__,
-                    close_on_fill: close_on_fill__.unwrap_or_default(),
+                    executions: executions__.unwrap_or_default(),
+                    fees: fees__,
+                    rewards: rewards__,
+                    withdrawn: withdrawn__,
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.Position", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionActivity", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for PositionClose {
@@ -6553,28 +7935,216 @@ impl serde::Serialize for PositionWithdrawPlan {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
+impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "reserves",
+            "position_id",
+            "positionId",
+            "pair",
+            "sequence",
+            "rewards",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Reserves,
+            PositionId,
+            Pair,
+            Sequence,
+            Rewards,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "reserves" => Ok(GeneratedField::Reserves),
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "pair" => Ok(GeneratedField::Pair),
+                            "sequence" => Ok(GeneratedField::Sequence),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionWithdrawPlan;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut reserves__ = None;
+                let mut position_id__ = None;
+                let mut pair__ = None;
+                let mut sequence__ = None;
+                let mut rewards__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Reserves => {
+                            if reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("reserves"));
+                            }
+                            reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Pair => {
+                            if pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pair"));
+                            }
+                            pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::Sequence => {
+                            if sequence__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("sequence"));
+                            }
+                            sequence__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionWithdrawPlan {
+                    reserves: reserves__,
+                    position_id: position_id__,
+                    pair: pair__,
+                    sequence: sequence__.unwrap_or_default(),
+                    rewards: rewards__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PriceAccumulator {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.height != 0 {
+            len += 1;
+        }
+        if !self.price_1.is_empty() {
+            len += 1;
+        }
+        if !self.price_2.is_empty() {
+            len += 1;
+        }
+        if !self.cumulative_price_1.is_empty() {
+            len += 1;
+        }
+        if !self.cumulative_price_2.is_empty() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.PriceAccumulator", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("height", ToString::to_string(&self.height).as_str())?;
+        }
+        if !self.price_1.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("price1", pbjson::private::base64::encode(&self.price_1).as_str())?;
+        }
+        if !self.price_2.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("price2", pbjson::private::base64::encode(&self.price_2).as_str())?;
+        }
+        if !self.cumulative_price_1.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("cumulativePrice1", pbjson::private::base64::encode(&self.cumulative_price_1).as_str())?;
+        }
+        if !self.cumulative_price_2.is_empty() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("cumulativePrice2", pbjson::private::base64::encode(&self.cumulative_price_2).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PriceAccumulator {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "reserves",
-            "position_id",
-            "positionId",
-            "pair",
-            "sequence",
-            "rewards",
+            "trading_pair",
+            "tradingPair",
+            "height",
+            "price_1",
+            "price1",
+            "price_2",
+            "price2",
+            "cumulative_price_1",
+            "cumulativePrice1",
+            "cumulative_price_2",
+            "cumulativePrice2",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Reserves,
-            PositionId,
-            Pair,
-            Sequence,
-            Rewards,
+            TradingPair,
+            Height,
+            Price1,
+            Price2,
+            CumulativePrice1,
+            CumulativePrice2,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -6597,11 +8167,12 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
                         E: serde::de::Error,
                     {
                         match value {
-                            "reserves" => Ok(GeneratedField::Reserves),
-                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
-                            "pair" => Ok(GeneratedField::Pair),
-                            "sequence" => Ok(GeneratedField::Sequence),
-                            "rewards" => Ok(GeneratedField::Rewards),
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "height" => Ok(GeneratedField::Height),
+                            "price1" | "price_1" => Ok(GeneratedField::Price1),
+                            "price2" | "price_2" => Ok(GeneratedField::Price2),
+                            "cumulativePrice1" | "cumulative_price_1" => Ok(GeneratedField::CumulativePrice1),
+                            "cumulativePrice2" | "cumulative_price_2" => Ok(GeneratedField::CumulativePrice2),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -6611,70 +8182,86 @@ impl<'de> serde::Deserialize<'de> for PositionWithdrawPlan {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = PositionWithdrawPlan;
+            type Value = PriceAccumulator;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.dex.v1.PositionWithdrawPlan")
+                formatter.write_str("struct penumbra.core.component.dex.v1.PriceAccumulator")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionWithdrawPlan, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PriceAccumulator, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut reserves__ = None;
-                let mut position_id__ = None;
-                let mut pair__ = None;
-                let mut sequence__ = None;
-                let mut rewards__ = None;
+                let mut trading_pair__ = None;
+                let mut height__ = None;
+                let mut price_1__ = None;
+                let mut price_2__ = None;
+                let mut cumulative_price_1__ = None;
+                let mut cumulative_price_2__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Reserves => {
-                            if reserves__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("reserves"));
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
                             }
-                            reserves__ = map_.next_value()?;
+                            trading_pair__ = map_.next_value()?;
                         }
-                        GeneratedField::PositionId => {
-                            if position_id__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("positionId"));
+                        GeneratedField::Height => {
+                            if height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("height"));
                             }
-                            position_id__ = map_.next_value()?;
+                            height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Pair => {
-                            if pair__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("pair"));
+                        GeneratedField::Price1 => {
+                            if price_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price1"));
                             }
-                            pair__ = map_.next_value()?;
+                            price_1__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Sequence => {
-                            if sequence__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("sequence"));
+                        GeneratedField::Price2 => {
+                            if price_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price2"));
                             }
-                            sequence__ = 
-                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            price_2__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
                             ;
                         }
-                        GeneratedField::Rewards => {
-                            if rewards__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rewards"));
+                        GeneratedField::CumulativePrice1 => {
+                            if cumulative_price_1__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativePrice1"));
                             }
-                            rewards__ = Some(map_.next_value()?);
+                            cumulative_price_1__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CumulativePrice2 => {
+                            if cumulative_price_2__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("cumulativePrice2"));
+                            }
+                            cumulative_price_2__ = 
+                                Some(map_.next_value::<::pbjson::private::BytesDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(PositionWithdrawPlan {
-                    reserves: reserves__,
-                    position_id: position_id__,
-                    pair: pair__,
-                    sequence: sequence__.unwrap_or_default(),
-                    rewards: rewards__.unwrap_or_default(),
+                Ok(PriceAccumulator {
+                    trading_pair: trading_pair__,
+                    height: height__.unwrap_or_default(),
+                    price_1: price_1__.unwrap_or_default(),
+                    price_2: price_2__.unwrap_or_default(),
+                    cumulative_price_1: cumulative_price_1__.unwrap_or_default(),
+                    cumulative_price_2: cumulative_price_2__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PositionWithdrawPlan", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.PriceAccumulator", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for Reserves {
@@ -7190,6 +8777,24 @@ impl serde::Serialize for SimulateTradeResponse {
         if self.unfilled.is_some() {
             len += 1;
         }
+        if !self.position_executions.is_empty() {
+            len += 1;
+        }
+        if self.effective_price.is_some() {
+            len += 1;
+        }
+        if self.mid_price.is_some() {
+            len += 1;
+        }
+        if self.price_impact_bps.is_some() {
+            len += 1;
+        }
+        if !self.lp_fees.is_empty() {
+            len += 1;
+        }
+        if self.spread_after.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", len)?;
         if let Some(v) = self.output.as_ref() {
             struct_ser.serialize_field("output", v)?;
@@ -7197,6 +8802,24 @@ impl serde::Serialize for SimulateTradeResponse {
         if let Some(v) = self.unfilled.as_ref() {
             struct_ser.serialize_field("unfilled", v)?;
         }
+        if !self.position_executions.is_empty() {
+            struct_ser.serialize_field("positionExecutions", &self.position_executions)?;
+        }
+        if let Some(v) = self.effective_price.as_ref() {
+            struct_ser.serialize_field("effectivePrice", v)?;
+        }
+        if let Some(v) = self.mid_price.as_ref() {
+            struct_ser.serialize_field("midPrice", v)?;
+        }
+        if let Some(v) = self.price_impact_bps.as_ref() {
+            struct_ser.serialize_field("priceImpactBps", v)?;
+        }
+        if !self.lp_fees.is_empty() {
+            struct_ser.serialize_field("lpFees", &self.lp_fees)?;
+        }
+        if let Some(v) = self.spread_after.as_ref() {
+            struct_ser.serialize_field("spreadAfter", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -7209,12 +8832,30 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
         const FIELDS: &[&str] = &[
             "output",
             "unfilled",
+            "position_executions",
+            "positionExecutions",
+            "effective_price",
+            "effectivePrice",
+            "mid_price",
+            "midPrice",
+            "price_impact_bps",
+            "priceImpactBps",
+            "lp_fees",
+            "lpFees",
+            "spread_after",
+            "spreadAfter",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
             Output,
             Unfilled,
+            PositionExecutions,
+            EffectivePrice,
+            MidPrice,
+            PriceImpactBps,
+            LpFees,
+            SpreadAfter,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -7239,6 +8880,12 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
                         match value {
                             "output" => Ok(GeneratedField::Output),
                             "unfilled" => Ok(GeneratedField::Unfilled),
+                            "positionExecutions" | "position_executions" => Ok(GeneratedField::PositionExecutions),
+                            "effectivePrice" | "effective_price" => Ok(GeneratedField::EffectivePrice),
+                            "midPrice" | "mid_price" => Ok(GeneratedField::MidPrice),
+                            "priceImpactBps" | "price_impact_bps" => Ok(GeneratedField::PriceImpactBps),
+                            "lpFees" | "lp_fees" => Ok(GeneratedField::LpFees),
+                            "spreadAfter" | "spread_after" => Ok(GeneratedField::SpreadAfter),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -7260,32 +8907,267 @@ impl<'de> serde::Deserialize<'de> for SimulateTradeResponse {
             {
                 let mut output__ = None;
                 let mut unfilled__ = None;
+                let mut position_executions__ = None;
+                let mut effective_price__ = None;
+                let mut mid_price__ = None;
+                let mut price_impact_bps__ = None;
+                let mut lp_fees__ = None;
+                let mut spread_after__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Output => {
+                            if output__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("output"));
+                            }
+                            output__ = map_.next_value()?;
+                        }
+                        GeneratedField::Unfilled => {
+                            if unfilled__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unfilled"));
+                            }
+                            unfilled__ = map_.next_value()?;
+                        }
+                        GeneratedField::PositionExecutions => {
+                            if position_executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionExecutions"));
+                            }
+                            position_executions__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EffectivePrice => {
+                            if effective_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectivePrice"));
+                            }
+                            effective_price__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::MidPrice => {
+                            if mid_price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("midPrice"));
+                            }
+                            mid_price__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::PriceImpactBps => {
+                            if price_impact_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("priceImpactBps"));
+                            }
+                            price_impact_bps__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::LpFees => {
+                            if lp_fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("lpFees"));
+                            }
+                            lp_fees__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::SpreadAfter => {
+                            if spread_after__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("spreadAfter"));
+                            }
+                            spread_after__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(SimulateTradeResponse {
+                    output: output__,
+                    unfilled: unfilled__,
+                    position_executions: position_executions__.unwrap_or_default(),
+                    effective_price: effective_price__,
+                    mid_price: mid_price__,
+                    price_impact_bps: price_impact_bps__,
+                    lp_fees: lp_fees__.unwrap_or_default(),
+                    spread_after: spread_after__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for SimulatedPositionExecution {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position_id.is_some() {
+            len += 1;
+        }
+        if self.hop.is_some() {
+            len += 1;
+        }
+        if self.input.is_some() {
+            len += 1;
+        }
+        if self.output.is_some() {
+            len += 1;
+        }
+        if self.fee.is_some() {
+            len += 1;
+        }
+        if self.exhausted {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.SimulatedPositionExecution", len)?;
+        if let Some(v) = self.position_id.as_ref() {
+            struct_ser.serialize_field("positionId", v)?;
+        }
+        if let Some(v) = self.hop.as_ref() {
+            struct_ser.serialize_field("hop", v)?;
+        }
+        if let Some(v) = self.input.as_ref() {
+            struct_ser.serialize_field("input", v)?;
+        }
+        if let Some(v) = self.output.as_ref() {
+            struct_ser.serialize_field("output", v)?;
+        }
+        if let Some(v) = self.fee.as_ref() {
+            struct_ser.serialize_field("fee", v)?;
+        }
+        if self.exhausted {
+            struct_ser.serialize_field("exhausted", &self.exhausted)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for SimulatedPositionExecution {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_id",
+            "positionId",
+            "hop",
+            "input",
+            "output",
+            "fee",
+            "exhausted",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionId,
+            Hop,
+            Input,
+            Output,
+            Fee,
+            Exhausted,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionId" | "position_id" => Ok(GeneratedField::PositionId),
+                            "hop" => Ok(GeneratedField::Hop),
+                            "input" => Ok(GeneratedField::Input),
+                            "output" => Ok(GeneratedField::Output),
+                            "fee" => Ok(GeneratedField::Fee),
+                            "exhausted" => Ok(GeneratedField::Exhausted),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = SimulatedPositionExecution;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.SimulatedPositionExecution")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<SimulatedPositionExecution, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_id__ = None;
+                let mut hop__ = None;
+                let mut input__ = None;
+                let mut output__ = None;
+                let mut fee__ = None;
+                let mut exhausted__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
+                        GeneratedField::PositionId => {
+                            if position_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionId"));
+                            }
+                            position_id__ = map_.next_value()?;
+                        }
+                        GeneratedField::Hop => {
+                            if hop__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("hop"));
+                            }
+                            hop__ = map_.next_value()?;
+                        }
+                        GeneratedField::Input => {
+                            if input__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("input"));
+                            }
+                            input__ = map_.next_value()?;
+                        }
                         GeneratedField::Output => {
                             if output__.is_some() {
                                 return Err(serde::de::Error::duplicate_field("output"));
                             }
                             output__ = map_.next_value()?;
                         }
-                        GeneratedField::Unfilled => {
-                            if unfilled__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("unfilled"));
+                        GeneratedField::Fee => {
+                            if fee__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fee"));
                             }
-                            unfilled__ = map_.next_value()?;
+                            fee__ = map_.next_value()?;
+                        }
+                        GeneratedField::Exhausted => {
+                            if exhausted__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("exhausted"));
+                            }
+                            exhausted__ = Some(map_.next_value()?);
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(SimulateTradeResponse {
+                Ok(SimulatedPositionExecution {
+                    position_id: position_id__,
+                    hop: hop__,
+                    input: input__,
                     output: output__,
-                    unfilled: unfilled__,
+                    fee: fee__,
+                    exhausted: exhausted__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulateTradeResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.SimulatedPositionExecution", FIELDS, GeneratedVisitor)
     }
 }
 impl serde::Serialize for SpreadRequest {
@@ -10376,6 +12258,265 @@ impl<'de> serde::Deserialize<'de> for swap_view::Visible {
         deserializer.deserialize_struct("penumbra.core.component.dex.v1.SwapView.Visible", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for TimeWeightedAveragePriceRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.trading_pair.is_some() {
+            len += 1;
+        }
+        if self.start_height != 0 {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest", len)?;
+        if let Some(v) = self.trading_pair.as_ref() {
+            struct_ser.serialize_field("tradingPair", v)?;
+        }
+        if self.start_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startHeight", ToString::to_string(&self.start_height).as_str())?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePriceRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "trading_pair",
+            "tradingPair",
+            "start_height",
+            "startHeight",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            TradingPair,
+            StartHeight,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "tradingPair" | "trading_pair" => Ok(GeneratedField::TradingPair),
+                            "startHeight" | "start_height" => Ok(GeneratedField::StartHeight),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePriceRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedAveragePriceRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut trading_pair__ = None;
+                let mut start_height__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::TradingPair => {
+                            if trading_pair__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("tradingPair"));
+                            }
+                            trading_pair__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartHeight => {
+                            if start_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startHeight"));
+                            }
+                            start_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePriceRequest {
+                    trading_pair: trading_pair__,
+                    start_height: start_height__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for TimeWeightedAveragePriceResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.price != 0. {
+            len += 1;
+        }
+        if self.end_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse", len)?;
+        if self.price != 0. {
+            struct_ser.serialize_field("price", &self.price)?;
+        }
+        if self.end_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endHeight", ToString::to_string(&self.end_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for TimeWeightedAveragePriceResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "price",
+            "end_height",
+            "endHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Price,
+            EndHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "price" => Ok(GeneratedField::Price),
+                            "endHeight" | "end_height" => Ok(GeneratedField::EndHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = TimeWeightedAveragePriceResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<TimeWeightedAveragePriceResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut price__ = None;
+                let mut end_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Price => {
+                            if price__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("price"));
+                            }
+                            price__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndHeight => {
+                            if end_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endHeight"));
+                            }
+                            end_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(TimeWeightedAveragePriceResponse {
+                    price: price__.unwrap_or_default(),
+                    end_height: end_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.dex.v1.TimeWeightedAveragePriceResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for TradingFunction {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
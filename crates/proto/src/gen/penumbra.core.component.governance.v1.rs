@@ -636,7 +636,7 @@ pub struct Proposal {
     #[prost(string, tag = "2")]
     pub description: ::prost::alloc::string::String,
     /// The proposal's payload.
-    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11")]
+    #[prost(oneof = "proposal::Payload", tags = "5, 6, 7, 8, 9, 10, 11, 12")]
     pub payload: ::core::option::Option<proposal::Payload>,
 }
 /// Nested message and enum types in `Proposal`.
@@ -790,6 +790,33 @@ pub mod proposal {
             "/penumbra.core.component.governance.v1.Proposal.UnfreezeIbcClient".into()
         }
     }
+    /// A scheduled parameter change proposal bundles one or more parameter changes, which take
+    /// effect at an explicit activation height or epoch rather than as soon as the proposal passes.
+    ///
+    /// Exactly one of `activation_height` and `activation_epoch` must be set.
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct ScheduledParameterChange {
+        /// The parameter changes to apply, in order. Each change is applied independently: if the
+        /// preconditions of a change do not hold at activation time, only that change is skipped.
+        #[prost(message, repeated, tag = "1")]
+        pub changes: ::prost::alloc::vec::Vec<ParameterChange>,
+        /// The height of the block at the beginning of which the changes are applied.
+        #[prost(uint64, tag = "2")]
+        pub activation_height: u64,
+        /// The index of the epoch at the beginning of which the changes are applied.
+        #[prost(uint64, tag = "3")]
+        pub activation_epoch: u64,
+    }
+    impl ::prost::Name for ScheduledParameterChange {
+        const NAME: &'static str = "ScheduledParameterChange";
+        const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+        fn full_name() -> ::prost::alloc::string::String {
+            "penumbra.core.component.governance.v1.Proposal.ScheduledParameterChange".into()
+        }
+        fn type_url() -> ::prost::alloc::string::String {
+            "/penumbra.core.component.governance.v1.Proposal.ScheduledParameterChange".into()
+        }
+    }
     /// The proposal's payload.
     #[derive(Clone, PartialEq, ::prost::Oneof)]
     pub enum Payload {
@@ -807,6 +834,8 @@ pub mod proposal {
         FreezeIbcClient(FreezeIbcClient),
         #[prost(message, tag = "11")]
         UnfreezeIbcClient(UnfreezeIbcClient),
+        #[prost(message, tag = "12")]
+        ScheduledParameterChange(ScheduledParameterChange),
     }
 }
 impl ::prost::Name for Proposal {
@@ -936,6 +965,144 @@ impl ::prost::Name for ProposalRateDataResponse {
         "/penumbra.core.component.governance.v1.ProposalRateDataResponse".into()
     }
 }
+/// Requests the queue of scheduled parameter changes.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ScheduledParameterChangesRequest {}
+impl ::prost::Name for ScheduledParameterChangesRequest {
+    const NAME: &'static str = "ScheduledParameterChangesRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.governance.v1.ScheduledParameterChangesRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.governance.v1.ScheduledParameterChangesRequest".into()
+    }
+}
+/// A parameter change waiting for its activation.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ScheduledParameterChangesResponse {
+    /// The id of the proposal that scheduled the change.
+    #[prost(uint64, tag = "1")]
+    pub proposal_id: u64,
+    /// The scheduled parameter change.
+    #[prost(message, optional, tag = "2")]
+    pub scheduled_change: ::core::option::Option<proposal::ScheduledParameterChange>,
+    /// The height at which the change will be applied, if it is known yet.
+    ///
+    /// This is zero for changes scheduled for an epoch that has not started yet.
+    #[prost(uint64, tag = "3")]
+    pub activation_height: u64,
+}
+impl ::prost::Name for ScheduledParameterChangesResponse {
+    const NAME: &'static str = "ScheduledParameterChangesResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.governance.v1.ScheduledParameterChangesResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.governance.v1.ScheduledParameterChangesResponse".into()
+    }
+}
+/// Requests a projection of the outcome of a proposal.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ProposalProjectionRequest {
+    /// The proposal id to request information on.
+    #[prost(uint64, tag = "2")]
+    pub proposal_id: u64,
+}
+impl ::prost::Name for ProposalProjectionRequest {
+    const NAME: &'static str = "ProposalProjectionRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.governance.v1.ProposalProjectionRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.governance.v1.ProposalProjectionRequest".into()
+    }
+}
+/// The projected outcome of a proposal, if voting ended now.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ProposalProjectionResponse {
+    /// The votes tallied so far, including validator votes on behalf of delegators who have not voted.
+    #[prost(message, optional, tag = "1")]
+    pub current_tally: ::core::option::Option<Tally>,
+    /// The outcome of the proposal if voting ended with the current tally.
+    #[prost(message, optional, tag = "2")]
+    pub projected_outcome: ::core::option::Option<ProposalOutcome>,
+    /// The total voting power of all validators at the start of the proposal.
+    #[prost(uint64, tag = "3")]
+    pub total_voting_power: u64,
+    /// The voting power which has not been cast, either by a validator or by its delegators.
+    #[prost(uint64, tag = "4")]
+    pub unvoted_power: u64,
+    /// The voting power counted through validator votes, which delegators can still override.
+    #[prost(uint64, tag = "5")]
+    pub validator_default_power: u64,
+    /// The smallest amount of voting power which would flip the projected outcome: `yes` votes if the
+    /// proposal would not pass, or `no` votes if it would. These can be cast with the unvoted power,
+    /// or by delegators overriding their validator's vote, which counts on both sides.
+    ///
+    /// This is zero if casting all of this voting power would not change the outcome.
+    #[prost(uint64, tag = "6")]
+    pub votes_to_flip: u64,
+}
+impl ::prost::Name for ProposalProjectionResponse {
+    const NAME: &'static str = "ProposalProjectionResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.governance.v1.ProposalProjectionResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.governance.v1.ProposalProjectionResponse".into()
+    }
+}
+/// Requests the per-validator breakdown of the votes on a proposal.
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct ValidatorVoteBreakdownRequest {
+    /// The proposal id to request information on.
+    #[prost(uint64, tag = "2")]
+    pub proposal_id: u64,
+}
+impl ::prost::Name for ValidatorVoteBreakdownRequest {
+    const NAME: &'static str = "ValidatorVoteBreakdownRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.governance.v1.ValidatorVoteBreakdownRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.governance.v1.ValidatorVoteBreakdownRequest".into()
+    }
+}
+/// The breakdown of the votes on a proposal for a single validator active at proposal start.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorVoteBreakdownResponse {
+    /// The validator identity.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The voting power of the validator at the start of the proposal.
+    #[prost(uint64, tag = "2")]
+    pub voting_power: u64,
+    /// The validator's own vote, if it has voted.
+    #[prost(message, optional, tag = "3")]
+    pub validator_vote: ::core::option::Option<Vote>,
+    /// The votes of delegators to the validator, including those not tallied yet, which override
+    /// the validator's vote for their share of its voting power.
+    #[prost(message, optional, tag = "4")]
+    pub delegator_tally: ::core::option::Option<Tally>,
+    /// The net votes contributed by the validator and its delegators to the proposal's tally.
+    #[prost(message, optional, tag = "5")]
+    pub net_tally: ::core::option::Option<Tally>,
+}
+impl ::prost::Name for ValidatorVoteBreakdownResponse {
+    const NAME: &'static str = "ValidatorVoteBreakdownResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.governance.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.governance.v1.ValidatorVoteBreakdownResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.governance.v1.ValidatorVoteBreakdownResponse".into()
+    }
+}
 /// Requests the list of all proposals.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct ProposalListRequest {
@@ -1457,6 +1624,7 @@ pub enum ProposalKind {
     UpgradePlan = 5,
     FreezeIbcClient = 6,
     UnfreezeIbcClient = 7,
+    ScheduledParameterChange = 8,
 }
 impl ProposalKind {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -1473,6 +1641,9 @@ impl ProposalKind {
             Self::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             Self::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             Self::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            Self::ScheduledParameterChange => {
+                "PROPOSAL_KIND_SCHEDULED_PARAMETER_CHANGE"
+            }
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -1486,6 +1657,9 @@ impl ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN" => Some(Self::UpgradePlan),
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Some(Self::FreezeIbcClient),
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Some(Self::UnfreezeIbcClient),
+            "PROPOSAL_KIND_SCHEDULED_PARAMETER_CHANGE" => {
+                Some(Self::ScheduledParameterChange)
+            }
             _ => None,
         }
     }
@@ -1822,6 +1996,96 @@ pub mod query_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Lists the parameter changes from passed proposals that are waiting for their activation.
+        pub async fn scheduled_parameter_changes(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ScheduledParameterChangesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ScheduledParameterChangesResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/ScheduledParameterChanges",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "ScheduledParameterChanges",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
+        /// Projects the outcome of a proposal if voting ended now, and how much voting power could change it.
+        pub async fn proposal_projection(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ProposalProjectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProposalProjectionResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/ProposalProjection",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "ProposalProjection",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        /// Breaks down the votes on a proposal by validator, showing how delegator votes overrode each validator's vote.
+        pub async fn validator_vote_breakdown(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorVoteBreakdownRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ValidatorVoteBreakdownResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.governance.v1.QueryService/ValidatorVoteBreakdown",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.governance.v1.QueryService",
+                        "ValidatorVoteBreakdown",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1925,6 +2189,42 @@ pub mod query_service_server {
             tonic::Response<Self::ProposalRateDataStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ScheduledParameterChanges method.
+        type ScheduledParameterChangesStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ScheduledParameterChangesResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Lists the parameter changes from passed proposals that are waiting for their activation.
+        async fn scheduled_parameter_changes(
+            &self,
+            request: tonic::Request<super::ScheduledParameterChangesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ScheduledParameterChangesStream>,
+            tonic::Status,
+        >;
+        /// Projects the outcome of a proposal if voting ended now, and how much voting power could change it.
+        async fn proposal_projection(
+            &self,
+            request: tonic::Request<super::ProposalProjectionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ProposalProjectionResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the ValidatorVoteBreakdown method.
+        type ValidatorVoteBreakdownStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ValidatorVoteBreakdownResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Breaks down the votes on a proposal by validator, showing how delegator votes overrode each validator's vote.
+        async fn validator_vote_breakdown(
+            &self,
+            request: tonic::Request<super::ValidatorVoteBreakdownRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ValidatorVoteBreakdownStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the governance component.
     #[derive(Debug)]
@@ -2385,6 +2685,143 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.governance.v1.QueryService/ScheduledParameterChanges" => {
+                    #[allow(non_camel_case_types)]
+                    struct ScheduledParameterChangesSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::ScheduledParameterChangesRequest>
+                    for ScheduledParameterChangesSvc<T> {
+                        type Response = super::ScheduledParameterChangesResponse;
+                        type ResponseStream = T::ScheduledParameterChangesStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ScheduledParameterChangesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::scheduled_parameter_changes(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ScheduledParameterChangesSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.governance.v1.QueryService/ProposalProjection" => {
+                    #[allow(non_camel_case_types)]
+                    struct ProposalProjectionSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::UnaryService<super::ProposalProjectionRequest>
+                    for ProposalProjectionSvc<T> {
+                        type Response = super::ProposalProjectionResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ProposalProjectionRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::proposal_projection(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ProposalProjectionSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/penumbra.core.component.governance.v1.QueryService/ValidatorVoteBreakdown" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorVoteBreakdownSvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::ValidatorVoteBreakdownRequest>
+                    for ValidatorVoteBreakdownSvc<T> {
+                        type Response = super::ValidatorVoteBreakdownResponse;
+                        type ResponseStream = T::ValidatorVoteBreakdownStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorVoteBreakdownRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_vote_breakdown(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorVoteBreakdownSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
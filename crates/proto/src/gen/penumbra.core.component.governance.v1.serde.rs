@@ -3290,6 +3290,9 @@ impl serde::Serialize for Proposal {
                 proposal::Payload::UnfreezeIbcClient(v) => {
                     struct_ser.serialize_field("unfreezeIbcClient", v)?;
                 }
+                proposal::Payload::ScheduledParameterChange(v) => {
+                    struct_ser.serialize_field("scheduledParameterChange", v)?;
+                }
             }
         }
         struct_ser.end()
@@ -3317,6 +3320,8 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             "freezeIbcClient",
             "unfreeze_ibc_client",
             "unfreezeIbcClient",
+            "scheduled_parameter_change",
+            "scheduledParameterChange",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3331,6 +3336,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
             UpgradePlan,
             FreezeIbcClient,
             UnfreezeIbcClient,
+            ScheduledParameterChange,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3363,6 +3369,7 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                             "upgradePlan" | "upgrade_plan" => Ok(GeneratedField::UpgradePlan),
                             "freezeIbcClient" | "freeze_ibc_client" => Ok(GeneratedField::FreezeIbcClient),
                             "unfreezeIbcClient" | "unfreeze_ibc_client" => Ok(GeneratedField::UnfreezeIbcClient),
+                            "scheduledParameterChange" | "scheduled_parameter_change" => Ok(GeneratedField::ScheduledParameterChange),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3455,6 +3462,13 @@ impl<'de> serde::Deserialize<'de> for Proposal {
                                 return Err(serde::de::Error::duplicate_field("unfreezeIbcClient"));
                             }
                             payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::UnfreezeIbcClient)
+;
+                        }
+                        GeneratedField::ScheduledParameterChange => {
+                            if payload__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledParameterChange"));
+                            }
+                            payload__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal::Payload::ScheduledParameterChange)
 ;
                         }
                         GeneratedField::__SkipField__ => {
@@ -3909,6 +3923,145 @@ impl<'de> serde::Deserialize<'de> for proposal::ParameterChange {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ParameterChange", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for proposal::ScheduledParameterChange {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.changes.is_empty() {
+            len += 1;
+        }
+        if self.activation_height != 0 {
+            len += 1;
+        }
+        if self.activation_epoch != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Proposal.ScheduledParameterChange", len)?;
+        if !self.changes.is_empty() {
+            struct_ser.serialize_field("changes", &self.changes)?;
+        }
+        if self.activation_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("activationHeight", ToString::to_string(&self.activation_height).as_str())?;
+        }
+        if self.activation_epoch != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("activationEpoch", ToString::to_string(&self.activation_epoch).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for proposal::ScheduledParameterChange {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "changes",
+            "activation_height",
+            "activationHeight",
+            "activation_epoch",
+            "activationEpoch",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Changes,
+            ActivationHeight,
+            ActivationEpoch,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "changes" => Ok(GeneratedField::Changes),
+                            "activationHeight" | "activation_height" => Ok(GeneratedField::ActivationHeight),
+                            "activationEpoch" | "activation_epoch" => Ok(GeneratedField::ActivationEpoch),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = proposal::ScheduledParameterChange;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.Proposal.ScheduledParameterChange")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<proposal::ScheduledParameterChange, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut changes__ = None;
+                let mut activation_height__ = None;
+                let mut activation_epoch__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Changes => {
+                            if changes__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("changes"));
+                            }
+                            changes__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::ActivationHeight => {
+                            if activation_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activationHeight"));
+                            }
+                            activation_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ActivationEpoch => {
+                            if activation_epoch__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activationEpoch"));
+                            }
+                            activation_epoch__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(proposal::ScheduledParameterChange {
+                    changes: changes__.unwrap_or_default(),
+                    activation_height: activation_height__.unwrap_or_default(),
+                    activation_epoch: activation_epoch__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.Proposal.ScheduledParameterChange", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for proposal::Signaling {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -4866,6 +5019,7 @@ impl serde::Serialize for ProposalKind {
             Self::UpgradePlan => "PROPOSAL_KIND_UPGRADE_PLAN",
             Self::FreezeIbcClient => "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             Self::UnfreezeIbcClient => "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            Self::ScheduledParameterChange => "PROPOSAL_KIND_SCHEDULED_PARAMETER_CHANGE",
         };
         serializer.serialize_str(variant)
     }
@@ -4885,6 +5039,7 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
             "PROPOSAL_KIND_UPGRADE_PLAN",
             "PROPOSAL_KIND_FREEZE_IBC_CLIENT",
             "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT",
+            "PROPOSAL_KIND_SCHEDULED_PARAMETER_CHANGE",
         ];
 
         struct GeneratedVisitor;
@@ -4933,6 +5088,7 @@ impl<'de> serde::Deserialize<'de> for ProposalKind {
                     "PROPOSAL_KIND_UPGRADE_PLAN" => Ok(ProposalKind::UpgradePlan),
                     "PROPOSAL_KIND_FREEZE_IBC_CLIENT" => Ok(ProposalKind::FreezeIbcClient),
                     "PROPOSAL_KIND_UNFREEZE_IBC_CLIENT" => Ok(ProposalKind::UnfreezeIbcClient),
+                    "PROPOSAL_KIND_SCHEDULED_PARAMETER_CHANGE" => Ok(ProposalKind::ScheduledParameterChange),
                     _ => Err(serde::de::Error::unknown_variant(value, FIELDS)),
                 }
             }
@@ -5696,7 +5852,7 @@ impl<'de> serde::Deserialize<'de> for proposal_outcome::Withdrawn {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalOutcome.Withdrawn", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalRateDataRequest {
+impl serde::Serialize for ProposalProjectionRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5707,7 +5863,7 @@ impl serde::Serialize for ProposalRateDataRequest {
         if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", len)?;
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalProjectionRequest", len)?;
         if self.proposal_id != 0 {
             #[allow(clippy::needless_borrow)]
             #[allow(clippy::needless_borrows_for_generic_args)]
@@ -5716,7 +5872,7 @@ impl serde::Serialize for ProposalRateDataRequest {
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
+impl<'de> serde::Deserialize<'de> for ProposalProjectionRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
@@ -5762,13 +5918,13 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalRateDataRequest;
+            type Value = ProposalProjectionRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataRequest")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalProjectionRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataRequest, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalProjectionRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
@@ -5788,15 +5944,15 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
                         }
                     }
                 }
-                Ok(ProposalRateDataRequest {
+                Ok(ProposalProjectionRequest {
                     proposal_id: proposal_id__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalProjectionRequest", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalRateDataResponse {
+impl serde::Serialize for ProposalProjectionResponse {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5804,30 +5960,83 @@ impl serde::Serialize for ProposalRateDataResponse {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.rate_data.is_some() {
+        if self.current_tally.is_some() {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", len)?;
-        if let Some(v) = self.rate_data.as_ref() {
-            struct_ser.serialize_field("rateData", v)?;
+        if self.projected_outcome.is_some() {
+            len += 1;
+        }
+        if self.total_voting_power != 0 {
+            len += 1;
+        }
+        if self.unvoted_power != 0 {
+            len += 1;
+        }
+        if self.validator_default_power != 0 {
+            len += 1;
+        }
+        if self.votes_to_flip != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalProjectionResponse", len)?;
+        if let Some(v) = self.current_tally.as_ref() {
+            struct_ser.serialize_field("currentTally", v)?;
+        }
+        if let Some(v) = self.projected_outcome.as_ref() {
+            struct_ser.serialize_field("projectedOutcome", v)?;
+        }
+        if self.total_voting_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("totalVotingPower", ToString::to_string(&self.total_voting_power).as_str())?;
+        }
+        if self.unvoted_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("unvotedPower", ToString::to_string(&self.unvoted_power).as_str())?;
+        }
+        if self.validator_default_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("validatorDefaultPower", ToString::to_string(&self.validator_default_power).as_str())?;
+        }
+        if self.votes_to_flip != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("votesToFlip", ToString::to_string(&self.votes_to_flip).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
+impl<'de> serde::Deserialize<'de> for ProposalProjectionResponse {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "rate_data",
-            "rateData",
+            "current_tally",
+            "currentTally",
+            "projected_outcome",
+            "projectedOutcome",
+            "total_voting_power",
+            "totalVotingPower",
+            "unvoted_power",
+            "unvotedPower",
+            "validator_default_power",
+            "validatorDefaultPower",
+            "votes_to_flip",
+            "votesToFlip",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            RateData,
+            CurrentTally,
+            ProjectedOutcome,
+            TotalVotingPower,
+            UnvotedPower,
+            ValidatorDefaultPower,
+            VotesToFlip,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5850,7 +6059,12 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
                         E: serde::de::Error,
                     {
                         match value {
-                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "currentTally" | "current_tally" => Ok(GeneratedField::CurrentTally),
+                            "projectedOutcome" | "projected_outcome" => Ok(GeneratedField::ProjectedOutcome),
+                            "totalVotingPower" | "total_voting_power" => Ok(GeneratedField::TotalVotingPower),
+                            "unvotedPower" | "unvoted_power" => Ok(GeneratedField::UnvotedPower),
+                            "validatorDefaultPower" | "validator_default_power" => Ok(GeneratedField::ValidatorDefaultPower),
+                            "votesToFlip" | "votes_to_flip" => Ok(GeneratedField::VotesToFlip),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5860,39 +6074,87 @@ impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalRateDataResponse;
+            type Value = ProposalProjectionResponse;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataResponse")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalProjectionResponse")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataResponse, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalProjectionResponse, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut rate_data__ = None;
+                let mut current_tally__ = None;
+                let mut projected_outcome__ = None;
+                let mut total_voting_power__ = None;
+                let mut unvoted_power__ = None;
+                let mut validator_default_power__ = None;
+                let mut votes_to_flip__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::RateData => {
-                            if rate_data__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("rateData"));
+                        GeneratedField::CurrentTally => {
+                            if current_tally__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("currentTally"));
                             }
-                            rate_data__ = map_.next_value()?;
+                            current_tally__ = map_.next_value()?;
+                        }
+                        GeneratedField::ProjectedOutcome => {
+                            if projected_outcome__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("projectedOutcome"));
+                            }
+                            projected_outcome__ = map_.next_value()?;
+                        }
+                        GeneratedField::TotalVotingPower => {
+                            if total_voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("totalVotingPower"));
+                            }
+                            total_voting_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::UnvotedPower => {
+                            if unvoted_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unvotedPower"));
+                            }
+                            unvoted_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorDefaultPower => {
+                            if validator_default_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorDefaultPower"));
+                            }
+                            validator_default_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::VotesToFlip => {
+                            if votes_to_flip__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votesToFlip"));
+                            }
+                            votes_to_flip__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
                     }
                 }
-                Ok(ProposalRateDataResponse {
-                    rate_data: rate_data__,
+                Ok(ProposalProjectionResponse {
+                    current_tally: current_tally__,
+                    projected_outcome: projected_outcome__,
+                    total_voting_power: total_voting_power__.unwrap_or_default(),
+                    unvoted_power: unvoted_power__.unwrap_or_default(),
+                    validator_default_power: validator_default_power__.unwrap_or_default(),
+                    votes_to_flip: votes_to_flip__.unwrap_or_default(),
                 })
             }
         }
-        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", FIELDS, GeneratedVisitor)
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalProjectionResponse", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for ProposalState {
+impl serde::Serialize for ProposalRateDataRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
@@ -5900,48 +6162,32 @@ impl serde::Serialize for ProposalState {
     {
         use serde::ser::SerializeStruct;
         let mut len = 0;
-        if self.state.is_some() {
+        if self.proposal_id != 0 {
             len += 1;
         }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState", len)?;
-        if let Some(v) = self.state.as_ref() {
-            match v {
-                proposal_state::State::Voting(v) => {
-                    struct_ser.serialize_field("voting", v)?;
-                }
-                proposal_state::State::Withdrawn(v) => {
-                    struct_ser.serialize_field("withdrawn", v)?;
-                }
-                proposal_state::State::Finished(v) => {
-                    struct_ser.serialize_field("finished", v)?;
-                }
-                proposal_state::State::Claimed(v) => {
-                    struct_ser.serialize_field("claimed", v)?;
-                }
-            }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
         }
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for ProposalState {
+impl<'de> serde::Deserialize<'de> for ProposalRateDataRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "voting",
-            "withdrawn",
-            "finished",
-            "claimed",
+            "proposal_id",
+            "proposalId",
         ];
 
         #[allow(clippy::enum_variant_names)]
         enum GeneratedField {
-            Voting,
-            Withdrawn,
-            Finished,
-            Claimed,
+            ProposalId,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -5964,10 +6210,7 @@ impl<'de> serde::Deserialize<'de> for ProposalState {
                         E: serde::de::Error,
                     {
                         match value {
-                            "voting" => Ok(GeneratedField::Voting),
-                            "withdrawn" => Ok(GeneratedField::Withdrawn),
-                            "finished" => Ok(GeneratedField::Finished),
-                            "claimed" => Ok(GeneratedField::Claimed),
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -5977,39 +6220,254 @@ impl<'de> serde::Deserialize<'de> for ProposalState {
         }
         struct GeneratedVisitor;
         impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
-            type Value = ProposalState;
+            type Value = ProposalRateDataRequest;
 
             fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState")
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataRequest")
             }
 
-            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalState, V::Error>
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataRequest, V::Error>
                 where
                     V: serde::de::MapAccess<'de>,
             {
-                let mut state__ = None;
+                let mut proposal_id__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
-                        GeneratedField::Voting => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("voting"));
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
                             }
-                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Voting)
-;
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
                         }
-                        GeneratedField::Withdrawn => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("withdrawn"));
-                            }
-                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Withdrawn)
-;
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
-                        GeneratedField::Finished => {
-                            if state__.is_some() {
-                                return Err(serde::de::Error::duplicate_field("finished"));
-                            }
-                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Finished)
-;
+                    }
+                }
+                Ok(ProposalRateDataRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalRateDataResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", len)?;
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalRateDataResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "rate_data",
+            "rateData",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            RateData,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalRateDataResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalRateDataResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalRateDataResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut rate_data__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ProposalRateDataResponse {
+                    rate_data: rate_data__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ProposalRateDataResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ProposalState {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.state.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ProposalState", len)?;
+        if let Some(v) = self.state.as_ref() {
+            match v {
+                proposal_state::State::Voting(v) => {
+                    struct_ser.serialize_field("voting", v)?;
+                }
+                proposal_state::State::Withdrawn(v) => {
+                    struct_ser.serialize_field("withdrawn", v)?;
+                }
+                proposal_state::State::Finished(v) => {
+                    struct_ser.serialize_field("finished", v)?;
+                }
+                proposal_state::State::Claimed(v) => {
+                    struct_ser.serialize_field("claimed", v)?;
+                }
+            }
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ProposalState {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "voting",
+            "withdrawn",
+            "finished",
+            "claimed",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Voting,
+            Withdrawn,
+            Finished,
+            Claimed,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "voting" => Ok(GeneratedField::Voting),
+                            "withdrawn" => Ok(GeneratedField::Withdrawn),
+                            "finished" => Ok(GeneratedField::Finished),
+                            "claimed" => Ok(GeneratedField::Claimed),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ProposalState;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ProposalState")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ProposalState, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut state__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Voting => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("voting"));
+                            }
+                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Voting)
+;
+                        }
+                        GeneratedField::Withdrawn => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawn"));
+                            }
+                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Withdrawn)
+;
+                        }
+                        GeneratedField::Finished => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("finished"));
+                            }
+                            state__ = map_.next_value::<::std::option::Option<_>>()?.map(proposal_state::State::Finished)
+;
                         }
                         GeneratedField::Claimed => {
                             if state__.is_some() {
@@ -6737,52 +7195,266 @@ impl<'de> serde::Deserialize<'de> for Ratio {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.Ratio", FIELDS, GeneratedVisitor)
     }
 }
-impl serde::Serialize for Tally {
+impl serde::Serialize for ScheduledParameterChangesRequest {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
         use serde::ser::SerializeStruct;
-        let mut len = 0;
-        if self.yes != 0 {
-            len += 1;
-        }
-        if self.no != 0 {
-            len += 1;
-        }
-        if self.abstain != 0 {
-            len += 1;
-        }
-        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Tally", len)?;
-        if self.yes != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("yes", ToString::to_string(&self.yes).as_str())?;
-        }
-        if self.no != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("no", ToString::to_string(&self.no).as_str())?;
-        }
-        if self.abstain != 0 {
-            #[allow(clippy::needless_borrow)]
-            #[allow(clippy::needless_borrows_for_generic_args)]
-            struct_ser.serialize_field("abstain", ToString::to_string(&self.abstain).as_str())?;
-        }
+        let len = 0;
+        let struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ScheduledParameterChangesRequest", len)?;
         struct_ser.end()
     }
 }
-impl<'de> serde::Deserialize<'de> for Tally {
+impl<'de> serde::Deserialize<'de> for ScheduledParameterChangesRequest {
     #[allow(deprecated)]
     fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
     {
         const FIELDS: &[&str] = &[
-            "yes",
-            "no",
-            "abstain",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledParameterChangesRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ScheduledParameterChangesRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledParameterChangesRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                while map_.next_key::<GeneratedField>()?.is_some() {
+                    let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                }
+                Ok(ScheduledParameterChangesRequest {
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ScheduledParameterChangesRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ScheduledParameterChangesResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        if self.scheduled_change.is_some() {
+            len += 1;
+        }
+        if self.activation_height != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ScheduledParameterChangesResponse", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        if let Some(v) = self.scheduled_change.as_ref() {
+            struct_ser.serialize_field("scheduledChange", v)?;
+        }
+        if self.activation_height != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("activationHeight", ToString::to_string(&self.activation_height).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ScheduledParameterChangesResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+            "scheduled_change",
+            "scheduledChange",
+            "activation_height",
+            "activationHeight",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            ScheduledChange,
+            ActivationHeight,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            "scheduledChange" | "scheduled_change" => Ok(GeneratedField::ScheduledChange),
+                            "activationHeight" | "activation_height" => Ok(GeneratedField::ActivationHeight),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ScheduledParameterChangesResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ScheduledParameterChangesResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ScheduledParameterChangesResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                let mut scheduled_change__ = None;
+                let mut activation_height__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ScheduledChange => {
+                            if scheduled_change__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("scheduledChange"));
+                            }
+                            scheduled_change__ = map_.next_value()?;
+                        }
+                        GeneratedField::ActivationHeight => {
+                            if activation_height__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("activationHeight"));
+                            }
+                            activation_height__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ScheduledParameterChangesResponse {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                    scheduled_change: scheduled_change__,
+                    activation_height: activation_height__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ScheduledParameterChangesResponse", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for Tally {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.yes != 0 {
+            len += 1;
+        }
+        if self.no != 0 {
+            len += 1;
+        }
+        if self.abstain != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.Tally", len)?;
+        if self.yes != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("yes", ToString::to_string(&self.yes).as_str())?;
+        }
+        if self.no != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("no", ToString::to_string(&self.no).as_str())?;
+        }
+        if self.abstain != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("abstain", ToString::to_string(&self.abstain).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for Tally {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "yes",
+            "no",
+            "abstain",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -7160,6 +7832,278 @@ impl<'de> serde::Deserialize<'de> for ValidatorVoteBody {
         deserializer.deserialize_struct("penumbra.core.component.governance.v1.ValidatorVoteBody", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidatorVoteBreakdownRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.proposal_id != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ValidatorVoteBreakdownRequest", len)?;
+        if self.proposal_id != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("proposalId", ToString::to_string(&self.proposal_id).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorVoteBreakdownRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "proposal_id",
+            "proposalId",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            ProposalId,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "proposalId" | "proposal_id" => Ok(GeneratedField::ProposalId),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorVoteBreakdownRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ValidatorVoteBreakdownRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorVoteBreakdownRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut proposal_id__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::ProposalId => {
+                            if proposal_id__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("proposalId"));
+                            }
+                            proposal_id__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorVoteBreakdownRequest {
+                    proposal_id: proposal_id__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ValidatorVoteBreakdownRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorVoteBreakdownResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.voting_power != 0 {
+            len += 1;
+        }
+        if self.validator_vote.is_some() {
+            len += 1;
+        }
+        if self.delegator_tally.is_some() {
+            len += 1;
+        }
+        if self.net_tally.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.governance.v1.ValidatorVoteBreakdownResponse", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.voting_power != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("votingPower", ToString::to_string(&self.voting_power).as_str())?;
+        }
+        if let Some(v) = self.validator_vote.as_ref() {
+            struct_ser.serialize_field("validatorVote", v)?;
+        }
+        if let Some(v) = self.delegator_tally.as_ref() {
+            struct_ser.serialize_field("delegatorTally", v)?;
+        }
+        if let Some(v) = self.net_tally.as_ref() {
+            struct_ser.serialize_field("netTally", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorVoteBreakdownResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "voting_power",
+            "votingPower",
+            "validator_vote",
+            "validatorVote",
+            "delegator_tally",
+            "delegatorTally",
+            "net_tally",
+            "netTally",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            VotingPower,
+            ValidatorVote,
+            DelegatorTally,
+            NetTally,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
+                            "validatorVote" | "validator_vote" => Ok(GeneratedField::ValidatorVote),
+                            "delegatorTally" | "delegator_tally" => Ok(GeneratedField::DelegatorTally),
+                            "netTally" | "net_tally" => Ok(GeneratedField::NetTally),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorVoteBreakdownResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.governance.v1.ValidatorVoteBreakdownResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorVoteBreakdownResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut voting_power__ = None;
+                let mut validator_vote__ = None;
+                let mut delegator_tally__ = None;
+                let mut net_tally__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
+                            }
+                            voting_power__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::ValidatorVote => {
+                            if validator_vote__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("validatorVote"));
+                            }
+                            validator_vote__ = map_.next_value()?;
+                        }
+                        GeneratedField::DelegatorTally => {
+                            if delegator_tally__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("delegatorTally"));
+                            }
+                            delegator_tally__ = map_.next_value()?;
+                        }
+                        GeneratedField::NetTally => {
+                            if net_tally__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("netTally"));
+                            }
+                            net_tally__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorVoteBreakdownResponse {
+                    identity_key: identity_key__,
+                    voting_power: voting_power__.unwrap_or_default(),
+                    validator_vote: validator_vote__,
+                    delegator_tally: delegator_tally__,
+                    net_tally: net_tally__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.governance.v1.ValidatorVoteBreakdownResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatorVoteReason {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
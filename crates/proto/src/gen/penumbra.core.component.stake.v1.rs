@@ -363,6 +363,9 @@ pub struct ValidatorInfo {
     pub status: ::core::option::Option<ValidatorStatus>,
     #[prost(message, optional, tag = "3")]
     pub rate_data: ::core::option::Option<RateData>,
+    /// A commission increase announced by the validator that has not taken effect yet, if any.
+    #[prost(message, optional, tag = "4")]
+    pub pending_commission_increase: ::core::option::Option<PendingCommissionIncrease>,
 }
 impl ::prost::Name for ValidatorInfo {
     const NAME: &'static str = "ValidatorInfo";
@@ -374,6 +377,26 @@ impl ::prost::Name for ValidatorInfo {
         "/penumbra.core.component.stake.v1.ValidatorInfo".into()
     }
 }
+/// A commission increase announced by a validator, that has not taken effect yet.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PendingCommissionIncrease {
+    /// The funding streams the validator will use once the increase takes effect.
+    #[prost(message, repeated, tag = "1")]
+    pub funding_streams: ::prost::alloc::vec::Vec<FundingStream>,
+    /// The index of the epoch at the end of which the increase takes effect.
+    #[prost(uint64, tag = "2")]
+    pub effective_epoch_index: u64,
+}
+impl ::prost::Name for PendingCommissionIncrease {
+    const NAME: &'static str = "PendingCommissionIncrease";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.stake.v1.PendingCommissionIncrease".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.stake.v1.PendingCommissionIncrease".into()
+    }
+}
 /// A transaction action (re)defining a validator.
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ValidatorDefinition {
@@ -597,6 +620,48 @@ impl ::prost::Name for Uptime {
         "/penumbra.core.component.stake.v1.Uptime".into()
     }
 }
+/// A summary of a validator's performance over a single epoch, recorded when the epoch ends.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorEpochSummary {
+    /// The validator's identity key.
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The index of the summarized epoch.
+    #[prost(uint64, tag = "2")]
+    pub epoch_index: u64,
+    /// The validator's state at the end of the epoch.
+    #[prost(message, optional, tag = "3")]
+    pub state: ::core::option::Option<ValidatorState>,
+    /// The validator's rate data during the epoch, including any penalty recorded in it.
+    #[prost(message, optional, tag = "4")]
+    pub rate_data: ::core::option::Option<RateData>,
+    /// The validator's voting power during the epoch.
+    #[prost(message, optional, tag = "5")]
+    pub voting_power: ::core::option::Option<super::super::super::num::v1::Amount>,
+    /// The total rate of the validator's funding streams during the epoch, in basis points.
+    #[prost(uint32, tag = "6")]
+    pub commission_bps: u32,
+    /// The number of blocks of the epoch that the validator failed to sign.
+    #[prost(uint64, tag = "7")]
+    pub missed_blocks: u64,
+    /// The number of blocks of the epoch covered by the validator's uptime window.
+    ///
+    /// This is less than the length of the epoch if the epoch is longer than the
+    /// signed blocks window, in which case `missed_blocks` only accounts for the
+    /// blocks at the end of the epoch.
+    #[prost(uint64, tag = "8")]
+    pub tracked_blocks: u64,
+}
+impl ::prost::Name for ValidatorEpochSummary {
+    const NAME: &'static str = "ValidatorEpochSummary";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.stake.v1.ValidatorEpochSummary".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.stake.v1.ValidatorEpochSummary".into()
+    }
+}
 /// Tracks our view of Tendermint's view of the validator set, so we can keep it
 /// from getting confused.
 #[derive(Clone, PartialEq, ::prost::Message)]
@@ -821,6 +886,45 @@ impl ::prost::Name for ValidatorUptimeResponse {
         "/penumbra.core.component.stake.v1.ValidatorUptimeResponse".into()
     }
 }
+/// Requests the per-epoch performance summaries of a validator.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorHistoryRequest {
+    #[prost(message, optional, tag = "1")]
+    pub identity_key: ::core::option::Option<super::super::super::keys::v1::IdentityKey>,
+    /// The index of the first epoch to return.
+    #[prost(uint64, tag = "2")]
+    pub start_epoch_index: u64,
+    /// The index of the last epoch to return, inclusive.
+    ///
+    /// If unset, summaries up to the latest completed epoch are returned.
+    #[prost(uint64, optional, tag = "3")]
+    pub end_epoch_index: ::core::option::Option<u64>,
+}
+impl ::prost::Name for ValidatorHistoryRequest {
+    const NAME: &'static str = "ValidatorHistoryRequest";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.stake.v1.ValidatorHistoryRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.stake.v1.ValidatorHistoryRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ValidatorHistoryResponse {
+    #[prost(message, optional, tag = "1")]
+    pub summary: ::core::option::Option<ValidatorEpochSummary>,
+}
+impl ::prost::Name for ValidatorHistoryResponse {
+    const NAME: &'static str = "ValidatorHistoryResponse";
+    const PACKAGE: &'static str = "penumbra.core.component.stake.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.core.component.stake.v1.ValidatorHistoryResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.core.component.stake.v1.ValidatorHistoryResponse".into()
+    }
+}
 /// Staking configuration data.
 #[derive(Clone, Copy, PartialEq, ::prost::Message)]
 pub struct StakeParameters {
@@ -855,6 +959,13 @@ pub struct StakeParameters {
     /// The number of blocks that must elapse before an unbonding note can be claimed.
     #[prost(uint64, tag = "9")]
     pub unbonding_delay: u64,
+    /// The maximum increase of a validator's total commission that can take effect in a single epoch,
+    /// in basis points. If zero, commission increases are not bounded.
+    #[prost(uint64, tag = "10")]
+    pub max_commission_increase_bps: u64,
+    /// The number of epochs a commission increase must be announced for before it takes effect.
+    #[prost(uint64, tag = "11")]
+    pub commission_increase_delay_epochs: u64,
 }
 impl ::prost::Name for StakeParameters {
     const NAME: &'static str = "StakeParameters";
@@ -1352,6 +1463,36 @@ pub mod query_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        /// Streams the per-epoch performance summaries of a validator over a range of epochs.
+        pub async fn validator_history(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ValidatorHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::ValidatorHistoryResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorHistory",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.core.component.stake.v1.QueryService",
+                        "ValidatorHistory",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -1418,6 +1559,20 @@ pub mod query_service_server {
             tonic::Response<super::ValidatorUptimeResponse>,
             tonic::Status,
         >;
+        /// Server streaming response type for the ValidatorHistory method.
+        type ValidatorHistoryStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::ValidatorHistoryResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Streams the per-epoch performance summaries of a validator over a range of epochs.
+        async fn validator_history(
+            &self,
+            request: tonic::Request<super::ValidatorHistoryRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::ValidatorHistoryStream>,
+            tonic::Status,
+        >;
     }
     /// Query operations for the staking component.
     #[derive(Debug)]
@@ -1770,6 +1925,52 @@ pub mod query_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.core.component.stake.v1.QueryService/ValidatorHistory" => {
+                    #[allow(non_camel_case_types)]
+                    struct ValidatorHistorySvc<T: QueryService>(pub Arc<T>);
+                    impl<
+                        T: QueryService,
+                    > tonic::server::ServerStreamingService<super::ValidatorHistoryRequest>
+                    for ValidatorHistorySvc<T> {
+                        type Response = super::ValidatorHistoryResponse;
+                        type ResponseStream = T::ValidatorHistoryStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ValidatorHistoryRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as QueryService>::validator_history(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ValidatorHistorySvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
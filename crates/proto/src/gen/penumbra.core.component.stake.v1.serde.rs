@@ -2828,6 +2828,124 @@ impl<'de> serde::Deserialize<'de> for Penalty {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.Penalty", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PendingCommissionIncrease {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.funding_streams.is_empty() {
+            len += 1;
+        }
+        if self.effective_epoch_index != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.PendingCommissionIncrease", len)?;
+        if !self.funding_streams.is_empty() {
+            struct_ser.serialize_field("fundingStreams", &self.funding_streams)?;
+        }
+        if self.effective_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("effectiveEpochIndex", ToString::to_string(&self.effective_epoch_index).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PendingCommissionIncrease {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "funding_streams",
+            "fundingStreams",
+            "effective_epoch_index",
+            "effectiveEpochIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            FundingStreams,
+            EffectiveEpochIndex,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "fundingStreams" | "funding_streams" => Ok(GeneratedField::FundingStreams),
+                            "effectiveEpochIndex" | "effective_epoch_index" => Ok(GeneratedField::EffectiveEpochIndex),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PendingCommissionIncrease;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.PendingCommissionIncrease")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PendingCommissionIncrease, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut funding_streams__ = None;
+                let mut effective_epoch_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::FundingStreams => {
+                            if funding_streams__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fundingStreams"));
+                            }
+                            funding_streams__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::EffectiveEpochIndex => {
+                            if effective_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("effectiveEpochIndex"));
+                            }
+                            effective_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PendingCommissionIncrease {
+                    funding_streams: funding_streams__.unwrap_or_default(),
+                    effective_epoch_index: effective_epoch_index__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.PendingCommissionIncrease", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for RateData {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -3017,6 +3135,12 @@ impl serde::Serialize for StakeParameters {
         if self.unbonding_delay != 0 {
             len += 1;
         }
+        if self.max_commission_increase_bps != 0 {
+            len += 1;
+        }
+        if self.commission_increase_delay_epochs != 0 {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.StakeParameters", len)?;
         if self.unbonding_epochs != 0 {
             #[allow(clippy::needless_borrow)]
@@ -3061,6 +3185,16 @@ impl serde::Serialize for StakeParameters {
             #[allow(clippy::needless_borrows_for_generic_args)]
             struct_ser.serialize_field("unbondingDelay", ToString::to_string(&self.unbonding_delay).as_str())?;
         }
+        if self.max_commission_increase_bps != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("maxCommissionIncreaseBps", ToString::to_string(&self.max_commission_increase_bps).as_str())?;
+        }
+        if self.commission_increase_delay_epochs != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("commissionIncreaseDelayEpochs", ToString::to_string(&self.commission_increase_delay_epochs).as_str())?;
+        }
         struct_ser.end()
     }
 }
@@ -3089,6 +3223,10 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
             "minValidatorStake",
             "unbonding_delay",
             "unbondingDelay",
+            "max_commission_increase_bps",
+            "maxCommissionIncreaseBps",
+            "commission_increase_delay_epochs",
+            "commissionIncreaseDelayEpochs",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -3102,6 +3240,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
             MissedBlocksMaximum,
             MinValidatorStake,
             UnbondingDelay,
+            MaxCommissionIncreaseBps,
+            CommissionIncreaseDelayEpochs,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -3133,6 +3273,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                             "missedBlocksMaximum" | "missed_blocks_maximum" => Ok(GeneratedField::MissedBlocksMaximum),
                             "minValidatorStake" | "min_validator_stake" => Ok(GeneratedField::MinValidatorStake),
                             "unbondingDelay" | "unbonding_delay" => Ok(GeneratedField::UnbondingDelay),
+                            "maxCommissionIncreaseBps" | "max_commission_increase_bps" => Ok(GeneratedField::MaxCommissionIncreaseBps),
+                            "commissionIncreaseDelayEpochs" | "commission_increase_delay_epochs" => Ok(GeneratedField::CommissionIncreaseDelayEpochs),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -3161,6 +3303,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                 let mut missed_blocks_maximum__ = None;
                 let mut min_validator_stake__ = None;
                 let mut unbonding_delay__ = None;
+                let mut max_commission_increase_bps__ = None;
+                let mut commission_increase_delay_epochs__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::UnbondingEpochs => {
@@ -3233,6 +3377,22 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                                 Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
                             ;
                         }
+                        GeneratedField::MaxCommissionIncreaseBps => {
+                            if max_commission_increase_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("maxCommissionIncreaseBps"));
+                            }
+                            max_commission_increase_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::CommissionIncreaseDelayEpochs => {
+                            if commission_increase_delay_epochs__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commissionIncreaseDelayEpochs"));
+                            }
+                            commission_increase_delay_epochs__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -3248,6 +3408,8 @@ impl<'de> serde::Deserialize<'de> for StakeParameters {
                     missed_blocks_maximum: missed_blocks_maximum__.unwrap_or_default(),
                     min_validator_stake: min_validator_stake__,
                     unbonding_delay: unbonding_delay__.unwrap_or_default(),
+                    max_commission_increase_bps: max_commission_increase_bps__.unwrap_or_default(),
+                    commission_increase_delay_epochs: commission_increase_delay_epochs__.unwrap_or_default(),
                 })
             }
         }
@@ -4458,6 +4620,476 @@ impl<'de> serde::Deserialize<'de> for ValidatorDefinition {
         deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorDefinition", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for ValidatorEpochSummary {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.epoch_index != 0 {
+            len += 1;
+        }
+        if self.state.is_some() {
+            len += 1;
+        }
+        if self.rate_data.is_some() {
+            len += 1;
+        }
+        if self.voting_power.is_some() {
+            len += 1;
+        }
+        if self.commission_bps != 0 {
+            len += 1;
+        }
+        if self.missed_blocks != 0 {
+            len += 1;
+        }
+        if self.tracked_blocks != 0 {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorEpochSummary", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("epochIndex", ToString::to_string(&self.epoch_index).as_str())?;
+        }
+        if let Some(v) = self.state.as_ref() {
+            struct_ser.serialize_field("state", v)?;
+        }
+        if let Some(v) = self.rate_data.as_ref() {
+            struct_ser.serialize_field("rateData", v)?;
+        }
+        if let Some(v) = self.voting_power.as_ref() {
+            struct_ser.serialize_field("votingPower", v)?;
+        }
+        if self.commission_bps != 0 {
+            struct_ser.serialize_field("commissionBps", &self.commission_bps)?;
+        }
+        if self.missed_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("missedBlocks", ToString::to_string(&self.missed_blocks).as_str())?;
+        }
+        if self.tracked_blocks != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("trackedBlocks", ToString::to_string(&self.tracked_blocks).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorEpochSummary {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "epoch_index",
+            "epochIndex",
+            "state",
+            "rate_data",
+            "rateData",
+            "voting_power",
+            "votingPower",
+            "commission_bps",
+            "commissionBps",
+            "missed_blocks",
+            "missedBlocks",
+            "tracked_blocks",
+            "trackedBlocks",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            EpochIndex,
+            State,
+            RateData,
+            VotingPower,
+            CommissionBps,
+            MissedBlocks,
+            TrackedBlocks,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "epochIndex" | "epoch_index" => Ok(GeneratedField::EpochIndex),
+                            "state" => Ok(GeneratedField::State),
+                            "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "votingPower" | "voting_power" => Ok(GeneratedField::VotingPower),
+                            "commissionBps" | "commission_bps" => Ok(GeneratedField::CommissionBps),
+                            "missedBlocks" | "missed_blocks" => Ok(GeneratedField::MissedBlocks),
+                            "trackedBlocks" | "tracked_blocks" => Ok(GeneratedField::TrackedBlocks),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorEpochSummary;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorEpochSummary")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorEpochSummary, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut epoch_index__ = None;
+                let mut state__ = None;
+                let mut rate_data__ = None;
+                let mut voting_power__ = None;
+                let mut commission_bps__ = None;
+                let mut missed_blocks__ = None;
+                let mut tracked_blocks__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::EpochIndex => {
+                            if epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("epochIndex"));
+                            }
+                            epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::State => {
+                            if state__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("state"));
+                            }
+                            state__ = map_.next_value()?;
+                        }
+                        GeneratedField::RateData => {
+                            if rate_data__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rateData"));
+                            }
+                            rate_data__ = map_.next_value()?;
+                        }
+                        GeneratedField::VotingPower => {
+                            if voting_power__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("votingPower"));
+                            }
+                            voting_power__ = map_.next_value()?;
+                        }
+                        GeneratedField::CommissionBps => {
+                            if commission_bps__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("commissionBps"));
+                            }
+                            commission_bps__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::MissedBlocks => {
+                            if missed_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("missedBlocks"));
+                            }
+                            missed_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::TrackedBlocks => {
+                            if tracked_blocks__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("trackedBlocks"));
+                            }
+                            tracked_blocks__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorEpochSummary {
+                    identity_key: identity_key__,
+                    epoch_index: epoch_index__.unwrap_or_default(),
+                    state: state__,
+                    rate_data: rate_data__,
+                    voting_power: voting_power__,
+                    commission_bps: commission_bps__.unwrap_or_default(),
+                    missed_blocks: missed_blocks__.unwrap_or_default(),
+                    tracked_blocks: tracked_blocks__.unwrap_or_default(),
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorEpochSummary", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorHistoryRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.identity_key.is_some() {
+            len += 1;
+        }
+        if self.start_epoch_index != 0 {
+            len += 1;
+        }
+        if self.end_epoch_index.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorHistoryRequest", len)?;
+        if let Some(v) = self.identity_key.as_ref() {
+            struct_ser.serialize_field("identityKey", v)?;
+        }
+        if self.start_epoch_index != 0 {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("startEpochIndex", ToString::to_string(&self.start_epoch_index).as_str())?;
+        }
+        if let Some(v) = self.end_epoch_index.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("endEpochIndex", ToString::to_string(&v).as_str())?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorHistoryRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "identity_key",
+            "identityKey",
+            "start_epoch_index",
+            "startEpochIndex",
+            "end_epoch_index",
+            "endEpochIndex",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            IdentityKey,
+            StartEpochIndex,
+            EndEpochIndex,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "identityKey" | "identity_key" => Ok(GeneratedField::IdentityKey),
+                            "startEpochIndex" | "start_epoch_index" => Ok(GeneratedField::StartEpochIndex),
+                            "endEpochIndex" | "end_epoch_index" => Ok(GeneratedField::EndEpochIndex),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorHistoryRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorHistoryRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorHistoryRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut identity_key__ = None;
+                let mut start_epoch_index__ = None;
+                let mut end_epoch_index__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::IdentityKey => {
+                            if identity_key__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("identityKey"));
+                            }
+                            identity_key__ = map_.next_value()?;
+                        }
+                        GeneratedField::StartEpochIndex => {
+                            if start_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("startEpochIndex"));
+                            }
+                            start_epoch_index__ = 
+                                Some(map_.next_value::<::pbjson::private::NumberDeserialize<_>>()?.0)
+                            ;
+                        }
+                        GeneratedField::EndEpochIndex => {
+                            if end_epoch_index__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("endEpochIndex"));
+                            }
+                            end_epoch_index__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorHistoryRequest {
+                    identity_key: identity_key__,
+                    start_epoch_index: start_epoch_index__.unwrap_or_default(),
+                    end_epoch_index: end_epoch_index__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorHistoryRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for ValidatorHistoryResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.summary.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorHistoryResponse", len)?;
+        if let Some(v) = self.summary.as_ref() {
+            struct_ser.serialize_field("summary", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for ValidatorHistoryResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "summary",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Summary,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "summary" => Ok(GeneratedField::Summary),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = ValidatorHistoryResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.core.component.stake.v1.ValidatorHistoryResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<ValidatorHistoryResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut summary__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Summary => {
+                            if summary__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("summary"));
+                            }
+                            summary__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(ValidatorHistoryResponse {
+                    summary: summary__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.core.component.stake.v1.ValidatorHistoryResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for ValidatorInfo {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
@@ -4475,6 +5107,9 @@ impl serde::Serialize for ValidatorInfo {
         if self.rate_data.is_some() {
             len += 1;
         }
+        if self.pending_commission_increase.is_some() {
+            len += 1;
+        }
         let mut struct_ser = serializer.serialize_struct("penumbra.core.component.stake.v1.ValidatorInfo", len)?;
         if let Some(v) = self.validator.as_ref() {
             struct_ser.serialize_field("validator", v)?;
@@ -4485,6 +5120,9 @@ impl serde::Serialize for ValidatorInfo {
         if let Some(v) = self.rate_data.as_ref() {
             struct_ser.serialize_field("rateData", v)?;
         }
+        if let Some(v) = self.pending_commission_increase.as_ref() {
+            struct_ser.serialize_field("pendingCommissionIncrease", v)?;
+        }
         struct_ser.end()
     }
 }
@@ -4499,6 +5137,8 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
             "status",
             "rate_data",
             "rateData",
+            "pending_commission_increase",
+            "pendingCommissionIncrease",
         ];
 
         #[allow(clippy::enum_variant_names)]
@@ -4506,6 +5146,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
             Validator,
             Status,
             RateData,
+            PendingCommissionIncrease,
             __SkipField__,
         }
         impl<'de> serde::Deserialize<'de> for GeneratedField {
@@ -4531,6 +5172,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                             "validator" => Ok(GeneratedField::Validator),
                             "status" => Ok(GeneratedField::Status),
                             "rateData" | "rate_data" => Ok(GeneratedField::RateData),
+                            "pendingCommissionIncrease" | "pending_commission_increase" => Ok(GeneratedField::PendingCommissionIncrease),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
                     }
@@ -4553,6 +5195,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                 let mut validator__ = None;
                 let mut status__ = None;
                 let mut rate_data__ = None;
+                let mut pending_commission_increase__ = None;
                 while let Some(k) = map_.next_key()? {
                     match k {
                         GeneratedField::Validator => {
@@ -4573,6 +5216,12 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                             }
                             rate_data__ = map_.next_value()?;
                         }
+                        GeneratedField::PendingCommissionIncrease => {
+                            if pending_commission_increase__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pendingCommissionIncrease"));
+                            }
+                            pending_commission_increase__ = map_.next_value()?;
+                        }
                         GeneratedField::__SkipField__ => {
                             let _ = map_.next_value::<serde::de::IgnoredAny>()?;
                         }
@@ -4582,6 +5231,7 @@ impl<'de> serde::Deserialize<'de> for ValidatorInfo {
                     validator: validator__,
                     status: status__,
                     rate_data: rate_data__,
+                    pending_commission_increase: pending_commission_increase__,
                 })
             }
         }
@@ -139,7 +139,7 @@ impl ::prost::Name for DetectionData {
 pub struct Action {
     #[prost(
         oneof = "action::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55, 70, 200"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 30, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55, 56, 57, 58, 70, 200"
     )]
     pub action: ::core::option::Option<action::Action>,
 }
@@ -221,6 +221,19 @@ pub mod action {
         ActionDutchAuctionWithdraw(
             super::super::super::component::auction::v1::ActionDutchAuctionWithdraw,
         ),
+        /// TWAP auctions
+        #[prost(message, tag = "56")]
+        ActionTwapAuctionSchedule(
+            super::super::super::component::auction::v1::ActionTwapAuctionSchedule,
+        ),
+        #[prost(message, tag = "57")]
+        ActionTwapAuctionEnd(
+            super::super::super::component::auction::v1::ActionTwapAuctionEnd,
+        ),
+        #[prost(message, tag = "58")]
+        ActionTwapAuctionWithdraw(
+            super::super::super::component::auction::v1::ActionTwapAuctionWithdraw,
+        ),
         /// Funding
         #[prost(message, tag = "70")]
         ActionLiquidityTournamentVote(
@@ -471,7 +484,7 @@ impl ::prost::Name for TransactionBodyView {
 pub struct ActionView {
     #[prost(
         oneof = "action_view::ActionView",
-        tags = "1, 2, 3, 4, 21, 35, 16, 17, 18, 19, 20, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 53, 54, 55, 43, 70, 200"
+        tags = "1, 2, 3, 4, 21, 35, 16, 17, 18, 19, 20, 22, 30, 31, 32, 34, 41, 42, 50, 51, 52, 53, 54, 55, 56, 57, 58, 43, 70, 200"
     )]
     pub action_view: ::core::option::Option<action_view::ActionView>,
 }
@@ -553,6 +566,19 @@ pub mod action_view {
         ActionDutchAuctionWithdraw(
             super::super::super::component::auction::v1::ActionDutchAuctionWithdrawView,
         ),
+        /// TWAP auctions
+        #[prost(message, tag = "56")]
+        ActionTwapAuctionSchedule(
+            super::super::super::component::auction::v1::ActionTwapAuctionScheduleView,
+        ),
+        #[prost(message, tag = "57")]
+        ActionTwapAuctionEnd(
+            super::super::super::component::auction::v1::ActionTwapAuctionEnd,
+        ),
+        #[prost(message, tag = "58")]
+        ActionTwapAuctionWithdraw(
+            super::super::super::component::auction::v1::ActionTwapAuctionWithdrawView,
+        ),
         /// TODO: we have no way to recover the opening of the undelegate_claim's
         /// balance commitment, and can only infer the value from looking at the rest
         /// of the transaction. is that fine?
@@ -686,7 +712,7 @@ impl ::prost::Name for DetectionDataPlan {
 pub struct ActionPlan {
     #[prost(
         oneof = "action_plan::Action",
-        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 35, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55, 70"
+        tags = "1, 2, 3, 4, 16, 17, 18, 19, 20, 21, 22, 200, 30, 35, 31, 32, 34, 40, 41, 42, 50, 51, 52, 53, 54, 55, 56, 57, 58, 70"
     )]
     pub action: ::core::option::Option<action_plan::Action>,
 }
@@ -776,6 +802,19 @@ pub mod action_plan {
         ActionDutchAuctionWithdraw(
             super::super::super::component::auction::v1::ActionDutchAuctionWithdrawPlan,
         ),
+        /// TWAP auctions
+        #[prost(message, tag = "56")]
+        ActionTwapAuctionSchedule(
+            super::super::super::component::auction::v1::ActionTwapAuctionSchedule,
+        ),
+        #[prost(message, tag = "57")]
+        ActionTwapAuctionEnd(
+            super::super::super::component::auction::v1::ActionTwapAuctionEnd,
+        ),
+        #[prost(message, tag = "58")]
+        ActionTwapAuctionWithdraw(
+            super::super::super::component::auction::v1::ActionTwapAuctionWithdrawPlan,
+        ),
         /// Funding
         #[prost(message, tag = "70")]
         ActionLiquidityTournamentVote(
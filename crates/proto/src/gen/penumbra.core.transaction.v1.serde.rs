@@ -84,6 +84,15 @@ impl serde::Serialize for Action {
                 action::Action::ActionDutchAuctionWithdraw(v) => {
                     struct_ser.serialize_field("actionDutchAuctionWithdraw", v)?;
                 }
+                action::Action::ActionTwapAuctionSchedule(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionSchedule", v)?;
+                }
+                action::Action::ActionTwapAuctionEnd(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionEnd", v)?;
+                }
+                action::Action::ActionTwapAuctionWithdraw(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionWithdraw", v)?;
+                }
                 action::Action::ActionLiquidityTournamentVote(v) => {
                     struct_ser.serialize_field("actionLiquidityTournamentVote", v)?;
                 }
@@ -145,6 +154,12 @@ impl<'de> serde::Deserialize<'de> for Action {
             "actionDutchAuctionEnd",
             "action_dutch_auction_withdraw",
             "actionDutchAuctionWithdraw",
+            "action_twap_auction_schedule",
+            "actionTwapAuctionSchedule",
+            "action_twap_auction_end",
+            "actionTwapAuctionEnd",
+            "action_twap_auction_withdraw",
+            "actionTwapAuctionWithdraw",
             "action_liquidity_tournament_vote",
             "actionLiquidityTournamentVote",
             "ics20_withdrawal",
@@ -177,6 +192,9 @@ impl<'de> serde::Deserialize<'de> for Action {
             ActionDutchAuctionSchedule,
             ActionDutchAuctionEnd,
             ActionDutchAuctionWithdraw,
+            ActionTwapAuctionSchedule,
+            ActionTwapAuctionEnd,
+            ActionTwapAuctionWithdraw,
             ActionLiquidityTournamentVote,
             Ics20Withdrawal,
             __SkipField__,
@@ -225,6 +243,9 @@ impl<'de> serde::Deserialize<'de> for Action {
                             "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
                             "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
+                            "actionTwapAuctionSchedule" | "action_twap_auction_schedule" => Ok(GeneratedField::ActionTwapAuctionSchedule),
+                            "actionTwapAuctionEnd" | "action_twap_auction_end" => Ok(GeneratedField::ActionTwapAuctionEnd),
+                            "actionTwapAuctionWithdraw" | "action_twap_auction_withdraw" => Ok(GeneratedField::ActionTwapAuctionWithdraw),
                             "actionLiquidityTournamentVote" | "action_liquidity_tournament_vote" => Ok(GeneratedField::ActionLiquidityTournamentVote),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
                             _ => Ok(GeneratedField::__SkipField__),
@@ -415,6 +436,27 @@ impl<'de> serde::Deserialize<'de> for Action {
                                 return Err(serde::de::Error::duplicate_field("actionDutchAuctionWithdraw"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ActionDutchAuctionWithdraw)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionSchedule => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionSchedule"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ActionTwapAuctionSchedule)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionEnd => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionEnd"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ActionTwapAuctionEnd)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionWithdraw => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionWithdraw"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action::Action::ActionTwapAuctionWithdraw)
 ;
                         }
                         GeneratedField::ActionLiquidityTournamentVote => {
@@ -536,6 +578,15 @@ impl serde::Serialize for ActionPlan {
                 action_plan::Action::ActionDutchAuctionWithdraw(v) => {
                     struct_ser.serialize_field("actionDutchAuctionWithdraw", v)?;
                 }
+                action_plan::Action::ActionTwapAuctionSchedule(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionSchedule", v)?;
+                }
+                action_plan::Action::ActionTwapAuctionEnd(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionEnd", v)?;
+                }
+                action_plan::Action::ActionTwapAuctionWithdraw(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionWithdraw", v)?;
+                }
                 action_plan::Action::ActionLiquidityTournamentVote(v) => {
                     struct_ser.serialize_field("actionLiquidityTournamentVote", v)?;
                 }
@@ -598,6 +649,12 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             "actionDutchAuctionEnd",
             "action_dutch_auction_withdraw",
             "actionDutchAuctionWithdraw",
+            "action_twap_auction_schedule",
+            "actionTwapAuctionSchedule",
+            "action_twap_auction_end",
+            "actionTwapAuctionEnd",
+            "action_twap_auction_withdraw",
+            "actionTwapAuctionWithdraw",
             "action_liquidity_tournament_vote",
             "actionLiquidityTournamentVote",
         ];
@@ -630,6 +687,9 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
             ActionDutchAuctionSchedule,
             ActionDutchAuctionEnd,
             ActionDutchAuctionWithdraw,
+            ActionTwapAuctionSchedule,
+            ActionTwapAuctionEnd,
+            ActionTwapAuctionWithdraw,
             ActionLiquidityTournamentVote,
             __SkipField__,
         }
@@ -679,6 +739,9 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                             "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
                             "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
+                            "actionTwapAuctionSchedule" | "action_twap_auction_schedule" => Ok(GeneratedField::ActionTwapAuctionSchedule),
+                            "actionTwapAuctionEnd" | "action_twap_auction_end" => Ok(GeneratedField::ActionTwapAuctionEnd),
+                            "actionTwapAuctionWithdraw" | "action_twap_auction_withdraw" => Ok(GeneratedField::ActionTwapAuctionWithdraw),
                             "actionLiquidityTournamentVote" | "action_liquidity_tournament_vote" => Ok(GeneratedField::ActionLiquidityTournamentVote),
                             _ => Ok(GeneratedField::__SkipField__),
                         }
@@ -882,6 +945,27 @@ impl<'de> serde::Deserialize<'de> for ActionPlan {
                                 return Err(serde::de::Error::duplicate_field("actionDutchAuctionWithdraw"));
                             }
                             action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ActionDutchAuctionWithdraw)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionSchedule => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionSchedule"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ActionTwapAuctionSchedule)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionEnd => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionEnd"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ActionTwapAuctionEnd)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionWithdraw => {
+                            if action__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionWithdraw"));
+                            }
+                            action__ = map_.next_value::<::std::option::Option<_>>()?.map(action_plan::Action::ActionTwapAuctionWithdraw)
 ;
                         }
                         GeneratedField::ActionLiquidityTournamentVote => {
@@ -990,6 +1074,15 @@ impl serde::Serialize for ActionView {
                 action_view::ActionView::ActionDutchAuctionWithdraw(v) => {
                     struct_ser.serialize_field("actionDutchAuctionWithdraw", v)?;
                 }
+                action_view::ActionView::ActionTwapAuctionSchedule(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionSchedule", v)?;
+                }
+                action_view::ActionView::ActionTwapAuctionEnd(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionEnd", v)?;
+                }
+                action_view::ActionView::ActionTwapAuctionWithdraw(v) => {
+                    struct_ser.serialize_field("actionTwapAuctionWithdraw", v)?;
+                }
                 action_view::ActionView::UndelegateClaim(v) => {
                     struct_ser.serialize_field("undelegateClaim", v)?;
                 }
@@ -1054,6 +1147,12 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             "actionDutchAuctionEnd",
             "action_dutch_auction_withdraw",
             "actionDutchAuctionWithdraw",
+            "action_twap_auction_schedule",
+            "actionTwapAuctionSchedule",
+            "action_twap_auction_end",
+            "actionTwapAuctionEnd",
+            "action_twap_auction_withdraw",
+            "actionTwapAuctionWithdraw",
             "undelegate_claim",
             "undelegateClaim",
             "action_liquidity_tournament_vote",
@@ -1088,6 +1187,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
             ActionDutchAuctionSchedule,
             ActionDutchAuctionEnd,
             ActionDutchAuctionWithdraw,
+            ActionTwapAuctionSchedule,
+            ActionTwapAuctionEnd,
+            ActionTwapAuctionWithdraw,
             UndelegateClaim,
             ActionLiquidityTournamentVote,
             Ics20Withdrawal,
@@ -1137,6 +1239,9 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                             "actionDutchAuctionSchedule" | "action_dutch_auction_schedule" => Ok(GeneratedField::ActionDutchAuctionSchedule),
                             "actionDutchAuctionEnd" | "action_dutch_auction_end" => Ok(GeneratedField::ActionDutchAuctionEnd),
                             "actionDutchAuctionWithdraw" | "action_dutch_auction_withdraw" => Ok(GeneratedField::ActionDutchAuctionWithdraw),
+                            "actionTwapAuctionSchedule" | "action_twap_auction_schedule" => Ok(GeneratedField::ActionTwapAuctionSchedule),
+                            "actionTwapAuctionEnd" | "action_twap_auction_end" => Ok(GeneratedField::ActionTwapAuctionEnd),
+                            "actionTwapAuctionWithdraw" | "action_twap_auction_withdraw" => Ok(GeneratedField::ActionTwapAuctionWithdraw),
                             "undelegateClaim" | "undelegate_claim" => Ok(GeneratedField::UndelegateClaim),
                             "actionLiquidityTournamentVote" | "action_liquidity_tournament_vote" => Ok(GeneratedField::ActionLiquidityTournamentVote),
                             "ics20Withdrawal" | "ics20_withdrawal" => Ok(GeneratedField::Ics20Withdrawal),
@@ -1328,6 +1433,27 @@ impl<'de> serde::Deserialize<'de> for ActionView {
                                 return Err(serde::de::Error::duplicate_field("actionDutchAuctionWithdraw"));
                             }
                             action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ActionDutchAuctionWithdraw)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionSchedule => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionSchedule"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ActionTwapAuctionSchedule)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionEnd => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionEnd"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ActionTwapAuctionEnd)
+;
+                        }
+                        GeneratedField::ActionTwapAuctionWithdraw => {
+                            if action_view__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("actionTwapAuctionWithdraw"));
+                            }
+                            action_view__ = map_.next_value::<::std::option::Option<_>>()?.map(action_view::ActionView::ActionTwapAuctionWithdraw)
 ;
                         }
                         GeneratedField::UndelegateClaim => {
@@ -2303,6 +2303,106 @@ impl ::prost::Name for LpStrategyCatalogResponse {
         "/penumbra.view.v1.LpStrategyCatalogResponse".into()
     }
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionPnlRequest {
+    /// The positions to report on. If empty, all owned positions are reported on.
+    #[prost(message, repeated, tag = "1")]
+    pub position_ids: ::prost::alloc::vec::Vec<super::super::core::component::dex::v1::PositionId>,
+    /// Filter by subaccount index.
+    #[prost(message, optional, tag = "2")]
+    pub subaccount: ::core::option::Option<super::super::core::keys::v1::AddressIndex>,
+    /// The asset to value positions in.
+    #[prost(message, optional, tag = "3")]
+    pub numeraire: ::core::option::Option<super::super::core::asset::v1::AssetId>,
+}
+impl ::prost::Name for PositionPnlRequest {
+    const NAME: &'static str = "PositionPnlRequest";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.view.v1.PositionPnlRequest".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.view.v1.PositionPnlRequest".into()
+    }
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionPnlResponse {
+    #[prost(message, optional, tag = "1")]
+    pub pnl: ::core::option::Option<PositionPnl>,
+}
+impl ::prost::Name for PositionPnlResponse {
+    const NAME: &'static str = "PositionPnlResponse";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.view.v1.PositionPnlResponse".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.view.v1.PositionPnlResponse".into()
+    }
+}
+/// The accounting of a liquidity position controlled by the user's wallet.
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct PositionPnl {
+    /// The current state of the position.
+    #[prost(message, optional, tag = "1")]
+    pub position: ::core::option::Option<
+        super::super::core::component::dex::v1::Position,
+    >,
+    /// The reserves the position was opened with.
+    ///
+    /// Omitted if the wallet didn't see the position being opened.
+    #[prost(message, optional, tag = "2")]
+    pub initial_reserves: ::core::option::Option<
+        super::super::core::component::dex::v1::Reserves,
+    >,
+    /// The number of executions against the position.
+    ///
+    /// This and the fees, rewards and withdrawn reserves are omitted if the
+    /// position's activity is incomplete, e.g. because the full node pruned it.
+    #[prost(uint64, optional, tag = "3")]
+    pub executions: ::core::option::Option<u64>,
+    /// The fees earned by those executions.
+    #[prost(message, optional, tag = "4")]
+    pub fees: ::core::option::Option<super::super::core::component::dex::v1::Reserves>,
+    /// The rewards credited to the position.
+    #[prost(message, optional, tag = "5")]
+    pub rewards: ::core::option::Option<
+        super::super::core::component::dex::v1::Reserves,
+    >,
+    /// The reserves withdrawn from the position.
+    #[prost(message, optional, tag = "6")]
+    pub withdrawn: ::core::option::Option<
+        super::super::core::component::dex::v1::Reserves,
+    >,
+    /// The profit and loss locked in by withdrawing the position, in the numeraire.
+    ///
+    /// Omitted if the position's assets couldn't be priced in the numeraire, or
+    /// if the position was withdrawn and its activity is incomplete.
+    #[prost(message, optional, tag = "7")]
+    pub realized_pnl: ::core::option::Option<super::super::core::asset::v1::Balance>,
+    /// The profit and loss of the reserves still in the position, in the numeraire.
+    ///
+    /// Omitted if the position's assets couldn't be priced in the numeraire, or
+    /// if its initial reserves are unknown.
+    #[prost(message, optional, tag = "8")]
+    pub unrealized_pnl: ::core::option::Option<super::super::core::asset::v1::Balance>,
+    /// The value of the fees earned, in the numeraire.
+    #[prost(message, optional, tag = "9")]
+    pub fees_value: ::core::option::Option<super::super::core::asset::v1::Value>,
+    /// The value of the rewards credited, in the numeraire.
+    #[prost(message, optional, tag = "10")]
+    pub rewards_value: ::core::option::Option<super::super::core::asset::v1::Value>,
+}
+impl ::prost::Name for PositionPnl {
+    const NAME: &'static str = "PositionPnl";
+    const PACKAGE: &'static str = "penumbra.view.v1";
+    fn full_name() -> ::prost::alloc::string::String {
+        "penumbra.view.v1.PositionPnl".into()
+    }
+    fn type_url() -> ::prost::alloc::string::String {
+        "/penumbra.view.v1.PositionPnl".into()
+    }
+}
 /// Generated client implementations.
 #[cfg(feature = "rpc")]
 pub mod view_service_client {
@@ -3375,6 +3475,36 @@ pub mod view_service_client {
                 );
             self.inner.server_streaming(req, path, codec).await
         }
+        /// Gets the fees, rewards and profit and loss of the liquidity positions controlled by the user's wallet.
+        pub async fn position_pnl(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PositionPnlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::PositionPnlResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic::codec::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/penumbra.view.v1.ViewService/PositionPnl",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "penumbra.view.v1.ViewService",
+                        "PositionPnl",
+                    ),
+                );
+            self.inner.server_streaming(req, path, codec).await
+        }
     }
 }
 /// Generated server implementations.
@@ -3824,6 +3954,20 @@ pub mod view_service_server {
             tonic::Response<Self::LpStrategyCatalogStream>,
             tonic::Status,
         >;
+        /// Server streaming response type for the PositionPnl method.
+        type PositionPnlStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::PositionPnlResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        /// Gets the fees, rewards and profit and loss of the liquidity positions controlled by the user's wallet.
+        async fn position_pnl(
+            &self,
+            request: tonic::Request<super::PositionPnlRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::PositionPnlStream>,
+            tonic::Status,
+        >;
     }
     /// The view RPC is used by a view client, who wants to do some
     /// transaction-related actions, to request data from a view service, which is
@@ -5535,6 +5679,52 @@ pub mod view_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/penumbra.view.v1.ViewService/PositionPnl" => {
+                    #[allow(non_camel_case_types)]
+                    struct PositionPnlSvc<T: ViewService>(pub Arc<T>);
+                    impl<
+                        T: ViewService,
+                    > tonic::server::ServerStreamingService<super::PositionPnlRequest>
+                    for PositionPnlSvc<T> {
+                        type Response = super::PositionPnlResponse;
+                        type ResponseStream = T::PositionPnlStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PositionPnlRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as ViewService>::position_pnl(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PositionPnlSvc(inner);
+                        let codec = tonic::codec::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 _ => {
                     Box::pin(async move {
                         let mut response = http::Response::new(empty_body());
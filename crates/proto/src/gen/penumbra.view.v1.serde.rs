@@ -5754,6 +5754,488 @@ impl<'de> serde::Deserialize<'de> for OwnedPositionIdsResponse {
         deserializer.deserialize_struct("penumbra.view.v1.OwnedPositionIdsResponse", FIELDS, GeneratedVisitor)
     }
 }
+impl serde::Serialize for PositionPnl {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.position.is_some() {
+            len += 1;
+        }
+        if self.initial_reserves.is_some() {
+            len += 1;
+        }
+        if self.executions.is_some() {
+            len += 1;
+        }
+        if self.fees.is_some() {
+            len += 1;
+        }
+        if self.rewards.is_some() {
+            len += 1;
+        }
+        if self.withdrawn.is_some() {
+            len += 1;
+        }
+        if self.realized_pnl.is_some() {
+            len += 1;
+        }
+        if self.unrealized_pnl.is_some() {
+            len += 1;
+        }
+        if self.fees_value.is_some() {
+            len += 1;
+        }
+        if self.rewards_value.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PositionPnl", len)?;
+        if let Some(v) = self.position.as_ref() {
+            struct_ser.serialize_field("position", v)?;
+        }
+        if let Some(v) = self.initial_reserves.as_ref() {
+            struct_ser.serialize_field("initialReserves", v)?;
+        }
+        if let Some(v) = self.executions.as_ref() {
+            #[allow(clippy::needless_borrow)]
+            #[allow(clippy::needless_borrows_for_generic_args)]
+            struct_ser.serialize_field("executions", ToString::to_string(&v).as_str())?;
+        }
+        if let Some(v) = self.fees.as_ref() {
+            struct_ser.serialize_field("fees", v)?;
+        }
+        if let Some(v) = self.rewards.as_ref() {
+            struct_ser.serialize_field("rewards", v)?;
+        }
+        if let Some(v) = self.withdrawn.as_ref() {
+            struct_ser.serialize_field("withdrawn", v)?;
+        }
+        if let Some(v) = self.realized_pnl.as_ref() {
+            struct_ser.serialize_field("realizedPnl", v)?;
+        }
+        if let Some(v) = self.unrealized_pnl.as_ref() {
+            struct_ser.serialize_field("unrealizedPnl", v)?;
+        }
+        if let Some(v) = self.fees_value.as_ref() {
+            struct_ser.serialize_field("feesValue", v)?;
+        }
+        if let Some(v) = self.rewards_value.as_ref() {
+            struct_ser.serialize_field("rewardsValue", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionPnl {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position",
+            "initial_reserves",
+            "initialReserves",
+            "executions",
+            "fees",
+            "rewards",
+            "withdrawn",
+            "realized_pnl",
+            "realizedPnl",
+            "unrealized_pnl",
+            "unrealizedPnl",
+            "fees_value",
+            "feesValue",
+            "rewards_value",
+            "rewardsValue",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Position,
+            InitialReserves,
+            Executions,
+            Fees,
+            Rewards,
+            Withdrawn,
+            RealizedPnl,
+            UnrealizedPnl,
+            FeesValue,
+            RewardsValue,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "position" => Ok(GeneratedField::Position),
+                            "initialReserves" | "initial_reserves" => Ok(GeneratedField::InitialReserves),
+                            "executions" => Ok(GeneratedField::Executions),
+                            "fees" => Ok(GeneratedField::Fees),
+                            "rewards" => Ok(GeneratedField::Rewards),
+                            "withdrawn" => Ok(GeneratedField::Withdrawn),
+                            "realizedPnl" | "realized_pnl" => Ok(GeneratedField::RealizedPnl),
+                            "unrealizedPnl" | "unrealized_pnl" => Ok(GeneratedField::UnrealizedPnl),
+                            "feesValue" | "fees_value" => Ok(GeneratedField::FeesValue),
+                            "rewardsValue" | "rewards_value" => Ok(GeneratedField::RewardsValue),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionPnl;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PositionPnl")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionPnl, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position__ = None;
+                let mut initial_reserves__ = None;
+                let mut executions__ = None;
+                let mut fees__ = None;
+                let mut rewards__ = None;
+                let mut withdrawn__ = None;
+                let mut realized_pnl__ = None;
+                let mut unrealized_pnl__ = None;
+                let mut fees_value__ = None;
+                let mut rewards_value__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Position => {
+                            if position__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("position"));
+                            }
+                            position__ = map_.next_value()?;
+                        }
+                        GeneratedField::InitialReserves => {
+                            if initial_reserves__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("initialReserves"));
+                            }
+                            initial_reserves__ = map_.next_value()?;
+                        }
+                        GeneratedField::Executions => {
+                            if executions__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("executions"));
+                            }
+                            executions__ = 
+                                map_.next_value::<::std::option::Option<::pbjson::private::NumberDeserialize<_>>>()?.map(|x| x.0)
+                            ;
+                        }
+                        GeneratedField::Fees => {
+                            if fees__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("fees"));
+                            }
+                            fees__ = map_.next_value()?;
+                        }
+                        GeneratedField::Rewards => {
+                            if rewards__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewards"));
+                            }
+                            rewards__ = map_.next_value()?;
+                        }
+                        GeneratedField::Withdrawn => {
+                            if withdrawn__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("withdrawn"));
+                            }
+                            withdrawn__ = map_.next_value()?;
+                        }
+                        GeneratedField::RealizedPnl => {
+                            if realized_pnl__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("realizedPnl"));
+                            }
+                            realized_pnl__ = map_.next_value()?;
+                        }
+                        GeneratedField::UnrealizedPnl => {
+                            if unrealized_pnl__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("unrealizedPnl"));
+                            }
+                            unrealized_pnl__ = map_.next_value()?;
+                        }
+                        GeneratedField::FeesValue => {
+                            if fees_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("feesValue"));
+                            }
+                            fees_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::RewardsValue => {
+                            if rewards_value__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("rewardsValue"));
+                            }
+                            rewards_value__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionPnl {
+                    position: position__,
+                    initial_reserves: initial_reserves__,
+                    executions: executions__,
+                    fees: fees__,
+                    rewards: rewards__,
+                    withdrawn: withdrawn__,
+                    realized_pnl: realized_pnl__,
+                    unrealized_pnl: unrealized_pnl__,
+                    fees_value: fees_value__,
+                    rewards_value: rewards_value__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PositionPnl", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionPnlRequest {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if !self.position_ids.is_empty() {
+            len += 1;
+        }
+        if self.subaccount.is_some() {
+            len += 1;
+        }
+        if self.numeraire.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PositionPnlRequest", len)?;
+        if !self.position_ids.is_empty() {
+            struct_ser.serialize_field("positionIds", &self.position_ids)?;
+        }
+        if let Some(v) = self.subaccount.as_ref() {
+            struct_ser.serialize_field("subaccount", v)?;
+        }
+        if let Some(v) = self.numeraire.as_ref() {
+            struct_ser.serialize_field("numeraire", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionPnlRequest {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "position_ids",
+            "positionIds",
+            "subaccount",
+            "numeraire",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            PositionIds,
+            Subaccount,
+            Numeraire,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "positionIds" | "position_ids" => Ok(GeneratedField::PositionIds),
+                            "subaccount" => Ok(GeneratedField::Subaccount),
+                            "numeraire" => Ok(GeneratedField::Numeraire),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionPnlRequest;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PositionPnlRequest")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionPnlRequest, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut position_ids__ = None;
+                let mut subaccount__ = None;
+                let mut numeraire__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::PositionIds => {
+                            if position_ids__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("positionIds"));
+                            }
+                            position_ids__ = Some(map_.next_value()?);
+                        }
+                        GeneratedField::Subaccount => {
+                            if subaccount__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("subaccount"));
+                            }
+                            subaccount__ = map_.next_value()?;
+                        }
+                        GeneratedField::Numeraire => {
+                            if numeraire__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("numeraire"));
+                            }
+                            numeraire__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionPnlRequest {
+                    position_ids: position_ids__.unwrap_or_default(),
+                    subaccount: subaccount__,
+                    numeraire: numeraire__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PositionPnlRequest", FIELDS, GeneratedVisitor)
+    }
+}
+impl serde::Serialize for PositionPnlResponse {
+    #[allow(deprecated)]
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        use serde::ser::SerializeStruct;
+        let mut len = 0;
+        if self.pnl.is_some() {
+            len += 1;
+        }
+        let mut struct_ser = serializer.serialize_struct("penumbra.view.v1.PositionPnlResponse", len)?;
+        if let Some(v) = self.pnl.as_ref() {
+            struct_ser.serialize_field("pnl", v)?;
+        }
+        struct_ser.end()
+    }
+}
+impl<'de> serde::Deserialize<'de> for PositionPnlResponse {
+    #[allow(deprecated)]
+    fn deserialize<D>(deserializer: D) -> std::result::Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        const FIELDS: &[&str] = &[
+            "pnl",
+        ];
+
+        #[allow(clippy::enum_variant_names)]
+        enum GeneratedField {
+            Pnl,
+            __SkipField__,
+        }
+        impl<'de> serde::Deserialize<'de> for GeneratedField {
+            fn deserialize<D>(deserializer: D) -> std::result::Result<GeneratedField, D::Error>
+            where
+                D: serde::Deserializer<'de>,
+            {
+                struct GeneratedVisitor;
+
+                impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+                    type Value = GeneratedField;
+
+                    fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                        write!(formatter, "expected one of: {:?}", &FIELDS)
+                    }
+
+                    #[allow(unused_variables)]
+                    fn visit_str<E>(self, value: &str) -> std::result::Result<GeneratedField, E>
+                    where
+                        E: serde::de::Error,
+                    {
+                        match value {
+                            "pnl" => Ok(GeneratedField::Pnl),
+                            _ => Ok(GeneratedField::__SkipField__),
+                        }
+                    }
+                }
+                deserializer.deserialize_identifier(GeneratedVisitor)
+            }
+        }
+        struct GeneratedVisitor;
+        impl<'de> serde::de::Visitor<'de> for GeneratedVisitor {
+            type Value = PositionPnlResponse;
+
+            fn expecting(&self, formatter: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                formatter.write_str("struct penumbra.view.v1.PositionPnlResponse")
+            }
+
+            fn visit_map<V>(self, mut map_: V) -> std::result::Result<PositionPnlResponse, V::Error>
+                where
+                    V: serde::de::MapAccess<'de>,
+            {
+                let mut pnl__ = None;
+                while let Some(k) = map_.next_key()? {
+                    match k {
+                        GeneratedField::Pnl => {
+                            if pnl__.is_some() {
+                                return Err(serde::de::Error::duplicate_field("pnl"));
+                            }
+                            pnl__ = map_.next_value()?;
+                        }
+                        GeneratedField::__SkipField__ => {
+                            let _ = map_.next_value::<serde::de::IgnoredAny>()?;
+                        }
+                    }
+                }
+                Ok(PositionPnlResponse {
+                    pnl: pnl__,
+                })
+            }
+        }
+        deserializer.deserialize_struct("penumbra.view.v1.PositionPnlResponse", FIELDS, GeneratedVisitor)
+    }
+}
 impl serde::Serialize for SpendableNoteRecord {
     #[allow(deprecated)]
     fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
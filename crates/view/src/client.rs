@@ -30,7 +30,7 @@ use penumbra_sdk_transaction::{
     txhash::TransactionId, AuthorizationData, Transaction, TransactionPlan, WitnessData,
 };
 
-use crate::{SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
+use crate::{PositionPnl, SpendableNoteRecord, StatusStreamResponse, SwapRecord, TransactionInfo};
 
 pub(crate) type BroadcastStatusStream = Pin<
     Box<dyn Future<Output = Result<Streaming<BroadcastTransactionResponse>, anyhow::Error>> + Send>,
@@ -188,6 +188,16 @@ pub trait ViewClient {
         subaccount: Option<AddressIndex>,
     ) -> Pin<Box<dyn Future<Output = Result<Vec<position::Id>>> + Send + 'static>>;
 
+    /// Queries for the accounting of owned liquidity positions, valued in `numeraire`.
+    ///
+    /// If `position_ids` is empty, all owned positions in `subaccount` are included.
+    fn position_pnl(
+        &mut self,
+        numeraire: asset::Id,
+        position_ids: Vec<position::Id>,
+        subaccount: Option<AddressIndex>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PositionPnl>>> + Send + 'static>>;
+
     /// Generates a full perspective for a selected transaction using a full viewing key
     fn transaction_info_by_hash(
         &mut self,
@@ -738,6 +748,37 @@ where
         .boxed()
     }
 
+    fn position_pnl(
+        &mut self,
+        numeraire: asset::Id,
+        position_ids: Vec<position::Id>,
+        subaccount: Option<AddressIndex>,
+    ) -> Pin<Box<dyn Future<Output = Result<Vec<PositionPnl>>> + Send + 'static>> {
+        let mut self2 = self.clone();
+        async move {
+            let rsp = ViewServiceClient::position_pnl(
+                &mut self2,
+                tonic::Request::new(pb::PositionPnlRequest {
+                    position_ids: position_ids.into_iter().map(Into::into).collect(),
+                    subaccount: subaccount.map(Into::into),
+                    numeraire: Some(numeraire.into()),
+                }),
+            );
+
+            let pb_pnls: Vec<_> = rsp.await?.into_inner().try_collect().await?;
+
+            pb_pnls
+                .into_iter()
+                .map(|rsp| {
+                    rsp.pnl
+                        .ok_or_else(|| anyhow::anyhow!("empty PositionPnlResponse message"))?
+                        .try_into()
+                })
+                .collect()
+        }
+        .boxed()
+    }
+
     fn transaction_info_by_hash(
         &mut self,
         id: TransactionId,
@@ -0,0 +1,196 @@
+//! Account discovery for restored seed phrases.
+//!
+//! A single seed phrase can derive many BIP44 accounts, each with its own full viewing key, and
+//! each of those can receive funds at many address indices. When restoring a wallet, we don't know
+//! which of them were used, so we scan accounts in order until we have seen `gap_limit`
+//! consecutive accounts without any history.
+//!
+//! Discovery only reports the accounts it finds: a view database tracks a single full viewing
+//! key, so each discovered account has to be synced in its own home.
+
+use std::{fmt, future::Future};
+
+use futures::StreamExt;
+use penumbra_sdk_keys::FullViewingKey;
+use penumbra_sdk_proto::view::v1::{self as pb, view_service_server::ViewService};
+use url::Url;
+
+use crate::{Storage, ViewServer};
+
+/// The number of consecutive unused accounts after which discovery stops, if not otherwise set.
+pub const DEFAULT_GAP_LIMIT: u32 = 5;
+
+/// A BIP44 account that was found to have on-chain history.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiscoveredAccount {
+    /// The BIP44 account index used to derive the spend key.
+    pub bip44_account: u32,
+    /// The address indices within that account that have received notes, in ascending order.
+    pub address_indices: Vec<u32>,
+}
+
+impl fmt::Display for DiscoveredAccount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "BIP44 account {} (address indices ", self.bip44_account)?;
+        for (i, index) in self.address_indices.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{index}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Scan the chain for the BIP44 accounts derived by `fvk_for`, stopping after `gap_limit`
+/// consecutive accounts without history.
+///
+/// Each account is synchronized into a temporary in-memory database, so this performs one full
+/// scan of the chain per account checked.
+pub async fn discover_accounts(
+    node: Url,
+    gap_limit: u32,
+    mut fvk_for: impl FnMut(u32) -> FullViewingKey,
+) -> anyhow::Result<Vec<DiscoveredAccount>> {
+    scan_with_gap_limit(gap_limit, |bip44_account| {
+        let fvk = fvk_for(bip44_account);
+        let node = node.clone();
+        async move {
+            tracing::info!(bip44_account, "scanning account for history");
+            let address_indices = used_address_indices(&fvk, node).await?;
+            anyhow::Ok(DiscoveredAccount {
+                bip44_account,
+                address_indices,
+            })
+        }
+    })
+    .await
+}
+
+/// Sync a temporary view database for `fvk` to the current height, and return the address indices
+/// that have received notes.
+async fn used_address_indices(fvk: &FullViewingKey, node: Url) -> anyhow::Result<Vec<u32>> {
+    let storage = Storage::load_or_initialize(None::<&str>, fvk, node.clone()).await?;
+    let view = ViewServer::new(storage.clone(), node).await?;
+
+    // The status stream ends once the worker has caught up with the chain.
+    let mut status = view
+        .status_stream(tonic::Request::new(pb::StatusStreamRequest {}))
+        .await?
+        .into_inner();
+    while let Some(update) = status.next().await {
+        update?;
+    }
+
+    storage.used_address_indices().await
+}
+
+/// Probe accounts `0, 1, 2, ...` until `gap_limit` consecutive accounts come back without any
+/// address indices, returning the accounts that had some.
+async fn scan_with_gap_limit<F, Fut>(
+    gap_limit: u32,
+    mut probe: F,
+) -> anyhow::Result<Vec<DiscoveredAccount>>
+where
+    F: FnMut(u32) -> Fut,
+    Fut: Future<Output = anyhow::Result<DiscoveredAccount>>,
+{
+    anyhow::ensure!(gap_limit > 0, "gap limit must be at least 1");
+
+    let mut discovered = Vec::new();
+    let mut unused_in_a_row = 0;
+    let mut bip44_account = 0;
+    while unused_in_a_row < gap_limit {
+        let account = probe(bip44_account).await?;
+        if account.address_indices.is_empty() {
+            unused_in_a_row += 1;
+        } else {
+            unused_in_a_row = 0;
+            discovered.push(account);
+        }
+        bip44_account = bip44_account
+            .checked_add(1)
+            .ok_or_else(|| anyhow::anyhow!("ran out of BIP44 accounts to scan"))?;
+    }
+
+    Ok(discovered)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+
+    async fn scan(gap_limit: u32, used: &[(u32, &[u32])]) -> (Vec<DiscoveredAccount>, Vec<u32>) {
+        let used: BTreeMap<u32, Vec<u32>> = used.iter().map(|(a, i)| (*a, i.to_vec())).collect();
+        let mut probed = Vec::new();
+        let discovered = scan_with_gap_limit(gap_limit, |bip44_account| {
+            probed.push(bip44_account);
+            let address_indices = used.get(&bip44_account).cloned().unwrap_or_default();
+            async move {
+                anyhow::Ok(DiscoveredAccount {
+                    bip44_account,
+                    address_indices,
+                })
+            }
+        })
+        .await
+        .expect("scan succeeds");
+        (discovered, probed)
+    }
+
+    #[tokio::test]
+    async fn empty_wallet_scans_exactly_the_gap() {
+        let (discovered, probed) = scan(3, &[]).await;
+        assert!(discovered.is_empty());
+        assert_eq!(probed, vec![0, 1, 2]);
+    }
+
+    #[tokio::test]
+    async fn used_accounts_extend_the_scan() {
+        let (discovered, probed) = scan(2, &[(0, &[0, 3]), (2, &[1])]).await;
+        assert_eq!(
+            discovered,
+            vec![
+                DiscoveredAccount {
+                    bip44_account: 0,
+                    address_indices: vec![0, 3],
+                },
+                DiscoveredAccount {
+                    bip44_account: 2,
+                    address_indices: vec![1],
+                },
+            ]
+        );
+        assert_eq!(probed, vec![0, 1, 2, 3, 4]);
+    }
+
+    #[tokio::test]
+    async fn accounts_beyond_the_gap_are_not_found() {
+        let (discovered, probed) = scan(2, &[(0, &[0]), (3, &[0])]).await;
+        assert_eq!(discovered.len(), 1);
+        assert_eq!(probed, vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn displays_address_indices_as_a_list() {
+        let account = DiscoveredAccount {
+            bip44_account: 2,
+            address_indices: vec![0, 3],
+        };
+        assert_eq!(
+            account.to_string(),
+            "BIP44 account 2 (address indices 0, 3)"
+        );
+    }
+
+    #[tokio::test]
+    async fn zero_gap_limit_is_rejected() {
+        let result = scan_with_gap_limit(0, |_| async {
+            Err::<DiscoveredAccount, _>(anyhow::anyhow!("nothing should be probed"))
+        })
+        .await;
+        assert!(result.is_err());
+    }
+}
@@ -14,9 +14,11 @@
 // Requires nightly.
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 mod client;
+pub mod discovery;
 mod metrics;
 mod note_record;
 mod planner;
+mod position_pnl;
 mod service;
 mod status;
 mod storage;
@@ -29,6 +31,7 @@ pub use crate::client::ViewClient;
 pub use crate::metrics::register_metrics;
 pub use crate::note_record::SpendableNoteRecord;
 pub use crate::planner::Planner;
+pub use crate::position_pnl::{PairPrices, PositionPnl};
 pub use crate::service::ViewServer;
 pub use crate::status::StatusStreamResponse;
 pub use crate::storage::Storage;
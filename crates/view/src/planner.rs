@@ -21,6 +21,10 @@ use penumbra_sdk_auction::auction::dutch::DutchAuctionDescription;
 use penumbra_sdk_auction::auction::dutch::{actions::ActionDutchAuctionWithdrawPlan, DutchAuction};
 use penumbra_sdk_auction::auction::{
     dutch::actions::{ActionDutchAuctionEnd, ActionDutchAuctionSchedule},
+    twap::{
+        actions::{ActionTwapAuctionEnd, ActionTwapAuctionSchedule, ActionTwapAuctionWithdrawPlan},
+        TwapAuction, TwapAuctionDescription,
+    },
     AuctionId,
 };
 use penumbra_sdk_community_pool::CommunityPoolDeposit;
@@ -276,6 +280,53 @@ impl<R: RngCore + CryptoRng> Planner<R> {
         self
     }
 
+    /// Schedule a TWAP auction.
+    #[instrument(skip(self))]
+    pub fn twap_auction_schedule(&mut self, description: TwapAuctionDescription) -> &mut Self {
+        self.action_list
+            .push(ActionTwapAuctionSchedule { description });
+        self
+    }
+
+    /// Ends a TWAP auction.
+    #[instrument(skip(self))]
+    pub fn twap_auction_end(&mut self, auction_id: AuctionId) -> &mut Self {
+        self.action_list.push(ActionTwapAuctionEnd { auction_id });
+        self
+    }
+
+    /// Withdraws the reserves of the TWAP auction.
+    ///
+    /// Uses the provided auction state to automatically end the auction
+    /// if necessary.
+    #[instrument(skip(self))]
+    pub fn twap_auction_withdraw(&mut self, auction: &TwapAuction) -> &mut Self {
+        let auction_id = auction.description.id();
+        // Check if the auction needs to be ended
+        if auction.state.sequence == 0 {
+            self.twap_auction_end(auction_id);
+        }
+
+        let reserves_input = Value {
+            amount: auction.state.input_reserves,
+            asset_id: auction.description.input.asset_id,
+        };
+        let reserves_output = Value {
+            amount: auction.state.output_reserves,
+            asset_id: auction.description.output_id,
+        };
+
+        let plan = ActionTwapAuctionWithdrawPlan {
+            auction_id,
+            seq: 2, // 1 (closed) -> 2 (withdrawn)
+            reserves_input,
+            reserves_output,
+        };
+
+        self.action_list.push(plan);
+        self
+    }
+
     /// Perform a swap based on input notes in the transaction.
     #[instrument(skip(self))]
     pub fn swap(
@@ -0,0 +1,292 @@
+use anyhow::anyhow;
+use penumbra_sdk_asset::{asset, Balance, Value};
+use penumbra_sdk_dex::lp::{
+    position::{self, Position},
+    PositionActivity, Reserves,
+};
+use penumbra_sdk_num::Amount;
+use penumbra_sdk_proto::{view::v1 as pb, DomainType};
+use serde::{Deserialize, Serialize};
+
+/// The prices of both assets of a trading pair in a numeraire, in base units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PairPrices {
+    pub numeraire: asset::Id,
+    /// The price of asset 1 of the pair.
+    pub price_1: f64,
+    /// The price of asset 2 of the pair.
+    pub price_2: f64,
+}
+
+impl PairPrices {
+    /// Returns the value of `reserves` in the numeraire.
+    pub fn value(&self, reserves: &Reserves) -> Value {
+        let value =
+            reserves.r1.value() as f64 * self.price_1 + reserves.r2.value() as f64 * self.price_2;
+        Value {
+            asset_id: self.numeraire,
+            amount: Amount::from(value.round() as u128),
+        }
+    }
+}
+
+/// The accounting of a liquidity position controlled by the wallet, from the
+/// reserves it was opened with and its activity since.
+///
+/// Full nodes only keep recent activity, so the accounting of a position whose
+/// activity wasn't fetched in time is incomplete, and is reported as unknown.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "pb::PositionPnl", into = "pb::PositionPnl")]
+pub struct PositionPnl {
+    /// The current state of the position.
+    pub position: Position,
+    /// The reserves the position was opened with, if the wallet saw it opened.
+    pub initial_reserves: Option<Reserves>,
+    /// The number of executions, or `None` if the activity is incomplete.
+    pub executions: Option<u64>,
+    /// The fees earned, or `None` if the activity is incomplete.
+    pub fees: Option<Reserves>,
+    /// The rewards credited, or `None` if the activity is incomplete.
+    pub rewards: Option<Reserves>,
+    /// The reserves withdrawn, or `None` if the activity is incomplete.
+    pub withdrawn: Option<Reserves>,
+    /// The difference between the value of the withdrawn reserves and the
+    /// initial reserves, or zero if the position hasn't been withdrawn.
+    pub realized_pnl: Option<Balance>,
+    /// The value of the current reserves, less the value of the initial
+    /// reserves if the position hasn't been withdrawn.
+    pub unrealized_pnl: Option<Balance>,
+    pub fees_value: Option<Value>,
+    pub rewards_value: Option<Value>,
+}
+
+impl PositionPnl {
+    /// Folds the `activity` of `position` since it was opened with
+    /// `initial_reserves`, valuing it at the current `prices`, if any.
+    ///
+    /// Valuing the initial reserves at current prices means that the profit
+    /// and loss is measured against holding the initial reserves instead.
+    ///
+    /// The activity is complete if it accounts for every change to the reserves
+    /// of the position, from its initial reserves to its current ones.
+    pub fn new(
+        position: Position,
+        initial_reserves: Option<Reserves>,
+        activity: &[PositionActivity],
+        prices: Option<PairPrices>,
+    ) -> Self {
+        let mut reserves = initial_reserves.clone();
+        let mut executions = 0;
+        let mut fees = Reserves::zero();
+        let mut rewards = Reserves::zero();
+        let mut withdrawn = Reserves::zero();
+        for block in activity {
+            if reserves.as_ref() != Some(&block.prev_reserves) {
+                reserves = None;
+                break;
+            }
+            reserves = Some(block.reserves.clone());
+            executions += block.executions;
+            fees.r1 += block.fees.r1;
+            fees.r2 += block.fees.r2;
+            rewards.r1 += block.rewards.r1;
+            rewards.r2 += block.rewards.r2;
+            withdrawn.r1 += block.withdrawn.r1;
+            withdrawn.r2 += block.withdrawn.r2;
+        }
+        let complete = reserves.as_ref() == Some(&position.reserves);
+        let executions = complete.then_some(executions);
+        let fees = complete.then_some(fees);
+        let rewards = complete.then_some(rewards);
+        let withdrawn = complete.then_some(withdrawn);
+
+        let (realized_pnl, unrealized_pnl) = match prices {
+            Some(prices) => {
+                let initial = initial_reserves.as_ref().map(|r| prices.value(r));
+                let current = Balance::from(prices.value(&position.reserves));
+                if matches!(position.state, position::State::Withdrawn { .. }) {
+                    let realized = withdrawn.as_ref().zip(initial).map(|(withdrawn, initial)| {
+                        Balance::from(prices.value(withdrawn)) - initial
+                    });
+                    (realized, Some(current))
+                } else {
+                    (
+                        Some(Balance::zero()),
+                        initial.map(|initial| current - initial),
+                    )
+                }
+            }
+            None => (None, None),
+        };
+
+        Self {
+            fees_value: prices
+                .zip(fees.as_ref())
+                .map(|(prices, fees)| prices.value(fees)),
+            rewards_value: prices
+                .zip(rewards.as_ref())
+                .map(|(prices, rewards)| prices.value(rewards)),
+            position,
+            initial_reserves,
+            executions,
+            fees,
+            rewards,
+            withdrawn,
+            realized_pnl,
+            unrealized_pnl,
+        }
+    }
+}
+
+impl DomainType for PositionPnl {
+    type Proto = pb::PositionPnl;
+}
+
+impl From<PositionPnl> for pb::PositionPnl {
+    fn from(pnl: PositionPnl) -> Self {
+        Self {
+            position: Some(pnl.position.into()),
+            initial_reserves: pnl.initial_reserves.map(Into::into),
+            executions: pnl.executions,
+            fees: pnl.fees.map(Into::into),
+            rewards: pnl.rewards.map(Into::into),
+            withdrawn: pnl.withdrawn.map(Into::into),
+            realized_pnl: pnl.realized_pnl.map(Into::into),
+            unrealized_pnl: pnl.unrealized_pnl.map(Into::into),
+            fees_value: pnl.fees_value.map(Into::into),
+            rewards_value: pnl.rewards_value.map(Into::into),
+        }
+    }
+}
+
+impl TryFrom<pb::PositionPnl> for PositionPnl {
+    type Error = anyhow::Error;
+    fn try_from(pnl: pb::PositionPnl) -> Result<Self, Self::Error> {
+        Ok(Self {
+            position: pnl
+                .position
+                .ok_or_else(|| anyhow!("missing position"))?
+                .try_into()?,
+            initial_reserves: pnl.initial_reserves.map(TryInto::try_into).transpose()?,
+            executions: pnl.executions,
+            fees: pnl.fees.map(TryInto::try_into).transpose()?,
+            rewards: pnl.rewards.map(TryInto::try_into).transpose()?,
+            withdrawn: pnl.withdrawn.map(TryInto::try_into).transpose()?,
+            realized_pnl: pnl.realized_pnl.map(TryInto::try_into).transpose()?,
+            unrealized_pnl: pnl.unrealized_pnl.map(TryInto::try_into).transpose()?,
+            fees_value: pnl.fees_value.map(TryInto::try_into).transpose()?,
+            rewards_value: pnl.rewards_value.map(TryInto::try_into).transpose()?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use penumbra_sdk_dex::DirectedTradingPair;
+    use rand_core::OsRng;
+
+    use super::*;
+
+    #[test]
+    fn values_positions_against_holding() -> anyhow::Result<()> {
+        let cache = asset::Cache::with_known_assets();
+        let gm = cache.get_unit("gm").expect("gm is a known asset").id();
+        let gn = cache.get_unit("gn").expect("gn is a known asset").id();
+        let pair = DirectedTradingPair::new(gm, gn);
+
+        let initial = Reserves {
+            r1: 1_000u64.into(),
+            r2: 1_000u64.into(),
+        };
+        let mut position =
+            Position::new(OsRng, pair, 30, 1u64.into(), 1u64.into(), initial.clone());
+        position.reserves = initial.clone();
+        let prices = PairPrices {
+            numeraire: gn,
+            price_1: 2.0,
+            price_2: 1.0,
+        };
+
+        // An execution swaps 100 of asset 1 for 103 of asset 2, earning 3 in fees.
+        let mut executed = position.clone();
+        executed.reserves = Reserves {
+            r1: 900u64.into(),
+            r2: 1_103u64.into(),
+        };
+        let mut activity = vec![PositionActivity::new(position.id(), 1, initial.clone())];
+        activity[0].executions = 1;
+        activity[0].fees.r2 = 3u64.into();
+        activity[0].reserves = executed.reserves.clone();
+
+        // Before withdrawal, the whole difference is unrealized: 2903 - 3000.
+        let pnl = PositionPnl::new(
+            executed.clone(),
+            Some(initial.clone()),
+            &activity,
+            Some(prices),
+        );
+        assert_eq!(pnl.executions, Some(1));
+        assert_eq!(pnl.realized_pnl, Some(Balance::zero()));
+        assert_eq!(
+            pnl.unrealized_pnl,
+            Some(
+                Balance::zero()
+                    - Value {
+                        amount: 97u64.into(),
+                        asset_id: gn
+                    }
+            )
+        );
+        assert_eq!(
+            pnl.fees_value,
+            Some(Value {
+                amount: 3u64.into(),
+                asset_id: gn
+            })
+        );
+
+        // After withdrawal, it's realized, and only what's left is unrealized.
+        let mut withdrawal = PositionActivity::new(position.id(), 2, executed.reserves.clone());
+        withdrawal.record_withdrawal(&executed.reserves);
+        activity.push(withdrawal);
+        let mut withdrawn = executed.clone();
+        withdrawn.state = position::State::Withdrawn { sequence: 0 };
+        withdrawn.reserves = Reserves::zero();
+        let pnl = PositionPnl::new(
+            withdrawn.clone(),
+            Some(initial.clone()),
+            &activity,
+            Some(prices),
+        );
+        assert_eq!(pnl.withdrawn, Some(executed.reserves.clone()));
+        assert_eq!(
+            pnl.realized_pnl,
+            Some(
+                Balance::zero()
+                    - Value {
+                        amount: 97u64.into(),
+                        asset_id: gn
+                    }
+            )
+        );
+        assert_eq!(pnl.unrealized_pnl, Some(Balance::zero()));
+
+        // If the first block of activity was pruned before it was fetched, the
+        // history is incomplete, so neither the fees nor the realized profit
+        // and loss are known.
+        let pruned = PositionPnl::new(withdrawn, Some(initial), &activity[1..], Some(prices));
+        assert_eq!(pruned.executions, None);
+        assert_eq!(pruned.fees, None);
+        assert_eq!(pruned.fees_value, None);
+        assert_eq!(pruned.realized_pnl, None);
+
+        // Without the initial reserves, the activity can't be checked for
+        // completeness, so it's unknown too.
+        let unpriced = PositionPnl::new(executed, None, &activity[..1], None);
+        assert_eq!(unpriced.executions, None);
+        assert_eq!(unpriced.realized_pnl, None);
+        assert_eq!(unpriced.unrealized_pnl, None);
+
+        Ok(())
+    }
+}
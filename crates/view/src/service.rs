@@ -12,6 +12,9 @@ use futures::stream::{self, StreamExt, TryStreamExt};
 use penumbra_sdk_auction::auction::dutch::actions::view::{
     ActionDutchAuctionScheduleView, ActionDutchAuctionWithdrawView,
 };
+use penumbra_sdk_auction::auction::twap::actions::view::{
+    ActionTwapAuctionScheduleView, ActionTwapAuctionWithdrawView,
+};
 use rand::Rng;
 use rand_core::OsRng;
 use tap::{Tap, TapFallible};
@@ -27,7 +30,7 @@ use penumbra_sdk_asset::{asset, asset::Metadata, Value};
 use penumbra_sdk_dex::{
     lp::{
         position::{self, Position},
-        Reserves,
+        PositionActivity, Reserves,
     },
     swap_claim::SwapClaimPlan,
     TradingPair,
@@ -40,6 +43,10 @@ use penumbra_sdk_keys::{
 };
 use penumbra_sdk_num::Amount;
 use penumbra_sdk_proto::{
+    core::component::dex::v1::{
+        query_service_client::QueryServiceClient as DexQueryServiceClient,
+        LiquidityPositionActivityRequest, LiquidityPositionsByIdRequest, SpreadRequest,
+    },
     util::tendermint_proxy::v1::{
         tendermint_proxy_service_client::TendermintProxyServiceClient, BroadcastTxSyncRequest,
         GetStatusRequest, GetStatusResponse, SyncInfo,
@@ -62,7 +69,7 @@ use penumbra_sdk_transaction::{
     AuthorizationData, Transaction, TransactionPerspective, TransactionPlan, WitnessData,
 };
 
-use crate::{worker::Worker, Planner, SpendableNoteRecord, Storage};
+use crate::{worker::Worker, PairPrices, Planner, PositionPnl, SpendableNoteRecord, Storage};
 
 /// A [`futures::Stream`] of broadcast transaction responses.
 ///
@@ -433,6 +440,8 @@ impl ViewService for ViewServer {
             dyn futures::Stream<Item = Result<pb::LpStrategyCatalogResponse, tonic::Status>> + Send,
         >,
     >;
+    type PositionPnlStream =
+        Pin<Box<dyn futures::Stream<Item = Result<pb::PositionPnlResponse, tonic::Status>> + Send>>;
 
     #[instrument(skip_all, level = "trace")]
     async fn auctions(
@@ -1051,6 +1060,22 @@ impl ViewService for ViewServer {
                     asset_ids.insert(description.input.asset_id);
                     asset_ids.insert(description.output_id);
                 }
+                ActionView::ActionTwapAuctionWithdraw(ActionTwapAuctionWithdrawView {
+                    action: _,
+                    reserves,
+                }) => {
+                    for value in reserves {
+                        asset_ids.insert(value.asset_id());
+                    }
+                }
+                ActionView::ActionTwapAuctionSchedule(ActionTwapAuctionScheduleView {
+                    action,
+                    ..
+                }) => {
+                    let description = &action.description;
+                    asset_ids.insert(description.input.asset_id);
+                    asset_ids.insert(description.output_id);
+                }
                 _ => {}
             }
         }
@@ -1953,6 +1978,185 @@ impl ViewService for ViewServer {
     ) -> Result<tonic::Response<Self::LpStrategyCatalogStream>, tonic::Status> {
         unimplemented!("lp_strategy_catalog currently only implemented on web")
     }
+
+    #[instrument(skip_all, level = "trace")]
+    async fn position_pnl(
+        &self,
+        request: tonic::Request<pb::PositionPnlRequest>,
+    ) -> Result<tonic::Response<Self::PositionPnlStream>, tonic::Status> {
+        self.check_worker().await?;
+
+        let pb::PositionPnlRequest {
+            position_ids,
+            subaccount,
+            numeraire,
+        } = request.into_inner();
+
+        let numeraire: asset::Id = numeraire
+            .ok_or_else(|| tonic::Status::invalid_argument("missing numeraire"))?
+            .try_into()
+            .map_err(|e: anyhow::Error| e.context("could not decode numeraire"))
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        let subaccount: Option<AddressIndex> = subaccount
+            .map(|a| a.try_into())
+            .transpose()
+            .map_err(|e: anyhow::Error| e.context("could not decode subaccount"))
+            .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?;
+
+        let position_ids = if position_ids.is_empty() {
+            self.storage
+                .owned_position_ids(None, None, subaccount)
+                .await
+                .map_err(|e| {
+                    tonic::Status::unavailable(format!("error getting position ids: {e}"))
+                })?
+        } else {
+            position_ids
+                .into_iter()
+                .map(position::Id::try_from)
+                .collect::<anyhow::Result<Vec<_>>>()
+                .map_err(|e| e.context("could not decode position id"))
+                .map_err(|e| tonic::Status::invalid_argument(format!("{:#}", e)))?
+        };
+
+        let pnls = position_pnls(&self.storage, &self.node, position_ids, numeraire)
+            .await
+            .map_err(|e| {
+                tonic::Status::unavailable(format!("error computing position pnl: {e}"))
+            })?;
+
+        let stream = stream::iter(pnls.into_iter().map(|pnl| {
+            Ok(pb::PositionPnlResponse {
+                pnl: Some(pnl.into()),
+            })
+        }))
+        .boxed();
+
+        Ok(tonic::Response::new(stream))
+    }
+}
+
+/// Computes the [`PositionPnl`] of each of the owned `position_ids`, valued in
+/// `numeraire`.
+///
+/// The activity of each position is fetched from the full node at `node`
+/// starting from the last cached height, and cached in `storage`. Full nodes
+/// prune old activity, so the accounting of positions whose activity wasn't
+/// cached in time is reported as unknown.
+async fn position_pnls(
+    storage: &Storage,
+    node: &Url,
+    position_ids: Vec<position::Id>,
+    numeraire: asset::Id,
+) -> anyhow::Result<Vec<PositionPnl>> {
+    let mut client = DexQueryServiceClient::connect(node.to_string()).await?;
+
+    let positions: Vec<Position> = client
+        .liquidity_positions_by_id(LiquidityPositionsByIdRequest {
+            position_id: position_ids.into_iter().map(Into::into).collect(),
+        })
+        .await?
+        .into_inner()
+        .map_err(anyhow::Error::from)
+        .and_then(|msg| async move {
+            msg.data
+                .ok_or_else(|| anyhow!("missing liquidity position in response"))
+                .map(Position::try_from)?
+        })
+        .try_collect()
+        .await?;
+
+    let mut prices = BTreeMap::new();
+    let mut pnls = Vec::with_capacity(positions.len());
+    for position in positions {
+        let id = position.id();
+
+        let mut activity = storage.position_activity(id).await?;
+        let start_height = activity.last().map_or(0, |last| last.height + 1);
+        let new_activity: Vec<PositionActivity> = client
+            .liquidity_position_activity(LiquidityPositionActivityRequest {
+                position_id: Some(id.into()),
+                start_height,
+            })
+            .await?
+            .into_inner()
+            .map_err(anyhow::Error::from)
+            .and_then(|msg| async move {
+                msg.activity
+                    .ok_or_else(|| anyhow!("missing position activity in response"))
+                    .map(PositionActivity::try_from)?
+            })
+            .try_collect()
+            .await?;
+        storage
+            .record_position_activity(new_activity.clone())
+            .await?;
+        activity.extend(new_activity);
+
+        let initial_reserves = storage.position_initial_reserves(id).await?;
+
+        let pair = position.phi.pair;
+        let pair_prices = match prices.get(&pair) {
+            Some(pair_prices) => *pair_prices,
+            None => {
+                let price_1 = spot_price(&mut client, pair.asset_1(), numeraire).await?;
+                let price_2 = spot_price(&mut client, pair.asset_2(), numeraire).await?;
+                let pair_prices = price_1.zip(price_2).map(|(price_1, price_2)| PairPrices {
+                    numeraire,
+                    price_1,
+                    price_2,
+                });
+                prices.insert(pair, pair_prices);
+                pair_prices
+            }
+        };
+
+        pnls.push(PositionPnl::new(
+            position,
+            initial_reserves,
+            &activity,
+            pair_prices,
+        ));
+    }
+
+    Ok(pnls)
+}
+
+/// Prices `asset` in `numeraire`, in base units, at the midpoint of the best
+/// positions on either side of their pair, ignoring fees.
+///
+/// Returns `None` if there is no liquidity on the pair.
+async fn spot_price(
+    client: &mut DexQueryServiceClient<Channel>,
+    asset: asset::Id,
+    numeraire: asset::Id,
+) -> anyhow::Result<Option<f64>> {
+    if asset == numeraire {
+        return Ok(Some(1.0));
+    }
+
+    let spread = client
+        .spread(SpreadRequest {
+            trading_pair: Some(TradingPair::new(asset, numeraire).into()),
+        })
+        .await?
+        .into_inner();
+
+    let prices = [spread.best_1_to_2_position, spread.best_2_to_1_position]
+        .into_iter()
+        .flatten()
+        .map(|position| {
+            let position = Position::try_from(position)?;
+            let phi = position
+                .phi
+                .orient_start(asset)
+                .ok_or_else(|| anyhow!("position is not on the requested pair"))?;
+            Ok(phi.p.value() as f64 / phi.q.value() as f64)
+        })
+        .collect::<anyhow::Result<Vec<f64>>>()?;
+
+    Ok((!prices.is_empty()).then(|| prices.iter().sum::<f64>() / prices.len() as f64))
 }
 
 /// Convert a pd node URL to a Tonic `Endpoint`.
@@ -1,4 +1,10 @@
-use std::{collections::BTreeMap, num::NonZeroU64, str::FromStr, sync::Arc, time::Duration};
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    num::NonZeroU64,
+    str::FromStr,
+    sync::Arc,
+    time::Duration,
+};
 
 use anyhow::{anyhow, Context};
 use camino::Utf8Path;
@@ -22,7 +28,10 @@ use url::Url;
 use penumbra_sdk_app::params::AppParameters;
 use penumbra_sdk_asset::{asset, asset::Id, asset::Metadata, Value};
 use penumbra_sdk_dex::{
-    lp::position::{self, Position, State},
+    lp::{
+        position::{self, Position, State},
+        PositionActivity, Reserves,
+    },
     TradingPair,
 };
 use penumbra_sdk_fee::GasPrices;
@@ -42,7 +51,7 @@ use penumbra_sdk_transaction::Transaction;
 use sct::TreeStore;
 use tct::StateCommitment;
 
-use crate::{sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
+use crate::{discovery::DiscoveredAccount, sync::FilteredBlock, SpendableNoteRecord, SwapRecord};
 
 mod sct;
 
@@ -648,6 +657,95 @@ impl Storage {
         .await?
     }
 
+    /// The address indices of this wallet's full viewing key that have received notes, in
+    /// ascending order.
+    ///
+    /// These are the [`AddressIndex::account`] numbers within the one BIP44 account this database
+    /// tracks, not BIP44 accounts.
+    pub async fn used_address_indices(&self) -> anyhow::Result<Vec<u32>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut accounts = BTreeSet::new();
+            for index in pool
+                .get()?
+                .prepare_cached("SELECT DISTINCT address_index FROM spendable_notes")?
+                .query_map([], |row| row.get::<_, Vec<u8>>("address_index"))?
+            {
+                accounts.insert(AddressIndex::try_from(index?.as_slice())?.account);
+            }
+
+            anyhow::Ok(accounts.into_iter().collect())
+        })
+        .await?
+    }
+
+    /// Record the results of an account discovery scan, replacing any previous results.
+    pub async fn record_discovered_accounts(
+        &self,
+        accounts: Vec<DiscoveredAccount>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut conn = pool.get()?;
+            let tx = conn.transaction()?;
+
+            tx.execute("DELETE FROM discovered_accounts", ())?;
+            for account in accounts {
+                for address_index in account.address_indices {
+                    tx.execute(
+                        "INSERT INTO discovered_accounts (bip44_account, address_index) VALUES (?1, ?2)",
+                        (account.bip44_account, address_index),
+                    )?;
+                }
+            }
+
+            tx.commit()?;
+            anyhow::Ok(())
+        })
+        .await?
+    }
+
+    /// The accounts found by the last account discovery scan, if one was run.
+    pub async fn discovered_accounts(&self) -> anyhow::Result<Vec<DiscoveredAccount>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut accounts: BTreeMap<u32, Vec<u32>> = BTreeMap::new();
+            for row in pool
+                .get()?
+                .prepare_cached(
+                    "SELECT bip44_account, address_index FROM discovered_accounts
+                     ORDER BY bip44_account, address_index",
+                )?
+                .query_map([], |row| {
+                    Ok((
+                        row.get::<_, u32>("bip44_account")?,
+                        row.get::<_, u32>("address_index")?,
+                    ))
+                })?
+            {
+                let (bip44_account, address_index) = row?;
+                accounts
+                    .entry(bip44_account)
+                    .or_default()
+                    .push(address_index);
+            }
+
+            anyhow::Ok(
+                accounts
+                    .into_iter()
+                    .map(|(bip44_account, address_indices)| DiscoveredAccount {
+                        bip44_account,
+                        address_indices,
+                    })
+                    .collect(),
+            )
+        })
+        .await?
+    }
+
     pub async fn state_commitment_tree(&self) -> anyhow::Result<tct::Tree> {
         let pool = self.pool.clone();
         spawn_blocking(move || {
@@ -1193,14 +1291,15 @@ impl Storage {
 
         let position_state = position.state.to_string();
         let trading_pair = position.phi.pair.to_string();
+        let initial_reserves = position.reserves.encode_to_vec();
 
         let pool = self.pool.clone();
 
         spawn_blocking(move || {
             pool.get()?
                 .execute(
-                    "INSERT OR REPLACE INTO positions (position_id, position_state, trading_pair) VALUES (?1, ?2, ?3)",
-                    (position_id, position_state, trading_pair),
+                    "INSERT OR REPLACE INTO positions (position_id, position_state, trading_pair, initial_reserves) VALUES (?1, ?2, ?3, ?4)",
+                    (position_id, position_state, trading_pair, initial_reserves),
                 )
                 .map_err(anyhow::Error::from)
         })
@@ -1746,6 +1845,78 @@ impl Storage {
         .await?
     }
 
+    /// Returns the reserves an owned position was opened with, if it is known.
+    pub async fn position_initial_reserves(
+        &self,
+        position_id: position::Id,
+    ) -> anyhow::Result<Option<Reserves>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached("SELECT initial_reserves FROM positions WHERE position_id = ?1")?
+                .query_and_then([position_id.0.to_vec()], |row| {
+                    let initial_reserves: Option<Vec<u8>> = row.get("initial_reserves")?;
+                    initial_reserves
+                        .map(|bytes| Reserves::decode(bytes.as_slice()))
+                        .transpose()
+                })?
+                .next()
+                .transpose()
+                .map(Option::flatten)
+        })
+        .await?
+    }
+
+    /// Returns the cached activity of a position, in order of height.
+    pub async fn position_activity(
+        &self,
+        position_id: position::Id,
+    ) -> anyhow::Result<Vec<PositionActivity>> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            pool.get()?
+                .prepare_cached(
+                    "SELECT activity FROM position_activity WHERE position_id = ?1 ORDER BY height ASC",
+                )?
+                .query_and_then([position_id.0.to_vec()], |row| {
+                    let activity: Vec<u8> = row.get("activity")?;
+                    PositionActivity::decode(activity.as_slice())
+                })?
+                .collect()
+        })
+        .await?
+    }
+
+    /// Caches the activity of positions.
+    pub async fn record_position_activity(
+        &self,
+        activity: Vec<PositionActivity>,
+    ) -> anyhow::Result<()> {
+        let pool = self.pool.clone();
+
+        spawn_blocking(move || {
+            let mut lock = pool.get()?;
+            let tx = lock.transaction()?;
+            for activity in activity {
+                tx.execute(
+                    "INSERT OR REPLACE INTO position_activity (position_id, height, activity) VALUES (?1, ?2, ?3)",
+                    (
+                        activity.position_id.0.to_vec(),
+                        activity.height as i64,
+                        activity.encode_to_vec(),
+                    ),
+                )?;
+            }
+            tx.commit()?;
+            Ok::<(), anyhow::Error>(())
+        })
+        .await??;
+
+        Ok(())
+    }
+
     pub async fn notes_by_sender(
         &self,
         return_address: &Address,
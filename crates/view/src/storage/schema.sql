@@ -129,7 +129,22 @@ CREATE TABLE positions (
      position_id            BLOB PRIMARY KEY NOT NULL,
      position_state         TEXT NOT NULL,
      trading_pair           TEXT NOT NULL,
-     account                BIGINT
+     account                BIGINT,
+     -- the reserves the position was opened with, if the position was
+     -- opened by a transaction the wallet scanned. Adding this column changed
+     -- the schema hash, so older view databases must be reset and resynced
+     -- (`pcli view reset`) to fill it in.
+     initial_reserves       BLOB
+);
+
+-- This table caches the per-block activity of the user's own positions,
+-- as reported by the DEX, so that only new activity has to be fetched, and
+-- so that activity the full node has since pruned is kept.
+CREATE TABLE position_activity (
+     position_id            BLOB NOT NULL,
+     height                 BIGINT NOT NULL,
+     activity               BLOB NOT NULL,
+     PRIMARY KEY (position_id, height)
 );
 
 -- This table records the user's own auction state, using the
@@ -147,3 +162,11 @@ CREATE TABLE epochs (
     root BLOB,
     start_height BIGINT
 );
+
+-- Accounts derived from the same seed phrase that an account discovery scan
+-- found to have history, along with the address indices used within each.
+CREATE TABLE discovered_accounts (
+    bip44_account   BIGINT NOT NULL,
+    address_index   BIGINT NOT NULL,
+    PRIMARY KEY (bip44_account, address_index)
+);
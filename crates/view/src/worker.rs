@@ -365,6 +365,43 @@ impl Worker {
                                     .record_auction_with_state(auction_id, withdraw_da.seq)
                                     .await?;
                             }
+                            penumbra_sdk_transaction::Action::ActionTwapAuctionSchedule(
+                                schedule_ta,
+                            ) => {
+                                let auction_id = schedule_ta.description.id();
+                                let auction_nft_opened = AuctionNft::new(auction_id, 0);
+
+                                self.storage
+                                    .record_asset(auction_nft_opened.metadata)
+                                    .await?;
+                                self.storage
+                                    .record_auction_with_state(auction_id, 0u64)
+                                    .await?;
+                            }
+                            penumbra_sdk_transaction::Action::ActionTwapAuctionEnd(end_ta) => {
+                                let auction_nft_closed = AuctionNft::new(end_ta.auction_id, 1);
+
+                                self.storage
+                                    .record_asset(auction_nft_closed.metadata)
+                                    .await?;
+                                self.storage
+                                    .record_auction_with_state(end_ta.auction_id, 1)
+                                    .await?;
+                            }
+                            penumbra_sdk_transaction::Action::ActionTwapAuctionWithdraw(
+                                withdraw_ta,
+                            ) => {
+                                let auction_id = withdraw_ta.auction_id;
+                                let auction_nft_withdrawn =
+                                    AuctionNft::new(auction_id, withdraw_ta.seq);
+
+                                self.storage
+                                    .record_asset(auction_nft_withdrawn.metadata)
+                                    .await?;
+                                self.storage
+                                    .record_auction_with_state(auction_id, withdraw_ta.seq)
+                                    .await?;
+                            }
                             _ => (),
                         };
                     }
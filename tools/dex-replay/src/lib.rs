@@ -0,0 +1,255 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context as _, Result};
+use clap::Parser;
+use cnidarium::Storage;
+use penumbra_sdk_app::SUBSTORE_PREFIXES;
+use penumbra_sdk_asset::{asset, Value};
+use penumbra_sdk_dex::{
+    component::replay::{Comparison, Snapshot},
+    DexParameters,
+};
+use penumbra_sdk_num::Amount;
+
+#[derive(Clone, Debug, Parser)]
+pub enum Command {
+    /// Export a snapshot of the DEX going into a block's batch execution.
+    ///
+    /// Node storage only serves its latest state, so this reads two copies of
+    /// it: one as of the block to snapshot, and one exported with `pd export`
+    /// as of the block before it. The node should be stopped while either is
+    /// read.
+    ///
+    /// Swaps are recovered from the block's batch swap outputs, so each pair's
+    /// swaps are exported as a single aggregate swap. Batch execution only
+    /// sees each pair's aggregate flow, so this doesn't change the replay, but
+    /// the comparison reports outputs per pair rather than per swap.
+    Export {
+        /// The home directory of the full node, or of a `pd export` of it, as of
+        /// the block to snapshot.
+        #[clap(long, env = "PENUMBRA_PD_HOME")]
+        home: PathBuf,
+        /// The directory of a `pd export` of the full node's storage, as of the
+        /// block before the one to snapshot.
+        #[clap(long)]
+        prev_home: PathBuf,
+        /// The file to write the snapshot to.
+        #[clap(long, short)]
+        output: PathBuf,
+    },
+    /// Replay a snapshot under its own parameters and under alternative ones,
+    /// and report how the outputs of each swap and the reserves of each LP differ.
+    Compare {
+        /// The file to read the snapshot from.
+        #[clap(long, short)]
+        snapshot: PathBuf,
+        /// Read the alternative parameters from a JSON file, rather than
+        /// starting from the snapshot's parameters.
+        #[clap(long)]
+        params: Option<PathBuf>,
+        /// Override the maximum number of hops in a route.
+        #[clap(long)]
+        max_hops: Option<u32>,
+        /// Override the fixed candidate assets used for routing.
+        #[clap(long, use_value_delimiter = true)]
+        fixed_candidates: Option<Vec<asset::Id>>,
+        /// Override the maximum number of executions per batch.
+        #[clap(long)]
+        max_execution_budget: Option<u32>,
+        /// Override the maximum number of paths a batch swap is split across.
+        #[clap(long)]
+        max_split_paths: Option<u32>,
+        /// Override whether paths are ranked by their composed price.
+        #[clap(long)]
+        composed_path_prices: Option<bool>,
+    },
+}
+
+/// This struct represents the command-line options
+#[derive(Clone, Debug, Parser)]
+#[clap(
+    name = "dex-replay",
+    about = "Replays DEX batch execution offline under alternative routing parameters",
+    version
+)]
+pub struct Options {
+    #[clap(subcommand)]
+    pub command: Command,
+}
+
+impl Options {
+    pub async fn run(self) -> Result<()> {
+        match self.command {
+            Command::Export {
+                home,
+                prev_home,
+                output,
+            } => {
+                let snapshot = export(home, prev_home).await?;
+                let file = File::create(&output)
+                    .with_context(|| format!("failed to create {}", output.display()))?;
+                serde_json::to_writer_pretty(file, &snapshot)?;
+                println!(
+                    "exported {} positions and {} swaps at height {} to {}",
+                    snapshot.positions.len(),
+                    snapshot.swaps.len(),
+                    snapshot.height,
+                    output.display()
+                );
+            }
+            Command::Compare {
+                snapshot,
+                params,
+                max_hops,
+                fixed_candidates,
+                max_execution_budget,
+                max_split_paths,
+                composed_path_prices,
+            } => {
+                let file = File::open(&snapshot)
+                    .with_context(|| format!("failed to open {}", snapshot.display()))?;
+                let snapshot: Snapshot = serde_json::from_reader(file)?;
+
+                let mut candidate = match params {
+                    Some(path) => serde_json::from_reader(
+                        File::open(&path)
+                            .with_context(|| format!("failed to open {}", path.display()))?,
+                    )?,
+                    None => snapshot.params.clone(),
+                };
+                if let Some(max_hops) = max_hops {
+                    candidate.max_hops = max_hops;
+                }
+                if let Some(fixed_candidates) = fixed_candidates {
+                    candidate.fixed_candidates = fixed_candidates;
+                }
+                if let Some(max_execution_budget) = max_execution_budget {
+                    candidate.max_execution_budget = max_execution_budget;
+                }
+                if let Some(max_split_paths) = max_split_paths {
+                    candidate.max_split_paths = max_split_paths;
+                }
+                if let Some(composed_path_prices) = composed_path_prices {
+                    candidate.composed_path_prices = composed_path_prices;
+                }
+
+                compare(&snapshot, candidate).await?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Exports a snapshot of the latest block in the node storage in `home`,
+/// reading the state before it from the node storage in `prev_home`.
+///
+/// Storage only keeps a snapshot of its latest version once loaded, so the two
+/// states have to come from separate copies of the node storage.
+async fn export(home: PathBuf, prev_home: PathBuf) -> Result<Snapshot> {
+    let storage = load_storage(&home).await?;
+    let prev_storage = load_storage(&prev_home).await?;
+    let height = storage.latest_version();
+    let prev_height = prev_storage.latest_version();
+    anyhow::ensure!(
+        prev_height.checked_add(1) == Some(height),
+        "the storage in {} is at height {prev_height}, rather than the one before height {height} in {}",
+        prev_home.display(),
+        home.display(),
+    );
+
+    let snapshot = Snapshot::export(
+        &prev_storage.latest_snapshot(),
+        &storage.latest_snapshot(),
+        height,
+    )
+    .await;
+    storage.release().await;
+    prev_storage.release().await;
+    snapshot
+}
+
+/// Loads the node storage in the `rocksdb` directory of `home`.
+async fn load_storage(home: &Path) -> Result<Storage> {
+    let path = home.join("rocksdb");
+    Storage::load(path.clone(), SUBSTORE_PREFIXES.to_vec())
+        .await
+        .with_context(|| format!("failed to load storage from {}", path.display()))
+}
+
+/// Replays `snapshot` under its own parameters and the `candidate` ones, and
+/// prints the differences.
+async fn compare(snapshot: &Snapshot, candidate: DexParameters) -> Result<()> {
+    let baseline_outcome = snapshot.replay(snapshot.params.clone()).await?;
+    let candidate_outcome = snapshot.replay(candidate).await?;
+    let comparison = Comparison::new(snapshot, &baseline_outcome, &candidate_outcome);
+
+    let cache = asset::Cache::with_known_assets();
+    let denom = |id: &asset::Id| {
+        cache
+            .get(id)
+            .map(|metadata| metadata.to_string())
+            .unwrap_or_else(|| id.to_string())
+    };
+    let value = |asset_id: asset::Id, amount: Amount| format!("{amount}{}", denom(&asset_id));
+    let signed = |baseline: Amount, candidate: Amount| {
+        if candidate >= baseline {
+            format!("+{}", candidate - baseline)
+        } else {
+            format!("-{}", baseline - candidate)
+        }
+    };
+
+    // Snapshots hold one aggregate swap per trading pair, see `Command::Export`.
+    println!("batch swaps by trading pair at height {}:", snapshot.height);
+    for swap in &comparison.swaps {
+        let pair = swap.swap.trading_pair;
+        let (asset_1, asset_2) = (pair.asset_1(), pair.asset_2());
+        println!(
+            "  {} + {} => baseline {} + {}, candidate {} + {} ({} / {})",
+            value(asset_1, swap.swap.delta_1),
+            value(asset_2, swap.swap.delta_2),
+            value(asset_1, swap.baseline.0),
+            value(asset_2, swap.baseline.1),
+            value(asset_1, swap.candidate.0),
+            value(asset_2, swap.candidate.1),
+            signed(swap.baseline.0, swap.candidate.0),
+            signed(swap.baseline.1, swap.candidate.1),
+        );
+    }
+
+    println!("positions with different reserves:");
+    if comparison.positions.is_empty() {
+        println!("  none");
+    }
+    for position in &comparison.positions {
+        let (asset_1, asset_2) = (
+            position.trading_pair.asset_1(),
+            position.trading_pair.asset_2(),
+        );
+        println!(
+            "  {}: initial {} + {}, baseline {} + {}, candidate {} + {}",
+            position.id,
+            value(asset_1, position.initial.r1),
+            value(asset_2, position.initial.r2),
+            value(asset_1, position.baseline.r1),
+            value(asset_2, position.baseline.r2),
+            value(asset_1, position.candidate.r1),
+            value(asset_2, position.candidate.r2),
+        );
+    }
+
+    let surplus = |surplus: Option<Value>| match surplus {
+        Some(Value { asset_id, amount }) => value(asset_id, amount),
+        None => "none".to_string(),
+    };
+    println!(
+        "arbitrage surplus: baseline {}, candidate {}",
+        surplus(comparison.baseline_arb_surplus),
+        surplus(comparison.candidate_arb_surplus),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,13 @@
+use clap::Parser;
+use penumbra_sdk_dex_replay::Options;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_default_env())
+        .with_writer(std::io::stderr)
+        .init();
+
+    Options::parse().run().await
+}